@@ -1,25 +1,41 @@
 mod ui;
 
-use std::{env, sync::mpsc, thread};
+use std::{
+    env,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    thread,
+};
 
 use anyhow::Result;
-use clap::{Parser, ValueEnum};
+use async_trait::async_trait;
+use clap::{Parser, Subcommand, ValueEnum};
 use futures::StreamExt;
-use minerva_controller::{AdbController, DeviceController, MockController};
-use minerva_engine::RuleBasedEngine;
-use minerva_network::{LocalServer, RealtimeServer};
-use minerva_ops::TelemetryStore;
-use minerva_orchestrator::{MatchRunner, Orchestrator};
+use minerva_controller::{AdbController, DeviceController, InputAction, MockController};
+use minerva_engine::{GameEngine, RuleBasedEngine};
+use minerva_network::{EventSource, RealtimeServer, StatusHandle, StatusServer, WebSocketServer};
+use minerva_ops::{
+    export_csv, prune_telemetry, resolve_log_path, write_match_report, GameRecord, HealthSampler,
+    ProcessHealth, RetentionPolicy, RetentionSweeper, TelemetryStore, GAME_RECORD_FILE,
+};
+use minerva_orchestrator::{MatchRunner, Orchestrator, OrchestratorControl, TracingObserver};
 use minerva_types::{
+    board::{BoardState, Square},
     config::{
-        EmulatorConfig, EngineConfig, MinervaConfig, NetworkConfig, OpsConfig, OrchestratorConfig,
-        VisionConfig,
+        EmulatorConfig, EngineConfig, LogFormat, MinervaConfig, NetworkConfig, OpsConfig,
+        OrchestratorConfig, OrchestratorMode, PositionalWeights, RecognizerBackend, RetryPolicy,
+        ScreencapFormat, TimingConfig, VisionConfig, MAX_SKILL_LEVEL,
     },
+    events::{EventFilter, EventKind, SystemEvent},
+    game::{GameSnapshot, TurnContext},
+    telemetry::MatchTelemetry,
     time_control::TimeControl,
-    ui::FormationPreset,
+    ui::{BoardGeometry, DialogButtons, FormationPreset},
 };
-use minerva_vision::TemplateMatchingRecognizer;
+use minerva_vision::{build_recognizer, BoardRecognizer};
 use ui::{run as run_ui, UiMessage};
+use uuid::Uuid;
 
 #[derive(Debug, Parser)]
 #[command(name = "minerva-cli", about = "Minerva 오케스트레이션 CLI", version)]
@@ -28,17 +44,113 @@ struct CliArgs {
     #[arg(value_name = "CONFIG")]
     config: Option<String>,
 
-    /// 대국 턴 반복 횟수 (기본 1)
+    /// 턴당 최대 시도 횟수 (기본 1)
     #[arg(long, value_name = "N")]
     max_retries: Option<u8>,
 
+    /// 대국을 중단할 최대 수 (반수, 기본 400)
+    #[arg(long, value_name = "N")]
+    max_plies: Option<u32>,
+
     /// 시작 진형 (MasangMasang | SangMasangMa | MasangSangMa | SangMaMaSang)
     #[arg(long, value_name = "PRESET")]
     formation: Option<String>,
 
+    /// 시작 국면 FEN (지정 시 시작/진형 선택 절차를 건너뜀)
+    #[arg(long, value_name = "FEN")]
+    start_fen: Option<String>,
+
+    /// 평가치 EMA 평활 계수 (0~1, 클수록 원래 값에 가깝게)
+    #[arg(long, value_name = "ALPHA", default_value_t = 0.3)]
+    eval_alpha: f32,
+
+    /// 턴마다 인식한 보드를 ASCII로 로그에 남김 (TUI 없이 실행할 때 유용)
+    #[arg(long)]
+    log_board: bool,
+
+    /// 관전 모드: 기기에 입력하지 않고 양측 수 인식과 엔진 평가만 발행
+    #[arg(long)]
+    spectate: bool,
+
+    /// 드라이런: 인식과 엔진은 그대로 돌리되 탭하지 않고 보낼 좌표만 Ops 이벤트로 출력
+    #[arg(long)]
+    dry_run: bool,
+
     /// 컨트롤러 모드 (adb | mock)
     #[arg(long, value_enum, default_value_t = ControllerKind::Adb)]
     controller: ControllerKind,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 기록된 이벤트 로그를 조회합니다
+    Events {
+        /// 이벤트 JSONL 파일 (기본: <telemetry_dir> 아래 모든 events.jsonl)
+        #[arg(long, value_name = "PATH")]
+        file: Option<String>,
+
+        /// 이벤트 종류 (Lifecycle | Board | Engine | Telemetry | Network | Ops), 반복 가능
+        #[arg(long, value_name = "KIND")]
+        kind: Vec<EventKind>,
+
+        /// 최근 N개만 출력
+        #[arg(long, value_name = "N")]
+        last: Option<usize>,
+
+        /// 메시지/세부 정보에 포함된 문자열
+        #[arg(long, value_name = "TEXT")]
+        grep: Option<String>,
+
+        /// 이 매치의 이벤트만 (match_id)
+        #[arg(long = "match", value_name = "UUID")]
+        match_id: Option<Uuid>,
+
+        /// 이 세션의 이벤트만 (session_id)
+        #[arg(long = "session", value_name = "UUID")]
+        session_id: Option<Uuid>,
+    },
+    /// match.json을 엔진/지연 시간 CSV(engine_metrics.csv, latency.csv)로 내보냅니다
+    ExportCsv {
+        /// 매치 텔레메트리 파일 (match.json)
+        #[arg(value_name = "MATCH_JSON")]
+        match_file: String,
+
+        /// 출력 디렉터리 (기본: match.json과 같은 디렉터리)
+        #[arg(long, value_name = "DIR")]
+        out: Option<String>,
+    },
+    /// match.json(과 같은 디렉터리의 record.json)으로 report.md를 다시 생성합니다
+    Report {
+        /// 매치 텔레메트리 파일 (match.json)
+        #[arg(value_name = "MATCH_JSON")]
+        match_file: String,
+    },
+    /// FEN 국면을 설정된 엔진으로 분석합니다 (EngineDecision 이벤트의 fen 재현용)
+    Analyze {
+        /// 분석할 국면 (FEN, 둘 차례 포함)
+        #[arg(value_name = "FEN")]
+        fen: String,
+    },
+    /// 보드 모서리를 탭하며 전역 탭 오프셋(dx, dy)을 보정합니다
+    Calibrate,
+    /// 텔레메트리 디렉터리 관리
+    Telemetry {
+        #[command(subcommand)]
+        action: TelemetryCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum TelemetryCommand {
+    /// telemetry_max_mb / telemetry_max_age_days 기준으로 오래된 매치 디렉터리를 삭제합니다
+    Prune {
+        /// 삭제하지 않고 대상만 출력
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -47,13 +159,42 @@ enum ControllerKind {
     Mock,
 }
 
+/// Serves the status server's `/events/query` from the session's telemetry.
+struct TelemetryEvents(TelemetryStore);
+
+#[async_trait]
+impl EventSource for TelemetryEvents {
+    async fn query(&self, filter: EventFilter) -> Vec<SystemEvent> {
+        self.0.query(filter).await
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = CliArgs::parse();
-    let mut config = load_config(args.config.as_deref());
+    let config_path = resolve_config_path(args.config.as_deref());
+    let mut config = load_config(&config_path);
+    if let Some(command) = args.command {
+        return run_command(command, args.controller, &config_path, config).await;
+    }
     if let Some(max_retries) = args.max_retries {
         config.orchestrator.max_retries = max_retries;
     }
+    if let Some(max_plies) = args.max_plies {
+        config.orchestrator.max_plies = max_plies;
+    }
+    if args.log_board {
+        config.orchestrator.log_board = true;
+    }
+    if args.spectate {
+        config.orchestrator.mode = OrchestratorMode::Spectate;
+    }
+    if args.dry_run {
+        config.orchestrator.dry_run = true;
+    }
+    if let Some(start_fen) = args.start_fen {
+        config.orchestrator.start_fen = Some(start_fen);
+    }
     if let Some(formation) = args.formation {
         match formation.parse::<FormationPreset>() {
             Ok(preset) => config.orchestrator.formation = preset,
@@ -66,29 +207,235 @@ async fn main() -> Result<()> {
         eprintln!("설정 값이 올바르지 않아 기본값으로 되돌립니다: {err}");
         config = default_config();
     }
-    let config_summary = format!(
-        "턴 {} | 진형 {}",
-        config.orchestrator.max_retries, config.orchestrator.formation
+    let mut config_summary = format!(
+        "최대 {}수 | 진형 {}",
+        config.orchestrator.max_plies, config.orchestrator.formation
     );
+    if config.orchestrator.mode == OrchestratorMode::Spectate {
+        config_summary.push_str(" | 관전");
+    }
     match args.controller {
         ControllerKind::Adb => {
             let controller = AdbController::new(config.emulator.clone())?;
-            run_application(controller, config, config_summary).await
+            run_application(controller, config, config_summary, args.eval_alpha).await
         }
         ControllerKind::Mock => {
             let controller = MockController::new(config.emulator.clone());
-            run_application(controller, config, config_summary).await
+            run_application(controller, config, config_summary, args.eval_alpha).await
         }
     }
 }
 
-fn load_config(cli_path: Option<&str>) -> MinervaConfig {
-    let path = cli_path
+async fn run_command(
+    command: Command,
+    controller: ControllerKind,
+    config_path: &str,
+    config: MinervaConfig,
+) -> Result<()> {
+    match command {
+        Command::Events {
+            file,
+            kind,
+            last,
+            grep,
+            match_id,
+            session_id,
+        } => {
+            let store = match file {
+                Some(path) => TelemetryStore::from_jsonl(path)?,
+                None => TelemetryStore::from_dir(&config.ops.telemetry_dir)?,
+            };
+            let filter = EventFilter {
+                kinds: kind,
+                text: grep,
+                match_id,
+                session_id,
+                limit: last,
+                ..EventFilter::default()
+            };
+            for event in store.query(filter).await {
+                println!("{}", serde_json::to_string(&event)?);
+            }
+            Ok(())
+        }
+        Command::ExportCsv { match_file, out } => {
+            let raw = std::fs::read_to_string(&match_file)?;
+            let telemetry: MatchTelemetry = serde_json::from_str(&raw)?;
+            let out_dir = match out {
+                Some(dir) => PathBuf::from(dir),
+                None => Path::new(&match_file)
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_default(),
+            };
+            let export = export_csv(&telemetry, &out_dir)?;
+            println!("{}", export.engine_metrics.display());
+            println!("{}", export.latency.display());
+            Ok(())
+        }
+        Command::Report { match_file } => {
+            let raw = std::fs::read_to_string(&match_file)?;
+            let telemetry: MatchTelemetry = serde_json::from_str(&raw)?;
+            let dir = Path::new(&match_file)
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            let record_path = dir.join(GAME_RECORD_FILE);
+            let record = match std::fs::read_to_string(&record_path) {
+                Ok(raw) => serde_json::from_str(&raw)?,
+                Err(err) => {
+                    eprintln!(
+                        "기보 파일을 읽지 못해 수순 없이 생성합니다({}): {err}",
+                        record_path.display()
+                    );
+                    GameRecord::new("Minerva", "Opponent")
+                }
+            };
+            let path = write_match_report(&telemetry, &record, &dir)?;
+            println!("{}", path.display());
+            Ok(())
+        }
+        Command::Telemetry {
+            action: TelemetryCommand::Prune { dry_run },
+        } => {
+            let policy = RetentionPolicy::from_config(&config.ops);
+            if policy.is_unbounded() {
+                println!("telemetry_max_mb / telemetry_max_age_days 가 설정되지 않아 정리할 대상이 없습니다.");
+                return Ok(());
+            }
+            let report = prune_telemetry(
+                Path::new(&config.ops.telemetry_dir),
+                &policy,
+                None,
+                std::time::SystemTime::now(),
+                dry_run,
+            )?;
+            for path in &report.removed {
+                println!("{}", path.display());
+            }
+            println!("{}", report.summary());
+            Ok(())
+        }
+        Command::Analyze { fen } => {
+            let board = BoardState::from_fen(&fen).map_err(anyhow::Error::msg)?;
+            let side = board.side_to_move;
+            let engine = RuleBasedEngine::new()
+                .with_weights(config.engine.positional)
+                .with_skill(config.engine.skill_level, config.engine.seed);
+            let ctx = TurnContext::new(
+                GameSnapshot {
+                    board,
+                    ..GameSnapshot::default()
+                },
+                side,
+            );
+            let decision = engine.evaluate_position(&ctx).await?;
+            match (&decision.best_move, decision.result) {
+                (Some(mv), _) => println!("{side:?} 최선수 {mv} (평가 {:.2})", decision.score),
+                (None, Some(result)) => println!("게임 종료: {result}"),
+                (None, None) => println!("둘 수 있는 수가 없습니다"),
+            }
+            for candidate in &decision.candidates {
+                println!("  {} {:.2}", candidate.mv, candidate.score);
+            }
+            Ok(())
+        }
+        Command::Calibrate => match controller {
+            ControllerKind::Adb => {
+                let controller = AdbController::new(config.emulator.clone())?;
+                run_calibration(controller, config, config_path).await
+            }
+            ControllerKind::Mock => {
+                let controller = MockController::new(config.emulator.clone());
+                run_calibration(controller, config, config_path).await
+            }
+        },
+    }
+}
+
+const CALIBRATION_SQUARES: [Square; 4] = [
+    Square { file: 0, rank: 0 },
+    Square { file: 8, rank: 0 },
+    Square { file: 0, rank: 9 },
+    Square { file: 8, rank: 9 },
+];
+
+async fn run_calibration<C>(
+    mut controller: C,
+    mut config: MinervaConfig,
+    config_path: &str,
+) -> Result<()>
+where
+    C: DeviceController,
+{
+    controller.connect().await?;
+    let mut offset = config.emulator.tap_offset;
+    println!("보드 모서리 4곳을 탭합니다. 현재 오프셋: {:?}", offset);
+    println!(
+        "입력: '<dx> <dy>' 만큼 이동 | 빈 줄 다시 탭 | save 저장 후 종료 | quit 저장 없이 종료"
+    );
+
+    let stdin = std::io::stdin();
+    loop {
+        for square in CALIBRATION_SQUARES {
+            let Some(point) = config.emulator.geometry.square_to_point(square) else {
+                continue;
+            };
+            let target = point.offset(offset);
+            println!("  square {square} -> ({}, {})", target.x, target.y);
+            controller
+                .inject_actions(vec![InputAction::Tap {
+                    x: target.x,
+                    y: target.y,
+                }])
+                .await?;
+        }
+
+        print!("오프셋 {:?}> ", offset);
+        std::io::stdout().flush()?;
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        match line.trim() {
+            "" => continue,
+            "quit" | "q" => return Ok(()),
+            "save" | "s" => {
+                config.emulator.tap_offset = offset;
+                config.save_to_file(config_path)?;
+                println!(
+                    "오프셋 {:?}을(를) '{}'에 저장했습니다.",
+                    offset, config_path
+                );
+                return Ok(());
+            }
+            input => match parse_nudge(input) {
+                Some((dx, dy)) => offset = (offset.0 + dx, offset.1 + dy),
+                None => println!("형식: '<dx> <dy>' (예: '0 -3')"),
+            },
+        }
+    }
+}
+
+fn parse_nudge(input: &str) -> Option<(i32, i32)> {
+    let mut parts = input.split_whitespace();
+    let dx = parts.next()?.parse().ok()?;
+    let dy = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((dx, dy))
+}
+
+fn resolve_config_path(cli_path: Option<&str>) -> String {
+    cli_path
         .map(|p| p.to_string())
         .or_else(|| env::var("MINERVA_CONFIG").ok())
-        .unwrap_or_else(|| "configs/dev.toml".into());
+        .unwrap_or_else(|| "configs/dev.toml".into())
+}
 
-    match MinervaConfig::from_file(&path) {
+fn load_config(path: &str) -> MinervaConfig {
+    match MinervaConfig::from_file(path) {
         Ok(cfg) => {
             if let Err(err) = cfg.validate() {
                 eprintln!(
@@ -117,32 +464,86 @@ fn default_config() -> MinervaConfig {
             socket: "127.0.0.1:5555".into(),
             fixed_resolution: Some((1080, 1920)),
             adb_path: None,
+            tap_offset: (0, 0),
+            geometry: BoardGeometry::default(),
+            screencap_format: ScreencapFormat::Png,
+            retry: RetryPolicy::default(),
+            timing: TimingConfig::default(),
+            batch_inputs: true,
+            tap_jitter_px: None,
         },
         vision: VisionConfig {
+            backend: RecognizerBackend::TemplateMatching,
             template_dir: "assets/templates".into(),
             confidence_threshold: 0.95,
             refresh_interval_ms: 500,
             capture_dir: Some("captures".into()),
             tile_capture_dir: Some("captures/tiles".into()),
+            capture_every_n: 1,
+            realign_low_confidence_squares: 9,
+            highlight_color: None,
+            clock_regions: None,
+            stable_captures: 1,
+            stable_capture_delay_ms: 80,
+            max_frame_age_ms: 2_000,
+            capture_region_margin: None,
+            popups: Vec::new(),
         },
         engine: EngineConfig {
             threads: 1,
             max_depth: 1,
             nnue_path: None,
+            positional: PositionalWeights::default(),
+            skill_level: MAX_SKILL_LEVEL,
+            seed: 0,
+            eval_log: None,
         },
         network: NetworkConfig {
             bind_addr: "127.0.0.1".into(),
             websocket_port: 3000,
             auth_token: None,
+            status_port: None,
         },
         ops: OpsConfig {
             log_level: "info".into(),
             telemetry_dir: "telemetry".into(),
+            log_file: None,
+            log_format: LogFormat::Text,
+            log_stderr: true,
+            max_events_in_memory: 5_000,
+            health_interval_secs: 30,
+            telemetry_max_mb: None,
+            telemetry_max_age_days: None,
         },
         orchestrator: OrchestratorConfig {
+            mode: OrchestratorMode::Play,
             time_control: TimeControl::blitz(),
             max_retries: 1,
             formation: FormationPreset::MasangSangMa,
+            opponent_formation: None,
+            start_fen: None,
+            opponent_timeout_secs: 300,
+            draw_after_halfmoves: None,
+            max_plies: 400,
+            skip_unchanged_boards: false,
+            log_board: false,
+            our_side: None,
+            low_time_warning_ms: None,
+            turn_deadline_ms: 30_000,
+            resign_threshold: None,
+            resign_persistence: 3,
+            resign_min_plies: 20,
+            max_move_rejections: 3,
+            pipeline: false,
+            poll_schedule: None,
+            accept_draw_below: -2.0,
+            dialog_buttons: DialogButtons::default(),
+            min_move_delay_ms: 0,
+            max_move_delay_ms: None,
+            resume_max_age_secs: 600,
+            dry_run: false,
+            max_anomaly_score: None,
+            back_on_anomaly_abort: false,
         },
     };
     debug_assert!(config.validate().is_ok());
@@ -153,14 +554,35 @@ async fn run_application<C>(
     controller: C,
     config: MinervaConfig,
     config_summary: String,
+    eval_alpha: f32,
 ) -> Result<()>
 where
     C: DeviceController + Send + Sync + 'static,
 {
-    let recognizer = TemplateMatchingRecognizer::new(config.vision.clone());
-    let engine = RuleBasedEngine::new();
-    let network = LocalServer::new(64);
-    let telemetry = TelemetryStore::new();
+    let recognizer = build_recognizer(&config.vision, &config.emulator.geometry);
+    // Tracing is not set up until boot, so report setup problems directly.
+    if let Some(problem) = recognizer.setup_problem() {
+        eprintln!("{problem}");
+    }
+    let mut engine = RuleBasedEngine::new()
+        .with_weights(config.engine.positional)
+        .with_skill(config.engine.skill_level, config.engine.seed);
+    if let Some(eval_log) = &config.engine.eval_log {
+        engine = engine.with_eval_log(resolve_log_path(&config.ops, eval_log));
+    }
+    // `Orchestrator::boot` starts accepting clients.
+    let network = WebSocketServer::bind(&config.network, 64).await?;
+    let telemetry = TelemetryStore::from_config(&config.ops);
+    let status = StatusHandle::new();
+    let status_server = match config.network.status_port {
+        Some(port) => Some(
+            StatusServer::bind(&config.network.bind_addr, port, status.clone())
+                .await?
+                .with_events(Arc::new(TelemetryEvents(telemetry.clone())))
+                .spawn(),
+        ),
+        None => None,
+    };
 
     let (ui_tx, ui_rx) = mpsc::channel::<UiMessage>();
     let ui_forward_network = network.clone();
@@ -168,29 +590,84 @@ where
     let ui_forward_handle = tokio::spawn(async move {
         let mut stream = ui_forward_network.subscribe();
         while let Some(event) = stream.next().await {
-            if ui_forward_tx.send(UiMessage::Event(event)).is_err() {
+            if ui_forward_tx
+                .send(UiMessage::Event(Box::new(event)))
+                .is_err()
+            {
                 break;
             }
         }
     });
 
+    let (command_tx, command_rx) = tokio::sync::mpsc::unbounded_channel();
     let ui_thread = thread::spawn(move || {
-        if let Err(err) = run_ui(ui_rx, config_summary) {
+        if let Err(err) = run_ui(
+            ui_rx,
+            OrchestratorControl::new(command_tx),
+            config_summary,
+            eval_alpha,
+        ) {
             eprintln!("터미널 UI 오류: {err:?}");
         }
     });
 
-    let mut orchestrator = Orchestrator::new(
-        config.orchestrator.clone(),
-        controller,
-        recognizer,
-        engine,
-        network,
-        telemetry,
-    );
+    // Health samples and retention summaries reach the UI through the network.
+    let (background_tx, mut background_rx) = tokio::sync::mpsc::unbounded_channel();
+    let background_network = network.clone();
+    let background_forward = tokio::spawn(async move {
+        while let Some(event) = background_rx.recv().await {
+            let _ = background_network.publish(event).await;
+        }
+    });
+    let mut background_tasks = Vec::new();
+    if config.ops.health_interval_secs > 0 {
+        background_tasks.push(HealthSampler::new(telemetry.clone(), ProcessHealth).spawn(
+            std::time::Duration::from_secs(config.ops.health_interval_secs),
+            background_tx.clone(),
+        ));
+    }
+
+    let mut orchestrator = Orchestrator::builder()
+        .config(config.orchestrator.clone())
+        .controller(controller)
+        .recognizer(recognizer)
+        .engine(engine)
+        .network(network.clone())
+        .telemetry(telemetry.clone())
+        .commands(command_rx)
+        .status_handle(status)
+        .observer(Box::new(TracingObserver))
+        .build()?;
+
+    let retention = RetentionPolicy::from_config(&config.ops);
+    if !retention.is_unbounded() {
+        background_tasks.push(
+            RetentionSweeper::new(
+                telemetry.clone(),
+                retention,
+                Some(orchestrator.session_id()),
+            )
+            .spawn(background_tx.clone()),
+        );
+    }
+    drop(background_tx);
 
     orchestrator.boot(&config).await?;
     let run_result = orchestrator.run().await;
+    for task in background_tasks {
+        task.abort();
+    }
+    if let Some(server) = status_server {
+        server.abort();
+    }
+    let _ = background_forward.await;
+    // Clients get the Shutdown event and a close frame before the process exits.
+    if let Err(err) = network.shutdown().await {
+        eprintln!("WebSocket 서버 종료 실패: {err}");
+    }
+    if let Err(err) = telemetry.shutdown().await {
+        eprintln!("텔레메트리 기록 마무리 실패: {err}");
+    }
 
     let _ = ui_tx.send(UiMessage::Shutdown);
     drop(ui_tx);
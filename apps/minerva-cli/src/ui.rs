@@ -10,24 +10,83 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use minerva_types::events::{EventPayload, SystemEvent};
+use minerva_orchestrator::OrchestratorControl;
+use minerva_types::events::{EventPayload, Severity, SystemEvent, TelemetryEvent};
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Sparkline},
     Terminal,
 };
 
 const MAX_LOG_ENTRIES: usize = 120;
+const MAX_EVAL_HISTORY: usize = 120;
+/// Evals beyond this magnitude (e.g. mate scores) are clipped in the sparkline.
+const EVAL_PLOT_RANGE: f32 = 50.0;
+
+/// Raw engine evals plus an exponential moving average to steady the display.
+pub struct EvalTrack {
+    alpha: f32,
+    raw: Option<f32>,
+    smoothed: Option<f32>,
+    history: VecDeque<f32>,
+}
+
+impl EvalTrack {
+    /// `alpha` in (0, 1]: higher values follow the raw eval more closely.
+    pub fn new(alpha: f32) -> Self {
+        Self {
+            alpha: alpha.clamp(f32::EPSILON, 1.0),
+            raw: None,
+            smoothed: None,
+            history: VecDeque::with_capacity(MAX_EVAL_HISTORY),
+        }
+    }
+
+    pub fn push(&mut self, eval: f32) {
+        let smoothed = match self.smoothed {
+            Some(prev) => prev + self.alpha * (eval - prev),
+            None => eval,
+        };
+        self.raw = Some(eval);
+        self.smoothed = Some(smoothed);
+        if self.history.len() == MAX_EVAL_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(smoothed);
+    }
+
+    /// Smoothed history shifted into `0..=2 * EVAL_PLOT_RANGE` for plotting.
+    fn sparkline_data(&self) -> Vec<u64> {
+        self.history
+            .iter()
+            .map(|eval| (eval.clamp(-EVAL_PLOT_RANGE, EVAL_PLOT_RANGE) + EVAL_PLOT_RANGE) as u64)
+            .collect()
+    }
+
+    fn summary(&self) -> String {
+        match (self.raw, self.smoothed) {
+            (Some(raw), Some(smoothed)) => format!("raw {raw:+.1} / EMA {smoothed:+.1}"),
+            _ => "평가치 없음".to_string(),
+        }
+    }
+}
 
 pub enum UiMessage {
-    Event(SystemEvent),
+    Event(Box<SystemEvent>),
     Shutdown,
 }
 
-pub fn run(receiver: Receiver<UiMessage>, summary: String) -> Result<()> {
+/// `control` carries operator requests back to the match: `r` resigns, `p`
+/// pauses or resumes, and space plays one turn while paused.
+pub fn run(
+    receiver: Receiver<UiMessage>,
+    control: OrchestratorControl,
+    summary: String,
+    eval_alpha: f32,
+) -> Result<()> {
     enable_raw_mode()?;
     let mut stdout = std::io::stdout();
     execute!(stdout, EnterAlternateScreen)?;
@@ -35,7 +94,13 @@ pub fn run(receiver: Receiver<UiMessage>, summary: String) -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
     terminal.hide_cursor()?;
 
-    let res = run_loop(&mut terminal, receiver, summary.as_str());
+    let res = run_loop(
+        &mut terminal,
+        receiver,
+        &control,
+        summary.as_str(),
+        eval_alpha,
+    );
 
     terminal.show_cursor()?;
     disable_raw_mode()?;
@@ -46,11 +111,17 @@ pub fn run(receiver: Receiver<UiMessage>, summary: String) -> Result<()> {
 fn run_loop<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     receiver: Receiver<UiMessage>,
+    control: &OrchestratorControl,
     summary: &str,
+    eval_alpha: f32,
 ) -> Result<()> {
-    let mut logs: VecDeque<String> = VecDeque::with_capacity(MAX_LOG_ENTRIES);
+    let mut logs: VecDeque<(String, Style)> = VecDeque::with_capacity(MAX_LOG_ENTRIES);
+    let mut evals = EvalTrack::new(eval_alpha);
     let mut last_status = String::from("대기 중");
+    let mut state = String::from("-");
+    let mut memory = String::from("메모리 -");
     let mut should_close = false;
+    let mut paused = false;
 
     loop {
         let mut receiver_closed = false;
@@ -58,11 +129,24 @@ fn run_loop<B: ratatui::backend::Backend>(
             match receiver.try_recv() {
                 Ok(UiMessage::Event(event)) => {
                     last_status = summarize_status(&event);
+                    match &event.payload {
+                        EventPayload::Engine(engine) => {
+                            if let Some(score) = engine.score {
+                                evals.push(score);
+                            }
+                        }
+                        EventPayload::Telemetry(TelemetryEvent {
+                            health: Some(health),
+                            ..
+                        }) => memory = format_memory(health.rss_bytes),
+                        EventPayload::State(change) => state = change.to.to_string(),
+                        _ => {}
+                    }
                     let formatted = format_event(&event);
                     if logs.len() == MAX_LOG_ENTRIES {
                         logs.pop_front();
                     }
-                    logs.push_back(formatted);
+                    logs.push_back((formatted, event_style(&event)));
                 }
                 Ok(UiMessage::Shutdown) => {
                     should_close = true;
@@ -79,7 +163,14 @@ fn run_loop<B: ratatui::backend::Backend>(
         terminal.draw(|f| {
             let chunks = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+                .constraints(
+                    [
+                        Constraint::Length(3),
+                        Constraint::Length(4),
+                        Constraint::Min(0),
+                    ]
+                    .as_ref(),
+                )
                 .split(f.size());
 
             let header = Paragraph::new(Line::from(vec![
@@ -90,29 +181,50 @@ fn run_loop<B: ratatui::backend::Backend>(
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
+                Span::styled(format!("[{state}]"), Style::default().fg(Color::Green)),
+                Span::raw("  "),
                 Span::raw(last_status.clone()),
                 Span::raw("  "),
+                Span::raw(memory.clone()),
+                Span::raw("  "),
                 Span::styled("설정:", Style::default().fg(Color::Magenta)),
                 Span::raw(" "),
                 Span::raw(summary),
                 Span::raw("  "),
                 Span::styled("q", Style::default().fg(Color::Yellow)),
-                Span::raw(" 를 눌러 종료"),
+                Span::raw(" 종료  "),
+                Span::styled("r", Style::default().fg(Color::Yellow)),
+                Span::raw(" 기권"),
             ]))
             .block(Block::default().borders(Borders::ALL).title("요약"));
             f.render_widget(header, chunks[0]);
 
+            let metrics = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(32), Constraint::Min(0)].as_ref())
+                .split(chunks[1]);
+            let eval_summary = Paragraph::new(evals.summary())
+                .block(Block::default().borders(Borders::ALL).title("평가치"));
+            f.render_widget(eval_summary, metrics[0]);
+            let eval_data = evals.sparkline_data();
+            let sparkline = Sparkline::default()
+                .block(Block::default().borders(Borders::ALL).title("EMA 추이"))
+                .data(&eval_data)
+                .max((2.0 * EVAL_PLOT_RANGE) as u64)
+                .style(Style::default().fg(Color::Green));
+            f.render_widget(sparkline, metrics[1]);
+
             let items: Vec<ListItem> = logs
                 .iter()
                 .rev()
-                .map(|entry| ListItem::new(entry.clone()))
+                .map(|(entry, style)| ListItem::new(entry.clone()).style(*style))
                 .collect();
 
             let list = List::new(items)
                 .block(Block::default().borders(Borders::ALL).title("최근 이벤트"))
                 .highlight_style(Style::default().fg(Color::Yellow));
 
-            f.render_widget(list, chunks[1]);
+            f.render_widget(list, chunks[2]);
         })?;
 
         if should_close && receiver_closed {
@@ -121,8 +233,39 @@ fn run_loop<B: ratatui::backend::Backend>(
 
         if event::poll(Duration::from_millis(100))? {
             if let CEvent::Key(key) = event::read()? {
-                if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
-                    break;
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => break,
+                    KeyCode::Char('r') => {
+                        last_status = match control.resign() {
+                            Ok(()) => String::from("기권 요청됨"),
+                            Err(_) => String::from("기권 불가: 진행 중인 대국 없음"),
+                        };
+                    }
+                    KeyCode::Char('p') => {
+                        let sent = if paused {
+                            control.resume()
+                        } else {
+                            control.pause()
+                        };
+                        last_status = match sent {
+                            Ok(()) => {
+                                paused = !paused;
+                                String::from(if paused {
+                                    "일시정지 요청됨"
+                                } else {
+                                    "재개 요청됨"
+                                })
+                            }
+                            Err(_) => String::from("제어 불가: 진행 중인 대국 없음"),
+                        };
+                    }
+                    KeyCode::Char(' ') if paused => {
+                        last_status = match control.step() {
+                            Ok(()) => String::from("한 턴 진행 요청됨"),
+                            Err(_) => String::from("제어 불가: 진행 중인 대국 없음"),
+                        };
+                    }
+                    _ => {}
                 }
             }
         }
@@ -154,10 +297,35 @@ fn summarize_status(event: &SystemEvent) -> String {
         EventPayload::Telemetry(_) => "지연/텔레메트리 수집".to_string(),
         EventPayload::Network(_) => "네트워크 이벤트".to_string(),
         EventPayload::Ops(_) => "운영 알림".to_string(),
+        EventPayload::MatchResult(summary) => match summary.result {
+            Some(result) => format!("대국 종료: {result}"),
+            None => "대국 종료 (결과 없음)".to_string(),
+        },
+        EventPayload::State(change) => format!("상태: {}", change.to),
+        EventPayload::OpponentMove(opponent) => format!("상대 수 {opponent}"),
         EventPayload::Unknown(_) => "알 수 없는 이벤트".to_string(),
     }
 }
 
+fn format_memory(rss_bytes: Option<u64>) -> String {
+    match rss_bytes {
+        Some(bytes) => format!("메모리 {:.1} MiB", bytes as f64 / (1024.0 * 1024.0)),
+        None => "메모리 -".to_string(),
+    }
+}
+
+/// Ops lines are colored by severity; everything else uses the default style.
+fn event_style(event: &SystemEvent) -> Style {
+    match &event.payload {
+        EventPayload::Ops(ops) => match ops.severity {
+            Severity::Info => Style::default(),
+            Severity::Warning => Style::default().fg(Color::Yellow),
+            Severity::Error => Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        },
+        _ => Style::default(),
+    }
+}
+
 fn format_event(event: &SystemEvent) -> String {
     let timestamp = event.timestamp.format("%H:%M:%S");
     match &event.payload {
@@ -168,16 +336,50 @@ fn format_event(event: &SystemEvent) -> String {
             lifecycle.details.clone().unwrap_or_default()
         ),
         EventPayload::Engine(engine) => format!(
-            "[{}] Engine depth={} nodes={} best_line={}",
+            "[{}] Engine depth={} nodes={} best={} best_line={}{}",
             timestamp,
             engine.metrics.depth,
             engine.metrics.nodes,
-            engine.best_line.len()
+            engine
+                .best_line
+                .first()
+                .map_or_else(|| "-".to_string(), ToString::to_string),
+            engine.best_line.len(),
+            if engine.overridden {
+                " (운영자 지정 수)"
+            } else {
+                ""
+            }
         ),
         EventPayload::Board(board) => format!(
-            "[{}] Board snapshot 수신 (diff {}개)",
+            "[{}] Board snapshot 수신 (diff {}개){}{}",
+            timestamp,
+            board.diffs.len(),
+            board
+                .snapshot
+                .last_move
+                .as_ref()
+                .map_or_else(String::new, |mv| format!(
+                    " {}",
+                    mv.notation(board.captured.is_some())
+                )),
+            board.captured.map_or_else(String::new, |piece| format!(
+                " {:?} {:?} 잡힘",
+                piece.owner, piece.kind
+            ))
+        ),
+        EventPayload::Telemetry(TelemetryEvent {
+            health: Some(health),
+            ..
+        }) => format!(
+            "[{}] Health {} tasks={} queue={} uptime={}s",
             timestamp,
-            board.diffs.len()
+            format_memory(health.rss_bytes),
+            health
+                .alive_tasks
+                .map_or_else(|| "-".to_string(), |n| n.to_string()),
+            health.writer_queue_depth,
+            health.uptime_secs
         ),
         EventPayload::Telemetry(_) => format!("[{}] Telemetry 업데이트", timestamp),
         EventPayload::Network(net) => format!(
@@ -185,11 +387,54 @@ fn format_event(event: &SystemEvent) -> String {
             timestamp, net.topic, net.payload
         ),
         EventPayload::Ops(ops) => format!(
-            "[{}] Ops {} [{}]",
+            "[{}] Ops {:?} {} [{}]",
             timestamp,
+            ops.severity,
             ops.message,
             ops.tags.join(", ")
         ),
+        EventPayload::MatchResult(summary) => format!(
+            "[{}] MatchResult {} plies={} nodes={} avg_latency={}ms",
+            timestamp,
+            summary
+                .result
+                .map_or_else(|| "-".to_string(), |result| result.to_string()),
+            summary.plies,
+            summary.total_nodes,
+            summary
+                .average_latency_ms
+                .map_or_else(|| "-".to_string(), |ms| ms.to_string())
+        ),
+        EventPayload::State(change) => {
+            format!("[{}] State {} -> {}", timestamp, change.from, change.to)
+        }
+        EventPayload::OpponentMove(opponent) => format!("[{}] {}", timestamp, opponent),
         EventPayload::Unknown(value) => format!("[{}] Unknown payload {}", timestamp, value),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ema_moves_part_way_toward_new_evals() {
+        let mut track = EvalTrack::new(0.5);
+        track.push(10.0);
+        track.push(0.0);
+        track.push(0.0);
+        assert_eq!(track.raw, Some(0.0));
+        assert_eq!(track.smoothed, Some(2.5));
+        assert_eq!(track.sparkline_data(), vec![60, 55, 52]);
+    }
+
+    #[test]
+    fn eval_history_is_bounded() {
+        let mut track = EvalTrack::new(1.0);
+        for i in 0..(MAX_EVAL_HISTORY + 10) {
+            track.push(i as f32);
+        }
+        assert_eq!(track.history.len(), MAX_EVAL_HISTORY);
+        assert_eq!(track.smoothed, track.raw);
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    future::Future,
     path::PathBuf,
     sync::{Arc, Mutex},
     time::Instant,
@@ -6,15 +7,22 @@ use std::{
 
 use async_trait::async_trait;
 use chrono::Utc;
-use image::ImageFormat;
+use minerva_ops::retry;
 use minerva_types::{
-    board::Square, config::EmulatorConfig, telemetry::LatencySample, ui::Point, vision::ImageFrame,
+    board::Square,
+    config::{EmulatorConfig, ScreencapFormat},
+    telemetry::LatencySample,
+    ui::Point,
+    vision::ImageFrame,
     Result,
 };
 use tokio::{process::Command, time::Duration};
 
 use crate::{
-    controller_error, ensure_actions_present, ControllerMetrics, DeviceController, InputAction,
+    batch::{input_command, InputBatch, MAX_BATCH_COMMAND_LEN},
+    controller_error, ensure_actions_present,
+    screencap::decode_screencap,
+    ControllerMetrics, DeviceController, InputAction, TapJitter,
 };
 
 const DEFAULT_ADB: &str = "adb";
@@ -23,6 +31,7 @@ pub struct AdbController {
     config: EmulatorConfig,
     adb_path: PathBuf,
     metrics: Arc<Mutex<ControllerMetrics>>,
+    jitter: Option<TapJitter>,
 }
 
 impl AdbController {
@@ -34,12 +43,19 @@ impl AdbController {
             .unwrap_or_else(|| PathBuf::from(DEFAULT_ADB));
 
         Ok(Self {
+            jitter: TapJitter::from_config(&config),
             config,
             adb_path,
             metrics: Arc::new(Mutex::new(ControllerMetrics::default())),
         })
     }
 
+    /// Seeds the tap jitter so its offsets repeat from run to run.
+    pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+        self.jitter = TapJitter::seeded(&self.config, seed);
+        self
+    }
+
     fn serial(&self) -> &str {
         if self.config.serial.is_empty() {
             "emulator-5554"
@@ -84,6 +100,29 @@ impl AdbController {
         Ok(())
     }
 
+    /// Runs `op` under the configured retry policy, counting repeat attempts.
+    async fn with_retry<T, F, Fut>(&self, mut op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut attempts = 0u64;
+        let result = retry(
+            || {
+                attempts += 1;
+                op()
+            },
+            &self.config.retry,
+        )
+        .await;
+        if attempts > 1 {
+            if let Ok(mut guard) = self.metrics.lock() {
+                guard.retries += attempts - 1;
+            }
+        }
+        result
+    }
+
     async fn record_success(&self, start: Instant, injection_ms: u64) {
         if let Ok(mut guard) = self.metrics.lock() {
             guard.last_latency = Some(LatencySample {
@@ -91,6 +130,7 @@ impl AdbController {
                 decision_ms: 0,
                 injection_ms,
                 total_ms: start.elapsed().as_millis() as u64,
+                prefetch_saved_ms: 0,
                 captured_at: Utc::now(),
             });
             guard.successful_inputs += 1;
@@ -116,27 +156,29 @@ impl DeviceController for AdbController {
     }
 
     async fn capture_frame(&self) -> Result<ImageFrame> {
-        let args = ["-s", self.serial(), "exec-out", "screencap", "-p"];
-        let raw = self.run_adb(&args).await?;
-        let img = image::load_from_memory_with_format(&raw, ImageFormat::Png)
-            .map_err(|err| controller_error(format!("스크린샷 디코딩 실패: {err}")))?;
-        let rgba = img.to_rgba8();
-        let (width, height) = rgba.dimensions();
-        let data = rgba.into_raw();
-        Ok(ImageFrame::from_rgba(width, height, data))
+        let mut args = vec!["-s", self.serial(), "exec-out", "screencap"];
+        if self.config.screencap_format == ScreencapFormat::Png {
+            args.push("-p");
+        }
+        self.with_retry(|| async { decode_screencap(&self.run_adb(&args).await?) })
+            .await
     }
 
     async fn tap_square(&self, square: Square) -> Result<()> {
-        let point = minerva_types::ui::square_to_point(square).ok_or_else(|| {
-            controller_error(format!(
-                "보드 좌표 범위를 벗어남: file={}, rank={}",
-                square.file, square.rank
-            ))
-        })?;
+        let point = self
+            .config
+            .geometry
+            .square_to_point(square)
+            .ok_or_else(|| controller_error(format!("보드 좌표 범위를 벗어남: {square}")))?;
         self.tap_point(point).await
     }
 
     async fn tap_point(&self, point: Point) -> Result<()> {
+        let point = point.offset(self.config.tap_offset);
+        let point = self
+            .jitter
+            .as_ref()
+            .map_or(point, |jitter| jitter.apply(point));
         self.inject_actions(vec![InputAction::Tap {
             x: point.x,
             y: point.y,
@@ -147,39 +189,27 @@ impl DeviceController for AdbController {
     async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()> {
         ensure_actions_present(&actions)?;
         let start = Instant::now();
-        for action in &actions {
-            let result = match action {
-                InputAction::Tap { x, y } => {
-                    self.run_shell(&["input".into(), "tap".into(), x.to_string(), y.to_string()])
-                        .await
-                }
-                InputAction::Swipe {
-                    start: s,
-                    end,
-                    duration_ms,
-                } => {
-                    self.run_shell(&[
-                        "input".into(),
-                        "swipe".into(),
-                        s.0.to_string(),
-                        s.1.to_string(),
-                        end.0.to_string(),
-                        end.1.to_string(),
-                        duration_ms.to_string(),
-                    ])
-                    .await
-                }
-                InputAction::KeyEvent { code } => {
-                    self.run_shell(&["input".into(), "keyevent".into(), code.to_string()])
-                        .await
-                }
-            };
+        let gap = Duration::from_millis(self.config.timing.inter_action_ms);
+        let commands: Vec<Vec<String>> = if self.config.batch_inputs && actions.len() > 1 {
+            InputBatch::plan(
+                &actions,
+                self.config.timing.inter_action_ms,
+                MAX_BATCH_COMMAND_LEN,
+            )
+            .into_iter()
+            .map(|batch| vec![batch.script().to_string()])
+            .collect()
+        } else {
+            actions.iter().map(input_command).collect()
+        };
+        for shell_args in &commands {
+            let result = self.with_retry(|| self.run_shell(shell_args)).await;
 
             if let Err(err) = result {
                 self.record_failure().await;
                 return Err(err);
             }
-            tokio::time::sleep(Duration::from_millis(10)).await;
+            tokio::time::sleep(gap).await;
         }
 
         let injection_ms = start.elapsed().as_millis() as u64;
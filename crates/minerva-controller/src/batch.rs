@@ -0,0 +1,135 @@
+//! Composition of several `input` commands into one `adb shell` invocation.
+//!
+//! Every action still runs as its own `input` command on the device, in
+//! order; only the host-side process spawn is shared. `input` returns once
+//! its event is injected (a swipe after its duration), so chained commands
+//! keep their order, and the inter-action pause becomes an on-device `sleep`.
+//! Commands are chained with `&&`, so a failing one stops the rest and its
+//! status becomes the shell's.
+
+use crate::InputAction;
+
+/// Longest command line sent as one batch; longer sequences are split.
+pub const MAX_BATCH_COMMAND_LEN: usize = 1_000;
+
+/// The `input` command performing `action`, as shell arguments.
+pub fn input_command(action: &InputAction) -> Vec<String> {
+    match action {
+        InputAction::Tap { x, y } => {
+            vec!["input".into(), "tap".into(), x.to_string(), y.to_string()]
+        }
+        InputAction::Swipe {
+            start,
+            end,
+            duration_ms,
+        } => vec![
+            "input".into(),
+            "swipe".into(),
+            start.0.to_string(),
+            start.1.to_string(),
+            end.0.to_string(),
+            end.1.to_string(),
+            duration_ms.to_string(),
+        ],
+        InputAction::KeyEvent { code } => {
+            vec!["input".into(), "keyevent".into(), code.to_string()]
+        }
+    }
+}
+
+/// A shell command line running several actions in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputBatch {
+    script: String,
+    actions: usize,
+}
+
+impl InputBatch {
+    /// Groups `actions` into batches whose command lines stay within
+    /// `max_len`, pausing `gap_ms` on the device between actions. An action
+    /// whose command alone exceeds the limit gets a batch of its own.
+    pub fn plan(actions: &[InputAction], gap_ms: u64, max_len: usize) -> Vec<InputBatch> {
+        let separator = if gap_ms == 0 {
+            " && ".to_string()
+        } else {
+            format!(" && sleep {}.{:03} && ", gap_ms / 1000, gap_ms % 1000)
+        };
+        let mut batches: Vec<InputBatch> = Vec::new();
+        for action in actions {
+            let command = input_command(action).join(" ");
+            match batches.last_mut() {
+                Some(batch) if batch.script.len() + separator.len() + command.len() <= max_len => {
+                    batch.script.push_str(&separator);
+                    batch.script.push_str(&command);
+                    batch.actions += 1;
+                }
+                _ => batches.push(InputBatch {
+                    script: command,
+                    actions: 1,
+                }),
+            }
+        }
+        batches
+    }
+
+    /// The command line to pass to `adb shell`.
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    /// How many actions the batch performs.
+    pub fn actions(&self) -> usize {
+        self.actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tap(x: u32, y: u32) -> InputAction {
+        InputAction::Tap { x, y }
+    }
+
+    #[test]
+    fn actions_are_chained_with_device_side_pauses() {
+        let actions = [
+            tap(10, 20),
+            InputAction::Swipe {
+                start: (1, 2),
+                end: (3, 4),
+                duration_ms: 300,
+            },
+            InputAction::KeyEvent { code: 4 },
+        ];
+
+        let batches = InputBatch::plan(&actions, 10, MAX_BATCH_COMMAND_LEN);
+
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].actions(), 3);
+        assert_eq!(
+            batches[0].script(),
+            "input tap 10 20 && sleep 0.010 && input swipe 1 2 3 4 300 \
+             && sleep 0.010 && input keyevent 4"
+        );
+        assert_eq!(
+            InputBatch::plan(&actions[..2], 0, MAX_BATCH_COMMAND_LEN)[0].script(),
+            "input tap 10 20 && input swipe 1 2 3 4 300"
+        );
+    }
+
+    #[test]
+    fn long_sequences_are_split_at_the_length_limit() {
+        let actions: Vec<_> = (0..5).map(|i| tap(100 + i, 200)).collect();
+        // "input tap 100 200" is 17 bytes and " && " adds 4: two fit in 40.
+        let batches = InputBatch::plan(&actions, 0, 40);
+
+        let sizes: Vec<_> = batches.iter().map(InputBatch::actions).collect();
+        assert_eq!(sizes, [2, 2, 1]);
+        assert!(batches.iter().all(|batch| batch.script().len() <= 40));
+        assert_eq!(batches[2].script(), "input tap 104 200");
+
+        let oversized = InputBatch::plan(&actions[..2], 0, 10);
+        assert_eq!(oversized.len(), 2);
+    }
+}
@@ -0,0 +1,66 @@
+//! Random offsets for board taps, so they do not always land on the exact
+//! intersection.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use minerva_types::{config::EmulatorConfig, ui::Point};
+
+/// Seeded generator of tap offsets of up to `max_px` on each axis.
+#[derive(Debug)]
+pub struct TapJitter {
+    max_px: u32,
+    /// Largest offset that keeps a tap inside its cell, per axis.
+    cell_half: (u32, u32),
+    state: AtomicU64,
+}
+
+impl TapJitter {
+    /// Jitter per `config.tap_jitter_px`, seeded from the clock; `None`
+    /// when taps are not jittered.
+    pub fn from_config(config: &EmulatorConfig) -> Option<Self> {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self::seeded(config, seed)
+    }
+
+    /// Like [`Self::from_config`], with a fixed seed so the offsets repeat.
+    pub fn seeded(config: &EmulatorConfig, seed: u64) -> Option<Self> {
+        let max_px = config.tap_jitter_px.filter(|&px| px > 0)?;
+        let (half_w, half_h) = config.geometry.cell_half_size();
+        Some(Self {
+            max_px,
+            cell_half: (half_w.saturating_sub(1), half_h.saturating_sub(1)),
+            state: AtomicU64::new(seed),
+        })
+    }
+
+    /// `point` shifted by a fresh offset, no further than `max_px` or the
+    /// edge of its cell.
+    pub fn apply(&self, point: Point) -> Point {
+        let dx = self.offset(self.max_px.min(self.cell_half.0));
+        let dy = self.offset(self.max_px.min(self.cell_half.1));
+        point.offset((dx, dy))
+    }
+
+    /// Uniform value in `-limit..=limit`.
+    fn offset(&self, limit: u32) -> i32 {
+        let span = u64::from(limit) * 2 + 1;
+        (self.next() % span) as i32 - limit as i32
+    }
+
+    /// Next splitmix64 output.
+    fn next(&self) -> u64 {
+        let mut z = self
+            .state
+            .fetch_add(0x9e37_79b9_7f4a_7c15, Ordering::Relaxed)
+            .wrapping_add(0x9e37_79b9_7f4a_7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
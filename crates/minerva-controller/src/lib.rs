@@ -1,6 +1,9 @@
 //! Emulator/ADB controller abstraction layer.
 
 mod adb;
+mod batch;
+mod jitter;
+mod screencap;
 
 use std::{
     sync::{Arc, Mutex},
@@ -8,16 +11,19 @@ use std::{
 };
 
 pub use adb::AdbController;
+pub use batch::{input_command, InputBatch, MAX_BATCH_COMMAND_LEN};
+pub use jitter::TapJitter;
+pub use screencap::decode_screencap;
 
 use async_trait::async_trait;
 use chrono::Utc;
 use minerva_types::{
     board::Square,
     config::EmulatorConfig,
-    telemetry::LatencySample,
+    telemetry::{ControllerSummary, LatencySample},
     ui::{
-        formation_point, square_to_point, start_flow_point, FormationPreset, Point, StartFlowStep,
-        FORMATION_CONFIRM,
+        formation_point, resign_point, start_flow_point, DialogButtons, FormationPreset, Point,
+        PopupTemplate, ResignStep, ScreenRect, StartFlowStep, CLEAR_SELECTION, FORMATION_CONFIRM,
     },
     vision::ImageFrame,
     MinervaError, Result,
@@ -25,6 +31,9 @@ use minerva_types::{
 use tokio::time::{sleep, Duration};
 use tracing::info;
 
+/// Android key code of the Back key.
+pub const KEYCODE_BACK: u32 = 4;
+
 /// High-level input primitives.
 #[derive(Debug, Clone)]
 pub enum InputAction {
@@ -48,12 +57,32 @@ pub struct ControllerMetrics {
     pub last_latency: Option<LatencySample>,
     pub successful_inputs: u64,
     pub failed_inputs: u64,
+    /// Repeat attempts made under the retry policy.
+    pub retries: u64,
+}
+
+impl ControllerMetrics {
+    pub fn summary(&self) -> ControllerSummary {
+        ControllerSummary {
+            successful_inputs: self.successful_inputs,
+            failed_inputs: self.failed_inputs,
+            retries: self.retries,
+            last_latency_ms: self.last_latency.as_ref().map(|sample| sample.total_ms),
+        }
+    }
 }
 
 #[async_trait]
 pub trait DeviceController: Send + Sync {
     async fn connect(&mut self) -> Result<()>;
     async fn capture_frame(&self) -> Result<ImageFrame>;
+
+    /// Captures only `region` of the screen. Devices that cannot grab part
+    /// of the screen crop a full capture.
+    async fn capture_region(&self, region: ScreenRect) -> Result<ImageFrame> {
+        Ok(self.capture_frame().await?.crop(region))
+    }
+
     async fn tap_square(&self, square: Square) -> Result<()>;
     async fn tap_point(&self, point: Point) -> Result<()>;
     async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()>;
@@ -64,15 +93,38 @@ pub trait DeviceController: Send + Sync {
 pub struct MockController {
     config: EmulatorConfig,
     metrics: Arc<Mutex<ControllerMetrics>>,
+    actions: Arc<Mutex<Vec<InputAction>>>,
+    frame: Option<ImageFrame>,
+    jitter: Option<TapJitter>,
 }
 
 impl MockController {
     pub fn new(config: EmulatorConfig) -> Self {
         Self {
+            jitter: TapJitter::from_config(&config),
             config,
             metrics: Arc::new(Mutex::new(ControllerMetrics::default())),
+            actions: Arc::new(Mutex::new(Vec::new())),
+            frame: None,
         }
     }
+
+    /// Seeds the tap jitter so its offsets repeat from run to run.
+    pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+        self.jitter = TapJitter::seeded(&self.config, seed);
+        self
+    }
+
+    /// Returns `frame` from every capture instead of an empty frame.
+    pub fn with_frame(mut self, frame: ImageFrame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Every action injected so far, in order.
+    pub fn recorded_actions(&self) -> Vec<InputAction> {
+        self.actions.lock().map(|a| a.clone()).unwrap_or_default()
+    }
 }
 
 #[async_trait]
@@ -86,24 +138,29 @@ impl DeviceController for MockController {
     async fn capture_frame(&self) -> Result<ImageFrame> {
         info!("Capturing frame using mock controller");
         sleep(Duration::from_millis(25)).await;
-        Ok(ImageFrame::empty())
+        Ok(self.frame.clone().unwrap_or_else(ImageFrame::empty))
     }
 
     async fn tap_square(&self, square: Square) -> Result<()> {
-        let point = square_to_point(square).ok_or_else(|| {
-            controller_error(format!(
-                "square out of bounds: file={}, rank={}",
-                square.file, square.rank
-            ))
-        })?;
-        info!(
-            "Mock tap on square ({}, {}) -> ({}, {})",
-            square.file, square.rank, point.x, point.y
-        );
+        let point = self
+            .config
+            .geometry
+            .square_to_point(square)
+            .ok_or_else(|| controller_error(format!("square out of bounds: {square}")))?;
+        info!("Mock tap on square {square} -> ({}, {})", point.x, point.y);
         self.tap_point(point).await
     }
 
     async fn tap_point(&self, point: Point) -> Result<()> {
+        let mut point = point.offset(self.config.tap_offset);
+        if let Some(jitter) = &self.jitter {
+            let jittered = jitter.apply(point);
+            info!(
+                "Mock tap jitter ({}, {}) -> ({}, {})",
+                point.x, point.y, jittered.x, jittered.y
+            );
+            point = jittered;
+        }
         self.inject_actions(vec![InputAction::Tap {
             x: point.x,
             y: point.y,
@@ -114,6 +171,9 @@ impl DeviceController for MockController {
     async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()> {
         ensure_actions_present(&actions)?;
         let start = Instant::now();
+        if let Ok(mut recorded) = self.actions.lock() {
+            recorded.extend(actions.iter().cloned());
+        }
         for action in actions {
             match action {
                 InputAction::Tap { x, y } => info!("Mock tap {} {}", x, y),
@@ -129,7 +189,7 @@ impl DeviceController for MockController {
                 }
                 InputAction::KeyEvent { code } => info!("Mock key event {}", code),
             }
-            sleep(Duration::from_millis(5)).await;
+            sleep(Duration::from_millis(self.config.timing.inter_action_ms)).await;
         }
         let total_ms = start.elapsed().as_millis() as u64;
         let mut metrics = self
@@ -141,6 +201,7 @@ impl DeviceController for MockController {
             decision_ms: 0,
             injection_ms: total_ms,
             total_ms,
+            prefetch_saved_ms: 0,
             captured_at: Utc::now(),
         });
         metrics.successful_inputs += 1;
@@ -185,9 +246,40 @@ pub fn formation_confirm_action() -> InputAction {
     point_to_action(FORMATION_CONFIRM)
 }
 
+pub fn resign_action(step: ResignStep) -> InputAction {
+    point_to_action(resign_point(step))
+}
+
+/// Press of the Back key.
+pub fn back_action() -> InputAction {
+    InputAction::KeyEvent { code: KEYCODE_BACK }
+}
+
+pub fn clear_selection_action() -> InputAction {
+    point_to_action(CLEAR_SELECTION)
+}
+
+/// Tap on `buttons`' accept or decline button.
+pub fn dialog_answer_action(buttons: DialogButtons, accept: bool) -> InputAction {
+    point_to_action(if accept {
+        buttons.accept
+    } else {
+        buttons.decline
+    })
+}
+
+/// Tap that closes `popup`.
+pub fn popup_dismiss_action(popup: &PopupTemplate) -> InputAction {
+    point_to_action(popup.dismiss)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use minerva_types::{
+        config::{RetryPolicy, ScreencapFormat, TimingConfig},
+        ui::BoardGeometry,
+    };
 
     #[test]
     fn start_flow_action_points() {
@@ -213,6 +305,85 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn mock_tap_applies_configured_offset() {
+        let geometry = BoardGeometry {
+            origin: Point::new(50, 900),
+            ..BoardGeometry::default()
+        };
+        let controller = MockController::new(EmulatorConfig {
+            serial: "mock".into(),
+            socket: "mock".into(),
+            fixed_resolution: None,
+            adb_path: None,
+            tap_offset: (5, -5),
+            geometry,
+            screencap_format: ScreencapFormat::Png,
+            retry: RetryPolicy::default(),
+            timing: TimingConfig::default(),
+            batch_inputs: true,
+            tap_jitter_px: None,
+        });
+        controller.tap_square(Square::new(0, 0)).await.unwrap();
+        match controller.recorded_actions().as_slice() {
+            [InputAction::Tap { x, y }] => {
+                assert_eq!((*x, *y), (55, 895));
+            }
+            other => panic!("unexpected actions: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn jittered_taps_stay_inside_the_cell_and_repeat_per_seed() {
+        // Cells 20 px wide: offsets are capped at 8 px across, 12 px down.
+        let geometry = BoardGeometry {
+            file_spacing: 20.0,
+            rank_spacing: -60.0,
+            ..BoardGeometry::default()
+        };
+        let config = EmulatorConfig {
+            serial: "mock".into(),
+            socket: "mock".into(),
+            fixed_resolution: None,
+            adb_path: None,
+            tap_offset: (0, 0),
+            geometry: geometry.clone(),
+            screencap_format: ScreencapFormat::Png,
+            retry: RetryPolicy::default(),
+            timing: TimingConfig::default(),
+            batch_inputs: true,
+            tap_jitter_px: Some(12),
+        };
+        let taps = |seed| {
+            let controller = MockController::new(config.clone()).with_jitter_seed(seed);
+            async move {
+                let square = Square::new(4, 4);
+                for _ in 0..40 {
+                    controller.tap_square(square).await.unwrap();
+                }
+                controller
+                    .recorded_actions()
+                    .iter()
+                    .map(|action| match action {
+                        InputAction::Tap { x, y } => (*x, *y),
+                        other => panic!("unexpected action: {other:?}"),
+                    })
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        let first = taps(7).await;
+        let center = geometry.square_to_point(Square::new(4, 4)).unwrap();
+        let (half_w, half_h) = geometry.cell_half_size();
+        assert_eq!((half_w, half_h), (9, 27));
+        assert!(first
+            .iter()
+            .all(|&(x, y)| x.abs_diff(center.x) <= 8 && y.abs_diff(center.y) <= 12));
+        assert!(first.iter().any(|&(x, _)| x != center.x));
+        assert_eq!(taps(7).await, first);
+        assert_ne!(taps(8).await, first);
+    }
+
     #[test]
     fn formation_confirm_action_matches_constant() {
         let action = formation_confirm_action();
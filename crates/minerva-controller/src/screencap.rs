@@ -0,0 +1,161 @@
+//! Decoding of `adb exec-out screencap` output into RGBA frames.
+//!
+//! Raw output starts with a little-endian header of width, height, and pixel
+//! format, followed on newer Android versions by a color-space word, then the
+//! pixel rows without padding.
+
+use image::ImageFormat;
+use minerva_types::{vision::ImageFrame, Result};
+
+use crate::controller_error;
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Android `PixelFormat` constants found in the screencap header.
+const RGBA_8888: u32 = 1;
+const RGBX_8888: u32 = 2;
+const RGB_565: u32 = 4;
+const BGRA_8888: u32 = 5;
+
+/// Decodes PNG or raw screencap bytes into an RGBA [`ImageFrame`].
+pub fn decode_screencap(raw: &[u8]) -> Result<ImageFrame> {
+    if raw.starts_with(&PNG_SIGNATURE) {
+        let img = image::load_from_memory_with_format(raw, ImageFormat::Png)
+            .map_err(|err| controller_error(format!("스크린샷 디코딩 실패: {err}")))?;
+        let rgba = img.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        return Ok(ImageFrame::from_rgba(width, height, rgba.into_raw()));
+    }
+    decode_raw(raw)
+}
+
+fn decode_raw(raw: &[u8]) -> Result<ImageFrame> {
+    let word = |idx: usize| -> Option<u32> {
+        raw.get(idx * 4..idx * 4 + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    };
+    let (Some(width), Some(height), Some(format)) = (word(0), word(1), word(2)) else {
+        return Err(controller_error(format!(
+            "screencap 헤더가 너무 짧습니다 ({} bytes)",
+            raw.len()
+        )));
+    };
+    let bytes_per_pixel = match format {
+        RGBA_8888 | RGBX_8888 | BGRA_8888 => 4,
+        RGB_565 => 2,
+        other => {
+            return Err(controller_error(format!(
+                "지원하지 않는 screencap 픽셀 형식: {other}"
+            )))
+        }
+    };
+    let pixel_bytes = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(bytes_per_pixel))
+        .ok_or_else(|| controller_error("screencap 크기가 너무 큽니다"))?;
+    // 12-byte header on older Android, 16 bytes once a color space was added.
+    let header = match raw.len().checked_sub(pixel_bytes) {
+        Some(len @ (12 | 16)) => len,
+        _ => {
+            return Err(controller_error(format!(
+                "screencap 크기 불일치: {}x{} 형식 {} 에 {} bytes",
+                width,
+                height,
+                format,
+                raw.len()
+            )))
+        }
+    };
+    let pixels = &raw[header..];
+
+    let data = match format {
+        RGBA_8888 => pixels.to_vec(),
+        RGBX_8888 => pixels
+            .chunks_exact(4)
+            .flat_map(|px| [px[0], px[1], px[2], 0xff])
+            .collect(),
+        BGRA_8888 => pixels
+            .chunks_exact(4)
+            .flat_map(|px| [px[2], px[1], px[0], px[3]])
+            .collect(),
+        _ => pixels
+            .chunks_exact(2)
+            .flat_map(|px| {
+                let value = u16::from_le_bytes([px[0], px[1]]);
+                let r = ((value >> 11) & 0x1f) as u8;
+                let g = ((value >> 5) & 0x3f) as u8;
+                let b = (value & 0x1f) as u8;
+                [
+                    (r << 3) | (r >> 2),
+                    (g << 2) | (g >> 4),
+                    (b << 3) | (b >> 2),
+                    0xff,
+                ]
+            })
+            .collect(),
+    };
+    Ok(ImageFrame::from_rgba(width, height, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_dump(width: u32, height: u32, format: u32, color_space: bool, pixels: &[u8]) -> Vec<u8> {
+        let mut raw = Vec::new();
+        raw.extend(width.to_le_bytes());
+        raw.extend(height.to_le_bytes());
+        raw.extend(format.to_le_bytes());
+        if color_space {
+            raw.extend(1u32.to_le_bytes());
+        }
+        raw.extend(pixels);
+        raw
+    }
+
+    #[test]
+    fn decodes_rgba_8888_with_either_header_size() {
+        let pixels = [10, 20, 30, 255, 40, 50, 60, 128];
+        for color_space in [false, true] {
+            let frame = decode_screencap(&raw_dump(2, 1, RGBA_8888, color_space, &pixels)).unwrap();
+            assert_eq!((frame.width, frame.height), (2, 1));
+            assert_eq!(frame.data, pixels);
+        }
+    }
+
+    #[test]
+    fn expands_rgb_565_to_rgba() {
+        // Pure red, pure green, pure blue, white.
+        let pixels: Vec<u8> = [0xf800u16, 0x07e0, 0x001f, 0xffff]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let frame = decode_screencap(&raw_dump(2, 2, RGB_565, true, &pixels)).unwrap();
+        assert_eq!(
+            frame.data,
+            [
+                255, 0, 0, 255, //
+                0, 255, 0, 255, //
+                0, 0, 255, 255, //
+                255, 255, 255, 255,
+            ]
+        );
+    }
+
+    #[test]
+    fn png_signature_is_decoded_as_png() {
+        let img = image::RgbaImage::from_pixel(3, 2, image::Rgba([1, 2, 3, 255]));
+        let mut png = std::io::Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+        let frame = decode_screencap(png.get_ref()).unwrap();
+        assert_eq!((frame.width, frame.height), (3, 2));
+        assert_eq!(&frame.data[..4], &[1, 2, 3, 255]);
+    }
+
+    #[test]
+    fn rejects_unknown_format_and_truncated_dump() {
+        assert!(decode_screencap(&raw_dump(1, 1, 99, false, &[0; 4])).is_err());
+        assert!(decode_screencap(&raw_dump(2, 2, RGBA_8888, false, &[0; 8])).is_err());
+        assert!(decode_screencap(&[1, 2, 3]).is_err());
+    }
+}
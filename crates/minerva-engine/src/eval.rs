@@ -0,0 +1,320 @@
+//! Static evaluation: material plus Janggi-specific positional terms.
+
+use minerva_types::{
+    board::{BoardState, Piece, PieceKind, PlayerSide, Square},
+    config::PositionalWeights,
+};
+
+use crate::pins::pinned_pieces;
+
+pub(crate) const ORTHOGONAL: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Material balance plus [`evaluate_features`] and Han's deom, from
+/// `side`'s perspective.
+pub fn evaluate(board: &BoardState, side: PlayerSide, weights: &PositionalWeights) -> f32 {
+    material(board, side) - material(board, side.opponent())
+        + evaluate_features(board, side, weights)
+        + compensation(side, weights)
+}
+
+/// `weights.han_compensation` for Han (Red), the same debited for Cho (Blue),
+/// so the evaluation still flips sign with the perspective.
+fn compensation(side: PlayerSide, weights: &PositionalWeights) -> f32 {
+    match side {
+        PlayerSide::Red => weights.han_compensation,
+        PlayerSide::Blue => -weights.han_compensation,
+    }
+}
+
+/// Positional terms for `side` minus the same terms for its opponent:
+/// General safety (Guards alongside, covered file, pinned pieces), Cannon
+/// screens, and Horse/Elephant development.
+pub fn evaluate_features(board: &BoardState, side: PlayerSide, weights: &PositionalWeights) -> f32 {
+    side_features(board, side, weights).total()
+        - side_features(board, side.opponent(), weights).total()
+}
+
+/// [`evaluate`] split into its terms, each from `side`'s perspective, plus
+/// mobility, which the evaluation does not score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EvalBreakdown {
+    pub material: f32,
+    /// Guards alongside the General, its covered file, and pinned pieces.
+    pub king_safety: f32,
+    pub cannon_screens: f32,
+    pub development: f32,
+    /// Han's deom for moving second; negative for Cho.
+    pub compensation: f32,
+    /// Pseudo-legal moves for `side` minus those for its opponent.
+    pub mobility: i32,
+}
+
+impl EvalBreakdown {
+    /// The [`evaluate`] score these terms add up to.
+    pub fn score(&self) -> f32 {
+        self.material
+            + self.king_safety
+            + self.cannon_screens
+            + self.development
+            + self.compensation
+    }
+}
+
+/// The terms behind [`evaluate`] for `side`, for tuning logs.
+pub fn evaluate_breakdown(
+    board: &BoardState,
+    side: PlayerSide,
+    weights: &PositionalWeights,
+) -> EvalBreakdown {
+    let ours = side_features(board, side, weights);
+    let theirs = side_features(board, side.opponent(), weights);
+    let mobility = |side| board.pseudo_legal_moves(side).len() as i32;
+    EvalBreakdown {
+        material: material(board, side) - material(board, side.opponent()),
+        king_safety: ours.king_safety - theirs.king_safety,
+        cannon_screens: ours.cannon_screens - theirs.cannon_screens,
+        development: ours.development - theirs.development,
+        compensation: compensation(side, weights),
+        mobility: mobility(side) - mobility(side.opponent()),
+    }
+}
+
+fn piece_value(piece: Piece) -> f32 {
+    match piece.kind {
+        PieceKind::General => 1000.0,
+        PieceKind::Guard => 3.0,
+        PieceKind::Elephant => 5.0,
+        PieceKind::Horse => 7.0,
+        PieceKind::Chariot => 13.0,
+        PieceKind::Cannon => 9.0,
+        PieceKind::Soldier => 1.0,
+    }
+}
+
+fn material(board: &BoardState, side: PlayerSide) -> f32 {
+    pieces(board, side)
+        .map(|(_, piece)| piece_value(piece))
+        .sum()
+}
+
+/// One side's positional terms.
+#[derive(Default)]
+struct SideFeatures {
+    king_safety: f32,
+    cannon_screens: f32,
+    development: f32,
+}
+
+impl SideFeatures {
+    fn total(&self) -> f32 {
+        self.king_safety + self.cannon_screens + self.development
+    }
+}
+
+fn side_features(
+    board: &BoardState,
+    side: PlayerSide,
+    weights: &PositionalWeights,
+) -> SideFeatures {
+    let back_rank = match side {
+        PlayerSide::Blue => 0,
+        PlayerSide::Red => board.height - 1,
+    };
+    let mut features = SideFeatures::default();
+    if let Some(general) = board.find_general(side) {
+        let shields = pieces(board, side)
+            .filter(|(square, piece)| {
+                piece.kind == PieceKind::Guard
+                    && square.file.abs_diff(general.file) <= 1
+                    && square.rank.abs_diff(general.rank) <= 1
+            })
+            .count();
+        features.king_safety += weights.guard_shield * shields as f32;
+        if file_is_open(board, side, general) {
+            features.king_safety -= weights.open_file;
+        }
+        features.king_safety -= weights.pinned * pinned_pieces(board, side).len() as f32;
+    }
+    for (square, piece) in pieces(board, side) {
+        match piece.kind {
+            PieceKind::Cannon => {
+                let screens = ORTHOGONAL
+                    .iter()
+                    .filter(|&&(df, dr)| has_screen(board, square, df, dr))
+                    .count();
+                features.cannon_screens += weights.cannon_screen * screens as f32;
+            }
+            PieceKind::Horse | PieceKind::Elephant if square.rank != back_rank => {
+                features.development += weights.development;
+            }
+            _ => {}
+        }
+    }
+    features
+}
+
+fn pieces(board: &BoardState, side: PlayerSide) -> impl Iterator<Item = (Square, Piece)> + '_ {
+    (0..board.height)
+        .flat_map(move |rank| (0..board.width).map(move |file| Square::new(file, rank)))
+        .filter_map(move |square| {
+            board
+                .piece_at(square)
+                .filter(|piece| piece.owner == side)
+                .map(|piece| (square, piece))
+        })
+}
+
+/// Whether the first piece ahead of the General (toward the enemy) is not
+/// one of `side`'s own.
+fn file_is_open(board: &BoardState, side: PlayerSide, general: Square) -> bool {
+    let forward = match side {
+        PlayerSide::Blue => 1,
+        PlayerSide::Red => -1,
+    };
+    first_piece(board, general, 0, forward).is_none_or(|(_, piece)| piece.owner != side)
+}
+
+/// Whether a Cannon on `from` could jump along (`df`, `dr`): the first piece
+/// that way exists and is not a Cannon.
+fn has_screen(board: &BoardState, from: Square, df: i8, dr: i8) -> bool {
+    first_piece(board, from, df, dr).is_some_and(|(_, piece)| piece.kind != PieceKind::Cannon)
+}
+
+fn first_piece(board: &BoardState, from: Square, df: i8, dr: i8) -> Option<(Square, Piece)> {
+    let mut square = from;
+    while let Some(next) = square.offset(df, dr) {
+        board.index(next)?;
+        if let Some(piece) = board.piece_at(next) {
+            return Some((next, piece));
+        }
+        square = next;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn moved(board: &BoardState, from: (u8, u8), to: (u8, u8)) -> BoardState {
+        let mut next = board.clone();
+        next.move_piece(Square::new(from.0, from.1), Square::new(to.0, to.1))
+            .unwrap();
+        next
+    }
+
+    #[test]
+    fn initial_position_is_balanced() {
+        let board = BoardState::initial();
+        let weights = PositionalWeights {
+            han_compensation: 0.0,
+            ..PositionalWeights::default()
+        };
+        assert_eq!(evaluate_features(&board, PlayerSide::Blue, &weights), 0.0);
+        assert_eq!(evaluate(&board, PlayerSide::Red, &weights), 0.0);
+    }
+
+    #[test]
+    fn developed_position_scores_higher() {
+        let weights = PositionalWeights::default();
+        let initial = BoardState::initial();
+        let horse = initial
+            .pseudo_legal_moves(PlayerSide::Blue)
+            .into_iter()
+            .find(|mv| {
+                initial
+                    .piece_at(mv.from)
+                    .is_some_and(|p| p.kind == PieceKind::Horse)
+            })
+            .unwrap();
+        let developed = moved(
+            &initial,
+            (horse.from.file, horse.from.rank),
+            (horse.to.file, horse.to.rank),
+        );
+        let before = evaluate_features(&initial, PlayerSide::Blue, &weights);
+        let after = evaluate_features(&developed, PlayerSide::Blue, &weights);
+        assert!(after > before, "{after} <= {before}");
+        assert!(evaluate_features(&developed, PlayerSide::Red, &weights) < 0.0);
+    }
+
+    #[test]
+    fn breakdown_adds_up_to_the_evaluation() {
+        let weights = PositionalWeights::default();
+        let board = moved(&BoardState::initial(), (0, 3), (0, 4));
+        let terms = evaluate_breakdown(&board, PlayerSide::Blue, &weights);
+        let score = evaluate(&board, PlayerSide::Blue, &weights);
+        assert!((terms.score() - score).abs() < 1e-4, "{terms:?} vs {score}");
+        let mirrored = evaluate_breakdown(&board, PlayerSide::Red, &weights);
+        assert_eq!(mirrored.mobility, -terms.mobility);
+    }
+
+    /// Swapping the perspective must only flip the sign. Positions come from
+    /// seeded random self-play, so every term sees varied material and
+    /// structure; Han's deom is checked at its default, off, and raised.
+    #[test]
+    fn evaluation_is_antisymmetric_over_self_play_positions() {
+        let mut positions = 0;
+        for (game, han_compensation) in (0..24u64).zip([1.5, 0.0, 4.0].into_iter().cycle()) {
+            let weights = PositionalWeights {
+                han_compensation,
+                ..PositionalWeights::default()
+            };
+            let mut board = BoardState::initial();
+            let mut side = PlayerSide::Blue;
+            let mut state = crate::splitmix(game);
+            for _ in 0..80 {
+                let blue = evaluate(&board, PlayerSide::Blue, &weights);
+                let red = evaluate(&board, PlayerSide::Red, &weights);
+                assert_eq!(blue, -red, "game {game}:\n{board:?}");
+                positions += 1;
+
+                let moves = board.legal_moves(side);
+                if moves.is_empty() {
+                    break;
+                }
+                state = crate::splitmix(state);
+                let mv = &moves[(state % moves.len() as u64) as usize];
+                board.move_piece(mv.from, mv.to).unwrap();
+                side = side.opponent();
+            }
+        }
+        assert!(positions > 1_000, "only {positions} positions checked");
+    }
+
+    #[test]
+    fn han_is_credited_for_moving_second() {
+        let weights = PositionalWeights::default();
+        let initial = BoardState::initial();
+        assert_eq!(
+            evaluate(&initial, PlayerSide::Red, &weights),
+            weights.han_compensation
+        );
+        assert_eq!(
+            evaluate(&initial, PlayerSide::Blue, &weights),
+            -weights.han_compensation
+        );
+        let terms = evaluate_breakdown(&initial, PlayerSide::Red, &weights);
+        assert_eq!(terms.compensation, weights.han_compensation);
+    }
+
+    #[test]
+    fn general_safety_rewards_guards_and_covered_file() {
+        let weights = PositionalWeights::default();
+        let mut board = BoardState::empty();
+        let place = |board: &mut BoardState, file, rank, owner, kind| {
+            board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
+        };
+        place(&mut board, 4, 1, PlayerSide::Blue, PieceKind::General);
+        place(&mut board, 4, 8, PlayerSide::Red, PieceKind::General);
+        place(&mut board, 4, 5, PlayerSide::Red, PieceKind::Chariot);
+        let exposed = evaluate_features(&board, PlayerSide::Blue, &weights);
+
+        place(&mut board, 3, 0, PlayerSide::Blue, PieceKind::Guard);
+        place(&mut board, 4, 2, PlayerSide::Blue, PieceKind::Guard);
+        let sheltered = evaluate_features(&board, PlayerSide::Blue, &weights);
+        // The Guard covering the file is pinned by the Chariot.
+        let gained = 2.0 * weights.guard_shield + weights.open_file - weights.pinned;
+        assert!((sheltered - exposed - gained).abs() < 1e-6);
+    }
+}
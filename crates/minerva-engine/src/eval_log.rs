@@ -0,0 +1,90 @@
+//! CSV log of the evaluation terms behind each decision, for tuning weights.
+
+use std::{
+    fs::OpenOptions,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use minerva_types::{
+    game::{Move, TurnContext},
+    MinervaError, Result,
+};
+
+use crate::eval::EvalBreakdown;
+
+pub const EVAL_LOG_HEADER: &str =
+    "ply,side,from,to,material,king_safety,cannon_screens,development,compensation,mobility,score";
+
+/// Appends one row per root decision to a CSV file, writing the header when
+/// the file is new or empty.
+pub struct EvalLog {
+    path: PathBuf,
+    /// Serializes appends from concurrent searches.
+    lock: Mutex<()>,
+}
+
+impl EvalLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Records the terms of the position in `ctx`, from the mover's
+    /// perspective, along with the chosen move and the decision's score.
+    pub fn append(
+        &self,
+        ctx: &TurnContext,
+        best: Option<&Move>,
+        terms: &EvalBreakdown,
+        score: f32,
+    ) -> Result<()> {
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|err| self.error(err))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|err| self.error(err))?;
+        let mut out = String::new();
+        if file.metadata().map_err(|err| self.error(err))?.len() == 0 {
+            out.push_str(EVAL_LOG_HEADER);
+            out.push('\n');
+        }
+        let (from, to) = best.map_or_else(Default::default, |mv| {
+            (mv.from.to_string(), mv.to.to_string())
+        });
+        out.push_str(&format!(
+            "{},{:?},{from},{to},{},{},{},{},{},{},{score}\n",
+            ctx.snapshot.ply,
+            ctx.side,
+            terms.material,
+            terms.king_safety,
+            terms.cannon_screens,
+            terms.development,
+            terms.compensation,
+            terms.mobility,
+        ));
+        file.write_all(out.as_bytes())
+            .map_err(|err| self.error(err))
+    }
+
+    fn error(&self, err: std::io::Error) -> MinervaError {
+        MinervaError::Engine(format!(
+            "failed to write eval log {}: {err}",
+            self.path.display()
+        ))
+    }
+}
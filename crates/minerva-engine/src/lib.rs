@@ -1,31 +1,116 @@
 //! Search and evaluation engine abstraction.
 
-use std::cmp::Ordering;
+mod eval;
+mod eval_log;
+mod pins;
+mod random;
+
+use std::{cmp::Ordering, collections::HashSet, path::PathBuf};
 
 use async_trait::async_trait;
 use minerva_types::{
-    board::{BoardState, Piece, PieceKind, PlayerSide, Square},
-    game::{EngineDecision, Move, MoveCandidate, TurnContext},
+    board::{BoardState, PlayerSide, Square},
+    config::{PositionalWeights, MAX_SKILL_LEVEL},
+    game::{EngineDecision, GameResult, Move, MoveCandidate, TurnContext, WinReason},
     MinervaError, Result,
 };
 use tokio::time::{sleep, Duration};
-use tracing::info;
+use tracing::{debug, info, warn};
+
+pub use eval::{evaluate, evaluate_breakdown, evaluate_features, EvalBreakdown};
+pub use eval_log::{EvalLog, EVAL_LOG_HEADER};
+pub use pins::{pinned_pieces, REJECT_PINNED};
+pub use random::RandomEngine;
 
 #[async_trait]
 pub trait GameEngine: Send + Sync {
     async fn warm_up(&mut self) -> Result<()>;
     async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision>;
+
+    /// Stops a search that ran past its deadline and returns the best
+    /// decision found so far, if the engine keeps one.
+    fn cancel(&self) -> Option<EngineDecision> {
+        None
+    }
 }
 
 /// Simple deterministic engine focusing on basic move generation.
-pub struct RuleBasedEngine;
+pub struct RuleBasedEngine {
+    weights: PositionalWeights,
+    skill_level: u8,
+    seed: u64,
+    eval_log: Option<EvalLog>,
+}
+
+impl Default for RuleBasedEngine {
+    fn default() -> Self {
+        Self {
+            weights: PositionalWeights::default(),
+            skill_level: MAX_SKILL_LEVEL,
+            seed: 0,
+            eval_log: None,
+        }
+    }
+}
 
 impl RuleBasedEngine {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Uses `weights` for the positional part of the evaluation.
+    pub fn with_weights(mut self, weights: PositionalWeights) -> Self {
+        self.weights = weights;
+        self
+    }
+
+    /// Plays at `level` (clamped to [`MAX_SKILL_LEVEL`]): below the maximum,
+    /// each move score gets noise derived from `seed`, so weaker levels
+    /// occasionally prefer a worse move but replay identically.
+    pub fn with_skill(mut self, level: u8, seed: u64) -> Self {
+        self.skill_level = level.min(MAX_SKILL_LEVEL);
+        self.seed = seed;
+        self
+    }
+
+    /// Appends each decision's evaluation terms to the CSV file at `path`
+    /// (see [`EvalLog`]).
+    pub fn with_eval_log(mut self, path: impl Into<PathBuf>) -> Self {
+        self.eval_log = Some(EvalLog::new(path));
+        self
+    }
+
+    fn add_skill_noise(&self, board: &BoardState, candidates: &mut [MoveCandidate]) {
+        let weakness = f32::from(MAX_SKILL_LEVEL - self.skill_level) / f32::from(MAX_SKILL_LEVEL);
+        if weakness == 0.0 {
+            return;
+        }
+        let amplitude = weakness * MAX_SKILL_NOISE;
+        let position = board.position_hash();
+        for candidate in candidates {
+            let mv = &candidate.mv;
+            let key = [mv.from.file, mv.from.rank, mv.to.file, mv.to.rank]
+                .into_iter()
+                .fold(self.seed ^ position, |acc, byte| {
+                    splitmix(acc ^ u64::from(byte))
+                });
+            let unit = (key >> 11) as f32 / (1u64 << 53) as f32;
+            candidate.score += amplitude * (2.0 * unit - 1.0);
+            candidate.mv.confidence = Some(candidate.score);
+        }
     }
 }
 
+/// Largest score offset added at skill level 0, in soldier values.
+const MAX_SKILL_NOISE: f32 = 3.0;
+
+fn splitmix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
 #[async_trait]
 impl GameEngine for RuleBasedEngine {
     async fn warm_up(&mut self) -> Result<()> {
@@ -35,9 +120,28 @@ impl GameEngine for RuleBasedEngine {
     }
 
     async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
-        let mut candidates = generate_candidates(&ctx.snapshot.board, ctx.side);
+        let (legal, reject_reasons) = pins::legal_moves_with_rejects(&ctx.snapshot.board, ctx.side);
+        for (mv, reason) in &reject_reasons {
+            debug!(target: "minerva_engine::rejected", "rejected {mv}: {reason}");
+        }
+        let mut candidates = scored_candidates(&ctx.snapshot.board, ctx.side, legal, &self.weights);
+        self.add_skill_noise(&ctx.snapshot.board, &mut candidates);
         candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
         let best_move = candidates.first().map(|c| c.mv.clone());
+        let result = terminal_result(&ctx.snapshot.board, ctx.side, candidates.is_empty());
+        let (score, gain) = decision_score(
+            &ctx.snapshot.board,
+            ctx.side,
+            best_move.as_ref(),
+            result,
+            &self.weights,
+        );
+        if let Some(log) = &self.eval_log {
+            let terms = evaluate_breakdown(&ctx.snapshot.board, ctx.side, &self.weights);
+            if let Err(err) = log.append(ctx, best_move.as_ref(), &terms, score) {
+                warn!("평가 로그 기록 실패: {err}");
+            }
+        }
 
         Ok(EngineDecision {
             best_move,
@@ -45,238 +149,289 @@ impl GameEngine for RuleBasedEngine {
             searched_nodes: 0,
             depth: 1,
             duration_ms: 5,
+            score,
+            gain,
+            result,
+            reject_reasons,
         })
     }
 }
 
-fn generate_candidates(board: &BoardState, side: PlayerSide) -> Vec<MoveCandidate> {
-    let mut moves = Vec::new();
-
-    for rank in 0..board.height {
-        for file in 0..board.width {
-            let square = Square::new(file, rank);
-            if let Some(piece) = board.piece_at(square) {
-                if piece.owner != side {
-                    continue;
-                }
-                let mut piece_moves = match piece.kind {
-                    PieceKind::Soldier => soldier_moves(board, side, square),
-                    PieceKind::Chariot => rook_like_moves(board, side, square),
-                    PieceKind::Horse => horse_moves(board, side, square),
-                    PieceKind::Cannon => cannon_moves(board, side, square),
-                    PieceKind::Guard | PieceKind::Elephant | PieceKind::General => {
-                        palace_moves(board, side, square, piece.kind)
-                    }
-                };
-                moves.append(&mut piece_moves);
-            }
-        }
-    }
+/// Score assigned to a lost position, from the loser's perspective.
+pub const MATE_SCORE: f32 = 100_000.0;
 
-    if moves.is_empty() {
-        if let Some(pass_move) = default_hold_move(board, side) {
-            moves.push(pass_move);
-        }
-    }
+/// Legal moves for `side`, scored by how much each improves [`evaluate`]
+/// under the default weights.
+pub fn legal_moves(board: &BoardState, side: PlayerSide) -> Vec<MoveCandidate> {
+    scored_candidates(
+        board,
+        side,
+        pins::legal_moves_with_rejects(board, side).0,
+        &PositionalWeights::default(),
+    )
+}
 
+fn scored_candidates(
+    board: &BoardState,
+    side: PlayerSide,
+    moves: Vec<Move>,
+    weights: &PositionalWeights,
+) -> Vec<MoveCandidate> {
+    let baseline = evaluate(board, side, weights);
     moves
+        .into_iter()
+        .map(|mut mv| {
+            let mut next = board.clone();
+            let score = match next.move_piece(mv.from, mv.to) {
+                Ok(_) => evaluate(&next, side, weights) - baseline,
+                Err(_) => f32::MIN,
+            };
+            mv.confidence = Some(score);
+            MoveCandidate {
+                mv,
+                score,
+                depth: 1,
+            }
+        })
+        .collect()
 }
 
-fn soldier_moves(board: &BoardState, side: PlayerSide, from: Square) -> Vec<MoveCandidate> {
-    let mut options = Vec::new();
-    let forward = match side {
-        PlayerSide::Blue => 1,
-        PlayerSide::Red => -1,
-    };
-    if let Some(to) = from.offset(0, forward) {
-        if board.is_empty(to) || board.piece_at(to).map(|p| p.owner != side).unwrap_or(false) {
-            options.push(candidate(from, to, board.piece_at(to)));
-        }
+/// The decision's `score` and `gain`: [`evaluate`] after `best_move` and how
+/// much the move changed it, or the evaluation of `board` itself (no gain)
+/// when there is no move. A decided game scores as lost.
+fn decision_score(
+    board: &BoardState,
+    side: PlayerSide,
+    best_move: Option<&Move>,
+    result: Option<GameResult>,
+    weights: &PositionalWeights,
+) -> (f32, f32) {
+    if result.is_some() {
+        return (-MATE_SCORE, 0.0);
     }
-    // Soldiers can move sideways after crossing river (ranks >=5 for Blue, <=4 for Red).
-    let river_rank = (board.height / 2) as u8;
-    if (side == PlayerSide::Blue && from.rank >= river_rank)
-        || (side == PlayerSide::Red && from.rank <= river_rank.saturating_sub(1))
-    {
-        for df in [-1, 1] {
-            if let Some(to) = from.offset(df, 0) {
-                if board.is_empty(to)
-                    || board.piece_at(to).map(|p| p.owner != side).unwrap_or(false)
-                {
-                    options.push(candidate(from, to, board.piece_at(to)));
-                }
-            }
+    let before = evaluate(board, side, weights);
+    let mut next = board.clone();
+    match best_move.map(|mv| next.move_piece(mv.from, mv.to)) {
+        Some(Ok(_)) => {
+            let after = evaluate(&next, side, weights);
+            (after, after - before)
         }
+        _ => (before, 0.0),
     }
-    options
 }
 
-fn rook_like_moves(board: &BoardState, side: PlayerSide, from: Square) -> Vec<MoveCandidate> {
-    let mut options = Vec::new();
-    let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
-    for (df, dr) in directions {
-        let mut current = from;
-        while let Some(next) = current.offset(df, dr) {
-            if let Some(piece) = board.piece_at(next) {
-                if piece.owner != side {
-                    options.push(candidate(from, next, Some(piece)));
-                }
-                break;
-            } else {
-                options.push(candidate(from, next, None));
-                current = next;
-            }
-        }
+/// Best one-ply move by the default evaluation, for when the engine cannot
+/// answer in time.
+pub fn emergency_decision(board: &BoardState, side: PlayerSide) -> EngineDecision {
+    let mut candidates = legal_moves(board, side);
+    candidates.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+    let result = terminal_result(board, side, candidates.is_empty());
+    let best_move = candidates.first().map(|c| c.mv.clone());
+    let (score, gain) = decision_score(
+        board,
+        side,
+        best_move.as_ref(),
+        result,
+        &PositionalWeights::default(),
+    );
+    EngineDecision {
+        best_move,
+        candidates,
+        searched_nodes: 0,
+        depth: 1,
+        duration_ms: 0,
+        score,
+        gain,
+        result,
+        reject_reasons: Vec::new(),
     }
-    options
 }
 
-fn cannon_moves(board: &BoardState, side: PlayerSide, from: Square) -> Vec<MoveCandidate> {
-    let mut options = Vec::new();
-    let directions = [(1, 0), (-1, 0), (0, 1), (0, -1)];
-    for (df, dr) in directions {
-        let mut current = from;
-        let mut screen_found = false;
-        while let Some(next) = current.offset(df, dr) {
-            if let Some(piece) = board.piece_at(next) {
-                if !screen_found {
-                    screen_found = true;
-                } else {
-                    if piece.owner != side {
-                        options.push(candidate(from, next, Some(piece)));
-                    }
-                    break;
-                }
-            } else if !screen_found {
-                options.push(candidate(from, next, None));
-            }
-            current = next;
-        }
-    }
-    options
+/// Squares `side` attacks, e.g. for highlighting threats (see
+/// [`BoardState::attacked_squares`]).
+pub fn attacked_squares(board: &BoardState, side: PlayerSide) -> HashSet<Square> {
+    board.attacked_squares(side)
 }
 
-fn horse_moves(board: &BoardState, side: PlayerSide, from: Square) -> Vec<MoveCandidate> {
-    let mut options = Vec::new();
-    let patterns = [
-        ((1, 0), (1, 1)),
-        ((1, 0), (1, -1)),
-        ((-1, 0), (-1, 1)),
-        ((-1, 0), (-1, -1)),
-        ((0, 1), (1, 1)),
-        ((0, 1), (-1, 1)),
-        ((0, -1), (1, -1)),
-        ((0, -1), (-1, -1)),
-    ];
-    for (leg, dest) in patterns {
-        if let Some(block) = from.offset(leg.0, leg.1) {
-            if board.is_empty(block) {
-                if let Some(to) = block.offset(dest.0, dest.1) {
-                    if board.is_empty(to)
-                        || board.piece_at(to).map(|p| p.owner != side).unwrap_or(false)
-                    {
-                        options.push(candidate(from, to, board.piece_at(to)));
-                    }
-                }
-            }
-        }
-    }
-    options
+/// Whether any opposing piece attacks `side`'s General.
+pub fn is_in_check(board: &BoardState, side: PlayerSide) -> bool {
+    board.is_in_check(side)
 }
 
-fn palace_moves(
-    board: &BoardState,
-    side: PlayerSide,
-    from: Square,
-    kind: PieceKind,
-) -> Vec<MoveCandidate> {
-    let palace_files = [3u8, 4, 5];
-    let palace_ranks = match side {
-        PlayerSide::Blue => [0u8, 1, 2],
-        PlayerSide::Red => [board.height - 1, board.height - 2, board.height - 3],
+/// Outcome for `side` to move, if the game is already over.
+pub fn game_result(board: &BoardState, side: PlayerSide) -> Option<GameResult> {
+    board.game_result(side)
+}
+
+fn terminal_result(board: &BoardState, side: PlayerSide, no_moves: bool) -> Option<GameResult> {
+    let reason = if board.find_general(side).is_none() {
+        WinReason::GeneralCaptured
+    } else if no_moves {
+        WinReason::NoLegalMoves
+    } else {
+        return None;
     };
+    Some(GameResult::Win {
+        winner: side.opponent(),
+        reason,
+    })
+}
 
-    let mut options = Vec::new();
-    let directions = match kind {
-        PieceKind::Guard | PieceKind::General => {
-            vec![
-                (1, 0),
-                (-1, 0),
-                (0, 1),
-                (0, -1),
-                (1, 1),
-                (-1, 1),
-                (1, -1),
-                (-1, -1),
-            ]
-        }
-        PieceKind::Elephant => vec![(2, 2), (2, -2), (-2, 2), (-2, -2)],
-        _ => vec![],
+pub fn engine_error(message: impl Into<String>) -> MinervaError {
+    MinervaError::Engine(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::{
+        board::{Piece, PieceKind, Square},
+        game::GameSnapshot,
     };
 
-    for (df, dr) in directions {
-        if let Some(to) = from.offset(df, dr) {
-            if palace_files.contains(&to.file) && palace_ranks.contains(&to.rank) {
-                if board.is_empty(to)
-                    || board.piece_at(to).map(|p| p.owner != side).unwrap_or(false)
-                {
-                    options.push(candidate(from, to, board.piece_at(to)));
-                }
-            }
-        }
+    fn place(board: &mut BoardState, file: u8, rank: u8, owner: PlayerSide, kind: PieceKind) {
+        board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
     }
-    options
-}
 
-fn candidate(from: Square, to: Square, capture: Option<Piece>) -> MoveCandidate {
-    let score = capture.map(piece_value).unwrap_or(0.1);
-    MoveCandidate {
-        mv: Move {
-            from,
-            to,
-            promotion: None,
-            confidence: Some(score as f32),
-        },
-        score,
-        depth: 1,
+    /// Blue's General is boxed into the palace corner without being in check.
+    fn blue_without_moves() -> BoardState {
+        let mut board = BoardState::empty();
+        place(&mut board, 3, 0, PlayerSide::Blue, PieceKind::General);
+        place(&mut board, 5, 8, PlayerSide::Red, PieceKind::General);
+        place(&mut board, 8, 1, PlayerSide::Red, PieceKind::Chariot);
+        place(&mut board, 4, 9, PlayerSide::Red, PieceKind::Chariot);
+        board
     }
-}
 
-fn piece_value(piece: Piece) -> f32 {
-    match piece.kind {
-        PieceKind::General => 1000.0,
-        PieceKind::Guard => 3.0,
-        PieceKind::Elephant => 5.0,
-        PieceKind::Horse => 7.0,
-        PieceKind::Chariot => 13.0,
-        PieceKind::Cannon => 9.0,
-        PieceKind::Soldier => 1.0,
+    #[test]
+    fn no_legal_moves_without_check_is_a_loss() {
+        let board = blue_without_moves();
+        assert!(!is_in_check(&board, PlayerSide::Blue));
+        assert!(legal_moves(&board, PlayerSide::Blue).is_empty());
+        assert_eq!(
+            game_result(&board, PlayerSide::Blue),
+            Some(GameResult::Win {
+                winner: PlayerSide::Red,
+                reason: WinReason::NoLegalMoves,
+            })
+        );
+        assert_eq!(game_result(&board, PlayerSide::Red), None);
+    }
+
+    #[tokio::test]
+    async fn decisions_append_their_terms_to_the_eval_log() {
+        let dir = std::env::temp_dir().join(format!("minerva-eval-log-{}", std::process::id()));
+        let path = dir.join("eval.csv");
+        let engine = RuleBasedEngine::new().with_eval_log(&path);
+        let mut board = BoardState::initial();
+        board.set_piece(Square::new(0, 6), None);
+        let ctx = TurnContext::new(
+            GameSnapshot {
+                board,
+                ..GameSnapshot::default()
+            },
+            PlayerSide::Blue,
+        );
+
+        let decision = engine.evaluate_position(&ctx).await.unwrap();
+        engine.evaluate_position(&ctx).await.unwrap();
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<_> = log.lines().collect();
+        assert_eq!(lines.len(), 3, "{log}");
+        assert_eq!(lines[0], EVAL_LOG_HEADER);
+        let row: Vec<_> = lines[1].split(',').collect();
+        let best = decision.best_move.unwrap();
+        assert_eq!(
+            row[..4],
+            ["0", "Blue", &best.from.to_string(), &best.to.to_string()]
+        );
+        // Red is a soldier short.
+        assert_eq!(row[4], "1");
+        assert_eq!(row[8], "-1.5");
+        assert_eq!(row[10], decision.score.to_string());
+        std::fs::remove_dir_all(&dir).unwrap();
     }
-}
 
-fn default_hold_move(board: &BoardState, side: PlayerSide) -> Option<MoveCandidate> {
-    for rank in 0..board.height {
-        for file in 0..board.width {
-            let square = Square::new(file, rank);
-            if let Some(piece) = board.piece_at(square) {
-                if piece.owner == side {
-                    return Some(MoveCandidate {
-                        mv: Move {
-                            from: square,
-                            to: square,
-                            promotion: None,
-                            confidence: Some(0.0),
-                        },
-                        score: 0.0,
-                        depth: 0,
-                    });
+    #[tokio::test]
+    async fn decision_score_is_the_evaluation_after_the_move() {
+        // Blue is a chariot down; no opening move wins it back.
+        let mut board = BoardState::initial();
+        board.set_piece(Square::new(0, 0), None);
+        let engine = RuleBasedEngine::new();
+        let decision = engine
+            .evaluate_position(&TurnContext::new(
+                GameSnapshot {
+                    board: board.clone(),
+                    ..GameSnapshot::default()
+                },
+                PlayerSide::Blue,
+            ))
+            .await
+            .unwrap();
+
+        let weights = PositionalWeights::default();
+        let best = decision.best_move.unwrap();
+        let mut next = board.clone();
+        next.move_piece(best.from, best.to).unwrap();
+        assert_eq!(decision.score, evaluate(&next, PlayerSide::Blue, &weights));
+        assert!(decision.score < -10.0, "{}", decision.score);
+        let before = evaluate(&board, PlayerSide::Blue, &weights);
+        assert_eq!(decision.gain, decision.score - before);
+        assert!(decision.gain.abs() < 1.0, "{}", decision.gain);
+    }
+
+    #[tokio::test]
+    async fn engine_scores_stalemated_side_as_lost() {
+        let engine = RuleBasedEngine::new();
+        let snapshot = GameSnapshot {
+            board: blue_without_moves(),
+            ..GameSnapshot::default()
+        };
+        let decision = engine
+            .evaluate_position(&TurnContext {
+                snapshot,
+                side: PlayerSide::Blue,
+                remaining_ms: None,
+                increment_ms: None,
+            })
+            .await
+            .unwrap();
+        assert!(decision.best_move.is_none());
+        assert!(decision.score < 0.0);
+        assert_eq!(
+            decision.result.and_then(|r| r.winner()),
+            Some(PlayerSide::Red)
+        );
+    }
+
+    #[tokio::test]
+    async fn low_skill_varies_the_chosen_move() {
+        async fn best_moves(level: u8) -> Vec<Move> {
+            let mut moves = Vec::new();
+            for seed in 0..50 {
+                let engine = RuleBasedEngine::new().with_skill(level, seed);
+                let decision = engine
+                    .evaluate_position(&TurnContext {
+                        snapshot: GameSnapshot::default(),
+                        side: PlayerSide::Blue,
+                        remaining_ms: None,
+                        increment_ms: None,
+                    })
+                    .await
+                    .unwrap();
+                let best = decision.best_move.unwrap();
+                if !moves
+                    .iter()
+                    .any(|mv: &Move| mv.from == best.from && mv.to == best.to)
+                {
+                    moves.push(best);
                 }
             }
+            moves
         }
-    }
-    None
-}
 
-pub fn engine_error(message: impl Into<String>) -> MinervaError {
-    MinervaError::Engine(message.into())
+        assert_eq!(best_moves(MAX_SKILL_LEVEL).await.len(), 1);
+        assert!(best_moves(0).await.len() > 1);
+    }
 }
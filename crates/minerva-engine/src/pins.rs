@@ -0,0 +1,190 @@
+//! Pieces pinned against their own General.
+
+use std::collections::{HashMap, HashSet};
+
+use minerva_types::{
+    board::{BoardState, Piece, PieceKind, PlayerSide, Square},
+    game::Move,
+    rules::REJECT_SELF_CHECK,
+};
+
+use crate::eval::ORTHOGONAL;
+
+/// Reason recorded for moves that take a pinned piece off its pin line.
+pub const REJECT_PINNED: &str = "pinned";
+
+/// Pieces of `side` that would expose their General if they left the line
+/// between it and an enemy Chariot, or one of the two screens before an
+/// enemy Cannon.
+pub fn pinned_pieces(board: &BoardState, side: PlayerSide) -> HashSet<Square> {
+    pins(board, side).into_keys().collect()
+}
+
+/// Each pinned piece with the direction from its General toward it.
+fn pins(board: &BoardState, side: PlayerSide) -> HashMap<Square, (i8, i8)> {
+    let mut pins = HashMap::new();
+    let Some(general) = board.find_general(side) else {
+        return pins;
+    };
+    for (df, dr) in ORTHOGONAL {
+        let ray = nearest_pieces(board, general, df, dr);
+        match ray[..] {
+            [(square, piece), (_, attacker), ..]
+                if piece.owner == side
+                    && attacker.owner != side
+                    && attacker.kind == PieceKind::Chariot =>
+            {
+                pins.insert(square, (df, dr));
+            }
+            [(first, first_piece), (second, second_piece), (_, attacker)]
+                if attacker.owner != side && attacker.kind == PieceKind::Cannon =>
+            {
+                // Either screen leaving lets the other one serve alone.
+                for (square, piece, other) in [
+                    (first, first_piece, second_piece),
+                    (second, second_piece, first_piece),
+                ] {
+                    if piece.owner == side && other.kind != PieceKind::Cannon {
+                        pins.insert(square, (df, dr));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    pins
+}
+
+/// Up to the first three pieces along (`df`, `dr`) from `from`.
+fn nearest_pieces(board: &BoardState, from: Square, df: i8, dr: i8) -> Vec<(Square, Piece)> {
+    let mut found = Vec::new();
+    let mut square = from;
+    while let Some(next) = square.offset(df, dr) {
+        if board.index(next).is_none() || found.len() == 3 {
+            break;
+        }
+        if let Some(piece) = board.piece_at(next) {
+            found.push((next, piece));
+        }
+        square = next;
+    }
+    found
+}
+
+/// Whether `to` lies on the ray from `general` in direction (`df`, `dr`).
+fn on_pin_line(general: Square, (df, dr): (i8, i8), to: Square) -> bool {
+    let file = i16::from(to.file) - i16::from(general.file);
+    let rank = i16::from(to.rank) - i16::from(general.rank);
+    match (df, dr) {
+        (0, dr) => file == 0 && rank.signum() == i16::from(dr),
+        (df, _) => rank == 0 && file.signum() == i16::from(df),
+    }
+}
+
+/// Legal moves for `side` plus the rejected pseudo-legal ones. Moves taking a
+/// pinned piece off its pin line are rejected without being played out; the
+/// rest are checked for self-check as in [`BoardState::legal_moves_with_rejects`].
+pub(crate) fn legal_moves_with_rejects(
+    board: &BoardState,
+    side: PlayerSide,
+) -> (Vec<Move>, Vec<(Move, String)>) {
+    let pins = pins(board, side);
+    let general = board.find_general(side);
+    let mut legal = Vec::new();
+    let mut rejected = Vec::new();
+    for mv in board.pseudo_legal_moves(side) {
+        let leaves_pin = match (pins.get(&mv.from), general) {
+            (Some(&direction), Some(general)) => !on_pin_line(general, direction, mv.to),
+            _ => false,
+        };
+        if leaves_pin {
+            rejected.push((mv, REJECT_PINNED.to_string()));
+            continue;
+        }
+        let mut next = board.clone();
+        match next.move_piece(mv.from, mv.to) {
+            Err(err) => rejected.push((mv, err)),
+            Ok(_) if next.is_in_check(side) => rejected.push((mv, REJECT_SELF_CHECK.to_string())),
+            Ok(_) => legal.push(mv),
+        }
+    }
+    (legal, rejected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::config::PositionalWeights;
+
+    use crate::eval::evaluate_features;
+
+    fn place(board: &mut BoardState, file: u8, rank: u8, owner: PlayerSide, kind: PieceKind) {
+        board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
+    }
+
+    fn sorted(moves: Vec<Move>) -> Vec<(Square, Square)> {
+        let mut moves: Vec<_> = moves.into_iter().map(|mv| (mv.from, mv.to)).collect();
+        moves.sort_by_key(|&(from, to)| (from.file, from.rank, to.file, to.rank));
+        moves
+    }
+
+    #[test]
+    fn chariot_and_cannon_pin_pieces_to_the_general() {
+        let mut board = BoardState::empty();
+        place(&mut board, 4, 1, PlayerSide::Blue, PieceKind::General);
+        place(&mut board, 3, 8, PlayerSide::Red, PieceKind::General);
+        place(&mut board, 4, 3, PlayerSide::Blue, PieceKind::Horse);
+        place(&mut board, 4, 7, PlayerSide::Red, PieceKind::Chariot);
+        let horse = Square::new(4, 3);
+
+        assert_eq!(
+            pinned_pieces(&board, PlayerSide::Blue),
+            HashSet::from([horse])
+        );
+        let (legal, rejected) = legal_moves_with_rejects(&board, PlayerSide::Blue);
+        assert!(legal.iter().all(|mv| mv.from != horse));
+        assert!(rejected
+            .iter()
+            .any(|(mv, reason)| mv.from == horse && reason == REJECT_PINNED));
+        assert_eq!(sorted(legal), sorted(board.legal_moves(PlayerSide::Blue)));
+
+        let weights = PositionalWeights::default();
+        let unweighted = PositionalWeights {
+            pinned: 0.0,
+            ..weights
+        };
+        let penalty = evaluate_features(&board, PlayerSide::Blue, &unweighted)
+            - evaluate_features(&board, PlayerSide::Blue, &weights);
+        assert!((penalty - weights.pinned).abs() < 1e-6);
+
+        // Behind a second screen a Cannon pins both; the Chariot still pins
+        // along the rank.
+        place(&mut board, 4, 7, PlayerSide::Red, PieceKind::Cannon);
+        place(&mut board, 4, 4, PlayerSide::Blue, PieceKind::Soldier);
+        place(&mut board, 6, 1, PlayerSide::Blue, PieceKind::Chariot);
+        place(&mut board, 8, 1, PlayerSide::Red, PieceKind::Chariot);
+        assert_eq!(
+            pinned_pieces(&board, PlayerSide::Blue),
+            HashSet::from([horse, Square::new(4, 4), Square::new(6, 1)])
+        );
+        let (legal, _) = legal_moves_with_rejects(&board, PlayerSide::Blue);
+        assert_eq!(sorted(legal), sorted(board.legal_moves(PlayerSide::Blue)));
+    }
+
+    #[test]
+    fn a_lone_cannon_screen_is_not_pinned() {
+        let mut board = BoardState::empty();
+        place(&mut board, 4, 1, PlayerSide::Blue, PieceKind::General);
+        place(&mut board, 3, 8, PlayerSide::Red, PieceKind::General);
+        place(&mut board, 4, 3, PlayerSide::Blue, PieceKind::Horse);
+        place(&mut board, 4, 7, PlayerSide::Red, PieceKind::Cannon);
+        assert!(pinned_pieces(&board, PlayerSide::Blue).is_empty());
+
+        // A Cannon cannot jump a Cannon, so only the other screen is pinned.
+        place(&mut board, 4, 4, PlayerSide::Blue, PieceKind::Cannon);
+        assert_eq!(
+            pinned_pieces(&board, PlayerSide::Blue),
+            HashSet::from([Square::new(4, 4)])
+        );
+    }
+}
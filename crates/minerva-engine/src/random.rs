@@ -0,0 +1,164 @@
+//! Engine that plays random legal moves, for self-play and stress tests.
+
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+use minerva_types::{
+    config::PositionalWeights,
+    game::{EngineDecision, MoveCandidate, TurnContext},
+    Result,
+};
+
+use crate::{decision_score, pins, scored_candidates, splitmix, terminal_result, GameEngine};
+
+/// Picks a legal move at random; the same seed replays the same choices.
+///
+/// By default every legal move is equally likely. [`RandomEngine::weighted`]
+/// instead draws moves in proportion to `exp(score)`, with scores from the
+/// same evaluation [`crate::RuleBasedEngine`] uses, so better moves come up
+/// more often.
+pub struct RandomEngine {
+    seed: u64,
+    weights: Option<PositionalWeights>,
+}
+
+impl RandomEngine {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            weights: None,
+        }
+    }
+
+    /// Weights the draw by each move's score under `weights`.
+    pub fn weighted(mut self, weights: PositionalWeights) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Index into `candidates` drawn from `unit` in [0, 1).
+    fn pick(&self, candidates: &[MoveCandidate], unit: f32) -> usize {
+        if self.weights.is_none() {
+            return ((unit * candidates.len() as f32) as usize).min(candidates.len() - 1);
+        }
+        let top = candidates.iter().map(|c| c.score).fold(f32::MIN, f32::max);
+        let odds: Vec<f32> = candidates.iter().map(|c| (c.score - top).exp()).collect();
+        let mut target = unit * odds.iter().sum::<f32>();
+        for (index, odd) in odds.iter().enumerate() {
+            if target < *odd {
+                return index;
+            }
+            target -= odd;
+        }
+        candidates.len() - 1
+    }
+}
+
+#[async_trait]
+impl GameEngine for RandomEngine {
+    async fn warm_up(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+        let board = &ctx.snapshot.board;
+        let (legal, reject_reasons) = pins::legal_moves_with_rejects(board, ctx.side);
+        let weights = self.weights.unwrap_or_default();
+        let mut candidates = scored_candidates(board, ctx.side, legal, &weights);
+        let result = terminal_result(board, ctx.side, candidates.is_empty());
+        let key = splitmix(self.seed ^ board.position_hash() ^ u64::from(ctx.snapshot.ply));
+        let unit = (key >> 11) as f32 / (1u64 << 53) as f32;
+        if !candidates.is_empty() {
+            let chosen = self.pick(&candidates, unit);
+            candidates.swap(0, chosen);
+            candidates[1..]
+                .sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+        }
+        let best_move = candidates.first().map(|c| c.mv.clone());
+        let (score, gain) = decision_score(board, ctx.side, best_move.as_ref(), result, &weights);
+
+        Ok(EngineDecision {
+            best_move,
+            candidates,
+            searched_nodes: 0,
+            depth: 1,
+            duration_ms: 0,
+            score,
+            gain,
+            result,
+            reject_reasons,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::{
+        board::{BoardState, Piece, PieceKind, PlayerSide, Square},
+        game::{GameSnapshot, Move},
+    };
+
+    async fn choices(engine: &RandomEngine, board: &BoardState) -> Vec<(Square, Square)> {
+        let mut moves = Vec::new();
+        for ply in 0..40 {
+            let decision = engine
+                .evaluate_position(&TurnContext {
+                    snapshot: GameSnapshot {
+                        board: board.clone(),
+                        ply,
+                        ..GameSnapshot::default()
+                    },
+                    side: PlayerSide::Blue,
+                    remaining_ms: None,
+                    increment_ms: None,
+                })
+                .await
+                .unwrap();
+            let mv: Move = decision.best_move.unwrap();
+            moves.push((mv.from, mv.to));
+        }
+        moves
+    }
+
+    #[tokio::test]
+    async fn random_moves_are_legal_and_reproducible() {
+        let board = BoardState::initial();
+        let legal: Vec<_> = board
+            .legal_moves(PlayerSide::Blue)
+            .into_iter()
+            .map(|mv| (mv.from, mv.to))
+            .collect();
+        let first = choices(&RandomEngine::new(7), &board).await;
+        assert!(first.iter().all(|mv| legal.contains(mv)));
+        assert_eq!(first, choices(&RandomEngine::new(7), &board).await);
+        assert_ne!(first, choices(&RandomEngine::new(8), &board).await);
+        let mut distinct = first.clone();
+        distinct.sort_by_key(|(from, to)| (from.file, from.rank, to.file, to.rank));
+        distinct.dedup();
+        assert!(distinct.len() > 10);
+    }
+
+    #[tokio::test]
+    async fn weighted_draws_favor_better_moves() {
+        let mut board = BoardState::initial();
+        // A Red Chariot hanging in front of Blue's Chariot.
+        board.set_piece(
+            Square::new(0, 5),
+            Some(Piece {
+                owner: PlayerSide::Red,
+                kind: PieceKind::Chariot,
+            }),
+        );
+        board.set_piece(Square::new(0, 3), None);
+        let capture = (Square::new(0, 0), Square::new(0, 5));
+        let count =
+            |moves: Vec<(Square, Square)>| moves.iter().filter(|&&mv| mv == capture).count();
+
+        let uniform = count(choices(&RandomEngine::new(3), &board).await);
+        let weighted = RandomEngine::new(3).weighted(PositionalWeights::default());
+        let weighted = count(choices(&weighted, &board).await);
+        assert!(weighted > 30, "{weighted}");
+        assert!(uniform < 10, "{uniform}");
+    }
+}
@@ -1,17 +1,27 @@
 //! Networking facade for real-time event publication.
 
+mod status;
+mod websocket;
+
 use async_trait::async_trait;
 use futures::{stream::BoxStream, StreamExt};
-use minerva_types::{events::SystemEvent, Result};
+use minerva_types::{events::SystemEvent, MinervaError, Result};
+pub use status::{EventSource, StatusHandle, StatusServer};
 use tokio::sync::broadcast;
 use tokio_stream::wrappers::BroadcastStream;
 use tracing::info;
+pub use websocket::{WebSocketServer, SHUTDOWN_GRACE};
 
 #[async_trait]
 pub trait RealtimeServer: Send + Sync {
     async fn run(&self) -> Result<()>;
     async fn publish(&self, event: SystemEvent) -> Result<()>;
     fn subscribe(&self) -> BoxStream<'static, SystemEvent>;
+
+    /// Notifies connected clients and closes their connections.
+    async fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// Simple in-process server backed by a broadcast channel.
@@ -25,6 +35,10 @@ impl LocalServer {
         let (tx, _) = broadcast::channel(capacity);
         Self { tx }
     }
+
+    pub(crate) fn sender(&self) -> &broadcast::Sender<SystemEvent> {
+        &self.tx
+    }
 }
 
 #[async_trait]
@@ -45,3 +59,7 @@ impl RealtimeServer for LocalServer {
             .boxed()
     }
 }
+
+pub fn network_error(message: impl Into<String>) -> MinervaError {
+    MinervaError::Network(message.into())
+}
@@ -0,0 +1,407 @@
+//! Read-only HTTP endpoint that reports the latest status as JSON, for
+//! health checks with plain `curl`, and answers event queries.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use minerva_types::{
+    events::{EventFilter, EventPayload, SystemEvent},
+    telemetry::{ControllerSummary, HistoryPosition, StatusReport},
+    Result,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle,
+};
+use tracing::{info, warn};
+
+use crate::network_error;
+
+/// Largest request head read before answering; anything longer is cut off.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+
+/// Shared [`StatusReport`], updated from published events and served by
+/// [`StatusServer`].
+#[derive(Debug, Clone, Default)]
+pub struct StatusHandle {
+    report: Arc<RwLock<StatusReport>>,
+}
+
+impl StatusHandle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Folds the lifecycle phase, orchestrator state, board ply, or engine
+    /// depth/score carried by `event` into the report.
+    pub fn observe(&self, event: &SystemEvent) {
+        self.update(|report| match &event.payload {
+            EventPayload::Lifecycle(lifecycle) => report.phase = Some(lifecycle.phase),
+            EventPayload::State(change) => report.state = Some(change.to),
+            EventPayload::Board(board) => report.ply = Some(board.snapshot.ply),
+            EventPayload::Engine(engine) => {
+                report.engine_depth = Some(engine.metrics.depth);
+                report.engine_score = engine.score;
+            }
+            _ => {}
+        });
+    }
+
+    pub fn set_controller(&self, summary: ControllerSummary) {
+        self.update(|report| report.controller = Some(summary));
+    }
+
+    /// Replaces the reported position history.
+    pub fn set_history(&self, history: Vec<HistoryPosition>) {
+        self.update(|report| report.history = history);
+    }
+
+    /// Records the interval until the next opponent-wait poll.
+    pub fn set_poll_interval(&self, interval_ms: u64) {
+        self.update(|report| report.poll_interval_ms = Some(interval_ms));
+    }
+
+    pub fn report(&self) -> StatusReport {
+        self.report
+            .read()
+            .map(|report| report.clone())
+            .unwrap_or_default()
+    }
+
+    fn update(&self, apply: impl FnOnce(&mut StatusReport)) {
+        if let Ok(mut report) = self.report.write() {
+            apply(&mut report);
+            report.updated_at = Some(Utc::now());
+        }
+    }
+}
+
+/// Recorded events that `GET /events/query` is answered from.
+#[async_trait]
+pub trait EventSource: Send + Sync {
+    /// Events matching `filter`, newest first.
+    async fn query(&self, filter: EventFilter) -> Vec<SystemEvent>;
+}
+
+/// Answers `GET /status` with the [`StatusHandle`]'s report and, with an
+/// [`EventSource`] attached, `GET /events/query` with the matching events;
+/// every other request gets 404 or 405.
+pub struct StatusServer {
+    listener: TcpListener,
+    local_addr: SocketAddr,
+    status: StatusHandle,
+    events: Option<Arc<dyn EventSource>>,
+}
+
+impl StatusServer {
+    pub async fn bind(bind_addr: &str, port: u16, status: StatusHandle) -> Result<Self> {
+        let addr = format!("{bind_addr}:{port}");
+        let listener = TcpListener::bind(&addr)
+            .await
+            .map_err(|err| network_error(format!("상태 HTTP 바인드 실패({addr}): {err}")))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|err| network_error(format!("상태 HTTP 주소 확인 실패: {err}")))?;
+        Ok(Self {
+            listener,
+            local_addr,
+            status,
+            events: None,
+        })
+    }
+
+    /// Serves `GET /events/query` from `events`. Query parameters map onto
+    /// [`EventFilter`]: `kind` (repeatable), `since` and `until` (RFC 3339),
+    /// `match`, `session`, `text`, and `limit`.
+    pub fn with_events(mut self, events: Arc<dyn EventSource>) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Serves requests in the background until the task is aborted.
+    pub fn spawn(self) -> JoinHandle<()> {
+        info!("상태 HTTP 서버 시작: http://{}/status", self.local_addr);
+        tokio::spawn(async move {
+            loop {
+                match self.listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(serve_request(
+                            stream,
+                            self.status.clone(),
+                            self.events.clone(),
+                        ));
+                    }
+                    Err(err) => warn!("상태 HTTP 연결 수락 실패: {err}"),
+                }
+            }
+        })
+    }
+}
+
+async fn serve_request(
+    mut stream: TcpStream,
+    status: StatusHandle,
+    events: Option<Arc<dyn EventSource>>,
+) {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST_BYTES {
+        match stream.read(&mut buf).await {
+            Ok(0) | Err(_) => break,
+            Ok(read) => head.extend_from_slice(&buf[..read]),
+        }
+    }
+    let request = String::from_utf8_lossy(&head);
+    let mut parts = request.split_whitespace();
+    let method = parts.next();
+    let (path, query) = parts.next().map_or(("", ""), |target| {
+        target.split_once('?').unwrap_or((target, ""))
+    });
+    let (status_line, body) = match (method, path, events) {
+        (Some("GET"), "/status", _) => json_response(&status.report()),
+        (Some("GET"), "/events/query", Some(events)) => match parse_event_query(query) {
+            Ok(filter) => json_response(&events.query(filter).await),
+            Err(err) => ("400 Bad Request", error_body(&err)),
+        },
+        (Some("GET"), _, _) => ("404 Not Found", error_body("not found")),
+        _ => ("405 Method Not Allowed", error_body("method not allowed")),
+    };
+    let response = format!(
+        "HTTP/1.1 {status_line}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    if let Err(err) = stream.write_all(response.as_bytes()).await {
+        warn!("상태 HTTP 응답 실패: {err}");
+    }
+    let _ = stream.shutdown().await;
+}
+
+fn json_response(value: &impl serde::Serialize) -> (&'static str, String) {
+    match serde_json::to_string(value) {
+        Ok(json) => ("200 OK", json),
+        Err(err) => ("500 Internal Server Error", error_body(&err.to_string())),
+    }
+}
+
+fn error_body(message: &str) -> String {
+    serde_json::json!({ "error": message }).to_string()
+}
+
+/// Builds an [`EventFilter`] from a `/events/query` query string.
+fn parse_event_query(query: &str) -> std::result::Result<EventFilter, String> {
+    let mut filter = EventFilter::new();
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = percent_decode(value)?;
+        let time = |value: &str| {
+            DateTime::parse_from_rfc3339(value)
+                .map(|time| time.with_timezone(&Utc))
+                .map_err(|err| format!("invalid {key} '{value}': {err}"))
+        };
+        let id = |value: &str| {
+            value
+                .parse()
+                .map_err(|err| format!("invalid {key} '{value}': {err}"))
+        };
+        match key {
+            "kind" => filter.kinds.push(value.parse()?),
+            "since" => filter.since = Some(time(&value)?),
+            "until" => filter.until = Some(time(&value)?),
+            "match" => filter.match_id = Some(id(&value)?),
+            "session" => filter.session_id = Some(id(&value)?),
+            "text" => filter.text = Some(value),
+            "limit" => {
+                filter.limit = Some(
+                    value
+                        .parse()
+                        .map_err(|err| format!("invalid limit '{value}': {err}"))?,
+                )
+            }
+            _ => return Err(format!("unknown query parameter '{key}'")),
+        }
+    }
+    Ok(filter)
+}
+
+/// Decodes `%XX` escapes and `+` (space) in a query string value.
+fn percent_decode(value: &str) -> std::result::Result<String, String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut input = value.bytes();
+    while let Some(byte) = input.next() {
+        bytes.push(match byte {
+            b'+' => b' ',
+            b'%' => {
+                let hex = [input.next(), input.next()];
+                let hex = match hex {
+                    [Some(high), Some(low)] => String::from_utf8(vec![high, low]).ok(),
+                    _ => None,
+                };
+                hex.and_then(|hex| u8::from_str_radix(&hex, 16).ok())
+                    .ok_or_else(|| format!("invalid escape in '{value}'"))?
+            }
+            byte => byte,
+        });
+    }
+    String::from_utf8(bytes).map_err(|_| format!("'{value}' is not UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::{
+        events::{
+            EventKind, LifecycleEvent, LifecyclePhase, OpsEvent, OrchestratorState, Severity,
+            StateChangeEvent,
+        },
+        telemetry::EngineMetrics,
+    };
+    use uuid::Uuid;
+
+    async fn get(addr: SocketAddr, request: &str) -> String {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[tokio::test]
+    async fn status_endpoint_reports_observed_events() {
+        let status = StatusHandle::new();
+        status.observe(&SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::MatchStart,
+                details: None,
+            }),
+        ));
+        status.observe(&SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::State(StateChangeEvent {
+                from: OrchestratorState::Observing,
+                to: OrchestratorState::Thinking,
+            }),
+        ));
+        status.observe(&SystemEvent::new(
+            EventKind::EngineDecision,
+            EventPayload::Engine(minerva_types::events::EngineEvent {
+                metrics: EngineMetrics {
+                    nodes: 10,
+                    depth: 3,
+                    nps: 0,
+                    hashfull: 0.0,
+                    duration_ms: None,
+                },
+                best_line: Vec::new(),
+                score: Some(1.5),
+                turn_id: None,
+                overridden: false,
+                fen: None,
+            }),
+        ));
+        status.set_controller(ControllerSummary {
+            successful_inputs: 4,
+            ..ControllerSummary::default()
+        });
+        let server = StatusServer::bind("127.0.0.1", 0, status.clone())
+            .await
+            .unwrap();
+        let addr = server.local_addr();
+        let task = server.spawn();
+
+        let response = get(addr, "GET /status HTTP/1.1\r\nHost: x\r\n\r\n").await;
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK"), "{head}");
+        let report: StatusReport = serde_json::from_str(body).unwrap();
+        assert_eq!(report, status.report());
+        assert_eq!(report.phase, Some(LifecyclePhase::MatchStart));
+        assert_eq!(report.state, Some(OrchestratorState::Thinking));
+        assert_eq!(report.engine_depth, Some(3));
+        assert_eq!(report.controller.map(|c| c.successful_inputs), Some(4));
+
+        let disabled = get(addr, "GET /events/query HTTP/1.1\r\n\r\n").await;
+        assert!(disabled.starts_with("HTTP/1.1 404"), "{disabled}");
+        let missing = get(addr, "GET /other HTTP/1.1\r\n\r\n").await;
+        assert!(missing.starts_with("HTTP/1.1 404"), "{missing}");
+        let write = get(addr, "POST /status HTTP/1.1\r\n\r\n").await;
+        assert!(write.starts_with("HTTP/1.1 405"), "{write}");
+        task.abort();
+    }
+
+    struct RecordedEvents(Vec<SystemEvent>);
+
+    #[async_trait]
+    impl EventSource for RecordedEvents {
+        async fn query(&self, filter: EventFilter) -> Vec<SystemEvent> {
+            filter.apply(&self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn event_query_filters_by_kind_match_and_text() {
+        let match_id = Uuid::new_v4();
+        let mut events: Vec<_> = ["capture slow", "board misread", "capture failed"]
+            .into_iter()
+            .map(|message| {
+                SystemEvent::new(
+                    EventKind::Ops,
+                    EventPayload::Ops(OpsEvent {
+                        message: message.into(),
+                        tags: Vec::new(),
+                        severity: Severity::Warning,
+                    }),
+                )
+            })
+            .collect();
+        events[1].match_id = Some(match_id);
+        events[2].match_id = Some(match_id);
+        let server = StatusServer::bind("127.0.0.1", 0, StatusHandle::new())
+            .await
+            .unwrap()
+            .with_events(Arc::new(RecordedEvents(events.clone())));
+        let addr = server.local_addr();
+        let task = server.spawn();
+
+        let request = format!(
+            "GET /events/query?kind=Ops&match={match_id}&text=capture+failed HTTP/1.1\r\n\r\n"
+        );
+        let response = get(addr, &request).await;
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK"), "{head}");
+        let found: Vec<SystemEvent> = serde_json::from_str(body).unwrap();
+        assert_eq!(
+            found.iter().map(|e| e.id).collect::<Vec<_>>(),
+            [events[2].id]
+        );
+
+        let all = get(addr, "GET /events/query?limit=2 HTTP/1.1\r\n\r\n").await;
+        let (_, body) = all.split_once("\r\n\r\n").unwrap();
+        assert_eq!(
+            serde_json::from_str::<Vec<SystemEvent>>(body)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        for bad in [
+            "limit=many",
+            "match=nope",
+            "kind=Bogus",
+            "colour=red",
+            "text=%zz",
+        ] {
+            let response = get(addr, &format!("GET /events/query?{bad} HTTP/1.1\r\n\r\n")).await;
+            assert!(response.starts_with("HTTP/1.1 400"), "{bad}: {response}");
+        }
+        task.abort();
+    }
+}
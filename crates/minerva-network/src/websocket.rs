@@ -0,0 +1,285 @@
+//! WebSocket server that streams every published event to connected clients
+//! as JSON text frames.
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use futures::{stream::BoxStream, SinkExt, StreamExt};
+use minerva_types::{
+    config::NetworkConfig,
+    events::{EventKind, EventPayload, LifecycleEvent, LifecyclePhase, SystemEvent},
+    Result,
+};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{broadcast, watch},
+    task::JoinHandle,
+    time::{timeout, Duration},
+};
+use tokio_tungstenite::tungstenite::{
+    protocol::{frame::coding::CloseCode, CloseFrame},
+    Message,
+};
+use tracing::{info, warn};
+
+use crate::{network_error, LocalServer, RealtimeServer};
+
+/// How long [`WebSocketServer::shutdown`] waits for clients to acknowledge
+/// the close before dropping them.
+pub const SHUTDOWN_GRACE: Duration = Duration::from_millis(500);
+
+/// Broadcasts events to WebSocket clients; [`RealtimeServer::subscribe`]
+/// still works in-process, as with [`LocalServer`].
+#[derive(Clone)]
+pub struct WebSocketServer {
+    local: LocalServer,
+    listener: Arc<Mutex<Option<TcpListener>>>,
+    local_addr: SocketAddr,
+    closing: watch::Sender<bool>,
+    accept_loop: Arc<Mutex<Option<JoinHandle<()>>>>,
+    connections: Arc<Mutex<Vec<JoinHandle<()>>>>,
+}
+
+impl WebSocketServer {
+    /// Binds `bind_addr:websocket_port`; clients are accepted once
+    /// [`RealtimeServer::run`] is called.
+    pub async fn bind(config: &NetworkConfig, capacity: usize) -> Result<Self> {
+        let addr = format!("{}:{}", config.bind_addr, config.websocket_port);
+        let listener = TcpListener::bind(&addr)
+            .await
+            .map_err(|err| network_error(format!("WebSocket 바인드 실패({addr}): {err}")))?;
+        let local_addr = listener
+            .local_addr()
+            .map_err(|err| network_error(format!("WebSocket 주소 확인 실패: {err}")))?;
+        let (closing, _) = watch::channel(false);
+        Ok(Self {
+            local: LocalServer::new(capacity),
+            listener: Arc::new(Mutex::new(Some(listener))),
+            local_addr,
+            closing,
+            accept_loop: Arc::new(Mutex::new(None)),
+            connections: Arc::new(Mutex::new(Vec::new())),
+        })
+    }
+
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    fn track(&self, handle: JoinHandle<()>) {
+        if let Ok(mut connections) = self.connections.lock() {
+            connections.retain(|handle| !handle.is_finished());
+            connections.push(handle);
+        }
+    }
+}
+
+#[async_trait]
+impl RealtimeServer for WebSocketServer {
+    /// Starts accepting clients in the background and returns.
+    async fn run(&self) -> Result<()> {
+        let listener = self
+            .listener
+            .lock()
+            .ok()
+            .and_then(|mut listener| listener.take())
+            .ok_or_else(|| network_error("WebSocket 서버가 이미 실행 중입니다"))?;
+        info!("WebSocket 서버 시작: ws://{}", self.local_addr);
+        let server = self.clone();
+        let handle = tokio::spawn(async move {
+            let mut closing = server.closing.subscribe();
+            loop {
+                tokio::select! {
+                    _ = closed(&mut closing) => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok((stream, peer)) => {
+                            let events = server.local.sender().subscribe();
+                            let closing = server.closing.subscribe();
+                            server.track(tokio::spawn(serve_client(stream, peer, events, closing)));
+                        }
+                        Err(err) => warn!("WebSocket 연결 수락 실패: {err}"),
+                    },
+                }
+            }
+        });
+        if let Ok(mut accept_loop) = self.accept_loop.lock() {
+            *accept_loop = Some(handle);
+        }
+        Ok(())
+    }
+
+    async fn publish(&self, event: SystemEvent) -> Result<()> {
+        self.local.publish(event).await
+    }
+
+    fn subscribe(&self) -> BoxStream<'static, SystemEvent> {
+        self.local.subscribe()
+    }
+
+    /// Publishes a `Shutdown` lifecycle event, sends every client a close
+    /// frame after the events still queued for it, and waits up to
+    /// [`SHUTDOWN_GRACE`] for the connections to finish.
+    async fn shutdown(&self) -> Result<()> {
+        self.publish(SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::Shutdown,
+                details: Some("server shutting down".into()),
+            }),
+        ))
+        .await?;
+        self.closing.send_replace(true);
+        let accept_loop = self
+            .accept_loop
+            .lock()
+            .ok()
+            .and_then(|mut handle| handle.take());
+        if let Some(handle) = accept_loop {
+            let _ = handle.await;
+        }
+        let connections = self
+            .connections
+            .lock()
+            .map(|mut connections| std::mem::take(&mut *connections))
+            .unwrap_or_default();
+        let aborts: Vec<_> = connections.iter().map(JoinHandle::abort_handle).collect();
+        if timeout(SHUTDOWN_GRACE, futures::future::join_all(connections))
+            .await
+            .is_err()
+        {
+            warn!("WebSocket 클라이언트 종료 대기 시간 초과; 연결을 끊습니다");
+            aborts.iter().for_each(|abort| abort.abort());
+        }
+        Ok(())
+    }
+}
+
+/// Forwards events to one client until it disconnects or the server closes.
+async fn serve_client(
+    stream: TcpStream,
+    peer: SocketAddr,
+    mut events: broadcast::Receiver<SystemEvent>,
+    mut closing: watch::Receiver<bool>,
+) {
+    let mut socket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(socket) => socket,
+        Err(err) => {
+            warn!("WebSocket 핸드셰이크 실패({peer}): {err}");
+            return;
+        }
+    };
+    info!("WebSocket 클라이언트 연결: {peer}");
+    loop {
+        tokio::select! {
+            _ = closed(&mut closing) => break,
+            event = events.recv() => match event {
+                Ok(event) => {
+                    if send_event(&mut socket, &event).await.is_err() {
+                        return;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    warn!("WebSocket 클라이언트 {peer}가 이벤트 {skipped}개를 놓쳤습니다");
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            incoming = socket.next() => match incoming {
+                Some(Ok(Message::Close(_))) | None | Some(Err(_)) => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+
+    // Deliver what was published before the close, including the shutdown event.
+    while let Ok(event) = events.try_recv() {
+        if send_event(&mut socket, &event).await.is_err() {
+            return;
+        }
+    }
+    let close = CloseFrame {
+        code: CloseCode::Away,
+        reason: "server shutting down".into(),
+    };
+    if socket.send(Message::Close(Some(close))).await.is_ok() {
+        // Wait for the client's close reply so the handshake completes.
+        while let Some(Ok(message)) = socket.next().await {
+            if message.is_close() {
+                break;
+            }
+        }
+    }
+    info!("WebSocket 클라이언트 종료: {peer}");
+}
+
+/// Resolves once the server starts closing (or is dropped).
+async fn closed(closing: &mut watch::Receiver<bool>) {
+    while !*closing.borrow_and_update() {
+        if closing.changed().await.is_err() {
+            return;
+        }
+    }
+}
+
+async fn send_event(
+    socket: &mut tokio_tungstenite::WebSocketStream<TcpStream>,
+    event: &SystemEvent,
+) -> std::result::Result<(), ()> {
+    let text = match serde_json::to_string(event) {
+        Ok(text) => text,
+        Err(err) => {
+            warn!("이벤트 직렬화 실패: {err}");
+            return Ok(());
+        }
+    };
+    socket.send(Message::Text(text)).await.map_err(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn shutdown_notifies_clients_and_closes_cleanly() {
+        let config = NetworkConfig {
+            bind_addr: "127.0.0.1".into(),
+            websocket_port: 0,
+            auth_token: None,
+            status_port: None,
+        };
+        let server = WebSocketServer::bind(&config, 16).await.unwrap();
+        server.run().await.unwrap();
+        assert!(server.run().await.is_err());
+        let url = format!("ws://{}", server.local_addr());
+        let (mut client, _) = tokio_tungstenite::connect_async(url).await.unwrap();
+        // The connection is served once it is tracked.
+        while server.connections.lock().unwrap().is_empty() {
+            tokio::task::yield_now().await;
+        }
+
+        let shutdown = tokio::spawn({
+            let server = server.clone();
+            async move { server.shutdown().await }
+        });
+        let mut phases = Vec::new();
+        let mut close = None;
+        while let Some(message) = client.next().await {
+            match message.unwrap() {
+                Message::Text(text) => {
+                    let event: SystemEvent = serde_json::from_str(&text).unwrap();
+                    if let EventPayload::Lifecycle(lifecycle) = event.payload {
+                        phases.push(lifecycle.phase);
+                    }
+                }
+                Message::Close(frame) => close = frame,
+                _ => {}
+            }
+        }
+
+        assert_eq!(phases, [LifecyclePhase::Shutdown]);
+        assert_eq!(close.map(|frame| frame.code), Some(CloseCode::Away));
+        shutdown.await.unwrap().unwrap();
+    }
+}
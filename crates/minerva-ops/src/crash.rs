@@ -0,0 +1,145 @@
+//! Panic hook that leaves a trace in the telemetry directory.
+
+use std::{
+    backtrace::Backtrace,
+    panic::PanicHookInfo,
+    sync::{Mutex, Once},
+    time::Duration,
+};
+
+use chrono::Utc;
+use minerva_types::events::{ops_event, Severity};
+
+use crate::{append_lines, OpsEventSink, TelemetryStore, EVENT_LOG_FILE};
+
+/// How long the hook waits for queued events to reach disk.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+static TARGET: Mutex<Option<(TelemetryStore, OpsEventSink)>> = Mutex::new(None);
+static INSTALL: Once = Once::new();
+
+/// Turns panics into an Error-severity Ops event sent to `sink`, flushes the
+/// store's file sink, appends the event to `<telemetry_dir>/events.jsonl`, and
+/// writes a `crash_<ts>.json` marker next to it. The previous hook still runs.
+///
+/// Calling this again only retargets the hook.
+pub fn install_crash_handler(store: TelemetryStore, sink: OpsEventSink) {
+    *TARGET
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some((store, sink));
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            record_panic(info);
+            previous(info);
+        }));
+    });
+}
+
+fn record_panic(info: &PanicHookInfo<'_>) {
+    let Some((store, sink)) = TARGET
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+    else {
+        return;
+    };
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "non-string panic payload".to_string());
+    let location = info
+        .location()
+        .map(|loc| format!("{}:{}", loc.file(), loc.line()))
+        .unwrap_or_default();
+    let thread = std::thread::current()
+        .name()
+        .unwrap_or("<unnamed>")
+        .to_string();
+    let backtrace = Backtrace::force_capture().to_string();
+
+    let event = ops_event(
+        Severity::Error,
+        format!("panic in {thread} at {location}: {message}\n{backtrace}"),
+        vec!["panic".into()],
+    );
+    sink.send(event.clone());
+
+    let Some(dir) = store.persist_dir() else {
+        return;
+    };
+    store.flush_blocking(FLUSH_TIMEOUT);
+    if let Ok(line) = serde_json::to_string(&event) {
+        let _ = append_lines(&dir.join(EVENT_LOG_FILE), &[line]);
+    }
+    let marker = serde_json::json!({
+        "timestamp": event.timestamp,
+        "event_id": event.id,
+        "thread": thread,
+        "location": location,
+        "message": message,
+        "backtrace": backtrace,
+    });
+    let file = dir.join(format!(
+        "crash_{}.json",
+        Utc::now().format("%Y%m%d_%H%M%S_%3f")
+    ));
+    if let Ok(doc) = serde_json::to_string_pretty(&marker) {
+        let _ = std::fs::write(file, doc);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::events::{EventPayload, SystemEvent};
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn panic_leaves_crash_marker_and_error_event() {
+        let dir = std::env::temp_dir().join(format!("minerva-crash-{}", Uuid::new_v4()));
+        let store = TelemetryStore::with_persistence(&dir);
+        let sink = OpsEventSink::default();
+        let mut events = sink.attach();
+        install_crash_handler(store, sink);
+
+        let joined = std::thread::Builder::new()
+            .name("doomed".into())
+            .spawn(|| panic!("board state went sideways"))
+            .unwrap()
+            .join();
+        assert!(joined.is_err());
+
+        let markers: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| {
+                path.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("crash_") && n.ends_with(".json"))
+            })
+            .collect();
+        assert_eq!(markers.len(), 1);
+        let marker: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&markers[0]).unwrap()).unwrap();
+        assert_eq!(marker["message"], "board state went sideways");
+        assert_eq!(marker["thread"], "doomed");
+
+        let persisted = std::fs::read_to_string(dir.join(EVENT_LOG_FILE)).unwrap();
+        let recorded: SystemEvent =
+            serde_json::from_str(persisted.lines().last().unwrap()).unwrap();
+        match &recorded.payload {
+            EventPayload::Ops(ops) => {
+                assert_eq!(ops.severity, Severity::Error);
+                assert!(ops.message.contains("board state went sideways"));
+                assert_eq!(ops.tags, ["panic"]);
+            }
+            other => panic!("expected ops event, got {other:?}"),
+        }
+        assert_eq!(events.try_recv().unwrap().id, recorded.id);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
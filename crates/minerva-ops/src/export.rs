@@ -0,0 +1,159 @@
+//! Flat CSV exports of per-match telemetry for spreadsheet analysis.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use minerva_types::{telemetry::MatchTelemetry, MinervaError, Result};
+
+pub const ENGINE_CSV_FILE: &str = "engine_metrics.csv";
+pub const LATENCY_CSV_FILE: &str = "latency.csv";
+
+const ENGINE_HEADER: [&str; 6] = ["turn", "depth", "nodes", "nps", "hashfull", "duration_ms"];
+const LATENCY_HEADER: [&str; 5] = ["turn", "observation", "decision", "injection", "total"];
+
+/// Paths written by [`export_csv`].
+#[derive(Debug, Clone)]
+pub struct CsvExport {
+    pub engine_metrics: PathBuf,
+    pub latency: PathBuf,
+}
+
+/// Writes `engine_metrics.csv` and `latency.csv` for `telemetry` into `dir`.
+///
+/// Rows are numbered by turn in recording order; missing values are left as
+/// empty cells.
+pub fn export_csv(telemetry: &MatchTelemetry, dir: &Path) -> Result<CsvExport> {
+    std::fs::create_dir_all(dir)
+        .map_err(|err| MinervaError::Ops(format!("failed to create export dir: {err}")))?;
+
+    let engine_rows = telemetry
+        .engine_history
+        .iter()
+        .enumerate()
+        .map(|(turn, m)| {
+            vec![
+                turn.to_string(),
+                m.depth.to_string(),
+                m.nodes.to_string(),
+                m.nps.to_string(),
+                m.hashfull.to_string(),
+                optional(m.duration_ms),
+            ]
+        });
+    let engine_metrics = dir.join(ENGINE_CSV_FILE);
+    write_csv(&engine_metrics, &ENGINE_HEADER, engine_rows)?;
+
+    let latency_rows = telemetry
+        .latency_samples
+        .iter()
+        .enumerate()
+        .map(|(turn, sample)| {
+            vec![
+                turn.to_string(),
+                sample.observation_ms.to_string(),
+                sample.decision_ms.to_string(),
+                sample.injection_ms.to_string(),
+                sample.total_ms.to_string(),
+            ]
+        });
+    let latency = dir.join(LATENCY_CSV_FILE);
+    write_csv(&latency, &LATENCY_HEADER, latency_rows)?;
+
+    Ok(CsvExport {
+        engine_metrics,
+        latency,
+    })
+}
+
+fn write_csv(path: &Path, header: &[&str], rows: impl Iterator<Item = Vec<String>>) -> Result<()> {
+    let mut out = csv_line(header.iter().copied());
+    for row in rows {
+        out.push_str(&csv_line(row.iter().map(String::as_str)));
+    }
+    std::fs::write(path, out)
+        .map_err(|err| MinervaError::Ops(format!("failed to write {}: {err}", path.display())))
+}
+
+fn csv_line<'a>(fields: impl Iterator<Item = &'a str>) -> String {
+    let mut line = fields.map(csv_field).collect::<Vec<_>>().join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// Quotes a field per RFC 4180 when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use minerva_types::telemetry::{EngineMetrics, LatencySample};
+
+    fn rows(path: &Path) -> Vec<Vec<String>> {
+        std::fs::read_to_string(path)
+            .unwrap()
+            .split("\r\n")
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(',').map(str::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn exports_engine_and_latency_series() {
+        let telemetry = MatchTelemetry {
+            engine_history: vec![
+                EngineMetrics {
+                    nodes: 120,
+                    depth: 3,
+                    nps: 4000,
+                    hashfull: 0.5,
+                    duration_ms: Some(30),
+                },
+                EngineMetrics::default(),
+            ],
+            latency_samples: vec![LatencySample {
+                observation_ms: 40,
+                decision_ms: 30,
+                injection_ms: 60,
+                total_ms: 130,
+                prefetch_saved_ms: 0,
+                captured_at: Utc::now(),
+            }],
+            ..MatchTelemetry::default()
+        };
+        let dir = std::env::temp_dir().join(format!("minerva-csv-{}", uuid::Uuid::new_v4()));
+
+        let export = export_csv(&telemetry, &dir).unwrap();
+
+        let engine = rows(&export.engine_metrics);
+        assert_eq!(engine.len(), 3);
+        assert_eq!(engine[0], ENGINE_HEADER);
+        assert_eq!(engine[1], ["0", "3", "120", "4000", "0.5", "30"]);
+        assert_eq!(engine[2][5], "");
+
+        let latency = rows(&export.latency);
+        assert_eq!(latency.len(), 2);
+        assert_eq!(latency[0], LATENCY_HEADER);
+        assert_eq!(latency[1][4], "130");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fields_with_delimiters_are_quoted() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,161 @@
+//! Periodic process health heartbeat (memory, tasks, uptime, event counts).
+
+use std::time::Duration;
+
+use chrono::Utc;
+use minerva_types::{
+    events::{EventKind, EventPayload, SystemEvent, TelemetryEvent},
+    telemetry::HealthSample,
+    Result,
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle, time::Instant};
+use tracing::warn;
+
+use crate::TelemetryStore;
+
+/// Where process-level figures come from; stubbed in tests.
+pub trait HealthSource: Send + Sync + 'static {
+    fn rss_bytes(&self) -> Option<u64>;
+    fn alive_tasks(&self) -> Option<usize>;
+}
+
+/// Reads the current process: `VmRSS` from `/proc/self/status` (Linux only)
+/// and the tokio runtime's alive task count.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProcessHealth;
+
+impl HealthSource for ProcessHealth {
+    fn rss_bytes(&self) -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kib * 1024)
+    }
+
+    fn alive_tasks(&self) -> Option<usize> {
+        tokio::runtime::Handle::try_current()
+            .ok()
+            .map(|handle| handle.metrics().num_alive_tasks())
+    }
+}
+
+/// Builds [`HealthSample`]s and records them as Telemetry events.
+pub struct HealthSampler<S> {
+    store: TelemetryStore,
+    source: S,
+    started: Instant,
+}
+
+impl<S: HealthSource> HealthSampler<S> {
+    pub fn new(store: TelemetryStore, source: S) -> Self {
+        Self {
+            store,
+            source,
+            started: Instant::now(),
+        }
+    }
+
+    /// Takes one sample, records it in the store, and returns the event.
+    pub async fn sample(&self) -> Result<SystemEvent> {
+        let health = HealthSample {
+            rss_bytes: self.source.rss_bytes(),
+            alive_tasks: self.source.alive_tasks(),
+            writer_queue_depth: self.store.writer_queue_depth(),
+            uptime_secs: self.started.elapsed().as_secs(),
+            events_recorded: self.store.recorded_events(),
+            events_dropped: self.store.dropped_events(),
+            captured_at: Utc::now(),
+        };
+        let event = SystemEvent::new(
+            EventKind::Telemetry,
+            EventPayload::Telemetry(TelemetryEvent {
+                latency: None,
+                notes: None,
+                turn_id: None,
+                health: Some(health),
+            }),
+        );
+        self.store.record_event(event.clone()).await?;
+        Ok(event)
+    }
+
+    /// Samples immediately and then every `interval`, forwarding each event
+    /// to `events` (e.g. for the network). Stops once `events` is closed.
+    pub fn spawn(self, interval: Duration, events: UnboundedSender<SystemEvent>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                match self.sample().await {
+                    Ok(event) => {
+                        if events.send(event).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => warn!("health sample failed: {err}"),
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    struct StubSource;
+
+    impl HealthSource for StubSource {
+        fn rss_bytes(&self) -> Option<u64> {
+            Some(64 * 1024 * 1024)
+        }
+
+        fn alive_tasks(&self) -> Option<usize> {
+            Some(3)
+        }
+    }
+
+    fn health_of(event: &SystemEvent) -> &HealthSample {
+        match &event.payload {
+            EventPayload::Telemetry(TelemetryEvent {
+                health: Some(health),
+                ..
+            }) => health,
+            other => panic!("expected a health sample, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn samples_are_emitted_at_the_interval_and_recorded() {
+        let store = TelemetryStore::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let handle =
+            HealthSampler::new(store.clone(), StubSource).spawn(Duration::from_secs(10), tx);
+
+        tokio::time::sleep(Duration::from_secs(25)).await;
+        handle.abort();
+
+        let mut samples = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            samples.push(event);
+        }
+        let uptimes: Vec<_> = samples.iter().map(|e| health_of(e).uptime_secs).collect();
+        assert_eq!(uptimes, [0, 10, 20]);
+        assert!(samples.iter().all(|e| {
+            let health = health_of(e);
+            health.rss_bytes == Some(64 * 1024 * 1024) && health.alive_tasks == Some(3)
+        }));
+        // Each sample counts the ones recorded before it.
+        let recorded: Vec<_> = samples
+            .iter()
+            .map(|e| health_of(e).events_recorded)
+            .collect();
+        assert_eq!(recorded, [0, 1, 2]);
+
+        let stored = store.snapshot_events().await;
+        assert_eq!(stored.len(), 3);
+        assert!(stored.iter().all(|e| e.kind == EventKind::Telemetry));
+    }
+}
@@ -1,31 +1,201 @@
 //! Operational helpers: logging, telemetry persistence, replay support.
 
-use std::{path::PathBuf, sync::Arc};
+mod crash;
+pub mod export;
+pub mod health;
+pub mod ops_layer;
+pub mod record;
+pub mod report;
+pub mod retention;
+pub mod retry;
+pub mod session;
+mod writer;
+
+use std::{
+    collections::VecDeque,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 use minerva_types::{
-    config::OpsConfig, events::SystemEvent, telemetry::MatchTelemetry, MinervaError, Result,
+    config::{LogFormat, OpsConfig},
+    events::{EventFilter, SystemEvent},
+    telemetry::{MatchTelemetry, TurnRecord},
+    MinervaError, Result,
 };
-use tokio::sync::Mutex;
-use tracing::info;
-use tracing_subscriber::{fmt, EnvFilter};
-
-pub fn init_tracing(config: &OpsConfig) -> Result<()> {
-    let filter = EnvFilter::try_new(config.log_level.clone())
-        .or_else(|_| EnvFilter::try_new("info"))
-        .map_err(|err| MinervaError::Ops(format!("failed to create log filter: {err}")))?;
-
-    fmt()
-        .with_env_filter(filter)
-        .try_init()
-        .map_err(|err| MinervaError::Ops(format!("tracing init error: {err}")))?;
-    Ok(())
+use tokio::sync::{Mutex, OnceCell};
+use tracing::{info, warn, Subscriber};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::{
+    fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry,
+};
+use uuid::Uuid;
+
+pub use crash::install_crash_handler;
+pub use export::{export_csv, CsvExport};
+pub use health::{HealthSampler, HealthSource, ProcessHealth};
+pub use ops_layer::{OpsEventLayer, OpsEventSink};
+pub use record::{GameRecord, RecordedMove};
+pub use report::{render_match_report, write_match_report, REPORT_FILE};
+pub use retention::{prune_telemetry, PruneReport, RetentionPolicy, RetentionSweeper};
+pub use retry::{backoff_delay, jittered, retry, retry_if};
+pub use session::{SessionState, SESSION_STATE_FILE};
+use writer::EventWriter;
+pub use writer::WRITER_CHANNEL_CAPACITY;
+
+/// Keeps the non-blocking file writer alive; buffered log lines are flushed on drop.
+#[must_use = "dropping the guard stops the file log writer"]
+#[derive(Default)]
+pub struct TracingGuard {
+    _file: Option<WorkerGuard>,
+}
+
+pub fn init_tracing(config: &OpsConfig) -> Result<TracingGuard> {
+    init_tracing_with_filter(config, None)
+}
+
+/// [`init_tracing`] that never fails: when the configured `log_file` cannot
+/// be opened, it warns and logs without the file instead.
+pub fn init_tracing_or_fallback(config: &OpsConfig) -> TracingGuard {
+    init_tracing(config).unwrap_or_else(|err| {
+        let without_file = OpsConfig {
+            log_file: None,
+            ..config.clone()
+        };
+        let guard = init_tracing(&without_file).unwrap_or_default();
+        warn!(
+            tags = "ops",
+            "logging setup failed; continuing without the log file: {err}"
+        );
+        guard
+    })
+}
+
+/// Like [`init_tracing`], but `filter` replaces the one derived from `log_level`.
+///
+/// The subscriber is built first, so an unusable `log_file` is reported even
+/// when a global subscriber is already installed (a second orchestrator in
+/// the same process, or a test harness); in that case the existing one is
+/// kept, a warning is logged, and this returns an empty guard.
+pub fn init_tracing_with_filter(
+    config: &OpsConfig,
+    filter: Option<EnvFilter>,
+) -> Result<TracingGuard> {
+    let (subscriber, guard) = build_subscriber(config, filter)?;
+    if tracing::dispatcher::has_been_set() {
+        warn!("tracing already initialized; keeping the existing subscriber");
+        return Ok(TracingGuard::default());
+    }
+    if let Err(err) = subscriber.try_init() {
+        warn!("tracing already initialized ({err}); keeping the existing subscriber");
+        return Ok(TracingGuard::default());
+    }
+    Ok(guard)
+}
+
+/// Installs a test-writer subscriber at debug level; safe to call repeatedly.
+pub fn init_tracing_for_tests() {
+    let _ = fmt()
+        .with_env_filter(log_filter("debug"))
+        .with_test_writer()
+        .try_init();
+}
+
+/// Parses `level` as an env filter, falling back to `info` when it is invalid.
+pub fn log_filter(level: &str) -> EnvFilter {
+    EnvFilter::try_new(level).unwrap_or_else(|_| EnvFilter::new("info"))
 }
 
-/// In-memory telemetry store for early development.
+/// Builds the subscriber described by `config` without installing it globally.
+pub fn build_subscriber(
+    config: &OpsConfig,
+    filter: Option<EnvFilter>,
+) -> Result<(impl Subscriber + Send + Sync + 'static, TracingGuard)> {
+    let filter = filter.unwrap_or_else(|| log_filter(&config.log_level));
+
+    let mut layers: Vec<Box<dyn Layer<Registry> + Send + Sync>> =
+        vec![OpsEventLayer::new(OpsEventSink::global()).boxed()];
+    if config.log_stderr {
+        let layer = fmt::layer().with_writer(std::io::stderr);
+        layers.push(match config.log_format {
+            LogFormat::Text => layer.boxed(),
+            LogFormat::Json => layer.json().boxed(),
+        });
+    }
+
+    let mut file_guard = None;
+    if let Some(file) = config.log_file.as_deref() {
+        let path = resolve_log_path(config, file);
+        let dir = path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let name = path
+            .file_name()
+            .ok_or_else(|| MinervaError::Ops(format!("invalid log file path: {file}")))?;
+        std::fs::create_dir_all(&dir)
+            .map_err(|err| MinervaError::Ops(format!("failed to create log dir: {err}")))?;
+        let (writer, worker) =
+            tracing_appender::non_blocking(tracing_appender::rolling::daily(dir, name));
+        let layer = fmt::layer().with_ansi(false).with_writer(writer);
+        layers.push(match config.log_format {
+            LogFormat::Text => layer.boxed(),
+            LogFormat::Json => layer.json().boxed(),
+        });
+        file_guard = Some(worker);
+    }
+
+    let subscriber = Registry::default().with(layers).with(filter);
+    Ok((subscriber, TracingGuard { _file: file_guard }))
+}
+
+/// Bare file names land in `telemetry_dir`; anything with a directory is used as given.
+pub fn resolve_log_path(config: &OpsConfig, file: &str) -> PathBuf {
+    let path = PathBuf::from(file);
+    let has_dir = path
+        .parent()
+        .is_some_and(|parent| !parent.as_os_str().is_empty());
+    if has_dir {
+        path
+    } else {
+        PathBuf::from(&config.telemetry_dir).join(path)
+    }
+}
+
+/// File name of the JSON-lines event log inside each telemetry directory.
+pub const EVENT_LOG_FILE: &str = "events.jsonl";
+/// File name of the persisted [`MatchTelemetry`] inside a match directory.
+pub const MATCH_FILE: &str = "match.json";
+/// File name of the JSON [`GameRecord`] written next to [`MATCH_FILE`].
+pub const GAME_RECORD_FILE: &str = "record.json";
+
+/// Telemetry store keeping events in memory and, optionally, on disk.
+///
+/// With persistence enabled, events are appended to
+/// `<dir>/session_<id>/match_<id>/events.jsonl`; events outside a match go to
+/// the session directory and unstamped events to `<dir>` itself.
+///
+/// Lines are handed to a background writer task that batches and periodically
+/// fsyncs them; call [`Self::flush`] or [`Self::shutdown`] when they must be on
+/// disk. If the writer queue is full, Telemetry-kind events are dropped from
+/// the file sink (see [`Self::dropped_events`]) while other kinds wait.
+///
+/// When `max_events` is set, the oldest in-memory events are evicted once the
+/// cap is exceeded. Eviction never affects the file sink.
 #[derive(Clone, Default)]
 pub struct TelemetryStore {
-    events: Arc<Mutex<Vec<SystemEvent>>>,
+    events: Arc<Mutex<VecDeque<SystemEvent>>>,
     matches: Arc<Mutex<Vec<MatchTelemetry>>>,
+    persist_dir: Option<PathBuf>,
+    max_events: Option<usize>,
+    evicted: Arc<AtomicU64>,
+    writer: Arc<OnceCell<EventWriter>>,
+    writer_capacity: Option<usize>,
+    dropped: Arc<AtomicU64>,
+    recorded: Arc<AtomicU64>,
 }
 
 impl TelemetryStore {
@@ -33,19 +203,248 @@ impl TelemetryStore {
         Self::default()
     }
 
+    /// Persistent, bounded store as described by the ops section of the config.
+    pub fn from_config(config: &OpsConfig) -> Self {
+        Self::with_persistence(&config.telemetry_dir).with_max_events(config.max_events_in_memory)
+    }
+
+    pub fn with_max_events(mut self, max_events: usize) -> Self {
+        self.max_events = Some(max_events);
+        self
+    }
+
+    /// Number of events dropped from memory because of the cap.
+    pub fn evicted_events(&self) -> u64 {
+        self.evicted.load(Ordering::Relaxed)
+    }
+
+    /// Queue depth of the background writer (default [`WRITER_CHANNEL_CAPACITY`]).
+    pub fn with_writer_capacity(mut self, capacity: usize) -> Self {
+        self.writer_capacity = Some(capacity);
+        self
+    }
+
+    /// Number of Telemetry events left out of the file sink because the
+    /// writer queue was full.
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Total events passed to [`Self::record_event`], including evicted ones.
+    pub fn recorded_events(&self) -> u64 {
+        self.recorded.load(Ordering::Relaxed)
+    }
+
+    /// Events waiting for the background writer; 0 before it has started.
+    pub fn writer_queue_depth(&self) -> usize {
+        self.writer.get().map_or(0, EventWriter::queue_depth)
+    }
+
+    pub fn with_persistence<P: Into<PathBuf>>(dir: P) -> Self {
+        Self {
+            persist_dir: Some(dir.into()),
+            ..Self::default()
+        }
+    }
+
+    pub fn persist_dir(&self) -> Option<&Path> {
+        self.persist_dir.as_deref()
+    }
+
+    /// Directory holding files for the given session/match, if persistence is enabled.
+    pub fn match_dir(&self, session_id: Option<Uuid>, match_id: Option<Uuid>) -> Option<PathBuf> {
+        let mut dir = self.persist_dir.clone()?;
+        if let Some(session_id) = session_id {
+            dir.push(format!("session_{session_id}"));
+            if let Some(match_id) = match_id {
+                dir.push(format!("match_{match_id}"));
+            }
+        }
+        Some(dir)
+    }
+
     pub async fn record_event(&self, event: SystemEvent) -> Result<()> {
-        self.events.lock().await.push(event);
+        self.recorded.fetch_add(1, Ordering::Relaxed);
+        if let Some(dir) = self.match_dir(event.session_id, event.match_id) {
+            let line = serde_json::to_string(&event)
+                .map_err(|err| MinervaError::Ops(format!("failed to encode event: {err}")))?;
+            let path = dir.join(EVENT_LOG_FILE);
+            let writer = self.writer().await;
+            if writer
+                .append(&event.kind, path.clone(), line.clone())
+                .await
+                .is_err()
+            {
+                // Writer already shut down: keep persisting, just inline.
+                append_lines(&path, &[line])?;
+            }
+        }
+        let mut events = self.events.lock().await;
+        events.push_back(event);
+        if let Some(max_events) = self.max_events {
+            while events.len() > max_events {
+                events.pop_front();
+                self.evicted.fetch_add(1, Ordering::Relaxed);
+            }
+        }
         Ok(())
     }
 
+    /// Waits until every event recorded so far is written and synced.
+    pub async fn flush(&self) -> Result<()> {
+        match self.writer.get() {
+            Some(writer) => writer.flush().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Drains queued events to disk and stops the writer task; later events
+    /// are written inline.
+    pub async fn shutdown(&self) -> Result<()> {
+        match self.writer.get() {
+            Some(writer) => writer.shutdown().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Synchronous [`Self::flush`] for contexts that cannot await; gives up
+    /// after `timeout`. Returns whether everything queued reached disk.
+    pub fn flush_blocking(&self, timeout: std::time::Duration) -> bool {
+        self.writer
+            .get()
+            .is_none_or(|writer| writer.flush_blocking(timeout))
+    }
+
+    async fn writer(&self) -> &EventWriter {
+        self.writer
+            .get_or_init(|| async {
+                EventWriter::spawn(
+                    self.writer_capacity.unwrap_or(WRITER_CHANNEL_CAPACITY),
+                    self.dropped.clone(),
+                )
+            })
+            .await
+    }
+
     pub async fn record_match(&self, telemetry: MatchTelemetry) -> Result<()> {
+        if let Some(dir) = self.match_dir(telemetry.session_id, telemetry.match_id) {
+            let doc = serde_json::to_string_pretty(&telemetry)
+                .map_err(|err| MinervaError::Ops(format!("failed to encode match: {err}")))?;
+            std::fs::create_dir_all(&dir)
+                .map_err(|err| MinervaError::Ops(format!("failed to create match dir: {err}")))?;
+            std::fs::write(dir.join(MATCH_FILE), doc)
+                .map_err(|err| MinervaError::Ops(format!("failed to write match file: {err}")))?;
+        }
         self.matches.lock().await.push(telemetry);
         Ok(())
     }
 
+    pub async fn snapshot_matches(&self) -> Vec<MatchTelemetry> {
+        self.matches.lock().await.clone()
+    }
+
+    /// Turn records of recorded matches, optionally restricted to one match.
+    pub async fn turns(&self, match_id: Option<Uuid>) -> Vec<TurnRecord> {
+        self.matches
+            .lock()
+            .await
+            .iter()
+            .filter(|m| match_id.is_none() || m.match_id == match_id)
+            .flat_map(|m| m.turns.iter().cloned())
+            .collect()
+    }
+
+    /// Events currently held in memory, oldest first; see [`Self::evicted_events`].
     pub async fn snapshot_events(&self) -> Vec<SystemEvent> {
-        self.events.lock().await.clone()
+        self.events.lock().await.iter().cloned().collect()
+    }
+
+    /// Returns recorded events matching `filter`, newest first.
+    pub async fn query(&self, filter: EventFilter) -> Vec<SystemEvent> {
+        filter.apply(self.events.lock().await.iter())
+    }
+
+    /// Builds a store from a JSON-lines event log, skipping malformed lines.
+    pub fn from_jsonl<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let events = read_event_log(path.as_ref())?;
+        Ok(Self {
+            events: Arc::new(Mutex::new(events.into())),
+            ..Self::default()
+        })
+    }
+
+    /// Builds a store from every event log found under a telemetry directory.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self> {
+        let mut logs = Vec::new();
+        collect_event_logs(dir.as_ref(), &mut logs)?;
+        let mut events = Vec::new();
+        for log in logs {
+            events.extend(read_event_log(&log)?);
+        }
+        Ok(Self {
+            events: Arc::new(Mutex::new(events.into())),
+            ..Self::default()
+        })
+    }
+}
+
+fn append_lines(path: &Path, lines: &[String]) -> Result<()> {
+    use std::io::Write;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| MinervaError::Ops(format!("failed to create telemetry dir: {err}")))?;
     }
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| MinervaError::Ops(format!("failed to open {}: {err}", path.display())))?;
+    let mut buf = String::new();
+    for line in lines {
+        buf.push_str(line);
+        buf.push('\n');
+    }
+    file.write_all(buf.as_bytes())
+        .map_err(|err| MinervaError::Ops(format!("failed to write {}: {err}", path.display())))
+}
+
+fn read_event_log(path: &Path) -> Result<Vec<SystemEvent>> {
+    let contents = std::fs::read_to_string(path).map_err(|err| {
+        MinervaError::Ops(format!(
+            "failed to read event log {}: {err}",
+            path.display()
+        ))
+    })?;
+    let mut events = Vec::new();
+    for (line_no, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<SystemEvent>(line) {
+            Ok(event) => events.push(event),
+            Err(err) => warn!("skipping event log line {}: {err}", line_no + 1),
+        }
+    }
+    Ok(events)
+}
+
+fn collect_event_logs(dir: &Path, logs: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir).map_err(|err| {
+        MinervaError::Ops(format!(
+            "failed to read telemetry dir {}: {err}",
+            dir.display()
+        ))
+    })?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_event_logs(&path, logs)?;
+        } else if path.file_name().is_some_and(|name| name == EVENT_LOG_FILE) {
+            logs.push(path);
+        }
+    }
+    Ok(())
 }
 
 pub fn ensure_telemetry_dir(path: &str) -> Result<PathBuf> {
@@ -55,3 +454,453 @@ pub fn ensure_telemetry_dir(path: &str) -> Result<PathBuf> {
     info!("Telemetry directory ready at {:?}", dir);
     Ok(dir)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+    use minerva_types::{
+        config::{LogFormat, OpsConfig},
+        events::{
+            ops_event, EngineEvent, EventKind, EventPayload, LifecycleEvent, LifecyclePhase,
+            OpsEvent, Severity, TelemetryEvent,
+        },
+        telemetry::EngineMetrics,
+    };
+
+    fn event_at(kind: EventKind, payload: EventPayload, offset_secs: i64) -> SystemEvent {
+        let mut event = SystemEvent::new(kind, payload);
+        event.timestamp = Utc::now() - Duration::seconds(100) + Duration::seconds(offset_secs);
+        event
+    }
+
+    async fn populated_store() -> (TelemetryStore, Vec<SystemEvent>) {
+        let events = vec![
+            event_at(
+                EventKind::Lifecycle,
+                EventPayload::Lifecycle(LifecycleEvent {
+                    phase: LifecyclePhase::Boot,
+                    details: Some("orchestrator boot complete".into()),
+                }),
+                0,
+            ),
+            event_at(
+                EventKind::EngineDecision,
+                EventPayload::Engine(EngineEvent {
+                    metrics: EngineMetrics::default(),
+                    best_line: Vec::new(),
+                    score: None,
+                    turn_id: None,
+                    overridden: false,
+                    fen: None,
+                }),
+                10,
+            ),
+            event_at(
+                EventKind::Ops,
+                EventPayload::Ops(OpsEvent {
+                    message: "Snapshot update failed".into(),
+                    tags: vec!["board".into()],
+                    severity: Severity::Warning,
+                }),
+                20,
+            ),
+            event_at(
+                EventKind::EngineDecision,
+                EventPayload::Engine(EngineEvent {
+                    metrics: EngineMetrics::default(),
+                    best_line: Vec::new(),
+                    score: None,
+                    turn_id: None,
+                    overridden: false,
+                    fen: None,
+                }),
+                30,
+            ),
+            event_at(
+                EventKind::Telemetry,
+                EventPayload::Telemetry(TelemetryEvent {
+                    latency: None,
+                    notes: Some("capture slow".into()),
+                    turn_id: None,
+                    health: None,
+                }),
+                40,
+            ),
+        ];
+        let store = TelemetryStore::new();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+        (store, events)
+    }
+
+    fn ids(events: &[SystemEvent]) -> Vec<uuid::Uuid> {
+        events.iter().map(|e| e.id).collect()
+    }
+
+    #[tokio::test]
+    async fn query_filters_by_kind_newest_first() {
+        let (store, events) = populated_store().await;
+        let result = store
+            .query(EventFilter::new().kind(EventKind::EngineDecision))
+            .await;
+        assert_eq!(ids(&result), vec![events[3].id, events[1].id]);
+
+        let limited = store
+            .query(
+                EventFilter::new()
+                    .kind(EventKind::EngineDecision)
+                    .kind(EventKind::Ops)
+                    .limit(2),
+            )
+            .await;
+        assert_eq!(ids(&limited), vec![events[3].id, events[2].id]);
+    }
+
+    #[tokio::test]
+    async fn query_filters_by_time_range_and_text() {
+        let (store, events) = populated_store().await;
+        let range = store
+            .query(
+                EventFilter::new()
+                    .since(events[1].timestamp)
+                    .until(events[3].timestamp),
+            )
+            .await;
+        assert_eq!(ids(&range), vec![events[3].id, events[2].id, events[1].id]);
+
+        let text = store.query(EventFilter::new().text("BOOT")).await;
+        assert_eq!(ids(&text), vec![events[0].id]);
+
+        let tagged = store.query(EventFilter::new().text("board")).await;
+        assert_eq!(ids(&tagged), vec![events[2].id]);
+
+        let none = store
+            .query(EventFilter::new().kind(EventKind::Ops).text("capture"))
+            .await;
+        assert!(none.is_empty());
+
+        let all = store.query(EventFilter::new()).await;
+        assert_eq!(all.len(), events.len());
+        assert_eq!(all.first().map(|e| e.id), Some(events[4].id));
+    }
+
+    #[tokio::test]
+    async fn query_filters_by_match_and_session() {
+        let session = uuid::Uuid::new_v4();
+        let matches = [uuid::Uuid::new_v4(), uuid::Uuid::new_v4()];
+        let (_, mut events) = populated_store().await;
+        for (index, event) in events.iter_mut().enumerate() {
+            event.session_id = Some(session);
+            // The boot event precedes any match.
+            event.match_id = (index > 0).then(|| matches[index % 2]);
+        }
+        let store = TelemetryStore::new();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+
+        let first = store.query(EventFilter::new().match_id(matches[0])).await;
+        assert_eq!(ids(&first), vec![events[4].id, events[2].id]);
+        let engine = store
+            .query(
+                EventFilter::new()
+                    .match_id(matches[1])
+                    .kind(EventKind::EngineDecision),
+            )
+            .await;
+        assert_eq!(ids(&engine), vec![events[3].id, events[1].id]);
+        let session_events = store.query(EventFilter::new().session_id(session)).await;
+        assert_eq!(session_events.len(), events.len());
+        let other = store
+            .query(EventFilter::new().session_id(uuid::Uuid::new_v4()))
+            .await;
+        assert!(other.is_empty());
+    }
+
+    fn log_config(dir: &Path, format: LogFormat) -> OpsConfig {
+        OpsConfig {
+            log_level: "debug".into(),
+            telemetry_dir: dir.to_string_lossy().into_owned(),
+            log_file: Some("minerva.log".into()),
+            log_format: format,
+            log_stderr: false,
+            max_events_in_memory: 100,
+            health_interval_secs: 30,
+            telemetry_max_mb: None,
+            telemetry_max_age_days: None,
+        }
+    }
+
+    fn read_log_lines(dir: &Path) -> Vec<String> {
+        let mut lines = Vec::new();
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let is_log = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("minerva.log"));
+            if is_log {
+                let contents = std::fs::read_to_string(&path).unwrap();
+                lines.extend(contents.lines().map(str::to_string));
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn file_tracing_writes_text_lines() {
+        let dir = std::env::temp_dir().join(format!("minerva-log-{}", uuid::Uuid::new_v4()));
+        let (subscriber, guard) =
+            build_subscriber(&log_config(&dir, LogFormat::Text), None).expect("build subscriber");
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("turn", ply = 3);
+            let _entered = span.enter();
+            info!("engine decided");
+            tracing::debug!(nodes = 42, "search finished");
+        });
+        drop(guard);
+
+        let contents = read_log_lines(&dir).join("\n");
+        assert!(contents.contains("engine decided"));
+        assert!(contents.contains("search finished"));
+        assert!(contents.contains("turn"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn file_tracing_writes_json_lines() {
+        let dir = std::env::temp_dir().join(format!("minerva-log-{}", uuid::Uuid::new_v4()));
+        let (subscriber, guard) =
+            build_subscriber(&log_config(&dir, LogFormat::Json), None).expect("build subscriber");
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("turn", ply = 7);
+            let _entered = span.enter();
+            info!("first");
+            warn!("second");
+        });
+        drop(guard);
+
+        let lines = read_log_lines(&dir);
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            let value: serde_json::Value = serde_json::from_str(line).expect("valid json line");
+            assert!(value.get("fields").is_some());
+        }
+        assert!(lines[0].contains("first"));
+        assert!(lines[1].contains("\"ply\":7"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn bounded_store_evicts_oldest_events() {
+        let cap = 50;
+        let store = TelemetryStore::new().with_max_events(cap);
+        let events: Vec<SystemEvent> = (0..cap + 100)
+            .map(|i| ops_event(Severity::Info, format!("event {i}"), Vec::new()))
+            .collect();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+
+        let kept = store.snapshot_events().await;
+        assert_eq!(kept.len(), cap);
+        assert_eq!(store.evicted_events(), 100);
+        assert_eq!(ids(&kept), ids(&events[100..]));
+    }
+
+    #[tokio::test]
+    async fn evicted_events_remain_in_file_sink() {
+        let dir = std::env::temp_dir().join(format!("minerva-evict-{}", uuid::Uuid::new_v4()));
+        let cap = 20;
+        let store = TelemetryStore::with_persistence(&dir).with_max_events(cap);
+        let events: Vec<SystemEvent> = (0..cap + 100)
+            .map(|i| ops_event(Severity::Info, format!("event {i}"), Vec::new()))
+            .collect();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+
+        assert_eq!(store.snapshot_events().await.len(), cap);
+        assert_eq!(store.evicted_events(), 100);
+        store.flush().await.unwrap();
+        let on_disk = TelemetryStore::from_jsonl(dir.join(EVENT_LOG_FILE))
+            .unwrap()
+            .snapshot_events()
+            .await;
+        assert_eq!(ids(&on_disk), ids(&events));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn init_tracing_twice_is_not_an_error() {
+        let dir = std::env::temp_dir().join(format!("minerva-init-{}", uuid::Uuid::new_v4()));
+        let mut config = log_config(&dir, LogFormat::Text);
+        config.log_file = None;
+        let _first = init_tracing(&config).expect("first init");
+        let _second = init_tracing(&config).expect("second init");
+        let _override =
+            init_tracing_with_filter(&config, Some(EnvFilter::new("warn"))).expect("override");
+        init_tracing_for_tests();
+    }
+
+    /// Warning and error messages `run` logs under a scoped subscriber.
+    fn logged_warnings(run: impl FnOnce()) -> Vec<String> {
+        let sink = OpsEventSink::default();
+        let mut events = sink.attach();
+        let subscriber = Registry::default().with(OpsEventLayer::new(sink));
+        tracing::subscriber::with_default(subscriber, run);
+        std::iter::from_fn(|| events.try_recv().ok())
+            .filter_map(|event| match event.payload {
+                EventPayload::Ops(ops) => Some(ops.message),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn double_init_warns_and_keeps_the_existing_subscriber() {
+        let dir = std::env::temp_dir().join(format!("minerva-init-{}", uuid::Uuid::new_v4()));
+        let mut config = log_config(&dir, LogFormat::Text);
+        config.log_file = None;
+        let warnings = logged_warnings(|| {
+            let _first = init_tracing(&config).expect("first init");
+            let _second = init_tracing(&config).expect("second init");
+        });
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings
+            .iter()
+            .all(|message| message.contains("already initialized")));
+    }
+
+    #[test]
+    fn unopenable_log_file_falls_back_to_logging_without_it() {
+        let dir = std::env::temp_dir().join(format!("minerva-init-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        // A regular file where the log directory should be.
+        let blocker = dir.join("blocker");
+        std::fs::write(&blocker, "").unwrap();
+        let mut config = log_config(&dir, LogFormat::Text);
+        config.log_file = Some(blocker.join("minerva.log").display().to_string());
+
+        let warnings = logged_warnings(|| {
+            assert!(init_tracing(&config).is_err());
+            let _guard = init_tracing_or_fallback(&config);
+        });
+        assert!(
+            warnings
+                .iter()
+                .any(|message| message.contains("continuing without the log file")),
+            "{warnings:?}"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn invalid_log_level_falls_back_to_info() {
+        assert_eq!(log_filter("minerva=[[bogus").to_string(), "info");
+        assert_eq!(log_filter("debug").to_string(), "debug");
+    }
+
+    fn persisted(dir: &Path) -> Vec<SystemEvent> {
+        let path = dir.join(EVENT_LOG_FILE);
+        if !path.exists() {
+            return Vec::new();
+        }
+        read_event_log(&path).unwrap()
+    }
+
+    #[tokio::test]
+    async fn events_are_on_disk_after_flush() {
+        let dir = std::env::temp_dir().join(format!("minerva-flush-{}", uuid::Uuid::new_v4()));
+        let store = TelemetryStore::with_persistence(&dir);
+        let events: Vec<SystemEvent> = (0..10)
+            .map(|i| ops_event(Severity::Info, format!("event {i}"), Vec::new()))
+            .collect();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+
+        store.flush().await.unwrap();
+        assert_eq!(ids(&persisted(&dir)), ids(&events));
+
+        let late = ops_event(Severity::Info, "after flush", Vec::new());
+        store.record_event(late.clone()).await.unwrap();
+        store.flush().await.unwrap();
+        assert_eq!(persisted(&dir).last().map(|e| e.id), Some(late.id));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_drains_queued_backlog() {
+        let dir = std::env::temp_dir().join(format!("minerva-drain-{}", uuid::Uuid::new_v4()));
+        let store = TelemetryStore::with_persistence(&dir).with_writer_capacity(512);
+        let events: Vec<SystemEvent> = (0..400)
+            .map(|i| ops_event(Severity::Info, format!("event {i}"), Vec::new()))
+            .collect();
+        for event in &events {
+            store.record_event(event.clone()).await.unwrap();
+        }
+
+        store.shutdown().await.unwrap();
+        assert_eq!(ids(&persisted(&dir)), ids(&events));
+
+        // Events recorded after shutdown are still persisted, inline.
+        let late = ops_event(Severity::Info, "after shutdown", Vec::new());
+        store.record_event(late.clone()).await.unwrap();
+        assert_eq!(persisted(&dir).last().map(|e| e.id), Some(late.id));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn full_writer_queue_drops_telemetry_events_first() {
+        let dir = std::env::temp_dir().join(format!("minerva-drop-{}", uuid::Uuid::new_v4()));
+        let store = TelemetryStore::with_persistence(&dir).with_writer_capacity(2);
+        // The current-thread writer task cannot run until we yield, so the
+        // queue fills after two lines.
+        for i in 0..5 {
+            let event = SystemEvent::new(
+                EventKind::Telemetry,
+                EventPayload::Telemetry(TelemetryEvent {
+                    latency: None,
+                    notes: Some(format!("sample {i}")),
+                    turn_id: None,
+                    health: None,
+                }),
+            );
+            store.record_event(event).await.unwrap();
+        }
+        let ops = ops_event(Severity::Error, "must persist", Vec::new());
+        store.record_event(ops.clone()).await.unwrap();
+        store.flush().await.unwrap();
+
+        assert_eq!(store.dropped_events(), 3);
+        assert_eq!(store.snapshot_events().await.len(), 6);
+        let on_disk = persisted(&dir);
+        assert_eq!(on_disk.len(), 3);
+        assert_eq!(on_disk.last().map(|e| e.id), Some(ops.id));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn load_store_from_jsonl() {
+        let (_, events) = populated_store().await;
+        let path =
+            std::env::temp_dir().join(format!("minerva-events-{}.jsonl", uuid::Uuid::new_v4()));
+        let mut doc = String::new();
+        for event in &events {
+            doc.push_str(&serde_json::to_string(event).unwrap());
+            doc.push('\n');
+        }
+        doc.push_str("not json\n");
+        std::fs::write(&path, doc).unwrap();
+
+        let store = TelemetryStore::from_jsonl(&path).expect("load events");
+        let result = store
+            .query(EventFilter::new().kind(EventKind::Telemetry))
+            .await;
+        assert_eq!(ids(&result), vec![events[4].id]);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
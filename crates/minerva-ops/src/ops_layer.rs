@@ -0,0 +1,161 @@
+//! Bridges WARN/ERROR tracing events onto the event bus as Ops events.
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use minerva_types::events::{ops_event, Severity, SystemEvent};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Tracing field whose comma-separated value becomes the Ops event's tags.
+pub const TAGS_FIELD: &str = "tags";
+
+/// Handle through which [`OpsEventLayer`] hands events to whoever attached.
+///
+/// Events emitted while nothing is attached are dropped.
+#[derive(Clone, Default)]
+pub struct OpsEventSink {
+    sender: Arc<Mutex<Option<UnboundedSender<SystemEvent>>>>,
+}
+
+impl OpsEventSink {
+    /// Process-wide sink used by the subscriber from [`crate::init_tracing`].
+    pub fn global() -> Self {
+        static GLOBAL: OnceLock<OpsEventSink> = OnceLock::new();
+        GLOBAL.get_or_init(OpsEventSink::default).clone()
+    }
+
+    /// Routes future events to the returned receiver, replacing any previous one.
+    pub fn attach(&self) -> UnboundedReceiver<SystemEvent> {
+        let (tx, rx) = unbounded_channel();
+        if let Ok(mut sender) = self.sender.lock() {
+            *sender = Some(tx);
+        }
+        rx
+    }
+
+    pub fn detach(&self) {
+        if let Ok(mut sender) = self.sender.lock() {
+            *sender = None;
+        }
+    }
+
+    pub(crate) fn send(&self, event: SystemEvent) {
+        if let Ok(mut sender) = self.sender.lock() {
+            if sender.as_ref().is_some_and(|tx| tx.send(event).is_err()) {
+                *sender = None;
+            }
+        }
+    }
+}
+
+/// Tracing layer that turns WARN and ERROR events into Ops [`SystemEvent`]s.
+pub struct OpsEventLayer {
+    sink: OpsEventSink,
+}
+
+impl OpsEventLayer {
+    pub fn new(sink: OpsEventSink) -> Self {
+        Self { sink }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for OpsEventLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let severity = match *metadata.level() {
+            Level::ERROR => Severity::Error,
+            Level::WARN => Severity::Warning,
+            _ => return,
+        };
+        let mut visitor = OpsVisitor::default();
+        event.record(&mut visitor);
+        let mut tags = vec![metadata.target().to_string()];
+        tags.extend(visitor.tags);
+        self.sink.send(ops_event(severity, visitor.message, tags));
+    }
+}
+
+#[derive(Default)]
+struct OpsVisitor {
+    message: String,
+    tags: Vec<String>,
+}
+
+impl OpsVisitor {
+    fn push_tags(&mut self, value: &str) {
+        self.tags.extend(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string),
+        );
+    }
+}
+
+impl Visit for OpsVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            TAGS_FIELD => self.push_tags(value),
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            TAGS_FIELD => self.push_tags(format!("{value:?}").trim_matches('"')),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TelemetryStore;
+    use minerva_types::events::{EventKind, EventPayload};
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    #[tokio::test]
+    async fn warn_lands_in_store_as_warning_ops_event() {
+        let sink = OpsEventSink::default();
+        let mut events = sink.attach();
+        let subscriber = Registry::default().with(OpsEventLayer::new(sink));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("not forwarded");
+            tracing::warn!(tags = "board,snapshot", "snapshot update failed: {}", 3);
+            tracing::error!("engine crashed");
+        });
+
+        let store = TelemetryStore::new();
+        while let Ok(event) = events.try_recv() {
+            store.record_event(event).await.unwrap();
+        }
+
+        let recorded = store.snapshot_events().await;
+        assert_eq!(recorded.len(), 2);
+        assert!(recorded.iter().all(|e| e.kind == EventKind::Ops));
+        match &recorded[0].payload {
+            EventPayload::Ops(ops) => {
+                assert_eq!(ops.severity, Severity::Warning);
+                assert_eq!(ops.message, "snapshot update failed: 3");
+                assert!(ops.tags.iter().any(|t| t == "board"));
+                assert!(ops.tags.iter().any(|t| t == "snapshot"));
+            }
+            other => panic!("unexpected payload: {other:?}"),
+        }
+        match &recorded[1].payload {
+            EventPayload::Ops(ops) => assert_eq!(ops.severity, Severity::Error),
+            other => panic!("unexpected payload: {other:?}"),
+        }
+    }
+}
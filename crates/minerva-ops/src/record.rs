@@ -0,0 +1,234 @@
+//! Match records exportable to the Korean gibo (`.gib`) text format.
+
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use minerva_types::{
+    board::{Piece, PieceKind, PlayerSide, Square},
+    game::{GameResult, Move},
+    ui::FormationPreset,
+    MinervaError, Result,
+};
+use serde::{Deserialize, Serialize};
+
+/// One ply of a recorded game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedMove {
+    pub side: PlayerSide,
+    pub mv: Move,
+    pub piece: Option<PieceKind>,
+    pub captured: Option<Piece>,
+}
+
+/// Move list and metadata for a finished (or in-progress) match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameRecord {
+    pub blue_player: String,
+    pub red_player: String,
+    pub blue_formation: Option<FormationPreset>,
+    pub red_formation: Option<FormationPreset>,
+    pub date: DateTime<Utc>,
+    pub result: Option<GameResult>,
+    pub moves: Vec<RecordedMove>,
+}
+
+impl GameRecord {
+    pub fn new(blue_player: impl Into<String>, red_player: impl Into<String>) -> Self {
+        Self {
+            blue_player: blue_player.into(),
+            red_player: red_player.into(),
+            blue_formation: None,
+            red_formation: None,
+            date: Utc::now(),
+            result: None,
+            moves: Vec::new(),
+        }
+    }
+
+    pub fn with_formation(mut self, side: PlayerSide, formation: FormationPreset) -> Self {
+        match side {
+            PlayerSide::Blue => self.blue_formation = Some(formation),
+            PlayerSide::Red => self.red_formation = Some(formation),
+        }
+        self
+    }
+
+    pub fn with_date(mut self, date: DateTime<Utc>) -> Self {
+        self.date = date;
+        self
+    }
+
+    pub fn push_move(
+        &mut self,
+        side: PlayerSide,
+        mv: Move,
+        piece: Option<PieceKind>,
+        captured: Option<Piece>,
+    ) {
+        self.moves.push(RecordedMove {
+            side,
+            mv,
+            piece,
+            captured,
+        });
+    }
+
+    pub fn set_result(&mut self, result: GameResult) {
+        self.result = Some(result);
+    }
+
+    /// Renders the record as gibo text: bracketed header tags followed by
+    /// numbered moves in `<from><piece><to>` form (row 1 = Han's back rank).
+    pub fn to_gibo(&self) -> String {
+        let mut out = String::new();
+        let tags = [
+            ("대국일자", self.date.format("%Y-%m-%d").to_string()),
+            ("초차림", formation_label(self.blue_formation)),
+            ("한차림", formation_label(self.red_formation)),
+            ("초대국자", self.blue_player.clone()),
+            ("한대국자", self.red_player.clone()),
+            ("대국결과", result_label(self.result)),
+            ("총수", self.moves.len().to_string()),
+        ];
+        for (tag, value) in tags {
+            let _ = writeln!(out, "[{tag} \"{value}\"]");
+        }
+        out.push('\n');
+
+        let plies: Vec<String> = self
+            .moves
+            .iter()
+            .enumerate()
+            .map(|(idx, recorded)| format!("{}. {}", idx + 1, gibo_move(recorded)))
+            .collect();
+        for line in plies.chunks(8) {
+            out.push_str(&line.join(" "));
+            out.push('\n');
+        }
+        out
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|err| MinervaError::Ops(format!("failed to encode game record: {err}")))
+    }
+
+    /// Writes `match_<ts>.gib` into `dir`, returning its path.
+    pub fn write_gibo(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)
+            .map_err(|err| MinervaError::Ops(format!("failed to create record dir: {err}")))?;
+        let path = dir.join(format!("match_{}.gib", self.date.format("%Y%m%d_%H%M%S")));
+        std::fs::write(&path, self.to_gibo())
+            .map_err(|err| MinervaError::Ops(format!("failed to write gibo: {err}")))?;
+        Ok(path)
+    }
+}
+
+fn gibo_move(recorded: &RecordedMove) -> String {
+    if recorded.mv.from == recorded.mv.to {
+        return "한수쉼".into();
+    }
+    let piece = recorded
+        .piece
+        .map(|kind| kind.label(recorded.side))
+        .unwrap_or("?");
+    format!(
+        "{}{}{}",
+        gibo_square(recorded.mv.from),
+        piece,
+        gibo_square(recorded.mv.to)
+    )
+}
+
+/// Row digit (1 at Han's back rank, 0 for the tenth row) followed by the column.
+fn gibo_square(square: Square) -> String {
+    let row = (10 - square.rank as u32) % 10;
+    format!("{}{}", row, square.file as u32 + 1)
+}
+
+fn formation_label(formation: Option<FormationPreset>) -> String {
+    match formation {
+        Some(FormationPreset::MasangMasang) => "마상마상",
+        Some(FormationPreset::SangMasangMa) => "상마상마",
+        Some(FormationPreset::MasangSangMa) => "마상상마",
+        Some(FormationPreset::SangMaMaSang) => "상마마상",
+        None => "",
+    }
+    .into()
+}
+
+fn result_label(result: Option<GameResult>) -> String {
+    match result {
+        Some(GameResult::Win {
+            winner: PlayerSide::Blue,
+            ..
+        }) => "초 완승".into(),
+        Some(GameResult::Win {
+            winner: PlayerSide::Red,
+            ..
+        }) => "한 완승".into(),
+        Some(GameResult::Draw { .. }) => "무승부".into(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use minerva_types::game::WinReason;
+
+    fn short_match() -> GameRecord {
+        let mut record = GameRecord::new("Minerva", "Opponent")
+            .with_formation(PlayerSide::Blue, FormationPreset::MasangSangMa)
+            .with_formation(PlayerSide::Red, FormationPreset::SangMasangMa)
+            .with_date(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        let plies = [
+            (PlayerSide::Blue, (0, 3), (0, 4), PieceKind::Soldier, None),
+            (PlayerSide::Red, (8, 6), (8, 5), PieceKind::Soldier, None),
+            (PlayerSide::Blue, (1, 2), (1, 7), PieceKind::Cannon, None),
+            (
+                PlayerSide::Red,
+                (0, 9),
+                (0, 4),
+                PieceKind::Chariot,
+                Some(Piece {
+                    owner: PlayerSide::Blue,
+                    kind: PieceKind::Soldier,
+                }),
+            ),
+        ];
+        for (side, from, to, kind, captured) in plies {
+            record.push_move(
+                side,
+                Move::new(Square::new(from.0, from.1), Square::new(to.0, to.1)),
+                Some(kind),
+                captured,
+            );
+        }
+        record.set_result(GameResult::Win {
+            winner: PlayerSide::Red,
+            reason: WinReason::Resignation,
+        });
+        record
+    }
+
+    #[test]
+    fn gibo_matches_golden_file() {
+        let expected = include_str!("../testdata/short_match.gib");
+        assert_eq!(short_match().to_gibo(), expected);
+    }
+
+    #[test]
+    fn json_round_trip_keeps_moves() {
+        let record = short_match();
+        let json = record.to_json().unwrap();
+        let parsed: GameRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.moves.len(), 4);
+        assert_eq!(parsed.result, record.result);
+        assert_eq!(parsed.moves[3].captured, record.moves[3].captured);
+    }
+}
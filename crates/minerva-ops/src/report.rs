@@ -0,0 +1,249 @@
+//! Human-readable per-match summary (`report.md`).
+
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use minerva_types::{board::PieceKind, telemetry::MatchTelemetry, MinervaError, Result};
+
+use crate::record::{GameRecord, RecordedMove};
+
+/// File name of the match report inside a match directory.
+pub const REPORT_FILE: &str = "report.md";
+
+/// Renders the markdown report for a finished match.
+///
+/// Moves are written in algebraic form: piece letter, origin, `-` or `x`
+/// (capture), destination, with files `a`-`i` and ranks `1`-`10` counted from
+/// Blue's back rank.
+pub fn render_match_report(telemetry: &MatchTelemetry, record: &GameRecord) -> String {
+    let mut out = String::from("# Match report\n\n");
+    let match_id = telemetry
+        .match_id
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| "-".into());
+    let result = record
+        .result
+        .map(|result| result.to_string())
+        .unwrap_or_else(|| "unfinished".into());
+    let decisions: Vec<u64> = telemetry
+        .latency_samples
+        .iter()
+        .map(|sample| sample.decision_ms)
+        .collect();
+    let decision_time = match decisions.iter().max() {
+        Some(max) => format!(
+            "avg {} ms, max {max} ms",
+            decisions.iter().sum::<u64>() / decisions.len() as u64
+        ),
+        None => "-".into(),
+    };
+    let low_confidence: usize = telemetry
+        .turns
+        .iter()
+        .map(|turn| turn.recognition.low_confidence_squares)
+        .sum();
+    let turn_anomalies: usize = telemetry
+        .turns
+        .iter()
+        .map(|turn| turn.anomalies.len())
+        .sum();
+    let controller = telemetry.controller_summary.clone().unwrap_or_default();
+
+    let lines = [
+        ("Match", match_id),
+        ("Date", record.date.format("%Y-%m-%d %H:%M UTC").to_string()),
+        (
+            "Players",
+            format!(
+                "{} (Blue) vs {} (Red)",
+                record.blue_player, record.red_player
+            ),
+        ),
+        ("Result", result),
+        ("Turns", telemetry.latency_samples.len().to_string()),
+        ("Plies", record.moves.len().to_string()),
+        ("Decision time", decision_time),
+        ("Recognition anomalies", low_confidence.to_string()),
+        ("Turn anomalies", turn_anomalies.to_string()),
+        ("Controller retries", controller.retries.to_string()),
+        ("Controller failures", controller.failed_inputs.to_string()),
+    ];
+    for (label, value) in lines {
+        let _ = writeln!(out, "- {label}: {value}");
+    }
+
+    out.push_str("\n## Moves\n\n");
+    if record.moves.is_empty() {
+        out.push_str("(none)\n");
+    }
+    for (idx, pair) in record.moves.chunks(2).enumerate() {
+        let plies: Vec<String> = pair.iter().map(algebraic_move).collect();
+        let _ = writeln!(out, "{}. {}", idx + 1, plies.join(" "));
+    }
+    out
+}
+
+/// Writes [`REPORT_FILE`] into `dir`, returning its path.
+pub fn write_match_report(
+    telemetry: &MatchTelemetry,
+    record: &GameRecord,
+    dir: &Path,
+) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)
+        .map_err(|err| MinervaError::Ops(format!("failed to create report dir: {err}")))?;
+    let path = dir.join(REPORT_FILE);
+    std::fs::write(&path, render_match_report(telemetry, record))
+        .map_err(|err| MinervaError::Ops(format!("failed to write match report: {err}")))?;
+    Ok(path)
+}
+
+fn algebraic_move(recorded: &RecordedMove) -> String {
+    if recorded.mv.from == recorded.mv.to {
+        return "pass".into();
+    }
+    let piece = recorded.piece.map(piece_letter).unwrap_or("?");
+    let separator = if recorded.captured.is_some() {
+        "x"
+    } else {
+        "-"
+    };
+    format!("{piece}{}{separator}{}", recorded.mv.from, recorded.mv.to)
+}
+
+fn piece_letter(kind: PieceKind) -> &'static str {
+    match kind {
+        PieceKind::General => "K",
+        PieceKind::Guard => "A",
+        PieceKind::Elephant => "E",
+        PieceKind::Horse => "H",
+        PieceKind::Chariot => "R",
+        PieceKind::Cannon => "C",
+        PieceKind::Soldier => "P",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use minerva_types::{
+        board::{Piece, PlayerSide, Square},
+        game::{GameResult, Move, WinReason},
+        telemetry::{
+            ControllerSummary, EngineMetrics, LatencySample, RecognitionSummary, TurnRecord,
+        },
+    };
+    use uuid::Uuid;
+
+    fn fixture() -> (MatchTelemetry, GameRecord) {
+        let mut record = GameRecord::new("Minerva", "Opponent")
+            .with_date(Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        let plies = [
+            (PlayerSide::Blue, (0, 3), (0, 4), PieceKind::Soldier, None),
+            (PlayerSide::Red, (8, 6), (8, 5), PieceKind::Soldier, None),
+            (
+                PlayerSide::Blue,
+                (0, 0),
+                (0, 2),
+                PieceKind::Chariot,
+                Some(Piece {
+                    owner: PlayerSide::Red,
+                    kind: PieceKind::Horse,
+                }),
+            ),
+        ];
+        for (side, from, to, kind, captured) in plies {
+            record.push_move(
+                side,
+                Move::new(Square::new(from.0, from.1), Square::new(to.0, to.1)),
+                Some(kind),
+                captured,
+            );
+        }
+        record.set_result(GameResult::Win {
+            winner: PlayerSide::Blue,
+            reason: WinReason::Resignation,
+        });
+
+        let turns: Vec<TurnRecord> = [(40, 1, vec![]), (80, 0, vec!["realigned".to_string()])]
+            .into_iter()
+            .enumerate()
+            .map(
+                |(index, (decision_ms, low_confidence, anomalies))| TurnRecord {
+                    index: index as u32,
+                    turn_id: Uuid::new_v4(),
+                    side: PlayerSide::Blue,
+                    board_hash: 0,
+                    chosen_move: None,
+                    engine: EngineMetrics::default(),
+                    latency: LatencySample {
+                        observation_ms: 5,
+                        decision_ms,
+                        injection_ms: 5,
+                        total_ms: decision_ms + 10,
+                        prefetch_saved_ms: 0,
+                        captured_at: Utc::now(),
+                    },
+                    stages: Default::default(),
+                    recognition: RecognitionSummary {
+                        low_confidence_squares: low_confidence,
+                        mean_confidence: None,
+                    },
+                    frame_path: None,
+                    anomalies,
+                },
+            )
+            .collect();
+        let telemetry = MatchTelemetry {
+            latency_samples: turns.iter().map(|turn| turn.latency.clone()).collect(),
+            turns,
+            controller_summary: Some(ControllerSummary {
+                successful_inputs: 4,
+                failed_inputs: 1,
+                retries: 3,
+                last_latency_ms: None,
+            }),
+            ..MatchTelemetry::default()
+        };
+        (telemetry, record)
+    }
+
+    #[test]
+    fn report_summarizes_fixture_match() {
+        let (telemetry, record) = fixture();
+        let report = render_match_report(&telemetry, &record);
+        let lines: Vec<&str> = report.lines().collect();
+        for expected in [
+            "- Result: Blue wins (Resignation)",
+            "- Turns: 2",
+            "- Plies: 3",
+            "- Decision time: avg 60 ms, max 80 ms",
+            "- Recognition anomalies: 1",
+            "- Turn anomalies: 1",
+            "- Controller retries: 3",
+            "- Controller failures: 1",
+            "1. Pa4-a5 Pi7-i6",
+            "2. Ra1xa3",
+        ] {
+            assert!(
+                lines.contains(&expected),
+                "missing {expected:?} in\n{report}"
+            );
+        }
+    }
+
+    #[test]
+    fn report_is_written_into_the_match_dir() {
+        let (telemetry, record) = fixture();
+        let dir = std::env::temp_dir().join(format!("minerva-report-{}", Uuid::new_v4()));
+        let path = write_match_report(&telemetry, &record, &dir).unwrap();
+        assert_eq!(path, dir.join(REPORT_FILE));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            render_match_report(&telemetry, &record)
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
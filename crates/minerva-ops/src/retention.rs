@@ -0,0 +1,306 @@
+//! Retention policy for the telemetry directory.
+//!
+//! Match directories (`session_<id>/match_<id>`) are the unit of deletion:
+//! anything older than the age limit goes first, then the oldest remaining
+//! matches until the whole directory fits the byte budget. The active session
+//! is never touched.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use minerva_types::{
+    config::OpsConfig,
+    events::{ops_event, Severity, SystemEvent},
+    MinervaError, Result,
+};
+use tokio::{sync::mpsc::UnboundedSender, task::JoinHandle};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::TelemetryStore;
+
+/// How often the sweeper re-applies the policy after the boot sweep.
+pub const SWEEP_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub max_bytes: Option<u64>,
+    pub max_age: Option<Duration>,
+}
+
+impl RetentionPolicy {
+    pub fn from_config(config: &OpsConfig) -> Self {
+        Self {
+            max_bytes: config.telemetry_max_mb.map(|mb| mb * 1024 * 1024),
+            max_age: config
+                .telemetry_max_age_days
+                .map(|days| Duration::from_secs(days * 24 * 60 * 60)),
+        }
+    }
+
+    pub fn is_unbounded(&self) -> bool {
+        self.max_bytes.is_none() && self.max_age.is_none()
+    }
+}
+
+/// What a prune removed (or would remove, for a dry run).
+#[derive(Debug, Clone, Default)]
+pub struct PruneReport {
+    pub removed: Vec<PathBuf>,
+    pub freed_bytes: u64,
+    /// Size of the telemetry directory after pruning.
+    pub remaining_bytes: u64,
+    pub dry_run: bool,
+}
+
+impl PruneReport {
+    pub fn summary(&self) -> String {
+        format!(
+            "{}텔레메트리 정리: 매치 {}개, {} bytes 삭제 (남은 용량 {} bytes)",
+            if self.dry_run { "[dry-run] " } else { "" },
+            self.removed.len(),
+            self.freed_bytes,
+            self.remaining_bytes
+        )
+    }
+}
+
+struct MatchDir {
+    path: PathBuf,
+    modified: SystemTime,
+    bytes: u64,
+}
+
+/// Applies `policy` to `dir`, skipping everything under `active_session`.
+pub fn prune_telemetry(
+    dir: &Path,
+    policy: &RetentionPolicy,
+    active_session: Option<Uuid>,
+    now: SystemTime,
+    dry_run: bool,
+) -> Result<PruneReport> {
+    let mut report = PruneReport {
+        dry_run,
+        ..PruneReport::default()
+    };
+    if !dir.is_dir() {
+        return Ok(report);
+    }
+    let mut total = dir_size(dir)?;
+    let active = active_session.map(|id| format!("session_{id}"));
+    let mut candidates = Vec::new();
+    for session in read_dir(dir)? {
+        let name = session.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if !session.is_dir() || !name.starts_with("session_") || active.as_deref() == Some(name) {
+            continue;
+        }
+        for path in read_dir(&session)? {
+            let is_match = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with("match_"));
+            if !is_match || !path.is_dir() {
+                continue;
+            }
+            let modified = fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .map_err(|err| ops_error(&path, err))?;
+            let bytes = dir_size(&path)?;
+            candidates.push(MatchDir {
+                path,
+                modified,
+                bytes,
+            });
+        }
+    }
+    candidates.sort_by_key(|candidate| candidate.modified);
+
+    for candidate in candidates {
+        let expired = policy.max_age.is_some_and(|max_age| {
+            now.duration_since(candidate.modified)
+                .is_ok_and(|age| age > max_age)
+        });
+        let over_budget = policy.max_bytes.is_some_and(|max| total > max);
+        if !expired && !over_budget {
+            continue;
+        }
+        if !dry_run {
+            fs::remove_dir_all(&candidate.path).map_err(|err| ops_error(&candidate.path, err))?;
+            remove_if_empty(candidate.path.parent());
+        }
+        total = total.saturating_sub(candidate.bytes);
+        report.freed_bytes += candidate.bytes;
+        report.removed.push(candidate.path);
+    }
+    report.remaining_bytes = total;
+    Ok(report)
+}
+
+/// Prunes the store's telemetry directory on spawn and every [`SWEEP_INTERVAL`].
+pub struct RetentionSweeper {
+    store: TelemetryStore,
+    policy: RetentionPolicy,
+    active_session: Option<Uuid>,
+}
+
+impl RetentionSweeper {
+    pub fn new(
+        store: TelemetryStore,
+        policy: RetentionPolicy,
+        active_session: Option<Uuid>,
+    ) -> Self {
+        Self {
+            store,
+            policy,
+            active_session,
+        }
+    }
+
+    /// Runs one prune. When something was removed, records and returns an
+    /// Ops event summarizing it.
+    pub async fn sweep(&self) -> Result<Option<SystemEvent>> {
+        let Some(dir) = self.store.persist_dir().map(Path::to_path_buf) else {
+            return Ok(None);
+        };
+        let policy = self.policy;
+        let active_session = self.active_session;
+        let report = tokio::task::spawn_blocking(move || {
+            prune_telemetry(&dir, &policy, active_session, SystemTime::now(), false)
+        })
+        .await
+        .map_err(|err| MinervaError::Ops(format!("retention sweep panicked: {err}")))??;
+        if report.removed.is_empty() {
+            return Ok(None);
+        }
+        let event = ops_event(Severity::Info, report.summary(), vec!["retention".into()]);
+        self.store.record_event(event.clone()).await?;
+        Ok(Some(event))
+    }
+
+    /// Sweeps immediately and then daily, forwarding summaries to `events`.
+    pub fn spawn(self, events: UnboundedSender<SystemEvent>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(SWEEP_INTERVAL);
+            loop {
+                ticker.tick().await;
+                match self.sweep().await {
+                    Ok(Some(event)) => {
+                        if events.send(event).is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(err) => warn!("telemetry retention sweep failed: {err}"),
+                }
+            }
+        })
+    }
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    fs::read_dir(dir)
+        .map_err(|err| ops_error(dir, err))?
+        .map(|entry| entry.map(|e| e.path()).map_err(|err| ops_error(dir, err)))
+        .collect()
+}
+
+fn dir_size(path: &Path) -> Result<u64> {
+    let meta = fs::symlink_metadata(path).map_err(|err| ops_error(path, err))?;
+    if !meta.is_dir() {
+        return Ok(meta.len());
+    }
+    read_dir(path)?
+        .iter()
+        .try_fold(0, |total, child| Ok(total + dir_size(child)?))
+}
+
+fn remove_if_empty(dir: Option<&Path>) {
+    if let Some(dir) = dir {
+        if fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_none()) {
+            let _ = fs::remove_dir(dir);
+        }
+    }
+}
+
+fn ops_error(path: &Path, err: std::io::Error) -> MinervaError {
+    MinervaError::Ops(format!("retention failed for {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Creates `session_<session>/match_<n>` holding `bytes` bytes, last
+    /// modified `age` before `now`.
+    fn fake_match(
+        root: &Path,
+        session: Uuid,
+        n: u32,
+        bytes: usize,
+        age: Duration,
+        now: SystemTime,
+    ) -> PathBuf {
+        let dir = root
+            .join(format!("session_{session}"))
+            .join(format!("match_{n}"));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("events.jsonl"), vec![b'x'; bytes]).unwrap();
+        fs::File::open(&dir)
+            .unwrap()
+            .set_modified(now - age)
+            .unwrap();
+        dir
+    }
+
+    #[test]
+    fn oldest_matches_go_first_and_active_session_is_kept() {
+        let root = std::env::temp_dir().join(format!("minerva-retention-{}", Uuid::new_v4()));
+        let now = SystemTime::now();
+        let (old, active) = (Uuid::new_v4(), Uuid::new_v4());
+        let oldest = fake_match(&root, old, 1, 400, 5 * DAY, now);
+        let older = fake_match(&root, old, 2, 400, 4 * DAY, now);
+        let recent = fake_match(&root, old, 3, 400, DAY, now);
+        let live = fake_match(&root, active, 1, 400, 10 * DAY, now);
+
+        let policy = RetentionPolicy {
+            max_bytes: Some(1_000),
+            max_age: None,
+        };
+        let dry = prune_telemetry(&root, &policy, Some(active), now, true).unwrap();
+        assert_eq!(dry.removed, [oldest.clone(), older.clone()]);
+        assert!(oldest.exists());
+
+        let report = prune_telemetry(&root, &policy, Some(active), now, false).unwrap();
+        assert_eq!(report.removed, [oldest.clone(), older.clone()]);
+        assert_eq!(report.freed_bytes, 800);
+        assert_eq!(report.remaining_bytes, 800);
+        assert!(!oldest.exists() && !older.exists());
+        assert!(recent.exists() && live.exists());
+
+        fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn matches_past_max_age_are_removed_regardless_of_size() {
+        let root = std::env::temp_dir().join(format!("minerva-retention-{}", Uuid::new_v4()));
+        let now = SystemTime::now();
+        let session = Uuid::new_v4();
+        let stale = fake_match(&root, session, 1, 10, 8 * DAY, now);
+        let fresh = fake_match(&root, session, 2, 10, DAY, now);
+
+        let policy = RetentionPolicy {
+            max_bytes: None,
+            max_age: Some(7 * DAY),
+        };
+        let report = prune_telemetry(&root, &policy, None, now, false).unwrap();
+        assert_eq!(report.removed, std::slice::from_ref(&stale));
+        assert!(!stale.exists() && fresh.exists());
+
+        fs::remove_dir_all(&root).ok();
+    }
+}
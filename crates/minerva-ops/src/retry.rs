@@ -0,0 +1,151 @@
+//! Retry-with-backoff for fallible async operations.
+
+use std::{
+    future::Future,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use minerva_types::{config::RetryPolicy, MinervaError, Result};
+use tracing::warn;
+
+/// Runs `op` until it succeeds or `policy.max_attempts` is used up, retrying
+/// every error. Returns the last error on failure.
+pub async fn retry<T, F, Fut>(op: F, policy: &RetryPolicy) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    retry_if(op, policy, |_| true).await
+}
+
+/// Like [`retry`], but gives up immediately on errors `retryable` rejects.
+pub async fn retry_if<T, F, Fut, R>(mut op: F, policy: &RetryPolicy, retryable: R) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+    R: Fn(&MinervaError) -> bool,
+{
+    let max_attempts = policy.max_attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt < max_attempts && retryable(&err) => {
+                let delay = backoff_delay(policy, attempt);
+                warn!(
+                    "attempt {attempt}/{max_attempts} failed, retrying in {}ms: {err}",
+                    delay.as_millis()
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Delay before the attempt following `attempt` (1-based).
+pub fn backoff_delay(policy: &RetryPolicy, attempt: u32) -> Duration {
+    let exponential = policy
+        .base_delay_ms
+        .saturating_mul(1u64 << (attempt - 1).min(32));
+    let capped = exponential.min(policy.max_delay_ms);
+    jittered(Duration::from_millis(capped), policy.jitter)
+}
+
+/// `delay` scaled by a random factor in `1 ± jitter` (clamped to `0..=1`).
+pub fn jittered(delay: Duration, jitter: f32) -> Duration {
+    let jitter = f64::from(jitter.clamp(0.0, 1.0));
+    let factor = 1.0 + jitter * (2.0 * unit_noise() - 1.0);
+    Duration::from_millis((delay.as_millis() as f64 * factor).round() as u64)
+}
+
+/// Cheap value in `[0, 1)`; good enough to spread out retries.
+fn unit_noise() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or_default();
+    let mixed = u64::from(nanos).wrapping_mul(0x9e37_79b9_7f4a_7c15) >> 11;
+    mixed as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use tokio::time::Instant;
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay_ms: 100,
+            max_delay_ms: 250,
+            jitter: 0.0,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_with_capped_exponential_backoff_until_success() {
+        let calls = AtomicU32::new(0);
+        let started = Instant::now();
+        let value = retry(
+            || async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0..=2 => Err(MinervaError::Controller("busy".into())),
+                    n => Ok(n),
+                }
+            },
+            &policy(5),
+        )
+        .await
+        .unwrap();
+        assert_eq!(value, 3);
+        // 100 + 200 + min(400, 250)
+        assert_eq!(started.elapsed(), Duration::from_millis(550));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_after_max_attempts_with_last_error() {
+        let calls = AtomicU32::new(0);
+        let err = retry(
+            || async {
+                let n = calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(MinervaError::Controller(format!("failure {n}")))
+            },
+            &policy(3),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(err.to_string().contains("failure 2"));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn non_retryable_errors_fail_fast() {
+        let calls = AtomicU32::new(0);
+        let result = retry_if(
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(MinervaError::Configuration("bad serial".into()))
+            },
+            &policy(5),
+            |err| matches!(err, MinervaError::Controller(_)),
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy {
+            jitter: 0.5,
+            ..policy(3)
+        };
+        for _ in 0..100 {
+            let delay = backoff_delay(&policy, 1).as_millis();
+            assert!((50..=150).contains(&delay), "{delay}");
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Match state saved after every turn so a restarted process can pick the
+//! game back up instead of starting a new one.
+
+use std::{fs, io::ErrorKind, path::Path, time::Duration};
+
+use chrono::{DateTime, Utc};
+use minerva_types::{board::PlayerSide, game::GameSnapshot, MinervaError, Result};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::GameRecord;
+
+/// File name of the persisted [`SessionState`] in the telemetry directory.
+pub const SESSION_STATE_FILE: &str = "state.json";
+
+/// Where a match in progress stands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionState {
+    pub session_id: Uuid,
+    pub match_id: Option<Uuid>,
+    pub our_side: Option<PlayerSide>,
+    /// The tracked position, with its ply and clocks.
+    pub snapshot: GameSnapshot,
+    /// Moves played so far, so the resumed match's gibo is complete.
+    pub record: GameRecord,
+    pub saved_at: DateTime<Utc>,
+}
+
+impl SessionState {
+    /// Writes the state to [`SESSION_STATE_FILE`] in `dir`, replacing the
+    /// previous one. The file is written aside and renamed into place, so a
+    /// crash mid-write leaves the last complete state.
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|err| MinervaError::Ops(format!("failed to encode session state: {err}")))?;
+        fs::create_dir_all(dir)
+            .map_err(|err| MinervaError::Ops(format!("failed to create state dir: {err}")))?;
+        let staged = dir.join(format!("{SESSION_STATE_FILE}.tmp"));
+        fs::write(&staged, json)
+            .and_then(|()| fs::rename(&staged, dir.join(SESSION_STATE_FILE)))
+            .map_err(|err| MinervaError::Ops(format!("failed to write session state: {err}")))
+    }
+
+    /// The state saved in `dir`, unless there is none or it was saved more
+    /// than `max_age` ago.
+    pub fn load_recent(dir: &Path, max_age: Duration) -> Result<Option<Self>> {
+        let contents = match fs::read_to_string(dir.join(SESSION_STATE_FILE)) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(MinervaError::Ops(format!(
+                    "failed to read session state: {err}"
+                )))
+            }
+        };
+        let state: Self = serde_json::from_str(&contents)
+            .map_err(|err| MinervaError::Ops(format!("failed to decode session state: {err}")))?;
+        let age = (Utc::now() - state.saved_at).to_std().unwrap_or_default();
+        Ok((age <= max_age).then_some(state))
+    }
+
+    /// Removes the state saved in `dir`, if any, once its match is over.
+    pub fn clear(dir: &Path) -> Result<()> {
+        match fs::remove_file(dir.join(SESSION_STATE_FILE)) {
+            Err(err) if err.kind() != ErrorKind::NotFound => Err(MinervaError::Ops(format!(
+                "failed to remove session state: {err}"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::board::BoardState;
+
+    #[test]
+    fn only_recent_states_are_loaded() {
+        let dir = std::env::temp_dir().join(format!("minerva-state-{}", Uuid::new_v4()));
+        let mut state = SessionState {
+            session_id: Uuid::new_v4(),
+            match_id: Some(Uuid::new_v4()),
+            our_side: Some(PlayerSide::Red),
+            snapshot: GameSnapshot {
+                board: BoardState::initial(),
+                ply: 12,
+                ..GameSnapshot::default()
+            },
+            record: GameRecord::new("Minerva", "Opponent"),
+            saved_at: Utc::now(),
+        };
+        assert!(SessionState::load_recent(&dir, Duration::from_secs(60))
+            .unwrap()
+            .is_none());
+
+        state.save(&dir).unwrap();
+        let loaded = SessionState::load_recent(&dir, Duration::from_secs(60))
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.match_id, state.match_id);
+        assert_eq!(loaded.our_side, Some(PlayerSide::Red));
+        assert_eq!(loaded.snapshot.ply, 12);
+
+        state.saved_at = Utc::now() - chrono::Duration::minutes(5);
+        state.save(&dir).unwrap();
+        assert!(SessionState::load_recent(&dir, Duration::from_secs(60))
+            .unwrap()
+            .is_none());
+
+        SessionState::clear(&dir).unwrap();
+        SessionState::clear(&dir).unwrap();
+        assert!(!dir.join(SESSION_STATE_FILE).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
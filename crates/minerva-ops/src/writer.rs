@@ -0,0 +1,176 @@
+//! Background task that appends persisted events off the publish path.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use minerva_types::{events::EventKind, MinervaError, Result};
+use tokio::sync::{
+    mpsc::{self, error::TrySendError},
+    oneshot,
+};
+use tracing::warn;
+
+/// Queue depth between `record_event` and the writer task.
+pub const WRITER_CHANNEL_CAPACITY: usize = 1024;
+/// Maximum time written lines may sit without an fsync.
+const SYNC_INTERVAL: Duration = Duration::from_secs(1);
+/// Upper bound on lines written per batch before checking for commands again.
+const MAX_BATCH: usize = 256;
+
+enum Command {
+    Append { path: PathBuf, line: String },
+    Flush(oneshot::Sender<()>),
+    Shutdown(oneshot::Sender<()>),
+}
+
+/// Handle to the writer task; cheap to clone.
+#[derive(Clone)]
+pub(crate) struct EventWriter {
+    tx: mpsc::Sender<Command>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl EventWriter {
+    /// Spawns the writer on the current tokio runtime.
+    pub(crate) fn spawn(capacity: usize, dropped: Arc<AtomicU64>) -> Self {
+        let (tx, rx) = mpsc::channel(capacity.max(1));
+        tokio::spawn(run(rx));
+        Self { tx, dropped }
+    }
+
+    /// Queues `line` for `path`. Telemetry events are dropped (and counted)
+    /// when the queue is full; everything else waits for room.
+    pub(crate) async fn append(&self, kind: &EventKind, path: PathBuf, line: String) -> Result<()> {
+        let command = Command::Append { path, line };
+        if *kind == EventKind::Telemetry {
+            return match self.tx.try_send(command) {
+                Ok(()) => Ok(()),
+                Err(TrySendError::Full(_)) => {
+                    self.dropped.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                }
+                Err(TrySendError::Closed(_)) => Err(writer_closed()),
+            };
+        }
+        self.tx.send(command).await.map_err(|_| writer_closed())
+    }
+
+    /// Commands queued but not yet picked up by the task.
+    pub(crate) fn queue_depth(&self) -> usize {
+        self.tx.max_capacity() - self.tx.capacity()
+    }
+
+    /// Waits until everything queued so far is written and synced.
+    pub(crate) async fn flush(&self) -> Result<()> {
+        let (done, wait) = oneshot::channel();
+        self.tx
+            .send(Command::Flush(done))
+            .await
+            .map_err(|_| writer_closed())?;
+        wait.await.map_err(|_| writer_closed())
+    }
+
+    /// Like [`Self::flush`], but callable from synchronous code such as a panic
+    /// hook. Returns whether the flush completed within `timeout`.
+    pub(crate) fn flush_blocking(&self, timeout: Duration) -> bool {
+        let (done, wait) = oneshot::channel();
+        if self.tx.try_send(Command::Flush(done)).is_err() {
+            return false;
+        }
+        // The caller may be on a runtime thread, where blocking_recv panics.
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let _ = tx.send(wait.blocking_recv().is_ok());
+        });
+        rx.recv_timeout(timeout).unwrap_or(false)
+    }
+
+    /// Drains the queue, syncs, and stops the task.
+    pub(crate) async fn shutdown(&self) -> Result<()> {
+        let (done, wait) = oneshot::channel();
+        if self.tx.send(Command::Shutdown(done)).await.is_err() {
+            return Ok(());
+        }
+        wait.await.map_err(|_| writer_closed())
+    }
+}
+
+fn writer_closed() -> MinervaError {
+    MinervaError::Ops("telemetry writer has shut down".into())
+}
+
+async fn run(mut rx: mpsc::Receiver<Command>) {
+    let mut dirty = BTreeSet::new();
+    let mut last_sync = Instant::now();
+    while let Some(first) = rx.recv().await {
+        let mut batch: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+        let mut waiters = Vec::new();
+        let mut stop = false;
+        let mut next = Some(first);
+        let mut queued = 0;
+        while let Some(command) = next.take() {
+            match command {
+                Command::Append { path, line } => {
+                    batch.entry(path).or_default().push(line);
+                    queued += 1;
+                }
+                Command::Flush(done) => waiters.push(done),
+                Command::Shutdown(done) => {
+                    waiters.push(done);
+                    stop = true;
+                }
+            }
+            if !stop && queued < MAX_BATCH {
+                next = rx.try_recv().ok();
+            }
+        }
+
+        let sync = !waiters.is_empty() || last_sync.elapsed() >= SYNC_INTERVAL;
+        let pending = std::mem::take(&mut dirty);
+        let result = tokio::task::spawn_blocking(move || write_batch(batch, pending, sync)).await;
+        match result {
+            Ok(Ok(remaining)) => dirty = remaining,
+            Ok(Err(err)) => warn!("telemetry write failed: {err}"),
+            Err(err) => warn!("telemetry writer panicked: {err}"),
+        }
+        if sync {
+            last_sync = Instant::now();
+        }
+        for done in waiters {
+            let _ = done.send(());
+        }
+        if stop {
+            break;
+        }
+    }
+}
+
+/// Appends each path's lines and fsyncs dirty files when `sync` is set.
+/// Returns the files written but not yet synced.
+fn write_batch(
+    batch: BTreeMap<PathBuf, Vec<String>>,
+    mut dirty: BTreeSet<PathBuf>,
+    sync: bool,
+) -> Result<BTreeSet<PathBuf>> {
+    for (path, lines) in batch {
+        crate::append_lines(&path, &lines)?;
+        dirty.insert(path);
+    }
+    if sync {
+        for path in std::mem::take(&mut dirty) {
+            std::fs::File::open(&path)
+                .and_then(|file| file.sync_all())
+                .map_err(|err| {
+                    MinervaError::Ops(format!("failed to sync {}: {err}", path.display()))
+                })?;
+        }
+    }
+    Ok(dirty)
+}
@@ -0,0 +1,138 @@
+//! Step-by-step construction of an [`Orchestrator`].
+
+use minerva_controller::DeviceController;
+use minerva_engine::GameEngine;
+use minerva_network::{RealtimeServer, StatusHandle};
+use minerva_ops::{OpsEventSink, TelemetryStore};
+use minerva_types::{config::OrchestratorConfig, events::RemoteCommand, Result};
+use minerva_vision::BoardRecognizer;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::{orchestrator_error, Orchestrator, TurnObserver};
+
+/// Collects an [`Orchestrator`]'s parts by name. The config, controller,
+/// recognizer, engine, network, and telemetry store are required; `build`
+/// reports the first one missing.
+pub struct OrchestratorBuilder<C, V, E, N> {
+    config: Option<OrchestratorConfig>,
+    controller: Option<C>,
+    recognizer: Option<V>,
+    engine: Option<E>,
+    network: Option<N>,
+    telemetry: Option<TelemetryStore>,
+    commands: Option<UnboundedReceiver<RemoteCommand>>,
+    ops_events: Option<OpsEventSink>,
+    status: Option<StatusHandle>,
+    observers: Vec<Box<dyn TurnObserver>>,
+}
+
+impl<C, V, E, N> Default for OrchestratorBuilder<C, V, E, N> {
+    fn default() -> Self {
+        Self {
+            config: None,
+            controller: None,
+            recognizer: None,
+            engine: None,
+            network: None,
+            telemetry: None,
+            commands: None,
+            ops_events: None,
+            status: None,
+            observers: Vec::new(),
+        }
+    }
+}
+
+impl<C, V, E, N> OrchestratorBuilder<C, V, E, N>
+where
+    C: DeviceController,
+    V: BoardRecognizer,
+    E: GameEngine,
+    N: RealtimeServer,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn config(mut self, config: OrchestratorConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    pub fn controller(mut self, controller: C) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    pub fn recognizer(mut self, recognizer: V) -> Self {
+        self.recognizer = Some(recognizer);
+        self
+    }
+
+    pub fn engine(mut self, engine: E) -> Self {
+        self.engine = Some(engine);
+        self
+    }
+
+    pub fn network(mut self, network: N) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    pub fn telemetry(mut self, telemetry: TelemetryStore) -> Self {
+        self.telemetry = Some(telemetry);
+        self
+    }
+
+    /// See [`Orchestrator::attach_commands`].
+    pub fn commands(mut self, commands: UnboundedReceiver<RemoteCommand>) -> Self {
+        self.commands = Some(commands);
+        self
+    }
+
+    /// See [`Orchestrator::attach_ops_events`].
+    pub fn ops_events(mut self, sink: &OpsEventSink) -> Self {
+        self.ops_events = Some(sink.clone());
+        self
+    }
+
+    /// See [`Orchestrator::attach_status`].
+    pub fn status_handle(mut self, status: StatusHandle) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// See [`Orchestrator::add_observer`]; may be called repeatedly.
+    pub fn observer(mut self, observer: Box<dyn TurnObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    pub fn build(self) -> Result<Orchestrator<C, V, E, N>> {
+        let mut orchestrator = Orchestrator::new(
+            required(self.config, "config")?,
+            required(self.controller, "controller")?,
+            required(self.recognizer, "recognizer")?,
+            required(self.engine, "engine")?,
+            required(self.network, "network")?,
+            required(self.telemetry, "telemetry")?,
+        );
+        if let Some(commands) = self.commands {
+            orchestrator.attach_commands(commands);
+        }
+        if let Some(sink) = self.ops_events {
+            orchestrator.attach_ops_events(&sink);
+        }
+        if let Some(status) = self.status {
+            orchestrator.attach_status(status);
+        }
+        for observer in self.observers {
+            orchestrator.add_observer(observer);
+        }
+        Ok(orchestrator)
+    }
+}
+
+fn required<T>(part: Option<T>, name: &str) -> Result<T> {
+    part.ok_or_else(|| orchestrator_error(format!("orchestrator builder is missing the {name}")))
+}
@@ -0,0 +1,49 @@
+//! Operator handle for steering a running match.
+
+use minerva_types::{board::Square, events::RemoteCommand, Result};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::orchestrator_error;
+
+/// Cloneable handle that sends [`RemoteCommand`]s to the match loop; attach
+/// the receiving end with [`crate::Orchestrator::attach_commands`]. Commands
+/// take effect between turns.
+#[derive(Debug, Clone)]
+pub struct OrchestratorControl {
+    commands: UnboundedSender<RemoteCommand>,
+}
+
+impl OrchestratorControl {
+    pub fn new(commands: UnboundedSender<RemoteCommand>) -> Self {
+        Self { commands }
+    }
+
+    pub fn pause(&self) -> Result<()> {
+        self.send(RemoteCommand::Pause)
+    }
+
+    pub fn resume(&self) -> Result<()> {
+        self.send(RemoteCommand::Resume)
+    }
+
+    /// Runs one more turn while paused; ignored otherwise.
+    pub fn step(&self) -> Result<()> {
+        self.send(RemoteCommand::Step)
+    }
+
+    pub fn resign(&self) -> Result<()> {
+        self.send(RemoteCommand::Resign)
+    }
+
+    /// Plays `from -> to` on our next turn instead of the engine's move; an
+    /// illegal move is reported and the engine plays instead.
+    pub fn force_move(&self, from: Square, to: Square) -> Result<()> {
+        self.send(RemoteCommand::ForceMove { from, to })
+    }
+
+    fn send(&self, command: RemoteCommand) -> Result<()> {
+        self.commands
+            .send(command)
+            .map_err(|_| orchestrator_error(format!("{command:?}: 명령을 받을 대국이 없습니다")))
+    }
+}
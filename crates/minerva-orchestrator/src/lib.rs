@@ -1,28 +1,120 @@
 //! High-level orchestrator coordinating controller, vision, and engine.
 
+mod builder;
+mod control;
+mod observer;
+mod poll;
+mod reconcile;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_support;
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    future::Future,
+    sync::Mutex,
+};
+
 use async_trait::async_trait;
+pub use builder::OrchestratorBuilder;
+use chrono::Utc;
+pub use control::OrchestratorControl;
 use minerva_controller::{
-    formation_action, formation_confirm_action, start_flow_action, DeviceController,
+    back_action, clear_selection_action, controller_error, dialog_answer_action, formation_action,
+    formation_confirm_action, popup_dismiss_action, resign_action, start_flow_action,
+    DeviceController, InputAction,
+};
+use minerva_engine::{emergency_decision, evaluate, GameEngine};
+use minerva_network::{RealtimeServer, StatusHandle};
+use minerva_ops::{
+    backoff_delay, ensure_telemetry_dir, init_tracing_or_fallback, install_crash_handler, jittered,
+    write_match_report, GameRecord, OpsEventSink, SessionState, TelemetryStore, TracingGuard,
+    GAME_RECORD_FILE,
 };
-use minerva_engine::GameEngine;
-use minerva_network::RealtimeServer;
-use minerva_ops::{ensure_telemetry_dir, init_tracing, TelemetryStore};
 use minerva_types::{
-    board::BoardDiff,
-    config::{MinervaConfig, OrchestratorConfig},
+    board::{BoardDiff, BoardState, Piece, PieceKind, PlayerSide, Square},
+    config::{
+        MinervaConfig, OrchestratorConfig, OrchestratorMode, PositionalWeights, RetryPolicy,
+        TimingConfig,
+    },
     events::{
-        BoardEvent, EngineEvent, EventKind, EventPayload, LifecycleEvent, LifecyclePhase,
-        SystemEvent,
+        ops_event, BoardEvent, EngineEvent, EventKind, EventPayload, LifecycleEvent,
+        LifecyclePhase, MatchResultEvent, OpponentMoveEvent, OrchestratorState, RemoteCommand,
+        Severity, StateChangeEvent, SystemEvent, TelemetryEvent,
+    },
+    game::{
+        DrawReason, EngineDecision, GameClocks, GameResult, GameSnapshot, HistoryEntry, Move,
+        TurnContext, WinReason,
+    },
+    telemetry::{
+        AnomalyBudget, AnomalyKind, CaptureRecord, EngineMetrics, LatencySample, MatchTelemetry,
+        RecognitionSummary, StageDurations, TurnRecord,
+    },
+    ui::{
+        BoardGeometry, FormationPreset, MatchDialog, ResignStep, ScreenRect, StartFlowStep,
+        StartScreen,
     },
-    game::{GameSnapshot, Move, TurnContext},
-    telemetry::EngineMetrics,
-    ui::{FormationPreset, StartFlowStep},
     vision::ImageFrame,
     MinervaError, Result,
 };
 use minerva_vision::{BoardRecognizer, RecognitionHints};
-use tokio::time::{sleep, Duration};
-use tracing::{info, warn};
+pub use observer::{TracingObserver, TurnObserver};
+use poll::PollCadence;
+use reconcile::{expected_snapshot, implausible_material, reconcile, Reconciliation};
+use tokio::{
+    sync::mpsc::UnboundedReceiver,
+    time::{sleep, timeout, Duration, Instant},
+};
+use tracing::{debug, error, field, info, info_span, warn, Instrument, Span};
+use uuid::Uuid;
+
+const PLAYER_NAME: &str = "Minerva";
+/// Opponent-wait poll interval until `boot` applies `vision.refresh_interval_ms`.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const OPPONENT_NAME: &str = "Opponent";
+/// Accepted positions kept for [`Orchestrator::history`].
+pub const HISTORY_LEN: usize = 64;
+/// Squares the first board may misread before it is reported as an error
+/// rather than a warning (see `verify_initial_board`).
+const INITIAL_BOARD_TOLERANCE: usize = 2;
+
+/// One capture-and-recognize pass.
+struct Observation {
+    snapshot: GameSnapshot,
+    /// Last-move highlight seen in the same frame.
+    highlight: Option<(Square, Square)>,
+    /// Result shown by the client's end-of-game screen.
+    game_over: Option<GameResult>,
+    /// Draw offer or bikjang dialog covering the board.
+    dialog: Option<MatchDialog>,
+}
+
+impl Observation {
+    /// Whether the frame showed something other than a board in play, so
+    /// it is not worth re-capturing for a stable or changed board.
+    fn interrupted(&self) -> bool {
+        self.game_over.is_some() || self.dialog.is_some()
+    }
+}
+
+/// One tap of the start sequence, the screen it is tapped on, and the
+/// screen it should bring up (`None` once the setup screens are gone).
+struct StartStage {
+    name: &'static str,
+    action: InputAction,
+    on: StartScreen,
+    leads_to: Option<StartScreen>,
+    /// How long `leads_to` may take to come up after the tap.
+    patience: Duration,
+}
+
+/// Our move applied to a copy of the tracked snapshot, not yet played.
+struct StagedMove {
+    mv: Move,
+    snapshot: GameSnapshot,
+    diffs: Vec<BoardDiff>,
+    piece: Option<PieceKind>,
+    captured: Option<Piece>,
+}
 
 pub struct Orchestrator<C, V, E, N>
 where
@@ -38,6 +130,82 @@ where
     telemetry: TelemetryStore,
     config: OrchestratorConfig,
     last_snapshot: Option<GameSnapshot>,
+    tracing_guard: Option<TracingGuard>,
+    game_result: Option<GameResult>,
+    record: GameRecord,
+    session_id: Uuid,
+    match_id: Option<Uuid>,
+    match_telemetry: MatchTelemetry,
+    ops_events: Mutex<Option<UnboundedReceiver<SystemEvent>>>,
+    /// Side whose reply the next turn waits for, set once our move is played.
+    awaiting_opponent: Option<PlayerSide>,
+    poll_interval: Duration,
+    /// Agreeing recognitions required per capture (`vision.stable_captures`).
+    stable_captures: u32,
+    stable_capture_delay: Duration,
+    /// Input delays (`emulator.timing`).
+    timing: TimingConfig,
+    /// Where board taps land (`emulator.geometry` and `tap_offset`), for
+    /// reporting taps in dry-run mode.
+    geometry: BoardGeometry,
+    tap_offset: (i32, i32),
+    /// Weights for scoring positions the engine is not asked to play from,
+    /// such as a draw offer (`engine.positional`).
+    eval_weights: PositionalWeights,
+    /// Oldest frame acted on (`vision.max_frame_age_ms`); `None` accepts any.
+    max_frame_age: Option<Duration>,
+    /// Pixels kept around the board when capturing just the board
+    /// (`vision.capture_region_margin`); `None` always captures full frames.
+    capture_margin: Option<u32>,
+    /// Board region learned from the last alignment; frames are captured to
+    /// it until recognition asks for the board to be aligned again.
+    capture_region: Mutex<Option<ScreenRect>>,
+    commands: Option<UnboundedReceiver<RemoteCommand>>,
+    /// Report served at `/status`, kept current by `publish_event`.
+    status: Option<StatusHandle>,
+    /// Fingerprint of the last published board event this match.
+    last_published_board: Option<u64>,
+    /// Side we play this match, resolved on the first recognized board.
+    our_side: Option<PlayerSide>,
+    /// Whether the client draws Red at the bottom, resolved on the first
+    /// recognized board with a General in the bottom palace. Such screens
+    /// are turned half around as they are read and each tap is turned back,
+    /// so tracked boards always seat Blue at rank 0.
+    screen_flipped: Mutex<Option<bool>>,
+    /// Both players' remaining time, charged as each move is seen or played.
+    clocks: GameClocks,
+    /// When the opponent's clock started running.
+    opponent_clock_started: Option<Instant>,
+    /// Whether the low-time warning was emitted this match.
+    low_time_warned: bool,
+    /// Consecutive turns scored below `resign_threshold`.
+    hopeless_turns: u32,
+    /// Moves the client ignored this match.
+    move_rejections: u32,
+    /// Time this turn's pipelined polls saved over capturing and recognizing
+    /// one after the other.
+    prefetch_saved: Duration,
+    /// Changed only through [`Self::set_state`].
+    state: OrchestratorState,
+    /// Set by [`RemoteCommand::Pause`]; no turns are played until resumed.
+    paused: bool,
+    /// One turn may run despite `paused` ([`RemoteCommand::Step`]).
+    step_pending: bool,
+    /// Operator move played on our next turn ([`RemoteCommand::ForceMove`]).
+    forced_move: Option<Move>,
+    /// Called around each turn's stages; see [`Self::add_observer`].
+    observers: Vec<Box<dyn TurnObserver>>,
+    /// Last [`HISTORY_LEN`] accepted positions this match, oldest first.
+    history: VecDeque<HistoryEntry>,
+    /// The opponent's formation, configured or read from the start board.
+    opponent_formation: Option<FormationPreset>,
+    /// This turn's time per stage so far; see [`Self::finish_stage`].
+    stage_durations: Mutex<StageDurations>,
+    /// Anomalies charged this match; see [`Self::charge_anomaly`].
+    anomaly_budget: Mutex<AnomalyBudget>,
+    /// Set when boot picked the match up from a saved [`SessionState`];
+    /// `run` then keeps its match id, side, clocks and record.
+    resumed: bool,
 }
 
 impl<C, V, E, N> Orchestrator<C, V, E, N>
@@ -47,6 +215,8 @@ where
     E: GameEngine,
     N: RealtimeServer,
 {
+    /// Builds from positional parts; [`Orchestrator::builder`] sets them by
+    /// name along with the optional ones.
     pub fn new(
         config: OrchestratorConfig,
         controller: C,
@@ -55,6 +225,7 @@ where
         network: N,
         telemetry: TelemetryStore,
     ) -> Self {
+        let clocks = GameClocks::new(config.time_control.base_ms);
         Self {
             controller,
             recognizer,
@@ -63,15 +234,145 @@ where
             telemetry,
             config,
             last_snapshot: None,
+            tracing_guard: None,
+            game_result: None,
+            record: GameRecord::new(PLAYER_NAME, OPPONENT_NAME),
+            session_id: Uuid::new_v4(),
+            match_id: None,
+            match_telemetry: MatchTelemetry::default(),
+            ops_events: Mutex::new(None),
+            awaiting_opponent: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            stable_captures: 1,
+            stable_capture_delay: Duration::ZERO,
+            max_frame_age: None,
+            capture_margin: None,
+            capture_region: Mutex::new(None),
+            timing: TimingConfig::default(),
+            geometry: BoardGeometry::default(),
+            tap_offset: (0, 0),
+            eval_weights: PositionalWeights::default(),
+            commands: None,
+            status: None,
+            last_published_board: None,
+            our_side: None,
+            screen_flipped: Mutex::new(None),
+            clocks,
+            opponent_clock_started: None,
+            low_time_warned: false,
+            hopeless_turns: 0,
+            move_rejections: 0,
+            prefetch_saved: Duration::ZERO,
+            state: OrchestratorState::Booting,
+            paused: false,
+            step_pending: false,
+            forced_move: None,
+            observers: Vec::new(),
+            history: VecDeque::new(),
+            opponent_formation: None,
+            stage_durations: Mutex::new(StageDurations::default()),
+            anomaly_budget: Mutex::new(AnomalyBudget::default()),
+            resumed: false,
         }
     }
 
+    pub fn builder() -> OrchestratorBuilder<C, V, E, N> {
+        OrchestratorBuilder::new()
+    }
+
+    /// Identifier shared by every event this orchestrator publishes.
+    pub fn session_id(&self) -> Uuid {
+        self.session_id
+    }
+
+    /// Identifier of the match in progress, if any.
+    pub fn match_id(&self) -> Option<Uuid> {
+        self.match_id
+    }
+
+    /// Moves played so far in the current (or last) match.
+    pub fn game_record(&self) -> &GameRecord {
+        &self.record
+    }
+
+    /// Both players' remaining time as tracked so far this match.
+    pub fn clocks(&self) -> GameClocks {
+        self.clocks
+    }
+
+    /// Positions accepted so far this match, oldest first, each with the
+    /// move that produced it; at most [`HISTORY_LEN`] are kept.
+    pub fn history(&self) -> &VecDeque<HistoryEntry> {
+        &self.history
+    }
+
+    /// The opponent's formation, once configured or read at boot.
+    pub fn opponent_formation(&self) -> Option<FormationPreset> {
+        self.opponent_formation
+    }
+
+    /// Side we play in the current match, once known.
+    pub fn our_side(&self) -> Option<PlayerSide> {
+        self.our_side
+    }
+
+    /// What the orchestrator is doing right now.
+    pub fn state(&self) -> OrchestratorState {
+        self.state
+    }
+
+    /// Outcome reported by the engine once the game is decided.
+    pub fn game_result(&self) -> Option<GameResult> {
+        self.game_result
+    }
+
+    /// Publishes WARN/ERROR tracing events routed through `sink` as Ops events.
+    pub fn attach_ops_events(&mut self, sink: &OpsEventSink) {
+        *self
+            .ops_events
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(sink.attach());
+    }
+
+    /// Accepts operator commands (resign, pause/resume/step) for matches
+    /// started by `run`; see [`OrchestratorControl`].
+    pub fn attach_commands(&mut self, commands: UnboundedReceiver<RemoteCommand>) {
+        self.commands = Some(commands);
+    }
+
+    /// Keeps `status` up to date with every published event and the
+    /// controller's counters.
+    pub fn attach_status(&mut self, status: StatusHandle) {
+        self.status = Some(status);
+    }
+
+    /// Registers `observer` to be called as turns capture, recognize,
+    /// decide, and move. Observers run in registration order.
+    pub fn add_observer(&mut self, observer: Box<dyn TurnObserver>) {
+        self.observers.push(observer);
+    }
+
     pub async fn boot(&mut self, full_config: &MinervaConfig) -> Result<()> {
-        init_tracing(&full_config.ops)?;
+        self.tracing_guard = Some(init_tracing_or_fallback(&full_config.ops));
+        install_crash_handler(self.telemetry.clone(), OpsEventSink::global());
+        self.attach_ops_events(&OpsEventSink::global());
+        self.set_state(OrchestratorState::Booting).await?;
         ensure_telemetry_dir(&full_config.ops.telemetry_dir)?;
+        self.poll_interval = Duration::from_millis(full_config.vision.refresh_interval_ms);
+        self.stable_captures = full_config.vision.stable_captures;
+        self.stable_capture_delay =
+            Duration::from_millis(full_config.vision.stable_capture_delay_ms);
+        self.timing = full_config.emulator.timing;
+        self.geometry = full_config.emulator.geometry.clone();
+        self.tap_offset = full_config.emulator.tap_offset;
+        self.eval_weights = full_config.engine.positional;
+        self.max_frame_age = Some(full_config.vision.max_frame_age_ms)
+            .filter(|&ms| ms > 0)
+            .map(Duration::from_millis);
+        self.capture_margin = full_config.vision.capture_region_margin;
 
         self.controller.connect().await?;
-        self.perform_start_sequence(self.config.formation).await?;
+        self.prepare_match().await?;
         self.engine.warm_up().await?;
         self.network.run().await?;
 
@@ -86,168 +387,6553 @@ where
         Ok(())
     }
 
+    /// Gets the board ready for the first turn: resumes a match saved before
+    /// a restart (see [`Self::resume_session`]), else starts from
+    /// `start_fen` or runs the start sequence. Spectating, nothing is tapped.
+    async fn prepare_match(&mut self) -> Result<()> {
+        if self.spectating() {
+            info!("관전 모드: 시작 절차 없이 화면만 관찰합니다");
+            self.seed_from_fen();
+        } else if !self.resume_session().await && !self.seed_from_fen() {
+            self.perform_start_sequence(self.config.formation).await?;
+            self.verify_initial_board().await;
+        }
+        Ok(())
+    }
+
+    /// Picks up the match saved in the telemetry directory if it is at most
+    /// `resume_max_age_secs` old and a fresh recognition agrees with it: the
+    /// same board, or one opponent move past it. Returns whether it did; a
+    /// state that cannot be used is reported as a `resume` Ops warning.
+    async fn resume_session(&mut self) -> bool {
+        let max_age = Duration::from_secs(self.config.resume_max_age_secs);
+        let Some(dir) = self.telemetry.match_dir(None, None) else {
+            return false;
+        };
+        if max_age.is_zero() {
+            return false;
+        }
+        let state = match SessionState::load_recent(&dir, max_age) {
+            Ok(Some(state)) => state,
+            Ok(None) => return false,
+            Err(err) => {
+                warn!(tags = "resume", "저장된 세션 상태를 읽지 못했습니다: {err}");
+                return false;
+            }
+        };
+        let mut anomalies = Vec::new();
+        let observation = match self.capture_and_recognize(&mut anomalies).await {
+            Ok(observation) => observation,
+            Err(err) => {
+                warn!(
+                    tags = "resume",
+                    "이어 두기 전 보드를 인식하지 못했습니다: {err}"
+                );
+                return false;
+            }
+        };
+        if let Reconciliation::Desync(squares) = reconcile(&state.snapshot, &observation.snapshot) {
+            warn!(
+                tags = "resume",
+                "저장된 국면이 화면과 다릅니다({}); 새 대국을 시작합니다",
+                format_squares(&squares)
+            );
+            return false;
+        }
+        info!(
+            "저장된 대국을 {}수째부터 이어서 둡니다 (match {:?})",
+            state.snapshot.ply, state.match_id
+        );
+        self.session_id = state.session_id;
+        self.match_id = state.match_id;
+        self.our_side = state.our_side;
+        self.clocks = state.snapshot.clocks;
+        self.record = state.record;
+        self.last_snapshot = Some(state.snapshot);
+        self.resumed = true;
+        true
+    }
+
+    /// Saves where the match stands for [`Self::resume_session`] after a
+    /// restart; failures are only reported.
+    fn save_session_state(&self) {
+        let (Some(dir), Some(snapshot)) = (
+            self.telemetry.match_dir(None, None),
+            self.last_snapshot.clone(),
+        ) else {
+            return;
+        };
+        let state = SessionState {
+            session_id: self.session_id,
+            match_id: self.match_id,
+            our_side: self.our_side,
+            snapshot,
+            record: self.record.clone(),
+            saved_at: Utc::now(),
+        };
+        if let Err(err) = state.save(&dir) {
+            warn!(tags = "resume", "세션 상태를 저장하지 못했습니다: {err}");
+        }
+    }
+
+    /// Recognizes the board right after the start sequence and compares it
+    /// with the starting position for `config.formation` (see
+    /// [`formation_mismatches`]), so a broken template set or a misaligned
+    /// board shows up before the first move. The opponent's formation is
+    /// `config.opponent_formation`, or else read from its back rank. More
+    /// than [`INITIAL_BOARD_TOLERANCE`] mismatched squares is reported as a
+    /// `warmup` Ops error, fewer as a warning; the match goes ahead either
+    /// way. Within the tolerance, and with both formations known, the
+    /// tracked game starts from the reconstructed layout rather than the
+    /// read board. Returns the mismatched squares.
+    async fn verify_initial_board(&mut self) -> Vec<Square> {
+        let mut anomalies = Vec::new();
+        let observation = match self.capture_and_recognize(&mut anomalies).await {
+            Ok(observation) => observation,
+            Err(err) => {
+                error!(tags = "warmup", "시작 보드를 인식하지 못했습니다: {err}");
+                return Vec::new();
+            }
+        };
+        let board = &observation.snapshot.board;
+        let our_side = self
+            .config
+            .our_side
+            .or_else(|| self.bottom_side())
+            .unwrap_or(PlayerSide::Blue);
+        let formation = self.config.formation;
+        let theirs = self.config.opponent_formation.or_else(|| {
+            let read = board.formation_of(our_side.opponent());
+            match read {
+                Some(theirs) => info!("상대 진형 인식: {theirs}"),
+                None => warn!(
+                    tags = "warmup",
+                    "상대 진형을 읽지 못했습니다; 상대 마·상 배치는 검사하지 않습니다"
+                ),
+            }
+            read
+        });
+        self.opponent_formation = theirs;
+        let mismatched = formation_mismatches(board, our_side, formation, theirs);
+        if theirs.is_some() && mismatched.len() <= INITIAL_BOARD_TOLERANCE {
+            let mut layout = formation_layout(our_side, formation, theirs);
+            layout.side_to_move = board.side_to_move;
+            self.last_snapshot = Some(GameSnapshot {
+                board: layout,
+                ..observation.snapshot.clone()
+            });
+        }
+        if mismatched.len() > INITIAL_BOARD_TOLERANCE {
+            error!(
+                tags = "warmup",
+                "시작 보드가 {formation} 배치와 {}칸 다릅니다: {}; 템플릿과 보드 정렬을 확인하세요",
+                mismatched.len(),
+                format_squares(&mismatched)
+            );
+        } else if !mismatched.is_empty() {
+            warn!(
+                tags = "warmup",
+                "시작 보드가 {formation} 배치와 {}칸 다릅니다: {}",
+                mismatched.len(),
+                format_squares(&mismatched)
+            );
+        } else {
+            info!("시작 보드 확인: {formation} 배치와 일치합니다");
+        }
+        mismatched
+    }
+
+    /// Seeds `last_snapshot` from `config.start_fen`, returning whether it did.
+    ///
+    /// An invalid FEN is logged and ignored so the standard start is used.
+    fn seed_from_fen(&mut self) -> bool {
+        let Some(fen) = self.config.start_fen.as_deref() else {
+            return false;
+        };
+        match BoardState::from_fen(fen) {
+            Ok(board) => {
+                info!("FEN 시작 국면 사용: {fen}");
+                self.last_snapshot = Some(GameSnapshot {
+                    board,
+                    ..GameSnapshot::default()
+                });
+                true
+            }
+            Err(err) => {
+                warn!(
+                    tags = "config",
+                    "시작 FEN '{fen}' 무시, 표준 배치로 시작합니다: {err}"
+                );
+                false
+            }
+        }
+    }
+
+    /// Plays one turn. The board, engine, and latency events it publishes all
+    /// carry the same fresh `turn_id`; a board event follows the recognition
+    /// and another our applied move.
+    ///
+    /// After one of our moves, the turn first waits for the opponent's reply
+    /// (see [`Self::wait_for_opponent`]) so the engine only runs on our move.
+    /// The engine always plays [`Self::our_side`]; when the tracked board says
+    /// it is the opponent's turn, nothing is played and the next turn waits
+    /// for their move. If the client shows its end-of-game screen, the result
+    /// is recorded and the engine is not consulted. A draw offer or bikjang
+    /// dialog is answered first (see [`Self::clear_dialogs`]).
+    ///
+    /// When spectating, the engine evaluates for whichever side is to move,
+    /// nothing is played, and the next turn waits for that side's move.
+    ///
+    /// The turn runs in a `turn` tracing span (`turn_id`, `ply`, `side`) whose
+    /// `capture`, `recognize`, `evaluate`, `apply_move` and `verify` child
+    /// spans record their `duration_ms` as they close; the turn's totals per
+    /// stage are kept in [`TurnRecord::stages`].
     pub async fn play_turn(&mut self) -> Result<()> {
-        let frame = self.controller.capture_frame().await?;
-        let snapshot = self.recognize_board(&frame).await?;
+        let span = info_span!(
+            "turn",
+            turn_id = field::Empty,
+            ply = field::Empty,
+            side = field::Empty,
+            duration_ms = field::Empty
+        );
+        let started = Instant::now();
+        let result = self.run_turn().instrument(span.clone()).await;
+        span.record("duration_ms", elapsed_ms(started));
+        result
+    }
+
+    /// [`Self::play_turn`] inside its `turn` span.
+    async fn run_turn(&mut self) -> Result<()> {
+        let turn_id = Uuid::new_v4();
+        Span::current().record("turn_id", field::display(turn_id));
+        let mut anomalies = Vec::new();
+        self.prefetch_saved = Duration::ZERO;
+        *self
+            .stage_durations
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = StageDurations::default();
+        self.set_state(match self.awaiting_opponent {
+            Some(_) => OrchestratorState::WaitingForOpponent,
+            None => OrchestratorState::Observing,
+        })
+        .await?;
+        let (turn_started, observation) = match self.awaiting_opponent {
+            Some(opponent) => self.wait_for_opponent(opponent, &mut anomalies).await?,
+            None => (
+                Instant::now(),
+                self.capture_and_recognize(&mut anomalies).await?,
+            ),
+        };
+        let Some(observation) = self.clear_dialogs(observation, &mut anomalies).await? else {
+            return Ok(());
+        };
+        self.awaiting_opponent = None;
+        let mut observation = self
+            .reconcile_observation(observation, &mut anomalies)
+            .await?;
+        if self.last_snapshot.is_none() {
+            // Recognizers cannot read whose turn it is; a fresh game opens with Blue.
+            observation.snapshot.board.side_to_move = PlayerSide::Blue;
+        }
+        let Observation {
+            mut snapshot,
+            highlight,
+            game_over,
+            ..
+        } = observation;
+        Span::current().record("ply", snapshot.ply);
+        let observation_ms = elapsed_ms(turn_started);
         let diffs = self
             .last_snapshot
             .as_ref()
             .map(|prev| prev.board.differences(&snapshot.board))
             .unwrap_or_default();
+        let mut captured_by_move = None;
+        let mut opponent = None;
         if !diffs.is_empty() {
             self.log_differences("opponent", &diffs);
+            if let Some((from, to, piece, captured)) =
+                opponent_move(&diffs, highlight, &mut anomalies).filter(|&(from, to, piece, _)| {
+                    self.accept_inferred_move(piece.owner, &Move::new(from, to), &mut anomalies)
+                })
+            {
+                if let Some(prev) = self.last_snapshot.as_ref() {
+                    snapshot.halfmove_clock = prev.halfmove_clock;
+                    snapshot.record_halfmove(captured.is_some());
+                }
+                // The move landed at the latest before the poll that saw it.
+                let spent_ms = self.opponent_clock_started.take().map_or(0, |started| {
+                    turn_started.saturating_duration_since(started).as_millis() as u64
+                });
+                self.clocks
+                    .charge(piece.owner, spent_ms, self.config.time_control.increment_ms);
+                let mv = Move::new(from, to);
+                snapshot.last_move = Some(mv.clone());
+                self.record
+                    .push_move(piece.owner, mv.clone(), Some(piece.kind), captured);
+                if let Some(captured) = captured {
+                    self.record_capture(snapshot.ply, to, captured);
+                }
+                captured_by_move = captured;
+                // A misread can make our own piece look moved; only the
+                // other side's moves are announced.
+                if self.spectating() || self.our_side != Some(piece.owner) {
+                    opponent = Some(OpponentMoveEvent {
+                        side: piece.owner,
+                        mv,
+                        piece: piece.kind,
+                        captured,
+                        turn_id: Some(turn_id),
+                    });
+                }
+            }
+        }
+        snapshot.clocks = self.clocks;
+        self.publish_board_event(snapshot.clone(), diffs, captured_by_move, turn_id)
+            .await?;
+        if let Some(opponent) = opponent {
+            info!("상대 수: {opponent}");
+            self.publish(SystemEvent::new(
+                EventKind::BoardUpdate,
+                EventPayload::OpponentMove(opponent),
+            ))
+            .await?;
         }
-        self.publish_board_event(snapshot.clone(), diffs).await?;
         self.last_snapshot = Some(snapshot.clone());
-        let side = snapshot.board.side_to_move;
-        let decision = self
-            .engine
-            .evaluate_position(&TurnContext { snapshot, side })
+        self.push_history(&snapshot, snapshot.last_move.clone());
+        self.log_board(&snapshot.board);
+        for observer in &self.observers {
+            observer_failed(
+                "on_board_recognized",
+                observer.on_board_recognized(&snapshot).await,
+            );
+        }
+        if let Some(result) = game_over {
+            info!("화면에서 게임 종료 감지: {result}");
+            self.game_result = Some(result);
+            return Ok(());
+        }
+        self.adjudicate_no_capture();
+        let side = if self.spectating() {
+            snapshot.board.side_to_move
+        } else {
+            self.resolve_our_side()
+        };
+        Span::current().record("side", field::debug(side));
+        if snapshot.board.side_to_move != side {
+            info!(
+                "{:?} 차례이므로 수를 두지 않고 상대 수를 기다립니다",
+                snapshot.board.side_to_move
+            );
+            self.awaiting_opponent = Some(side.opponent());
+            self.opponent_clock_started.get_or_insert(turn_started);
+            return Ok(());
+        }
+        let ply = snapshot.ply;
+        let board_hash = snapshot.board.position_hash();
+        let recognition = RecognitionSummary::from(&snapshot.recognition);
+        let frame_path = snapshot.recognition.frame_path.clone();
+        self.set_state(OrchestratorState::Thinking).await?;
+        let mut evaluated_fen = snapshot.board.to_fen();
+        let mut decision = self
+            .decide(&self.turn_context(snapshot, side), &mut anomalies)
             .await?;
+        // No move without a result means the engine saw a board it could not
+        // play from, most likely a misread; look again before giving up.
+        let mut recaptures = 0;
+        while decision.best_move.is_none() && decision.result.is_none() {
+            anomalies.push("engine returned no move".to_string());
+            if recaptures == self.config.max_retries {
+                error!(
+                    tags = "engine",
+                    "재인식 {recaptures}회 후에도 엔진이 수를 내지 않았습니다"
+                );
+                return Err(orchestrator_error(format!(
+                    "engine returned no move after {recaptures} re-captures"
+                )));
+            }
+            recaptures += 1;
+            warn!(
+                tags = "engine",
+                "엔진이 수를 내지 않았습니다; 보드를 다시 인식합니다 ({recaptures}/{})",
+                self.config.max_retries
+            );
+            let snapshot = self.recapture(turn_id, &mut anomalies).await?;
+            evaluated_fen = snapshot.board.to_fen();
+            decision = self
+                .decide(&self.turn_context(snapshot, side), &mut anomalies)
+                .await?;
+        }
+        let mut overridden =
+            !self.spectating() && self.take_forced_move(side, &mut decision, &mut anomalies);
+        self.notify_decision(&decision).await;
+        let decision_ms = elapsed_ms(turn_started) - observation_ms;
+        log_rejected_moves(&decision.reject_reasons);
 
-        if let Some(best_move) = decision.best_move.clone() {
-            self.apply_move(best_move.clone()).await?;
-        } else {
-            warn!("Engine returned no move; skipping controller action");
+        if let Some(result) = decision.result {
+            info!("게임 종료: {result}");
+            self.game_result = Some(result);
+        }
+        if self.spectating() {
+            // The evaluation is commentary; wait for whoever is to move.
+            self.awaiting_opponent = Some(side);
+            self.opponent_clock_started.get_or_insert(turn_started);
+        } else if self.game_result.is_none() && self.should_resign(ply, decision.score) {
+            warn!(
+                tags = "resign",
+                "엔진 평가가 {}턴 연속 {:.1} 미만({:.1}); 기권합니다",
+                self.hopeless_turns,
+                self.config.resign_threshold.unwrap_or_default(),
+                decision.score
+            );
+            anomalies.push(format!("resigned at score {:.1}", decision.score));
+            self.resign().await;
         }
 
-        if let Some(best_move) = decision.best_move.clone() {
-            if let Some(ref mut stored) = self.last_snapshot {
-                if let Err(err) = stored.apply_move(side, &best_move) {
-                    warn!("내부 스냅샷 업데이트 실패: {err}");
-                }
+        let chosen = match decision.best_move {
+            Some(_) if self.game_result.is_some() || self.spectating() => None,
+            Some(best_move) if best_move.from == best_move.to => {
+                warn!(
+                    tags = "engine",
+                    "엔진이 제자리 수({})를 반환했습니다; 탭하지 않습니다", best_move.from
+                );
+                anomalies.push(format!("engine returned hold move at {}", best_move.from));
+                None
+            }
+            Some(best_move) if self.moves_own_piece(side, &best_move) => Some(best_move),
+            Some(best_move) => {
+                self.reject_side_mismatch(side, &best_move, turn_id, &mut anomalies)
+                    .await?;
+                None
+            }
+            // Only with a result, which ended the game above.
+            None => None,
+        };
+        // The move is applied to a working copy first; the tracked snapshot
+        // only advances once the controller has played it.
+        let mut staged = chosen.and_then(|mv| self.stage_move(side, mv, &mut anomalies));
+        if staged.is_some() {
+            let thinking = Duration::from_millis(elapsed_ms(turn_started) - observation_ms);
+            self.hold_move(thinking).await;
+        }
+        while let Some(stage) = &staged {
+            self.set_state(OrchestratorState::Moving).await?;
+            if let Err(err) = self.apply_move(&stage.mv).await {
+                warn!(
+                    tags = "input",
+                    "수 입력 실패({} -> {}); 추적 보드를 유지합니다: {err}",
+                    stage.mv.from,
+                    stage.mv.to
+                );
+                return Err(err);
+            }
+            if !self.move_was_ignored(&stage.mv, &mut anomalies).await? {
+                break;
+            }
+            // The client thought our move illegal, so the tracked board is
+            // wrong; resynchronize and ask again.
+            let mv = stage.mv.clone();
+            let snapshot = self
+                .recover_ignored_move(&mv, turn_id, &mut anomalies)
+                .await?;
+            self.set_state(OrchestratorState::Thinking).await?;
+            evaluated_fen = snapshot.board.to_fen();
+            decision = self
+                .decide(&self.turn_context(snapshot, side), &mut anomalies)
+                .await?;
+            overridden = false;
+            self.notify_decision(&decision).await;
+            if let Some(result) = decision.result {
+                info!("게임 종료: {result}");
+                self.game_result = Some(result);
             }
+            staged = decision
+                .best_move
+                .clone()
+                .filter(|mv| self.game_result.is_none() && mv.from != mv.to)
+                .filter(|mv| self.moves_own_piece(side, mv))
+                .and_then(|mv| self.stage_move(side, mv, &mut anomalies));
         }
+        let total_ms = elapsed_ms(turn_started);
 
+        let played = match staged {
+            Some(StagedMove {
+                mv,
+                mut snapshot,
+                diffs,
+                piece,
+                captured,
+            }) => {
+                self.clocks.charge(
+                    side,
+                    total_ms - observation_ms,
+                    self.config.time_control.increment_ms,
+                );
+                self.opponent_clock_started = Some(Instant::now());
+                snapshot.clocks = self.clocks;
+                self.last_snapshot = Some(snapshot.clone());
+                self.push_history(&snapshot, Some(mv.clone()));
+                self.record.push_move(side, mv.clone(), piece, captured);
+                if let Some(captured) = captured {
+                    self.record_capture(snapshot.ply, mv.to, captured);
+                }
+                self.awaiting_opponent = Some(side.opponent());
+                self.adjudicate_no_capture();
+                self.publish_board_event(snapshot, diffs, captured, turn_id)
+                    .await?;
+                self.warn_on_low_time(side);
+                for observer in &self.observers {
+                    observer_failed("on_move_applied", observer.on_move_applied(&mv).await);
+                }
+                Some(mv)
+            }
+            None => None,
+        };
+
+        let metrics = EngineMetrics {
+            nodes: decision.searched_nodes,
+            depth: decision.depth,
+            nps: 0,
+            hashfull: 0.0,
+            duration_ms: Some(decision.duration_ms as u64),
+        };
+        self.match_telemetry.engine_history.push(metrics.clone());
         let engine_event = SystemEvent::new(
             EventKind::EngineDecision,
             EventPayload::Engine(EngineEvent {
-                metrics: EngineMetrics {
-                    nodes: decision.searched_nodes,
-                    depth: decision.depth,
-                    nps: 0,
-                    hashfull: 0.0,
-                },
+                metrics: metrics.clone(),
                 best_line: decision.candidates.iter().map(|c| c.mv.clone()).collect(),
+                score: Some(decision.score),
+                turn_id: Some(turn_id),
+                overridden,
+                fen: Some(evaluated_fen),
             }),
         );
         self.publish(engine_event).await?;
+
+        let latency = LatencySample {
+            observation_ms,
+            decision_ms,
+            injection_ms: total_ms - observation_ms - decision_ms,
+            total_ms,
+            prefetch_saved_ms: self.prefetch_saved.as_millis() as u64,
+            captured_at: Utc::now(),
+        };
+        self.match_telemetry.latency_samples.push(latency.clone());
+        let turn = TurnRecord {
+            index: self.match_telemetry.turns.len() as u32,
+            turn_id,
+            side,
+            board_hash,
+            chosen_move: played,
+            engine: metrics,
+            latency: latency.clone(),
+            stages: *self
+                .stage_durations
+                .get_mut()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+            recognition,
+            frame_path,
+            anomalies,
+        };
+        for observer in &self.observers {
+            observer_failed("on_turn_complete", observer.on_turn_complete(&turn).await);
+        }
+        self.match_telemetry.turns.push(turn);
+        let latency_event = SystemEvent::new(
+            EventKind::Telemetry,
+            EventPayload::Telemetry(TelemetryEvent {
+                latency: Some(latency),
+                notes: None,
+                turn_id: Some(turn_id),
+                health: None,
+            }),
+        );
+        self.publish(latency_event).await?;
         Ok(())
     }
 
-    async fn recognize_board(&mut self, frame: &ImageFrame) -> Result<GameSnapshot> {
-        let hints = RecognitionHints {
-            previous_snapshot: self.last_snapshot.clone(),
+    /// Polls capture and recognition until the board shows exactly one move
+    /// by `opponent`, the side to move flips, or the game is over, giving up
+    /// after `opponent_timeout_secs`. Polls follow `config.poll_schedule`
+    /// (every `poll_interval` without one), which starts over whenever the
+    /// recognized board changes. Returns when the final poll started along
+    /// with its result.
+    async fn wait_for_opponent(
+        &mut self,
+        opponent: PlayerSide,
+        anomalies: &mut Vec<String>,
+    ) -> Result<(Instant, Observation)> {
+        let timeout = Duration::from_secs(self.config.opponent_timeout_secs);
+        let deadline = Instant::now() + timeout;
+        let mut polls = 0u32;
+        let mut cadence = PollCadence::new(
+            self.config.poll_schedule,
+            self.poll_interval,
+            Instant::now(),
+        );
+        if self.config.pipeline {
+            return self
+                .wait_for_opponent_pipelined(opponent, deadline, cadence, anomalies)
+                .await;
+        }
+        let mut last_board = None;
+        loop {
+            let poll_started = Instant::now();
+            let observed = self.capture_and_recognize(anomalies).await?;
+            polls += 1;
+            note_board_change(&mut cadence, &mut last_board, &observed);
+            if observed.interrupted() || self.opponent_has_moved(&observed.snapshot, opponent) {
+                debug!("상대 수 감지: {polls}회 폴링");
+                return Ok((poll_started, observed));
+            }
+            if Instant::now() >= deadline {
+                return Err(orchestrator_error(format!(
+                    "opponent ({opponent:?}) did not move within {}s ({polls} polls)",
+                    timeout.as_secs()
+                )));
+            }
+            sleep(self.next_poll_interval(&mut cadence)).await;
+        }
+    }
+
+    /// The wait before the next opponent poll, with the schedule's jitter
+    /// applied, and reported as the status report's `poll_interval_ms`.
+    fn next_poll_interval(&self, cadence: &mut PollCadence) -> Duration {
+        let interval = jittered(cadence.next(Instant::now()), cadence.jitter());
+        if let Some(status) = &self.status {
+            status.set_poll_interval(interval.as_millis() as u64);
+        }
+        interval
+    }
+
+    /// [`Self::wait_for_opponent`] with capture and recognition overlapped:
+    /// while one frame is recognized, the next is captured a poll interval
+    /// later. Once a poll reveals the opponent's move, the frame still in
+    /// flight predates that discovery and is discarded. The time the overlap
+    /// saved is kept in `prefetch_saved` for the turn's latency sample.
+    /// Stabilization still runs on fresh captures once a move shows.
+    async fn wait_for_opponent_pipelined(
+        &mut self,
+        opponent: PlayerSide,
+        deadline: Instant,
+        mut cadence: PollCadence,
+        anomalies: &mut Vec<String>,
+    ) -> Result<(Instant, Observation)> {
+        let mut polls = 0u32;
+        let mut saved = Duration::ZERO;
+        let mut poll_started = Instant::now();
+        let mut frame = self.capture_frame().await?;
+        let mut last_board = None;
+        loop {
+            let overlap_started = Instant::now();
+            let interval = self.next_poll_interval(&mut cadence);
+            let (observed, next) = {
+                let recognition = self.recognize_frame(frame, anomalies);
+                let prefetch = async {
+                    sleep(interval).await;
+                    let captured_at = Instant::now();
+                    (captured_at, self.capture_frame().await)
+                };
+                tokio::pin!(recognition, prefetch);
+                let mut next = None;
+                let observed = tokio::select! {
+                    observed = &mut recognition => observed,
+                    captured = &mut prefetch => {
+                        next = Some((captured, overlap_started.elapsed()));
+                        recognition.await
+                    }
+                };
+                let recognize_time = overlap_started.elapsed();
+                let moved = observed.as_ref().map_or(true, |observed| {
+                    observed.interrupted() || self.opponent_has_moved(&observed.snapshot, opponent)
+                });
+                let next = match next {
+                    _ if moved => None,
+                    Some(next) => Some(next),
+                    None => Some((prefetch.await, overlap_started.elapsed())),
+                };
+                // Done one after the other, the poll would have taken both.
+                let next = next.map(|(captured, capture_time)| {
+                    saved += recognize_time.min(capture_time);
+                    captured
+                });
+                (observed, next)
+            };
+            polls += 1;
+            self.prefetch_saved = saved;
+            let mut observed = observed?;
+            note_board_change(&mut cadence, &mut last_board, &observed);
+            let Some((next_started, next)) = next else {
+                debug!(
+                    "상대 수 감지: {polls}회 폴링 (파이프라인, 절약 {}ms)",
+                    saved.as_millis()
+                );
+                if self.stable_captures > 1 && !observed.interrupted() {
+                    observed = self.capture_and_recognize(anomalies).await?;
+                }
+                return Ok((poll_started, observed));
+            };
+            if Instant::now() >= deadline {
+                return Err(orchestrator_error(format!(
+                    "opponent ({opponent:?}) did not move within {}s ({polls} polls)",
+                    self.config.opponent_timeout_secs
+                )));
+            }
+            poll_started = next_started;
+            frame = next?;
+        }
+    }
+
+    /// Checks `observation` against the tracked board (see [`reconcile`]).
+    /// When it agrees or shows one legal move, the tracked snapshot is advanced
+    /// by that move instead (see [`expected_snapshot`]) and leftover misreads
+    /// are logged. A board whose material cannot follow the tracked one by a
+    /// single move (see [`implausible_material`]) or a desync is re-captured
+    /// up to `max_retries` times; if a desync persists, the tracked game is
+    /// rolled back to the latest history entry the board is consistent with
+    /// (see [`Self::roll_back_to_history`]), and failing that the conflicting
+    /// squares are reported and the recognized board is accepted.
+    async fn reconcile_observation(
+        &mut self,
+        mut observation: Observation,
+        anomalies: &mut Vec<String>,
+    ) -> Result<Observation> {
+        let Some(mut tracked) = self.last_snapshot.clone() else {
+            return Ok(observation);
         };
-        self.recognizer.recognize(frame, hints).await
+        let mut recaptures = 0;
+        loop {
+            if observation.game_over.is_some() {
+                return Ok(observation);
+            }
+            if recaptures < self.config.max_retries {
+                if let Some(change) = implausible_material(&tracked, &observation.snapshot) {
+                    recaptures += 1;
+                    info!(
+                        "기물 수 변화가 비정상입니다({change}); 다시 인식합니다 ({recaptures}/{})",
+                        self.config.max_retries
+                    );
+                    anomalies.push(format!("implausible material change: {change}"));
+                    self.charge_anomaly(AnomalyKind::Recognition);
+                    observation = self.capture_and_recognize(anomalies).await?;
+                    continue;
+                }
+            }
+            let outcome = reconcile(&tracked, &observation.snapshot);
+            let Reconciliation::Desync(squares) = &outcome else {
+                if let Some(expected) = expected_snapshot(&tracked, &observation.snapshot, &outcome)
+                {
+                    let misread: Vec<Square> = expected
+                        .board
+                        .differences(&observation.snapshot.board)
+                        .into_iter()
+                        .map(|diff| diff.square)
+                        .collect();
+                    if !misread.is_empty() {
+                        info!(
+                            "기대 보드와 다른 저신뢰 칸 무시: {}",
+                            format_squares(&misread)
+                        );
+                    }
+                    observation.snapshot = expected;
+                }
+                if recaptures > 0 {
+                    anomalies.push(format!(
+                        "board desync resolved after {recaptures} re-captures"
+                    ));
+                }
+                return Ok(observation);
+            };
+            self.charge_anomaly(AnomalyKind::Desync);
+            let squares = format_squares(squares);
+            if recaptures >= self.config.max_retries {
+                if let Some(base) =
+                    self.roll_back_to_history(&observation.snapshot, &squares, anomalies)
+                {
+                    // Vision cannot tell whose turn it is; the restored position can.
+                    observation.snapshot.board.side_to_move = base.board.side_to_move;
+                    tracked = base;
+                    continue;
+                }
+                let message = format!("board desync on {squares}; accepting recognized board");
+                warn!(tags = "desync", "{message}");
+                anomalies.push(message);
+                return Ok(observation);
+            }
+            recaptures += 1;
+            info!(
+                "보드 불일치 {squares}; 다시 인식합니다 ({recaptures}/{})",
+                self.config.max_retries
+            );
+            observation = self.capture_and_recognize(anomalies).await?;
+        }
     }
 
-    async fn apply_move(&mut self, mv: Move) -> Result<()> {
-        self.controller.tap_square(mv.from).await?;
-        sleep(Duration::from_millis(30)).await;
-        self.controller.tap_square(mv.to).await?;
-        Ok(())
+    /// Whether `snapshot` differs from the tracked board by a single move of
+    /// `opponent`, or reports that it is no longer `opponent`'s turn.
+    fn opponent_has_moved(&self, snapshot: &GameSnapshot, opponent: PlayerSide) -> bool {
+        let Some(tracked) = self.last_snapshot.as_ref() else {
+            return true;
+        };
+        if snapshot.board.side_to_move != opponent {
+            return true;
+        }
+        // Most polls show the unchanged board; skip diffing those.
+        if snapshot.board.fingerprint() == tracked.board.fingerprint() {
+            return false;
+        }
+        let diffs = tracked.board.differences(&snapshot.board);
+        diffs.len() == 2
+            && BoardState::infer_move_from_diffs(&diffs)
+                .is_some_and(|(_, _, piece, _)| piece.owner == opponent)
     }
 
-    async fn publish(&self, event: SystemEvent) -> Result<()> {
-        let cloned = event.clone();
-        self.network.publish(event).await?;
-        self.telemetry.record_event(cloned).await?;
-        Ok(())
+    /// Captures until `stable_captures` consecutive recognitions agree on the
+    /// board, `stable_capture_delay` apart, so a frame caught mid-animation is
+    /// not acted on. After `max_retries` disagreements the latest board is
+    /// used and the instability is noted as an anomaly.
+    async fn capture_and_recognize(&mut self, anomalies: &mut Vec<String>) -> Result<Observation> {
+        let mut observation = self.capture_and_recognize_once(anomalies).await?;
+        let mut agreeing = 1;
+        let mut disagreements = 0;
+        while agreeing < self.stable_captures && !observation.interrupted() {
+            sleep(self.stable_capture_delay).await;
+            let next = self.capture_and_recognize_once(anomalies).await?;
+            if next.snapshot.board.fingerprint() == observation.snapshot.board.fingerprint() {
+                agreeing += 1;
+            } else if disagreements < self.config.max_retries {
+                disagreements += 1;
+                agreeing = 1;
+                debug!("연속 인식 불일치; 화면이 안정될 때까지 다시 캡처합니다");
+            } else {
+                anomalies.push(format!(
+                    "board did not stabilize after {disagreements} re-captures"
+                ));
+                self.charge_anomaly(AnomalyKind::Recognition);
+                return Ok(next);
+            }
+            observation = next;
+        }
+        Ok(observation)
     }
 
-    async fn publish_board_event(
+    /// Captures and recognizes the board, re-aligning once if too many squares
+    /// come back low-confidence (a shifted board rather than wrong pieces).
+    /// Also reads the last-move highlight and end-of-game screen from the
+    /// same frame.
+    async fn capture_and_recognize_once(&self, anomalies: &mut Vec<String>) -> Result<Observation> {
+        let frame = self.capture_frame().await?;
+        self.recognize_frame(frame, anomalies).await
+    }
+
+    /// The recognition half of [`Self::capture_and_recognize_once`], for a
+    /// frame that was already captured. A popup covering it is dismissed
+    /// first (see [`Self::dismiss_popups`]).
+    async fn recognize_frame(
         &self,
-        snapshot: GameSnapshot,
-        diffs: Vec<BoardDiff>,
-    ) -> Result<()> {
-        let event = SystemEvent::new(
-            EventKind::BoardUpdate,
-            EventPayload::Board(BoardEvent { snapshot, diffs }),
+        frame: ImageFrame,
+        anomalies: &mut Vec<String>,
+    ) -> Result<Observation> {
+        let frame = self.dismiss_popups(frame, anomalies).await?;
+        // The first frame is captured whole to find the board in.
+        if self.capture_margin.is_some()
+            && self.capture_region().is_none()
+            && self.recognizer.board_geometry().is_some()
+        {
+            self.align_board(&frame).await?;
+        }
+        let snapshot = self.recognize_board(&frame).await?;
+        if !self.recognizer.needs_realignment(&snapshot) {
+            return Ok(self.observe(snapshot, &frame));
+        }
+
+        warn!(
+            "저신뢰 칸 {}개 감지; 보드 재정렬 후 다시 인식합니다",
+            snapshot.recognition.low_confidence.len()
         );
-        self.publish(event).await
+        anomalies.push(format!(
+            "realigned after {} low-confidence squares",
+            snapshot.recognition.low_confidence.len()
+        ));
+        self.charge_anomaly(AnomalyKind::Recognition);
+        // The board may have moved out of the learned region; align on the
+        // whole screen and learn it again.
+        let cropped = self
+            .capture_region
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+            .is_some();
+        let frame = if cropped {
+            self.capture_frame().await?
+        } else {
+            frame
+        };
+        self.align_board(&frame).await?;
+        let frame = self.capture_frame().await?;
+        let snapshot = self.recognize_board(&frame).await?;
+        Ok(self.observe(snapshot, &frame))
     }
 
-    async fn perform_start_sequence(&mut self, formation: FormationPreset) -> Result<()> {
-        self.controller
-            .inject_actions(vec![
-                start_flow_action(StartFlowStep::Apply),
-                start_flow_action(StartFlowStep::ConfirmYes),
-                start_flow_action(StartFlowStep::ConfirmOk),
-            ])
-            .await?;
-
-        sleep(Duration::from_millis(150)).await;
+    /// While `frame` shows a configured popup, taps its dismiss point and
+    /// captures again, failing once `max_retries` taps have not closed it.
+    /// Spectating, nothing is tapped and the frame is read as it is.
+    async fn dismiss_popups(
+        &self,
+        mut frame: ImageFrame,
+        anomalies: &mut Vec<String>,
+    ) -> Result<ImageFrame> {
+        let mut dismissed = 0;
+        while let Some(popup) = self.recognizer.detect_popup(&frame) {
+            if self.spectating() {
+                info!("관전 중 '{}' 팝업이 보드를 가리고 있습니다", popup.name);
+                anomalies.push(format!("popup '{}' left open while spectating", popup.name));
+                break;
+            }
+            if dismissed >= self.config.max_retries {
+                error!(
+                    tags = "popup",
+                    "'{}' 팝업이 {dismissed}회 닫기 후에도 남아 있습니다", popup.name
+                );
+                return Err(orchestrator_error(format!(
+                    "popup '{}' still showing after {dismissed} dismissals",
+                    popup.name
+                )));
+            }
+            info!("'{}' 팝업을 닫고 다시 캡처합니다", popup.name);
+            anomalies.push(format!("dismissed popup '{}'", popup.name));
+            self.retry_stage("popup", || self.inject(vec![popup_dismiss_action(&popup)]))
+                .await?;
+            dismissed += 1;
+            sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+            frame = self.capture_frame().await?;
+        }
+        Ok(frame)
+    }
 
-        self.controller
-            .inject_actions(vec![
-                formation_action(formation),
-                formation_confirm_action(),
-            ])
-            .await?;
+    fn observe(&self, snapshot: GameSnapshot, frame: &ImageFrame) -> Observation {
+        Observation {
+            snapshot,
+            highlight: self
+                .recognizer
+                .recognize_last_move_highlight(frame)
+                .map(|(from, to)| (self.on_screen(from), self.on_screen(to))),
+            game_over: self.recognizer.recognize_game_over(frame),
+            dialog: self.recognizer.recognize_dialog(frame),
+        }
+    }
 
-        sleep(Duration::from_millis(150)).await;
+    /// Aligns the board on a full-screen `frame`. With a capture margin
+    /// configured, later frames are then captured to the board the
+    /// recognizer found, plus the margin.
+    async fn align_board(&self, frame: &ImageFrame) -> Result<()> {
+        self.recognizer.align_board(frame).await?;
+        let Some(margin) = self.capture_margin else {
+            return Ok(());
+        };
+        let Some(geometry) = self.recognizer.board_geometry() else {
+            return Ok(());
+        };
+        let region = geometry.bounds(margin);
+        info!(
+            "보드 캡처 영역: ({}, {}) {}x{}",
+            region.x, region.y, region.width, region.height
+        );
+        *self
+            .capture_region
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(region);
         Ok(())
     }
 
-    fn log_differences(&self, source: &str, diffs: &[BoardDiff]) {
-        for diff in diffs {
-            let before = diff
-                .before
-                .map(|p| format!("{:?}_{:?}", p.owner, p.kind))
-                .unwrap_or_else(|| "None".into());
-            let after = diff
-                .after
-                .map(|p| format!("{:?}_{:?}", p.owner, p.kind))
-                .unwrap_or_else(|| "None".into());
-            info!(
-                "{} 변화: square ({}, {}) {} -> {}",
-                source, diff.square.file, diff.square.rank, before, after
+    fn capture_region(&self) -> Option<ScreenRect> {
+        *self
+            .capture_region
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Captures a frame, cropped to the learned board region if there is
+    /// one, treating a frame that arrives older than `max_frame_age` as a
+    /// failed capture so it is retried.
+    async fn capture_frame(&self) -> Result<ImageFrame> {
+        let span = info_span!(
+            "capture",
+            frame_width = field::Empty,
+            frame_height = field::Empty,
+            duration_ms = field::Empty
+        );
+        let started = Instant::now();
+        let frame = self
+            .retry_stage("capture", || async {
+                let frame = match self.capture_region() {
+                    Some(region) => self.controller.capture_region(region).await?,
+                    None => self.controller.capture_frame().await?,
+                };
+                let age = (Utc::now() - frame.captured_at)
+                    .to_std()
+                    .unwrap_or_default();
+                match self.max_frame_age {
+                    Some(max_age) if age > max_age => Err(controller_error(format!(
+                        "stale frame: captured {}ms ago (limit {}ms)",
+                        age.as_millis(),
+                        max_age.as_millis()
+                    ))),
+                    _ => Ok(frame),
+                }
+            })
+            .instrument(span.clone())
+            .await;
+        self.finish_stage(&span, started, |stages| &mut stages.capture_ms);
+        let frame = frame?;
+        span.record("frame_width", frame.width);
+        span.record("frame_height", frame.height);
+        for observer in &self.observers {
+            observer_failed(
+                "on_frame_captured",
+                observer.on_frame_captured(&frame).await,
             );
         }
+        Ok(frame)
     }
-}
 
-#[async_trait]
-pub trait MatchRunner {
-    async fn run(&mut self) -> Result<()>;
-}
-
-#[async_trait]
-impl<C, V, E, N> MatchRunner for Orchestrator<C, V, E, N>
-where
-    C: DeviceController + Send + Sync,
-    V: BoardRecognizer + Send + Sync,
-    E: GameEngine + Send + Sync,
-    N: RealtimeServer + Send + Sync,
-{
-    async fn run(&mut self) -> Result<()> {
-        let start_event = SystemEvent::new(
-            EventKind::Lifecycle,
-            EventPayload::Lifecycle(LifecycleEvent {
-                phase: LifecyclePhase::MatchStart,
-                details: Some("mock match started".into()),
+    /// Recognizes `frame`, seated like the tracked boards (see
+    /// [`Self::seat_snapshot`]).
+    async fn recognize_board(&self, frame: &ImageFrame) -> Result<GameSnapshot> {
+        let flipped = self.screen_flipped();
+        let hints = RecognitionHints {
+            previous_snapshot: self.last_snapshot.clone().map(|snapshot| {
+                if flipped {
+                    rotate_snapshot(snapshot)
+                } else {
+                    snapshot
+                }
             }),
+        };
+        let span = info_span!(
+            "recognize",
+            low_confidence = field::Empty,
+            duration_ms = field::Empty
         );
-        self.publish(start_event).await?;
-
-        for turn in 0..self.config.max_retries {
-            info!("Executing turn {}", turn);
-            self.play_turn().await?;
+        let started = Instant::now();
+        let snapshot = self
+            .retry_stage("recognize", || {
+                self.recognizer.recognize(frame, hints.clone())
+            })
+            .instrument(span.clone())
+            .await;
+        self.finish_stage(&span, started, |stages| &mut stages.recognize_ms);
+        if let Ok(snapshot) = &snapshot {
+            span.record("low_confidence", snapshot.recognition.low_confidence.len());
         }
+        snapshot.map(|snapshot| self.seat_snapshot(snapshot))
+    }
 
-        let end_event = SystemEvent::new(
-            EventKind::Lifecycle,
-            EventPayload::Lifecycle(LifecycleEvent {
-                phase: LifecyclePhase::MatchEnd,
-                details: Some("mock match completed".into()),
-            }),
-        );
-        self.publish(end_event).await?;
-        Ok(())
+    /// Runs one turn stage up to `max_retries` times with exponential backoff,
+    /// reporting every failed attempt as an Ops event and charging it to the
+    /// anomaly budget. Returns the last error once the attempts are used up,
+    /// or at once when the budget has stopped input.
+    async fn retry_stage<T, F, Fut>(&self, stage: &str, mut op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let policy = RetryPolicy {
+            max_attempts: u32::from(self.config.max_retries),
+            ..RetryPolicy::default()
+        };
+        let mut attempt = 1;
+        loop {
+            let err = match op().await {
+                Ok(value) => return Ok(value),
+                // Input stopped on purpose; retrying cannot help.
+                Err(err) if self.exceeded_anomaly_budget().is_some() => return Err(err),
+                Err(err) => err,
+            };
+            self.charge_anomaly(if stage == "recognize" {
+                AnomalyKind::Recognition
+            } else {
+                AnomalyKind::ControllerFailure
+            });
+            if attempt >= policy.max_attempts {
+                warn!(
+                    tags = "retry",
+                    "{stage} 실패 ({attempt}/{}), 재시도를 모두 소진했습니다: {err}",
+                    policy.max_attempts
+                );
+                return Err(err);
+            }
+            let delay = backoff_delay(&policy, attempt);
+            warn!(
+                tags = "retry",
+                "{stage} 실패 ({attempt}/{}), {}ms 후 다시 시도합니다: {err}",
+                policy.max_attempts,
+                delay.as_millis()
+            );
+            sleep(delay).await;
+            attempt += 1;
+        }
     }
-}
 
-pub fn orchestrator_error(message: impl Into<String>) -> MinervaError {
+    /// Emits a `clock` Ops warning the first time `side`'s remaining time
+    /// drops below `low_time_warning_ms` this match.
+    fn warn_on_low_time(&mut self, side: PlayerSide) {
+        let Some(threshold) = self.config.low_time_warning_ms else {
+            return;
+        };
+        let remaining = self.clocks.remaining_ms(side);
+        if remaining < threshold && !self.low_time_warned {
+            self.low_time_warned = true;
+            warn!(
+                tags = "clock",
+                "남은 시간 {remaining}ms가 경고 기준 {threshold}ms보다 적습니다"
+            );
+        }
+    }
+
+    /// Declares a draw once the tracked board has gone `draw_after_halfmoves`
+    /// plies without a capture.
+    fn adjudicate_no_capture(&mut self) {
+        let (Some(limit), Some(tracked)) = (
+            self.config.draw_after_halfmoves,
+            self.last_snapshot.as_ref(),
+        ) else {
+            return;
+        };
+        if self.game_result.is_none() && tracked.halfmove_clock >= limit {
+            info!(
+                "{}수 동안 잡은 기물이 없어 무승부로 판정합니다",
+                tracked.halfmove_clock
+            );
+            self.game_result = Some(GameResult::Draw {
+                reason: DrawReason::NoCapture,
+            });
+        }
+    }
+
+    /// Side we play: `config.our_side`, else the side the client seats at
+    /// the bottom (it draws our pieces there), else Blue, who moves first
+    /// after the start flow. Fixed for the match once resolved.
+    fn resolve_our_side(&mut self) -> PlayerSide {
+        if let Some(side) = self.our_side {
+            return side;
+        }
+        let side = self
+            .config
+            .our_side
+            .or_else(|| self.bottom_side())
+            .unwrap_or(PlayerSide::Blue);
+        info!("이번 대국에서 {side:?} 진영을 둡니다");
+        self.our_side = Some(side);
+        self.record_formations(side);
+        side
+    }
+
+    /// Stamps `config.formation` on `side` in the game record, and the
+    /// opponent's formation, when known, on the other side.
+    fn record_formations(&mut self, side: PlayerSide) {
+        let mut record = self
+            .record
+            .clone()
+            .with_formation(side, self.config.formation);
+        if let Some(theirs) = self.opponent_formation {
+            record = record.with_formation(side.opponent(), theirs);
+        }
+        self.record = record;
+    }
+
+    /// The side the client seats at the bottom, once a recognized board has
+    /// shown it.
+    fn bottom_side(&self) -> Option<PlayerSide> {
+        let flipped = *self
+            .screen_flipped
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        flipped.map(|flipped| {
+            if flipped {
+                PlayerSide::Red
+            } else {
+                PlayerSide::Blue
+            }
+        })
+    }
+
+    /// Whether screens are read and tapped turned half around (see
+    /// `screen_flipped`). Until a board has shown the seating, the client is
+    /// assumed to draw a configured Red at the bottom.
+    fn screen_flipped(&self) -> bool {
+        self.screen_flipped
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .unwrap_or(self.config.our_side == Some(PlayerSide::Red))
+    }
+
+    /// Where `square` of the tracked board is on screen, and the other way
+    /// around: turning half around twice gives the square back.
+    fn on_screen(&self, square: Square) -> Square {
+        if self.screen_flipped() {
+            square.rotated()
+        } else {
+            square
+        }
+    }
+
+    /// Seats a freshly recognized `snapshot` like the tracked boards,
+    /// resolving `screen_flipped` from it if still unknown.
+    fn seat_snapshot(&self, snapshot: GameSnapshot) -> GameSnapshot {
+        {
+            let mut flipped = self
+                .screen_flipped
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            if flipped.is_none() {
+                if let Some(bottom) = bottom_palace_general(&snapshot.board) {
+                    info!("화면 아래쪽 진영: {bottom:?}");
+                    *flipped = Some(bottom == PlayerSide::Red);
+                }
+            }
+        }
+        if self.screen_flipped() {
+            rotate_snapshot(snapshot)
+        } else {
+            snapshot
+        }
+    }
+
+    /// Drains pending operator commands: a resignation concedes the game for
+    /// our side (Blue if not yet resolved), pause/resume/step control whether
+    /// turns run, and a forced move is queued for our next turn. Each change,
+    /// and each command ignored while spectating, is announced as a `control`
+    /// Ops event.
+    async fn handle_commands(&mut self) -> Result<()> {
+        let Some(commands) = self.commands.as_mut() else {
+            return Ok(());
+        };
+        let mut received = Vec::new();
+        while let Ok(command) = commands.try_recv() {
+            received.push(command);
+        }
+        for command in received {
+            let announcement = match command {
+                RemoteCommand::Resign if self.spectating() => {
+                    Some("resign ignored: spectating, not playing")
+                }
+                RemoteCommand::Resign if self.game_result.is_none() => {
+                    info!("원격 명령으로 기권합니다 ({:?})", self.our_side);
+                    self.resign().await;
+                    None
+                }
+                RemoteCommand::Resign => None,
+                RemoteCommand::Pause if !self.paused => {
+                    self.paused = true;
+                    Some("paused: no turns until resumed")
+                }
+                RemoteCommand::Resume if self.paused => {
+                    self.paused = false;
+                    self.step_pending = false;
+                    Some("resumed")
+                }
+                RemoteCommand::Step if self.paused => {
+                    self.step_pending = true;
+                    Some("stepping one turn")
+                }
+                RemoteCommand::Pause | RemoteCommand::Resume | RemoteCommand::Step => None,
+                RemoteCommand::ForceMove { .. } if self.spectating() => {
+                    Some("forced move ignored: spectating, not playing")
+                }
+                RemoteCommand::ForceMove { from, to } => {
+                    self.forced_move = Some(Move::new(from, to));
+                    Some("forced move queued for our next turn")
+                }
+            };
+            if let Some(message) = announcement {
+                info!("{message}");
+                self.publish(ops_event(
+                    Severity::Info,
+                    message,
+                    vec!["control".to_string()],
+                ))
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Captures and publishes the board without tracking it, so a paused
+    /// match still shows what is on screen. Failures are only logged.
+    async fn publish_status_board(&mut self) -> Result<()> {
+        let observation = match self.capture_and_recognize(&mut Vec::new()).await {
+            Ok(observation) => observation,
+            Err(err) => {
+                warn!(tags = "control", "일시정지 중 보드 캡처 실패: {err}");
+                return Ok(());
+            }
+        };
+        let mut snapshot = observation.snapshot;
+        snapshot.clocks = self.clocks;
+        let diffs = self
+            .last_snapshot
+            .as_ref()
+            .map(|prev| prev.board.differences(&snapshot.board))
+            .unwrap_or_default();
+        self.publish_board_event(snapshot, diffs, None, Uuid::new_v4())
+            .await
+    }
+
+    /// Plays one turn; a turn that fails after its per-stage retries is
+    /// tried once more before giving up with the last error.
+    async fn play_turn_with_retries(&mut self) -> Result<()> {
+        match self.play_turn().await {
+            Err(err) if self.exceeded_anomaly_budget().is_none() => {
+                warn!(tags = "turn", "턴 실패, 한 번 더 시도합니다: {err}");
+                self.play_turn().await
+            }
+            played => played,
+        }
+    }
+
+    /// What the engine is asked to decide: `snapshot` with `side` to play
+    /// and, in a timed game, `side`'s tracked clock.
+    fn turn_context(&self, snapshot: GameSnapshot, side: PlayerSide) -> TurnContext {
+        let ctx = TurnContext::new(snapshot, side);
+        if self.config.time_control.base_ms == 0 {
+            return ctx;
+        }
+        ctx.with_clock(
+            self.clocks.remaining_ms(side),
+            self.config.time_control.increment_ms,
+        )
+    }
+
+    /// Time the engine gets for one move: its clock budget (see
+    /// [`TurnContext::time_budget_ms`]), never more than `turn_deadline_ms`.
+    fn move_deadline(&self, ctx: &TurnContext) -> Duration {
+        let cap = self.config.turn_deadline_ms;
+        Duration::from_millis(ctx.time_budget_ms().map_or(cap, |budget| budget.min(cap)))
+    }
+
+    /// Replaces the engine's move with a queued operator move when it is
+    /// legal for `side` on the tracked board, returning whether it did. An
+    /// illegal one is reported as an Ops event and the engine's move stands.
+    fn take_forced_move(
+        &mut self,
+        side: PlayerSide,
+        decision: &mut EngineDecision,
+        anomalies: &mut Vec<String>,
+    ) -> bool {
+        let Some(forced) = self.forced_move.take() else {
+            return false;
+        };
+        if decision.result.is_some() {
+            return false;
+        }
+        let legal = self.last_snapshot.as_ref().is_some_and(|snapshot| {
+            snapshot
+                .board
+                .legal_moves(side)
+                .iter()
+                .any(|mv| mv.from == forced.from && mv.to == forced.to)
+        });
+        if !legal {
+            let message = format!(
+                "forced move {} -> {} is illegal; playing the engine's move",
+                forced.from, forced.to
+            );
+            warn!(tags = "override", "{message}");
+            anomalies.push(message);
+            return false;
+        }
+        info!("운영자 지정 수를 둡니다: {} -> {}", forced.from, forced.to);
+        anomalies.push(format!(
+            "engine move overridden by operator: {} -> {}",
+            forced.from, forced.to
+        ));
+        decision.best_move = Some(forced);
+        true
+    }
+
+    /// Answers any draw offer or bikjang dialog `observation` shows (see
+    /// [`Self::answer_dialog`]) and looks at the board again, giving up
+    /// after `max_retries` answers that leave a dialog up. `None` when the
+    /// turn ends here: the draw was accepted, or we are spectating and leave
+    /// the dialog to the players.
+    async fn clear_dialogs(
+        &mut self,
+        mut observation: Observation,
+        anomalies: &mut Vec<String>,
+    ) -> Result<Option<Observation>> {
+        let mut answered = 0;
+        while let Some(dialog) = observation.dialog {
+            if self.spectating() {
+                info!("관전 중 {dialog:?} 창이 떠 있어 다음 폴링을 기다립니다");
+                sleep(self.poll_interval).await;
+                return Ok(None);
+            }
+            if answered > self.config.max_retries {
+                return Err(orchestrator_error(format!(
+                    "{dialog:?} dialog still showing after {answered} answers"
+                )));
+            }
+            if self.answer_dialog(dialog, anomalies).await? {
+                return Ok(None);
+            }
+            answered += 1;
+            observation = self.capture_and_recognize(anomalies).await?;
+        }
+        Ok(Some(observation))
+    }
+
+    /// Decides a draw offer or bikjang: the tracked position is evaluated
+    /// for our side, and the draw is accepted at or below `accept_draw_below`
+    /// (declined without a tracked position). The answer
+    /// is tapped at `dialog_buttons` and published as a `dialog` Ops event;
+    /// an accepted draw ends the game. Returns whether it was accepted.
+    async fn answer_dialog(
+        &mut self,
+        dialog: MatchDialog,
+        anomalies: &mut Vec<String>,
+    ) -> Result<bool> {
+        let side = self.our_side.unwrap_or(PlayerSide::Blue);
+        // The position itself, not what our best move would gain from it.
+        let score = self
+            .last_snapshot
+            .as_ref()
+            .map(|snapshot| evaluate(&snapshot.board, side, &self.eval_weights));
+        let threshold = self.config.accept_draw_below;
+        let accept = score.is_some_and(|score| score <= threshold);
+        let buttons = self.config.dialog_buttons;
+        self.retry_stage("dialog", || {
+            self.inject(vec![dialog_answer_action(buttons, accept)])
+        })
+        .await?;
+        sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+        let score = score.map_or_else(
+            || "no tracked position".to_string(),
+            |score| format!("score {score:.2}"),
+        );
+        info!(
+            "{dialog:?} 창 {}: {score}, 기준 {threshold:.2}",
+            if accept { "수락" } else { "거절" }
+        );
+        let message = format!(
+            "{} {dialog:?} ({score}, threshold {threshold:.2})",
+            if accept { "accepted" } else { "declined" }
+        );
+        anomalies.push(message.clone());
+        self.publish(ops_event(Severity::Info, message, vec!["dialog".into()]))
+            .await?;
+        if accept {
+            self.game_result = Some(GameResult::Draw {
+                reason: match dialog {
+                    MatchDialog::DrawOffer => DrawReason::Agreement,
+                    MatchDialog::Bikjang => DrawReason::Bikjang,
+                },
+            });
+        }
+        Ok(accept)
+    }
+
+    /// Whether the match is only watched (`OrchestratorMode::Spectate`), so
+    /// no input may reach the device.
+    fn spectating(&self) -> bool {
+        self.config.mode == OrchestratorMode::Spectate
+    }
+
+    /// Counts turns whose position evaluation (the decision's `score`, after
+    /// our move) is below `resign_threshold` from `resign_min_plies` on; true
+    /// once `resign_persistence` of them come in a row.
+    fn should_resign(&mut self, ply: u32, score: f32) -> bool {
+        let Some(threshold) = self.config.resign_threshold else {
+            return false;
+        };
+        if ply < self.config.resign_min_plies || score >= threshold {
+            self.hopeless_turns = 0;
+            return false;
+        }
+        self.hopeless_turns += 1;
+        self.hopeless_turns >= self.config.resign_persistence
+    }
+
+    /// Concedes through the client's resign menu and records the loss. The
+    /// loss stands even if the taps fail, since we stop playing either way.
+    async fn resign(&mut self) {
+        let our_side = self.our_side.unwrap_or(PlayerSide::Blue);
+        for step in [ResignStep::Menu, ResignStep::Confirm] {
+            if let Err(err) = self.inject(vec![resign_action(step)]).await {
+                self.charge_anomaly(AnomalyKind::ControllerFailure);
+                warn!(tags = "input", "기권 입력 실패({step:?}): {err}");
+                break;
+            }
+            sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+        }
+        self.game_result = Some(GameResult::Win {
+            winner: our_side.opponent(),
+            reason: WinReason::Resignation,
+        });
+    }
+
+    /// Whether `mv` starts on one of `side`'s pieces in the tracked board.
+    fn moves_own_piece(&self, side: PlayerSide, mv: &Move) -> bool {
+        self.last_snapshot
+            .as_ref()
+            .and_then(|snapshot| snapshot.board.piece_at(mv.from))
+            .is_some_and(|piece| piece.owner == side)
+    }
+
+    /// Skips a move that would touch the wrong side's piece, reports it, and
+    /// re-recognizes the board since the recognized side is likely wrong.
+    async fn reject_side_mismatch(
+        &mut self,
+        side: PlayerSide,
+        mv: &Move,
+        turn_id: Uuid,
+        anomalies: &mut Vec<String>,
+    ) -> Result<()> {
+        let message = format!("engine move {mv} does not start on a {side:?} piece");
+        error!(
+            tags = "side-mismatch",
+            "{message}; 입력을 건너뛰고 보드를 다시 인식합니다"
+        );
+        anomalies.push(message);
+        self.recapture(turn_id, anomalies).await.map(|_| ())
+    }
+
+    /// Looks at the screen after `mv` was tapped; the client ignored it if
+    /// both of its squares still show what the tracked board has there.
+    async fn move_was_ignored(&mut self, mv: &Move, anomalies: &mut Vec<String>) -> Result<bool> {
+        if self.config.max_move_rejections == 0 || self.config.dry_run {
+            return Ok(false);
+        }
+        self.set_state(OrchestratorState::Verifying).await?;
+        let span = info_span!("verify", ignored = field::Empty, duration_ms = field::Empty);
+        let started = Instant::now();
+        let observation = self
+            .capture_and_recognize(anomalies)
+            .instrument(span.clone())
+            .await;
+        self.finish_stage(&span, started, |stages| &mut stages.verify_ms);
+        let observation = observation?;
+        let Some(tracked) = self.last_snapshot.as_ref() else {
+            return Ok(false);
+        };
+        let board = &observation.snapshot.board;
+        let ignored = !observation.interrupted()
+            && board.piece_at(mv.from) == tracked.board.piece_at(mv.from)
+            && board.piece_at(mv.to) == tracked.board.piece_at(mv.to);
+        span.record("ignored", ignored);
+        Ok(ignored)
+    }
+
+    /// Counts an ignored move, failing once `max_move_rejections` is
+    /// reached; otherwise clears the client's piece selection and adopts a
+    /// fresh recognition of the board as is.
+    async fn recover_ignored_move(
+        &mut self,
+        mv: &Move,
+        turn_id: Uuid,
+        anomalies: &mut Vec<String>,
+    ) -> Result<GameSnapshot> {
+        self.move_rejections += 1;
+        self.charge_anomaly(AnomalyKind::MoveRejection);
+        let message = format!("client ignored our move {} -> {}", mv.from, mv.to);
+        anomalies.push(message.clone());
+        if self.move_rejections >= self.config.max_move_rejections {
+            error!(
+                tags = "move-rejected",
+                "{message}; 무시된 수가 {}회에 이르러 대국을 중단합니다", self.move_rejections
+            );
+            return Err(orchestrator_error(format!(
+                "client ignored {} of our moves",
+                self.move_rejections
+            )));
+        }
+        warn!(
+            tags = "move-rejected",
+            "{message}; 선택을 해제하고 보드를 다시 인식합니다 ({}/{})",
+            self.move_rejections,
+            self.config.max_move_rejections
+        );
+        self.retry_stage("clear_selection", || {
+            self.inject(vec![clear_selection_action()])
+        })
+        .await?;
+        sleep(Duration::from_millis(self.timing.post_move_settle_ms)).await;
+        self.recapture(turn_id, anomalies).await
+    }
+
+    /// Captures and recognizes the board again, adopting and publishing it
+    /// as the tracked snapshot.
+    async fn recapture(
+        &mut self,
+        turn_id: Uuid,
+        anomalies: &mut Vec<String>,
+    ) -> Result<GameSnapshot> {
+        let observation = self.capture_and_recognize(anomalies).await?;
+        let diffs = self
+            .last_snapshot
+            .as_ref()
+            .map(|prev| prev.board.differences(&observation.snapshot.board))
+            .unwrap_or_default();
+        self.last_snapshot = Some(observation.snapshot.clone());
+        self.push_history(&observation.snapshot, None);
+        self.log_board(&observation.snapshot.board);
+        self.publish_board_event(observation.snapshot.clone(), diffs, None, turn_id)
+            .await?;
+        Ok(observation.snapshot)
+    }
+
+    /// Asks the engine for a move within [`Self::move_deadline`]; past it,
+    /// takes the engine's best-so-far decision or an emergency one-ply move.
+    async fn decide(
+        &self,
+        ctx: &TurnContext,
+        anomalies: &mut Vec<String>,
+    ) -> Result<EngineDecision> {
+        let deadline = self.move_deadline(ctx);
+        let span = info_span!(
+            "evaluate",
+            ply = ctx.snapshot.ply,
+            side = ?ctx.side,
+            nodes = field::Empty,
+            depth = field::Empty,
+            duration_ms = field::Empty
+        );
+        let started = Instant::now();
+        let decision = self
+            .decide_within(deadline, ctx, anomalies)
+            .instrument(span.clone())
+            .await;
+        if let Ok(decision) = &decision {
+            span.record("nodes", decision.searched_nodes);
+            span.record("depth", decision.depth);
+        }
+        self.finish_stage(&span, started, |stages| &mut stages.evaluate_ms);
+        decision
+    }
+
+    /// The search half of [`Self::decide`].
+    async fn decide_within(
+        &self,
+        deadline: Duration,
+        ctx: &TurnContext,
+        anomalies: &mut Vec<String>,
+    ) -> Result<EngineDecision> {
+        match timeout(deadline, self.engine.evaluate_position(ctx)).await {
+            Ok(decision) => decision,
+            Err(_) => {
+                let partial = self.engine.cancel();
+                warn!(
+                    tags = "engine",
+                    "엔진이 {}ms 안에 응답하지 않았습니다; {}",
+                    deadline.as_millis(),
+                    if partial.is_some() {
+                        "중간 결과를 사용합니다"
+                    } else {
+                        "비상 수를 고릅니다"
+                    }
+                );
+                anomalies.push(format!(
+                    "engine missed its {}ms deadline",
+                    deadline.as_millis()
+                ));
+                Ok(partial.unwrap_or_else(|| emergency_decision(&ctx.snapshot.board, ctx.side)))
+            }
+        }
+    }
+
+    async fn notify_decision(&self, decision: &EngineDecision) {
+        for observer in &self.observers {
+            observer_failed("on_decision", observer.on_decision(decision).await);
+        }
+    }
+
+    /// Waits until `thinking` reaches [`Self::move_delay`], so an instant
+    /// decision is not tapped instantly.
+    async fn hold_move(&self, thinking: Duration) {
+        let wait = self.move_delay().saturating_sub(thinking);
+        if !wait.is_zero() {
+            debug!("착수 전 {}ms 대기합니다", wait.as_millis());
+            sleep(wait).await;
+        }
+    }
+
+    /// Think time to reach before tapping our move: `min_move_delay_ms`, or
+    /// a random time up to `max_move_delay_ms` when that is set.
+    fn move_delay(&self) -> Duration {
+        let min = self.config.min_move_delay_ms;
+        let Some(max) = self.config.max_move_delay_ms.filter(|&max| max > min) else {
+            return Duration::from_millis(min);
+        };
+        // Spread around the middle of the range to reach both ends.
+        let spread = (max - min) as f32 / (max + min) as f32;
+        let delay = jittered(Duration::from_millis((min + max) / 2), spread);
+        delay.clamp(Duration::from_millis(min), Duration::from_millis(max))
+    }
+
+    async fn apply_move(&self, mv: &Move) -> Result<()> {
+        let span = info_span!(
+            "apply_move",
+            from = %mv.from,
+            to = %mv.to,
+            duration_ms = field::Empty
+        );
+        let started = Instant::now();
+        let applied = async {
+            self.retry_stage("apply_move", || self.tap_move(mv)).await?;
+            sleep(Duration::from_millis(self.timing.post_move_settle_ms)).await;
+            Ok(())
+        }
+        .instrument(span.clone())
+        .await;
+        self.finish_stage(&span, started, |stages| &mut stages.apply_move_ms);
+        applied
+    }
+
+    /// Records the time since `started` as `span`'s `duration_ms` and adds
+    /// it to the turn's stage total picked by `stage`.
+    fn finish_stage(
+        &self,
+        span: &Span,
+        started: Instant,
+        stage: fn(&mut StageDurations) -> &mut u64,
+    ) {
+        let ms = elapsed_ms(started);
+        span.record("duration_ms", ms);
+        let mut stages = self
+            .stage_durations
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *stage(&mut stages) += ms;
+    }
+
+    /// Whether `mv`, inferred from the board diffs as `side`'s move, is legal
+    /// on the tracked board. An illegal one (usually a misread) is flagged on
+    /// the turn and left out of the game record.
+    fn accept_inferred_move(
+        &self,
+        side: PlayerSide,
+        mv: &Move,
+        anomalies: &mut Vec<String>,
+    ) -> bool {
+        let Some(tracked) = self.last_snapshot.as_ref() else {
+            return true;
+        };
+        match tracked.board.is_legal_move(side, mv) {
+            Ok(()) => true,
+            Err(reason) => {
+                warn!(
+                    tags = "board",
+                    "추론한 상대 수 {mv}가 규칙에 맞지 않아 기록하지 않습니다: {reason}"
+                );
+                anomalies.push(format!("illegal inferred move {mv}: {reason}"));
+                false
+            }
+        }
+    }
+
+    /// Plays `mv` for `side` on a copy of the tracked snapshot; `None` (with
+    /// an anomaly) if the tracked board does not allow it.
+    fn stage_move(
+        &self,
+        side: PlayerSide,
+        mv: Move,
+        anomalies: &mut Vec<String>,
+    ) -> Option<StagedMove> {
+        let tracked = self.last_snapshot.as_ref()?;
+        let mut snapshot = tracked.clone();
+        let piece = snapshot.board.piece_at(mv.from).map(|p| p.kind);
+        let captured = match snapshot.apply_move(side, &mv) {
+            Ok(captured) => captured,
+            Err(err) => {
+                warn!(tags = "board", "내부 스냅샷 업데이트 실패: {err}");
+                anomalies.push(format!("snapshot update failed: {err}"));
+                return None;
+            }
+        };
+        let diffs = tracked.board.differences(&snapshot.board);
+        Some(StagedMove {
+            mv,
+            snapshot,
+            diffs,
+            piece,
+            captured,
+        })
+    }
+
+    async fn tap_move(&self, mv: &Move) -> Result<()> {
+        self.ensure_input_allowed()?;
+        if self.config.dry_run {
+            let taps = [mv.from, mv.to]
+                .into_iter()
+                .map(|square| {
+                    let square = self.on_screen(square);
+                    let point = self.geometry.square_to_point(square).ok_or_else(|| {
+                        orchestrator_error(format!("square {square} is off the screen"))
+                    })?;
+                    let point = point.offset(self.tap_offset);
+                    Ok(InputAction::Tap {
+                        x: point.x,
+                        y: point.y,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            return self.send(taps).await;
+        }
+        self.controller.tap_square(self.on_screen(mv.from)).await?;
+        sleep(Duration::from_millis(self.timing.tap_gap_ms)).await;
+        self.controller.tap_square(self.on_screen(mv.to)).await?;
+        Ok(())
+    }
+
+    /// Sends `actions` unless the anomaly budget has stopped input.
+    async fn inject(&self, actions: Vec<InputAction>) -> Result<()> {
+        self.ensure_input_allowed()?;
+        self.send(actions).await
+    }
+
+    /// Sends `actions` to the device; in dry-run mode only reports them as
+    /// a `dry_run` Ops event.
+    async fn send(&self, actions: Vec<InputAction>) -> Result<()> {
+        if !self.config.dry_run {
+            return self.controller.inject_actions(actions).await;
+        }
+        let message = dry_run_message(&actions);
+        info!("{message}");
+        self.publish(ops_event(Severity::Info, message, vec!["dry_run".into()]))
+            .await
+    }
+
+    /// Fails once the anomaly budget is exceeded, so no further input is
+    /// sent this match.
+    fn ensure_input_allowed(&self) -> Result<()> {
+        match self.exceeded_anomaly_budget() {
+            Some(budget) => Err(orchestrator_error(format!(
+                "anomaly budget exceeded (score {}); input stopped",
+                budget.score
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Charges one `kind` anomaly to this match's budget.
+    fn charge_anomaly(&self, kind: AnomalyKind) {
+        self.anomaly_budget
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .charge(kind);
+    }
+
+    /// The budget, once its score is past `max_anomaly_score`.
+    fn exceeded_anomaly_budget(&self) -> Option<AnomalyBudget> {
+        let max = self.config.max_anomaly_score?;
+        let budget = *self
+            .anomaly_budget
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        (budget.score > max).then_some(budget)
+    }
+
+    /// Reports the exceeded budget as an `anomaly` Ops error and, with
+    /// `back_on_anomaly_abort`, presses Back once to leave the board.
+    async fn abort_on_anomalies(&self, budget: AnomalyBudget) {
+        error!(
+            tags = "anomaly",
+            "이상 점수 {}이(가) 한도 {}를 넘어 입력을 멈추고 대국을 중단합니다 (인식 {}, 불일치 {}, 무시된 수 {}, 입력 실패 {})",
+            budget.score,
+            self.config.max_anomaly_score.unwrap_or_default(),
+            budget.recognition,
+            budget.desyncs,
+            budget.move_rejections,
+            budget.controller_failures
+        );
+        if self.config.back_on_anomaly_abort {
+            if let Err(err) = self.send(vec![back_action()]).await {
+                warn!(tags = "input", "뒤로 가기 입력 실패: {err}");
+            }
+        }
+    }
+
+    /// Enters `state` and publishes the transition; staying put publishes
+    /// nothing.
+    async fn set_state(&mut self, state: OrchestratorState) -> Result<()> {
+        let from = self.state;
+        if from == state {
+            return Ok(());
+        }
+        debug_assert!(
+            state.can_follow(from),
+            "invalid orchestrator transition {from} -> {state}"
+        );
+        debug!("상태 전환: {from} -> {state}");
+        self.state = state;
+        self.publish(SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::State(StateChangeEvent { from, to: state }),
+        ))
+        .await
+    }
+
+    /// Publishes pending bridged tracing events, then `event`.
+    async fn publish(&self, event: SystemEvent) -> Result<()> {
+        for pending in self.take_ops_events() {
+            self.publish_event(pending).await?;
+        }
+        self.publish_event(event).await
+    }
+
+    fn take_ops_events(&self) -> Vec<SystemEvent> {
+        let mut guard = self
+            .ops_events
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut pending = Vec::new();
+        if let Some(receiver) = guard.as_mut() {
+            while let Ok(event) = receiver.try_recv() {
+                pending.push(event);
+            }
+        }
+        pending
+    }
+
+    /// Stamps the session/match ids onto `event` and fans it out.
+    async fn publish_event(&self, mut event: SystemEvent) -> Result<()> {
+        event.session_id = Some(self.session_id);
+        event.match_id = self.match_id;
+        if let Some(status) = &self.status {
+            status.observe(&event);
+            status.set_controller(self.controller.metrics().summary());
+        }
+        let cloned = event.clone();
+        self.network.publish(event).await?;
+        self.telemetry.record_event(cloned).await?;
+        Ok(())
+    }
+
+    /// Publishes `snapshot` as a board event, unless `skip_unchanged_boards`
+    /// is set and the position matches the last one published.
+    async fn publish_board_event(
+        &mut self,
+        snapshot: GameSnapshot,
+        diffs: Vec<BoardDiff>,
+        captured: Option<Piece>,
+        turn_id: Uuid,
+    ) -> Result<()> {
+        let fingerprint = snapshot.board.fingerprint();
+        if self.config.skip_unchanged_boards && self.last_published_board == Some(fingerprint) {
+            debug!("보드 변화 없음; BoardUpdate 생략");
+            return Ok(());
+        }
+        self.last_published_board = Some(fingerprint);
+        let event = SystemEvent::new(
+            EventKind::BoardUpdate,
+            EventPayload::Board(BoardEvent {
+                frame_path: snapshot.recognition.frame_path.clone(),
+                snapshot,
+                diffs,
+                turn_id: Some(turn_id),
+                captured,
+            }),
+        );
+        self.publish(event).await
+    }
+
+    /// Adds a capture made at `ply` to the match telemetry.
+    fn record_capture(&mut self, ply: u32, square: Square, piece: Piece) {
+        info!("{:?} {:?} 잡힘 ({square})", piece.owner, piece.kind);
+        self.match_telemetry
+            .captures
+            .push(CaptureRecord { ply, square, piece });
+    }
+
+    /// Appends an accepted position to the history, or refreshes the newest
+    /// entry when the position is unchanged, and updates the status report.
+    fn push_history(&mut self, snapshot: &GameSnapshot, mv: Option<Move>) {
+        let hash = snapshot.board.position_hash();
+        match self.history.back_mut() {
+            Some(last)
+                if last.snapshot.ply == snapshot.ply
+                    && last.snapshot.board.position_hash() == hash =>
+            {
+                last.snapshot = snapshot.clone();
+            }
+            _ => {
+                self.history.push_back(HistoryEntry {
+                    snapshot: snapshot.clone(),
+                    mv,
+                });
+                if self.history.len() > HISTORY_LEN {
+                    self.history.pop_front();
+                }
+            }
+        }
+        self.publish_history();
+    }
+
+    fn publish_history(&self) {
+        if let Some(status) = &self.status {
+            status.set_history(self.history.iter().map(Into::into).collect());
+        }
+    }
+
+    /// Rolls the tracked game back to the most recent history entry that
+    /// `recognized` matches or follows by one legal move, discarding the
+    /// entries, recorded moves, and captures after it. Returns the entry's
+    /// snapshot, or `None` when no entry fits.
+    fn roll_back_to_history(
+        &mut self,
+        recognized: &GameSnapshot,
+        squares: &str,
+        anomalies: &mut Vec<String>,
+    ) -> Option<GameSnapshot> {
+        let index = self.history.iter().rposition(|entry| {
+            !matches!(
+                reconcile(&entry.snapshot, recognized),
+                Reconciliation::Desync(_)
+            )
+        })?;
+        let base = self.history[index].snapshot.clone();
+        let tracked_ply = self.last_snapshot.as_ref().map_or(base.ply, |s| s.ply);
+        let discarded = tracked_ply.saturating_sub(base.ply);
+        self.history.truncate(index + 1);
+        let kept = self.record.moves.len().saturating_sub(discarded as usize);
+        self.record.moves.truncate(kept);
+        self.match_telemetry
+            .captures
+            .retain(|capture| capture.ply <= base.ply);
+        self.last_snapshot = Some(base.clone());
+        self.publish_history();
+        let message = format!(
+            "board desync on {squares}; rolled back {discarded} plies to ply {} from history",
+            base.ply
+        );
+        warn!(tags = "desync", "{message}");
+        anomalies.push(message);
+        Some(base)
+    }
+
+    /// Summary of the current match for the end-of-match result event.
+    fn match_result(&self) -> MatchResultEvent {
+        let latencies = &self.match_telemetry.latency_samples;
+        let average_latency_ms = (!latencies.is_empty()).then(|| {
+            latencies.iter().map(|sample| sample.total_ms).sum::<u64>() / latencies.len() as u64
+        });
+        MatchResultEvent {
+            result: self.game_result,
+            plies: self.record.moves.len() as u32,
+            final_fen: self
+                .last_snapshot
+                .as_ref()
+                .map(|snapshot| snapshot.board.to_fen()),
+            total_nodes: self
+                .match_telemetry
+                .engine_history
+                .iter()
+                .map(|metrics| metrics.nodes)
+                .sum(),
+            average_latency_ms,
+        }
+    }
+
+    /// Taps through the client's match setup. When the recognizer knows the
+    /// setup screens, each tap waits for its screen and must bring up the
+    /// next (see [`Self::run_start_stage`]), and a configured Red waits up
+    /// to `opponent_timeout_secs` for the opponent to pick its formation
+    /// first; otherwise the taps are sent blind with a settle delay after
+    /// each group.
+    async fn perform_start_sequence(&mut self, formation: FormationPreset) -> Result<()> {
+        if self.recognizer.recognizes_screens() {
+            // Red picks its formation second: the picker only comes up once
+            // the opponent has chosen.
+            let formation_wait = if self.config.our_side == Some(PlayerSide::Red) {
+                info!("Red 진영이므로 상대의 진형 선택을 기다립니다");
+                Duration::from_secs(self.config.opponent_timeout_secs)
+            } else {
+                Duration::ZERO
+            };
+            let stages = [
+                StartStage {
+                    name: "apply",
+                    action: start_flow_action(StartFlowStep::Apply),
+                    on: StartScreen::Lobby,
+                    leads_to: Some(StartScreen::ConfirmDialog),
+                    patience: Duration::ZERO,
+                },
+                StartStage {
+                    name: "confirm",
+                    action: start_flow_action(StartFlowStep::ConfirmYes),
+                    on: StartScreen::ConfirmDialog,
+                    leads_to: Some(StartScreen::NoticeDialog),
+                    patience: Duration::ZERO,
+                },
+                StartStage {
+                    name: "notice",
+                    action: start_flow_action(StartFlowStep::ConfirmOk),
+                    on: StartScreen::NoticeDialog,
+                    leads_to: Some(StartScreen::FormationPicker),
+                    patience: formation_wait,
+                },
+                StartStage {
+                    name: "formation",
+                    action: formation_action(formation),
+                    on: StartScreen::FormationPicker,
+                    leads_to: Some(StartScreen::FormationPicker),
+                    patience: Duration::ZERO,
+                },
+                StartStage {
+                    name: "formation_confirm",
+                    action: formation_confirm_action(),
+                    on: StartScreen::FormationPicker,
+                    leads_to: None,
+                    patience: Duration::ZERO,
+                },
+            ];
+            for stage in &stages {
+                self.run_start_stage(stage).await?;
+            }
+            return Ok(());
+        }
+
+        self.inject(vec![
+            start_flow_action(StartFlowStep::Apply),
+            start_flow_action(StartFlowStep::ConfirmYes),
+            start_flow_action(StartFlowStep::ConfirmOk),
+        ])
+        .await?;
+
+        sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+
+        self.inject(vec![
+            formation_action(formation),
+            formation_confirm_action(),
+        ])
+        .await?;
+
+        sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+        Ok(())
+    }
+
+    /// Taps `stage` once its screen shows and checks that the screen it
+    /// leads to appears within the stage's patience, trying up to
+    /// `max_retries` more times. A screen
+    /// that is already past the stage (the previous tap took effect late)
+    /// counts as done.
+    async fn run_start_stage(&self, stage: &StartStage) -> Result<()> {
+        let attempts = self.config.max_retries + 1;
+        let mut seen = None;
+        for attempt in 1..=attempts {
+            seen = self.recognize_screen().await?;
+            if seen == stage.leads_to && stage.leads_to != Some(stage.on) {
+                return Ok(());
+            }
+            if seen == Some(stage.on) {
+                self.inject(vec![stage.action.clone()]).await?;
+                self.wait_for_idle().await;
+                seen = self.recognize_screen().await?;
+                let deadline = Instant::now() + stage.patience;
+                while seen != stage.leads_to && seen != Some(stage.on) && Instant::now() < deadline
+                {
+                    self.wait_for_idle().await;
+                    seen = self.recognize_screen().await?;
+                }
+                if seen == stage.leads_to {
+                    debug!("시작 절차 {} 단계 완료", stage.name);
+                    return Ok(());
+                }
+            } else {
+                self.wait_for_idle().await;
+            }
+            warn!(
+                tags = "start",
+                "시작 절차 {} 단계 확인 실패 ({attempt}/{attempts}): 화면 {seen:?}", stage.name
+            );
+        }
+        Err(orchestrator_error(format!(
+            "start sequence step {} failed after {attempts} attempts: expected {:?}, screen shows {seen:?}",
+            stage.name, stage.on
+        )))
+    }
+
+    /// Which setup screen a fresh full-screen capture shows.
+    async fn recognize_screen(&self) -> Result<Option<StartScreen>> {
+        let frame = self
+            .retry_stage("capture", || self.controller.capture_frame())
+            .await?;
+        Ok(self.recognizer.recognize_screen(&frame))
+    }
+
+    /// Gives the client `start_flow_step_ms` to settle after a tap.
+    async fn wait_for_idle(&self) {
+        sleep(Duration::from_millis(self.timing.start_flow_step_ms)).await;
+    }
+
+    /// Writes the gibo and JSON forms of the match record, plus the markdown
+    /// report, next to its events.
+    fn write_game_record(&self, match_telemetry: &MatchTelemetry) -> Result<()> {
+        let Some(dir) = self
+            .telemetry
+            .match_dir(Some(self.session_id), self.match_id)
+        else {
+            return Ok(());
+        };
+        let path = self.record.write_gibo(&dir)?;
+        std::fs::write(dir.join(GAME_RECORD_FILE), self.record.to_json()?)
+            .map_err(|err| orchestrator_error(format!("failed to write game record: {err}")))?;
+        info!("기보 저장: {:?}", path);
+        let report = write_match_report(match_telemetry, &self.record, &dir)?;
+        info!("매치 리포트 저장: {:?}", report);
+        Ok(())
+    }
+
+    /// Logs `board` as a diagram when `log_board` is set.
+    fn log_board(&self, board: &BoardState) {
+        if self.config.log_board {
+            info!("인식된 보드:\n{}", board.to_ascii());
+        }
+    }
+
+    fn log_differences(&self, source: &str, diffs: &[BoardDiff]) {
+        for diff in diffs {
+            let before = diff
+                .before
+                .map(|p| format!("{:?}_{:?}", p.owner, p.kind))
+                .unwrap_or_else(|| "None".into());
+            let after = diff
+                .after
+                .map(|p| format!("{:?}_{:?}", p.owner, p.kind))
+                .unwrap_or_else(|| "None".into());
+            info!("{source} 변화: {} {before} -> {after}", diff.square);
+        }
+    }
+}
+
+#[async_trait]
+pub trait MatchRunner {
+    async fn run(&mut self) -> Result<()>;
+}
+
+#[async_trait]
+impl<C, V, E, N> MatchRunner for Orchestrator<C, V, E, N>
+where
+    C: DeviceController + Send + Sync,
+    V: BoardRecognizer + Send + Sync,
+    E: GameEngine + Send + Sync,
+    N: RealtimeServer + Send + Sync,
+{
+    async fn run(&mut self) -> Result<()> {
+        let resumed = std::mem::take(&mut self.resumed);
+        let match_id = self
+            .match_id
+            .filter(|_| resumed)
+            .unwrap_or_else(Uuid::new_v4);
+        self.match_id = Some(match_id);
+        self.game_result = None;
+        self.awaiting_opponent = None;
+        self.last_published_board = None;
+        if !resumed {
+            self.our_side = self.config.our_side;
+            self.clocks = GameClocks::new(self.config.time_control.base_ms);
+            self.record = GameRecord::new(PLAYER_NAME, OPPONENT_NAME);
+            if let Some(side) = self.our_side {
+                self.record_formations(side);
+            }
+        }
+        self.opponent_clock_started = None;
+        self.low_time_warned = false;
+        self.hopeless_turns = 0;
+        self.move_rejections = 0;
+        self.step_pending = false;
+        self.forced_move = None;
+        self.match_telemetry = MatchTelemetry {
+            session_id: Some(self.session_id),
+            match_id: Some(match_id),
+            ..MatchTelemetry::default()
+        };
+        *self
+            .anomaly_budget
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = AnomalyBudget::default();
+        self.history.clear();
+        if let Some(seeded) = self.last_snapshot.clone() {
+            self.push_history(&seeded, None);
+        }
+        let start_event = SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::MatchStart,
+                details: Some("mock match started".into()),
+            }),
+        );
+        self.publish(start_event).await?;
+
+        // Turns are capped as well as plies so a match that stops producing
+        // moves still ends.
+        let max_plies = self.config.max_plies as usize;
+        let mut turn = 0;
+        let mut aborted = None;
+        loop {
+            self.handle_commands().await?;
+            if self.game_result.is_some() {
+                break;
+            }
+            if self.record.moves.len() >= max_plies || turn >= max_plies {
+                warn!(
+                    tags = "turn",
+                    "최대 수({max_plies}) 도달, 대국을 중단합니다"
+                );
+                break;
+            }
+            if self.paused && !self.step_pending {
+                self.set_state(OrchestratorState::Paused).await?;
+                self.publish_status_board().await?;
+                sleep(self.poll_interval).await;
+                continue;
+            }
+            self.step_pending = false;
+            info!("Executing turn {}", turn);
+            let played = self.play_turn_with_retries().await;
+            if let Some(budget) = self.exceeded_anomaly_budget() {
+                self.abort_on_anomalies(budget).await;
+                aborted = Some((
+                    turn,
+                    orchestrator_error(format!("anomaly budget exceeded (score {})", budget.score)),
+                ));
+                break;
+            }
+            if let Err(err) = played {
+                error!(tags = "turn", "턴 {turn} 실패, 대국을 중단합니다: {err}");
+                aborted = Some((turn, err));
+                break;
+            }
+            self.save_session_state();
+            turn += 1;
+        }
+
+        self.set_state(OrchestratorState::Finished).await?;
+        self.publish(SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::MatchResult(self.match_result()),
+        ))
+        .await?;
+        let controller_summary = self.controller.metrics().summary();
+        let details = match (&aborted, self.game_result) {
+            (Some(_), _) if self.exceeded_anomaly_budget().is_some() => {
+                format!("aborted: anomaly budget exceeded ({controller_summary})")
+            }
+            (Some((turn, err)), _) => {
+                format!(
+                    "match aborted: turn {turn} failed after retries: {err} ({controller_summary})"
+                )
+            }
+            (None, Some(result)) => format!("match finished: {result} ({controller_summary})"),
+            (None, None) => {
+                format!("match stopped: ply cap {max_plies} reached ({controller_summary})")
+            }
+        };
+        self.match_telemetry.controller_summary = Some(controller_summary);
+        self.match_telemetry.anomaly_budget = *self
+            .anomaly_budget
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        self.match_telemetry.history = self.history.iter().cloned().collect();
+        let end_event = SystemEvent::new(
+            EventKind::Lifecycle,
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::MatchEnd,
+                details: Some(details),
+            }),
+        );
+        self.publish(end_event).await?;
+        self.telemetry.flush().await?;
+        let match_telemetry = std::mem::take(&mut self.match_telemetry);
+        self.telemetry.record_match(match_telemetry.clone()).await?;
+        if let Some(result) = self.game_result {
+            self.record.set_result(result);
+        }
+        self.write_game_record(&match_telemetry)?;
+        // An aborted match can still be resumed after a restart.
+        if aborted.is_none() {
+            if let Some(dir) = self.telemetry.match_dir(None, None) {
+                SessionState::clear(&dir)?;
+            }
+        }
+        self.match_id = None;
+        match aborted {
+            Some((_, err)) => Err(err),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The opponent's move from this turn's diffs, cross-checked against the
+/// client's last-move highlight. When both highlighted squares changed, the
+/// highlight picks the move (it survives stray misread squares); otherwise a
+/// mismatch is flagged on the turn and the diff-inferred move is kept.
+fn opponent_move(
+    diffs: &[BoardDiff],
+    highlight: Option<(Square, Square)>,
+    anomalies: &mut Vec<String>,
+) -> Option<(Square, Square, Piece, Option<Piece>)> {
+    let inferred = BoardState::infer_move_from_diffs(diffs);
+    let Some((a, b)) = highlight else {
+        return inferred;
+    };
+    let diff_at = |sq: Square| diffs.iter().find(|diff| diff.square == sq);
+    if let (Some(da), Some(db)) = (diff_at(a), diff_at(b)) {
+        let oriented = match (da.after, db.after) {
+            (None, Some(_)) => Some((da, db)),
+            (Some(_), None) => Some((db, da)),
+            _ => None,
+        };
+        if let Some((from, to)) = oriented {
+            if let (Some(piece), Some(after)) = (from.before, to.after) {
+                if piece == after {
+                    debug!(
+                        "하이라이트로 상대 수 확인: {} -> {}",
+                        from.square, to.square
+                    );
+                    return Some((from.square, to.square, piece, to.before));
+                }
+            }
+        }
+    }
+    if let Some((from, to, _, _)) = inferred {
+        let message = format!("highlight {a} / {b} disagrees with inferred move {from} -> {to}");
+        warn!(tags = "board", "{message}; 차이 기반 수를 기록합니다");
+        anomalies.push(message);
+    }
+    inferred
+}
+
+fn format_squares(squares: &[Square]) -> String {
+    let squares: Vec<String> = squares.iter().map(Square::to_string).collect();
+    squares.join(" ")
+}
+
+/// Owner of a General standing in the bottom palace (ranks 0-2, files 3-5).
+fn bottom_palace_general(board: &BoardState) -> Option<PlayerSide> {
+    (0..3)
+        .flat_map(|rank| (3..6).map(move |file| Square::new(file, rank)))
+        .filter_map(|square| board.piece_at(square))
+        .find(|piece| piece.kind == PieceKind::General)
+        .map(|piece| piece.owner)
+}
+
+/// What `actions` would do, e.g. `DRY RUN: would tap (360,600) then
+/// (360,530)`; the verb is repeated only when it changes.
+fn dry_run_message(actions: &[InputAction]) -> String {
+    let mut message = String::from("DRY RUN: would");
+    let mut last_verb = None;
+    for (i, action) in actions.iter().enumerate() {
+        let (verb, detail) = match action {
+            InputAction::Tap { x, y } => ("tap", format!("({x},{y})")),
+            InputAction::Swipe {
+                start,
+                end,
+                duration_ms,
+            } => (
+                "swipe",
+                format!(
+                    "({},{})->({},{}) over {duration_ms}ms",
+                    start.0, start.1, end.0, end.1
+                ),
+            ),
+            InputAction::KeyEvent { code } => ("press key", code.to_string()),
+        };
+        if i > 0 {
+            message.push_str(" then");
+        }
+        if last_verb != Some(verb) {
+            message.push(' ');
+            message.push_str(verb);
+        }
+        message.push(' ');
+        message.push_str(&detail);
+        last_verb = Some(verb);
+    }
+    message
+}
+
+/// `snapshot` seen from the opposite seat (see [`BoardState::rotated`]).
+fn rotate_snapshot(mut snapshot: GameSnapshot) -> GameSnapshot {
+    snapshot.board = snapshot.board.rotated();
+    if let Some(mv) = snapshot.last_move.as_mut() {
+        mv.from = mv.from.rotated();
+        mv.to = mv.to.rotated();
+    }
+    for square in &mut snapshot.recognition.low_confidence {
+        *square = square.rotated();
+    }
+    snapshot
+}
+
+/// The starting position with `ours` on `our_side`'s back rank and, when
+/// known, `theirs` on the opponent's.
+fn formation_layout(
+    our_side: PlayerSide,
+    ours: FormationPreset,
+    theirs: Option<FormationPreset>,
+) -> BoardState {
+    let mut layout = BoardState::initial_for(ours, our_side);
+    if let Some(theirs) = theirs {
+        layout.place_formation(theirs, our_side.opponent());
+    }
+    layout
+}
+
+/// Squares where `board` differs from [`formation_layout`]. Without
+/// `theirs`, the opponent's horses and elephants may stand in either order.
+fn formation_mismatches(
+    board: &BoardState,
+    our_side: PlayerSide,
+    ours: FormationPreset,
+    theirs: Option<FormationPreset>,
+) -> Vec<Square> {
+    let expected = formation_layout(our_side, ours, theirs);
+    let their_rank = match our_side.opponent() {
+        PlayerSide::Blue => 0,
+        PlayerSide::Red => expected.height - 1,
+    };
+    expected
+        .differences(board)
+        .into_iter()
+        .map(|diff| diff.square)
+        .filter(|square| {
+            let their_minor = theirs.is_none()
+                && square.rank == their_rank
+                && FormationPreset::MINOR_FILES.contains(&square.file)
+                && board.piece_at(*square).is_some_and(|piece| {
+                    piece.owner == our_side.opponent()
+                        && matches!(piece.kind, PieceKind::Horse | PieceKind::Elephant)
+                });
+            !their_minor
+        })
+        .collect()
+}
+
+/// Restarts `cadence`'s fast polling when `observed` shows a different board
+/// than the previous poll, remembered in `last_board`.
+fn note_board_change(
+    cadence: &mut PollCadence,
+    last_board: &mut Option<u64>,
+    observed: &Observation,
+) {
+    let fingerprint = observed.snapshot.board.fingerprint();
+    if last_board.is_some_and(|last| last != fingerprint) {
+        cadence.reset(Instant::now());
+    }
+    *last_board = Some(fingerprint);
+}
+
+/// Logs how many engine moves were rejected for each reason this turn.
+fn log_rejected_moves(rejected: &[(Move, String)]) {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for (_, reason) in rejected {
+        *counts.entry(reason.as_str()).or_default() += 1;
+    }
+    for (reason, count) in counts {
+        debug!("엔진 후보 제외: {reason} {count}개");
+    }
+}
+
+/// Logs a failed [`TurnObserver`] callback; observers never fail the turn.
+fn observer_failed(hook: &str, result: Result<()>) {
+    if let Err(err) = result {
+        warn!(tags = "observer", "관찰자 {hook} 실패: {err}");
+    }
+}
+
+fn elapsed_ms(since: Instant) -> u64 {
+    since.elapsed().as_millis() as u64
+}
+
+pub fn orchestrator_error(message: impl Into<String>) -> MinervaError {
     MinervaError::Orchestrator(message.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use minerva_controller::{ControllerMetrics, InputAction, MockController};
+    use minerva_engine::RuleBasedEngine;
+    use minerva_network::LocalServer;
+    use minerva_types::{
+        board::{BoardState, Piece, PieceKind, PlayerSide, Square},
+        config::{
+            EmulatorConfig, PollSchedule, RecognizerBackend, RetryPolicy, ScreencapFormat,
+            TimingConfig, VisionConfig,
+        },
+        events::Severity,
+        game::{EngineDecision, WinReason},
+        ui::{BoardGeometry, Point, PopupTemplate},
+    };
+    use minerva_vision::TemplateMatchingRecognizer;
+
+    /// Recognizer that always reports the same board.
+    struct FixedRecognizer {
+        board: BoardState,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for FixedRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(self.board.clone())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let mut snapshot = hints.previous_snapshot.unwrap_or_default();
+            snapshot.board = self.board.clone();
+            Ok(snapshot)
+        }
+    }
+
+    /// Recognizer that reports the tracked board, so played moves carry over.
+    /// While Red is to move, Red answers with its first legal move after
+    /// `stalls` unchanged polls.
+    #[derive(Default)]
+    struct EchoRecognizer {
+        stalls: usize,
+        calls: AtomicUsize,
+        waiting: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for EchoRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let Some(mut snapshot) = hints.previous_snapshot else {
+                return Ok(GameSnapshot {
+                    board: BoardState::initial(),
+                    ..GameSnapshot::default()
+                });
+            };
+            if snapshot.board.side_to_move == PlayerSide::Red
+                && self.waiting.fetch_add(1, Ordering::SeqCst) >= self.stalls
+            {
+                self.waiting.store(0, Ordering::SeqCst);
+                if let Some(reply) = snapshot.board.legal_moves(PlayerSide::Red).first() {
+                    snapshot
+                        .apply_move(PlayerSide::Red, &reply.clone())
+                        .map_err(orchestrator_error)?;
+                }
+            }
+            Ok(snapshot)
+        }
+    }
+
+    /// Echo recognizer whose frames show the end-of-game screen from the
+    /// `after`th recognition on.
+    struct GameOverRecognizer {
+        echo: EchoRecognizer,
+        after: usize,
+        result: GameResult,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for GameOverRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.echo.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            self.echo.recognize(frame, hints).await
+        }
+
+        fn recognize_game_over(&self, _frame: &ImageFrame) -> Option<GameResult> {
+            (self.echo.calls.load(Ordering::SeqCst) >= self.after).then_some(self.result)
+        }
+    }
+
+    /// Echo recognizer that, like the template recognizer, reports pieces only:
+    /// the side to move is copied from the hint and ply/last move are unset.
+    #[derive(Default)]
+    struct PiecesOnlyRecognizer(EchoRecognizer);
+
+    #[async_trait]
+    impl BoardRecognizer for PiecesOnlyRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.0.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let side = hints
+                .previous_snapshot
+                .as_ref()
+                .map(|prev| prev.board.side_to_move);
+            let mut board = self.0.recognize(frame, hints).await?.board;
+            if let Some(side) = side {
+                board.side_to_move = side;
+            }
+            Ok(GameSnapshot {
+                board,
+                ..GameSnapshot::default()
+            })
+        }
+    }
+
+    /// Echo recognizer that always reports `side` to move.
+    struct MisreadSideRecognizer {
+        echo: EchoRecognizer,
+        side: PlayerSide,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for MisreadSideRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.echo.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let mut snapshot = self.echo.recognize(frame, hints).await?;
+            snapshot.board.side_to_move = self.side;
+            Ok(snapshot)
+        }
+    }
+
+    /// Rule-based engine that counts how often it is asked for a move, for
+    /// which side, and with what clock.
+    #[derive(Default)]
+    struct CountingEngine {
+        inner: RuleBasedEngine,
+        calls: AtomicUsize,
+        sides: Mutex<Vec<PlayerSide>>,
+        clocks: Mutex<Vec<(Option<u64>, Option<u64>)>>,
+    }
+
+    #[async_trait]
+    impl GameEngine for CountingEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.sides.lock().unwrap().push(ctx.side);
+            self.clocks
+                .lock()
+                .unwrap()
+                .push((ctx.remaining_ms, ctx.increment_ms));
+            self.inner.evaluate_position(ctx).await
+        }
+    }
+
+    /// Rule-based engine that takes `think` to decide; when cancelled it
+    /// offers `partial` as its best-so-far move.
+    struct SlowEngine {
+        inner: RuleBasedEngine,
+        think: Duration,
+        partial: Option<Move>,
+    }
+
+    #[async_trait]
+    impl GameEngine for SlowEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            sleep(self.think).await;
+            self.inner.evaluate_position(ctx).await
+        }
+
+        fn cancel(&self) -> Option<EngineDecision> {
+            self.partial.clone().map(|mv| EngineDecision {
+                best_move: Some(mv),
+                candidates: Vec::new(),
+                searched_nodes: 0,
+                depth: 1,
+                duration_ms: 0,
+                score: 0.0,
+                gain: 0.0,
+                result: None,
+                reject_reasons: Vec::new(),
+            })
+        }
+    }
+
+    /// Rule-based engine that pauses the match from inside its first call,
+    /// so the pause lands between turns 1 and 2.
+    struct PausingEngine {
+        inner: RuleBasedEngine,
+        control: OrchestratorControl,
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl GameEngine for PausingEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                self.control.pause()?;
+            }
+            self.inner.evaluate_position(ctx).await
+        }
+    }
+
+    /// Template recognizer whose board is replaced by the initial position,
+    /// keeping its recognition report (and saved frame path).
+    struct CapturingRecognizer(TemplateMatchingRecognizer);
+
+    #[async_trait]
+    impl BoardRecognizer for CapturingRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.0.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let mut snapshot = self.0.recognize(frame, hints).await?;
+            snapshot.board = BoardState::initial();
+            Ok(snapshot)
+        }
+    }
+
+    /// Engine that always proposes moving the opponent's chariot.
+    struct WrongSideEngine;
+
+    #[async_trait]
+    impl GameEngine for WrongSideEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            let red_chariot = Square::new(0, 9);
+            assert_ne!(
+                ctx.snapshot.board.piece_at(red_chariot).map(|p| p.owner),
+                Some(ctx.side)
+            );
+            Ok(EngineDecision {
+                best_move: Some(Move::new(red_chariot, Square::new(0, 8))),
+                candidates: Vec::new(),
+                searched_nodes: 0,
+                depth: 1,
+                duration_ms: 0,
+                score: 0.0,
+                gain: 0.0,
+                result: None,
+                reject_reasons: Vec::new(),
+            })
+        }
+    }
+
+    /// Engine that returns neither a move nor a result for its first
+    /// `empty_calls` calls, then plays like the rule-based engine.
+    #[derive(Default)]
+    struct EmptyEngine {
+        inner: RuleBasedEngine,
+        empty_calls: usize,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GameEngine for EmptyEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.empty_calls {
+                return Ok(EngineDecision {
+                    best_move: None,
+                    candidates: Vec::new(),
+                    searched_nodes: 0,
+                    depth: 1,
+                    duration_ms: 0,
+                    score: 0.0,
+                    gain: 0.0,
+                    result: None,
+                    reject_reasons: Vec::new(),
+                });
+            }
+            self.inner.evaluate_position(ctx).await
+        }
+    }
+
+    /// Rule-based engine whose score on call `n` is replaced by `scores[n]`.
+    #[derive(Default)]
+    struct ScriptedScoreEngine {
+        inner: RuleBasedEngine,
+        scores: Vec<f32>,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl GameEngine for ScriptedScoreEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut decision = self.inner.evaluate_position(ctx).await?;
+            decision.score = self.scores.get(call).copied().unwrap_or(0.0);
+            Ok(decision)
+        }
+    }
+
+    fn resign_taps() -> Vec<String> {
+        [ResignStep::Menu, ResignStep::Confirm]
+            .map(|step| format!("{:?}", resign_action(step)))
+            .to_vec()
+    }
+
+    /// Engine that answers with a hold move on Blue's General.
+    struct HoldEngine;
+
+    #[async_trait]
+    impl GameEngine for HoldEngine {
+        async fn warm_up(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn evaluate_position(&self, _ctx: &TurnContext) -> Result<EngineDecision> {
+            let general = Square::new(4, 1);
+            Ok(EngineDecision {
+                best_move: Some(Move::new(general, general)),
+                candidates: Vec::new(),
+                searched_nodes: 0,
+                depth: 1,
+                duration_ms: 0,
+                score: 0.0,
+                gain: 0.0,
+                result: None,
+                reject_reasons: Vec::new(),
+            })
+        }
+    }
+
+    /// Echo recognizer that knows where the board is, recording the origin
+    /// of every frame it aligns on or recognizes. The `misaligned_at`th
+    /// recognition comes back full of low-confidence squares.
+    struct RegionRecognizer {
+        echo: EchoRecognizer,
+        misaligned_at: usize,
+        aligned: Mutex<Vec<Point>>,
+        recognized: Mutex<Vec<Point>>,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for RegionRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.aligned.lock().unwrap().push(frame.origin);
+            self.echo.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let mut snapshot = self.echo.recognize(frame, hints).await?;
+            let mut recognized = self.recognized.lock().unwrap();
+            snapshot.recognition.low_confidence = if recognized.len() == self.misaligned_at {
+                (0..10).map(|file| Square::new(file % 9, 3)).collect()
+            } else {
+                Vec::new()
+            };
+            recognized.push(frame.origin);
+            Ok(snapshot)
+        }
+
+        fn needs_realignment(&self, snapshot: &GameSnapshot) -> bool {
+            snapshot.recognition.low_confidence.len() > 9
+        }
+
+        fn board_geometry(&self) -> Option<BoardGeometry> {
+            Some(BoardGeometry::default())
+        }
+    }
+
+    /// Recognizer whose first result is full of low-confidence squares.
+    #[derive(Default)]
+    struct MisalignedOnceRecognizer {
+        recognize_calls: AtomicUsize,
+        align_calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for MisalignedOnceRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            self.align_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let mut snapshot = hints.previous_snapshot.unwrap_or_default();
+            if self.recognize_calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                snapshot.recognition.low_confidence =
+                    (0..10).map(|file| Square::new(file % 9, 3)).collect();
+            } else {
+                snapshot.recognition.low_confidence.clear();
+            }
+            Ok(snapshot)
+        }
+
+        fn needs_realignment(&self, snapshot: &GameSnapshot) -> bool {
+            snapshot.recognition.low_confidence.len() > 9
+        }
+    }
+
+    /// Recognizer that reports `boards` in order, repeating the last one,
+    /// each after `delay`.
+    struct ScriptedBoardRecognizer {
+        boards: Mutex<Vec<BoardState>>,
+        delay: Duration,
+    }
+
+    impl ScriptedBoardRecognizer {
+        fn new(boards: Vec<BoardState>) -> Self {
+            Self {
+                boards: Mutex::new(boards),
+                delay: Duration::ZERO,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for ScriptedBoardRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            sleep(self.delay).await;
+            let board = {
+                let mut boards = self.boards.lock().unwrap();
+                if boards.len() > 1 {
+                    boards.remove(0)
+                } else {
+                    boards[0].clone()
+                }
+            };
+            let mut snapshot = hints.previous_snapshot.unwrap_or_default();
+            snapshot.board = board;
+            Ok(snapshot)
+        }
+    }
+
+    /// Mock controller whose first `failures` captures fail.
+    struct FlakyController {
+        inner: MockController,
+        failures: usize,
+        captures: AtomicUsize,
+        taps_fail: bool,
+        /// Captures after the failures that return a frame taken a minute ago.
+        stale_frames: usize,
+        capture_delay: Duration,
+    }
+
+    impl FlakyController {
+        fn new(failures: usize) -> Self {
+            Self {
+                inner: mock_controller(),
+                failures,
+                captures: AtomicUsize::new(0),
+                taps_fail: false,
+                stale_frames: 0,
+                capture_delay: Duration::ZERO,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl DeviceController for FlakyController {
+        async fn connect(&mut self) -> Result<()> {
+            self.inner.connect().await
+        }
+
+        async fn capture_frame(&self) -> Result<ImageFrame> {
+            sleep(self.capture_delay).await;
+            let capture = self.captures.fetch_add(1, Ordering::SeqCst);
+            if capture < self.failures {
+                return Err(controller_error("screencap failed"));
+            }
+            let mut frame = self.inner.capture_frame().await?;
+            if capture - self.failures < self.stale_frames {
+                frame.captured_at -= chrono::Duration::minutes(1);
+            }
+            Ok(frame)
+        }
+
+        async fn tap_square(&self, square: Square) -> Result<()> {
+            if self.taps_fail {
+                return Err(controller_error("input tap failed"));
+            }
+            self.inner.tap_square(square).await
+        }
+
+        async fn tap_point(&self, point: Point) -> Result<()> {
+            self.inner.tap_point(point).await
+        }
+
+        async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()> {
+            self.inner.inject_actions(actions).await
+        }
+
+        fn metrics(&self) -> ControllerMetrics {
+            self.inner.metrics()
+        }
+    }
+
+    /// Controller that walks the setup screens: each capture shows the
+    /// current screen, and the tap that leads off it moves to the next one.
+    /// The first `stuck` such taps on screen `stuck_at` are ignored, and the
+    /// formation picker stays hidden for `opponent_picking` captures.
+    struct SetupFlowController {
+        inner: MockController,
+        advancing_taps: Vec<InputAction>,
+        position: AtomicUsize,
+        stuck_at: usize,
+        stuck: AtomicUsize,
+        opponent_picking: AtomicUsize,
+    }
+
+    impl SetupFlowController {
+        /// Screens in order; the last tap leaves them for the board.
+        const SCREENS: [StartScreen; 4] = [
+            StartScreen::Lobby,
+            StartScreen::ConfirmDialog,
+            StartScreen::NoticeDialog,
+            StartScreen::FormationPicker,
+        ];
+
+        fn new(stuck_at: usize, stuck: usize) -> Self {
+            Self {
+                inner: mock_controller(),
+                advancing_taps: vec![
+                    start_flow_action(StartFlowStep::Apply),
+                    start_flow_action(StartFlowStep::ConfirmYes),
+                    start_flow_action(StartFlowStep::ConfirmOk),
+                    formation_confirm_action(),
+                ],
+                position: AtomicUsize::new(0),
+                stuck_at,
+                stuck: AtomicUsize::new(stuck),
+                opponent_picking: AtomicUsize::new(0),
+            }
+        }
+
+        /// Shows an unknown screen for `captures` captures while the
+        /// opponent picks its formation first.
+        fn picking_second(self, captures: usize) -> Self {
+            self.opponent_picking.store(captures, Ordering::SeqCst);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl DeviceController for SetupFlowController {
+        async fn connect(&mut self) -> Result<()> {
+            self.inner.connect().await
+        }
+
+        /// The screen index is painted into the only pixel; past the last
+        /// screen it reads 255.
+        async fn capture_frame(&self) -> Result<ImageFrame> {
+            let position = self.position.load(Ordering::SeqCst);
+            let picker = Self::SCREENS.get(position) == Some(&StartScreen::FormationPicker);
+            if picker && self.opponent_picking.load(Ordering::SeqCst) > 0 {
+                self.opponent_picking.fetch_sub(1, Ordering::SeqCst);
+                return Ok(ImageFrame::from_rgba(1, 1, vec![254, 0, 0, 255]));
+            }
+            let code = Self::SCREENS.get(position).map_or(255, |_| position as u8);
+            Ok(ImageFrame::from_rgba(1, 1, vec![code, 0, 0, 255]))
+        }
+
+        async fn tap_square(&self, square: Square) -> Result<()> {
+            self.inner.tap_square(square).await
+        }
+
+        async fn tap_point(&self, point: Point) -> Result<()> {
+            self.inner.tap_point(point).await
+        }
+
+        async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()> {
+            for action in &actions {
+                let position = self.position.load(Ordering::SeqCst);
+                let advances = self
+                    .advancing_taps
+                    .get(position)
+                    .is_some_and(|tap| format!("{tap:?}") == format!("{action:?}"));
+                if !advances {
+                    continue;
+                }
+                if position == self.stuck_at && self.stuck.load(Ordering::SeqCst) > 0 {
+                    self.stuck.fetch_sub(1, Ordering::SeqCst);
+                } else {
+                    self.position.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+            self.inner.inject_actions(actions).await
+        }
+
+        fn metrics(&self) -> ControllerMetrics {
+            self.inner.metrics()
+        }
+    }
+
+    /// Reads the screen [`SetupFlowController`] painted into its frames.
+    #[derive(Default)]
+    struct SetupScreenRecognizer;
+
+    #[async_trait]
+    impl BoardRecognizer for SetupScreenRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            _hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            Ok(GameSnapshot::default())
+        }
+
+        fn recognize_screen(&self, frame: &ImageFrame) -> Option<StartScreen> {
+            SetupFlowController::SCREENS
+                .get(usize::from(*frame.data.first()?))
+                .copied()
+        }
+
+        fn recognizes_screens(&self) -> bool {
+            true
+        }
+    }
+
+    fn mock_controller() -> MockController {
+        MockController::new(mock_controller_config())
+    }
+
+    fn mock_controller_config() -> EmulatorConfig {
+        EmulatorConfig {
+            serial: "mock".into(),
+            socket: "mock".into(),
+            fixed_resolution: None,
+            adb_path: None,
+            tap_offset: (0, 0),
+            geometry: BoardGeometry::default(),
+            screencap_format: ScreencapFormat::Png,
+            retry: RetryPolicy::default(),
+            timing: TimingConfig {
+                inter_action_ms: 5,
+                ..TimingConfig::default()
+            },
+            batch_inputs: true,
+            tap_jitter_px: None,
+        }
+    }
+
+    /// Details of the first `MatchEnd` lifecycle event.
+    fn match_end_details(events: &[SystemEvent]) -> String {
+        events
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Lifecycle(LifecycleEvent {
+                    phase: LifecyclePhase::MatchEnd,
+                    details,
+                }) => details.clone(),
+                _ => None,
+            })
+            .expect("match end event")
+    }
+
+    /// Echo recognizers read the board from the tracked snapshot, so a
+    /// post-move look would always show the move ignored; the scenario
+    /// settings leave it off.
+    fn orchestrator_config(max_plies: u32) -> OrchestratorConfig {
+        crate::test_support::scenario_config(max_plies)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn instant_decisions_wait_for_the_minimum_think_time() {
+        let mut config = orchestrator_config(2);
+        config.min_move_delay_ms = 1_500;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        let latency = &orchestrator.match_telemetry.turns[0].latency;
+        let thinking_to_tap = latency.total_ms - latency.observation_ms;
+        assert!(thinking_to_tap >= 1_500, "{latency:?}");
+        assert!(thinking_to_tap < 1_700, "{latency:?}");
+
+        // A range draws each think time from it.
+        orchestrator.config.min_move_delay_ms = 400;
+        orchestrator.config.max_move_delay_ms = Some(600);
+        for _ in 0..50 {
+            let delay = orchestrator.move_delay().as_millis();
+            assert!((400..=600).contains(&delay), "{delay}");
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn dry_run_reports_taps_without_sending_them() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(2);
+        config.dry_run = true;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator
+            .perform_start_sequence(FormationPreset::default())
+            .await
+            .unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 1);
+        let messages: Vec<_> = telemetry
+            .snapshot_events()
+            .await
+            .into_iter()
+            .filter_map(|e| match e.payload {
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "dry_run") => {
+                    Some(ops.message)
+                }
+                _ => None,
+            })
+            .collect();
+        let point = |action: InputAction| match action {
+            InputAction::Tap { x, y } => format!("({x},{y})"),
+            other => panic!("not a tap: {other:?}"),
+        };
+        assert_eq!(
+            messages[0],
+            format!(
+                "DRY RUN: would tap {} then {} then {}",
+                point(start_flow_action(StartFlowStep::Apply)),
+                point(start_flow_action(StartFlowStep::ConfirmYes)),
+                point(start_flow_action(StartFlowStep::ConfirmOk)),
+            )
+        );
+        let mv = &orchestrator.game_record().moves[0].mv;
+        let geometry = BoardGeometry::default();
+        let [from, to] = [mv.from, mv.to].map(|square| geometry.square_to_point(square).unwrap());
+        assert_eq!(
+            messages.last().unwrap(),
+            &format!(
+                "DRY RUN: would tap ({},{}) then ({},{})",
+                from.x, from.y, to.x, to.y
+            )
+        );
+        assert_eq!(messages.len(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn engine_runs_only_after_the_opponent_moves() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(2),
+            mock_controller(),
+            EchoRecognizer {
+                stalls: 2,
+                ..EchoRecognizer::default()
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 1);
+
+        orchestrator.play_turn().await.unwrap();
+        // Unchanged board twice, then the reply on the third poll.
+        assert_eq!(orchestrator.recognizer.calls.load(Ordering::SeqCst), 1 + 3);
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 2);
+        let sides: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|m| m.side)
+            .collect();
+        assert_eq!(sides, [PlayerSide::Blue, PlayerSide::Red, PlayerSide::Blue]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn silent_opponent_times_out() {
+        let mut config = orchestrator_config(2);
+        config.opponent_timeout_secs = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer {
+                stalls: usize::MAX,
+                ..EchoRecognizer::default()
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        let err = orchestrator.play_turn().await.unwrap_err();
+        assert!(matches!(err, MinervaError::Orchestrator(_)), "{err}");
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn quiet_plies_past_the_limit_are_adjudicated_a_draw() {
+        let mut config = orchestrator_config(4);
+        config.draw_after_halfmoves = Some(2);
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(
+            orchestrator.game_result(),
+            Some(GameResult::Draw {
+                reason: DrawReason::NoCapture,
+            })
+        );
+        // Our move and the reply reach the limit; no further move is played.
+        assert_eq!(orchestrator.game_record().moves.len(), 2);
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            orchestrator.last_snapshot.as_ref().unwrap().halfmove_clock,
+            2
+        );
+    }
+
+    /// Recognizer that reads an empty board for the first `transient` calls,
+    /// as if the pieces were still animating, then the initial position.
+    /// With `flicker` set it alternates between the two forever.
+    #[derive(Default)]
+    struct AnimatingRecognizer {
+        transient: usize,
+        flicker: bool,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for AnimatingRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            _hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            let board = if call < self.transient || (self.flicker && call.is_multiple_of(2)) {
+                BoardState::empty()
+            } else {
+                BoardState::initial()
+            };
+            Ok(GameSnapshot {
+                board,
+                ..GameSnapshot::default()
+            })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stabilization_waits_for_consecutive_recognitions_to_agree() {
+        let mut config = orchestrator_config(2);
+        config.max_retries = 3;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            AnimatingRecognizer {
+                transient: 1,
+                ..AnimatingRecognizer::default()
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator.stable_captures = 2;
+        orchestrator.stable_capture_delay = Duration::from_millis(80);
+
+        orchestrator.play_turn().await.unwrap();
+
+        // Transient read, first stable read, then the one agreeing with it.
+        assert_eq!(orchestrator.recognizer.calls.load(Ordering::SeqCst), 3);
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 1);
+        assert!(orchestrator.match_telemetry.turns[0].anomalies.is_empty());
+
+        // A board that never settles is used after the retry budget, noted.
+        orchestrator.recognizer.flicker = true;
+        orchestrator.recognizer.calls.store(0, Ordering::SeqCst);
+        let mut anomalies = Vec::new();
+        orchestrator
+            .capture_and_recognize(&mut anomalies)
+            .await
+            .unwrap();
+        assert_eq!(
+            orchestrator.recognizer.calls.load(Ordering::SeqCst),
+            1 + 3 + 1
+        );
+        assert!(anomalies[0].contains("did not stabilize"), "{anomalies:?}");
+    }
+
+    #[tokio::test]
+    async fn low_confidence_recognition_triggers_realignment() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(1),
+            mock_controller(),
+            MisalignedOnceRecognizer::default(),
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(
+            orchestrator.recognizer.align_calls.load(Ordering::SeqCst),
+            1
+        );
+        assert_eq!(
+            orchestrator
+                .recognizer
+                .recognize_calls
+                .load(Ordering::SeqCst),
+            2
+        );
+        let tracked = orchestrator.last_snapshot.as_ref().unwrap();
+        assert!(tracked.recognition.low_confidence.is_empty());
+    }
+
+    #[tokio::test]
+    async fn board_region_is_learned_from_alignment_and_relearned_when_lost() {
+        let controller =
+            mock_controller().with_frame(ImageFrame::from_rgba(720, 1280, vec![0; 720 * 1280 * 4]));
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            controller,
+            RegionRecognizer {
+                echo: EchoRecognizer::default(),
+                misaligned_at: 2,
+                aligned: Mutex::new(Vec::new()),
+                recognized: Mutex::new(Vec::new()),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator.capture_margin = Some(8);
+        let region = BoardGeometry::default().bounds(8);
+        let board = Point::new(region.x, region.y);
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let screen = Point::default();
+        // Aligned on the whole screen first, and again once the board was lost.
+        assert_eq!(
+            *orchestrator.recognizer.aligned.lock().unwrap(),
+            [screen, screen]
+        );
+        assert_eq!(
+            *orchestrator.recognizer.recognized.lock().unwrap(),
+            [screen, board, board, board]
+        );
+        assert_eq!(orchestrator.capture_region(), Some(region));
+    }
+
+    #[tokio::test]
+    async fn events_carry_session_and_per_match_ids() {
+        let dir = std::env::temp_dir().join(format!("minerva-session-{}", Uuid::new_v4()));
+        let telemetry = TelemetryStore::with_persistence(&dir);
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(1),
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+        orchestrator.run().await.unwrap();
+
+        let events = telemetry.snapshot_events().await;
+        assert!(!events.is_empty());
+        assert!(events
+            .iter()
+            .all(|e| e.session_id == Some(orchestrator.session_id())));
+        let mut match_ids: Vec<Uuid> = events.iter().filter_map(|e| e.match_id).collect();
+        assert_eq!(match_ids.len(), events.len());
+        match_ids.dedup();
+        assert_eq!(match_ids.len(), 2);
+        assert!(orchestrator.match_id().is_none());
+
+        for match_id in &match_ids {
+            let match_dir = dir
+                .join(format!("session_{}", orchestrator.session_id()))
+                .join(format!("match_{match_id}"));
+            assert!(match_dir.join(minerva_ops::EVENT_LOG_FILE).is_file());
+            assert!(match_dir.join(minerva_ops::MATCH_FILE).is_file());
+            assert!(match_dir.join(GAME_RECORD_FILE).is_file());
+            assert!(match_dir.join(minerva_ops::REPORT_FILE).is_file());
+            let gibo_files = std::fs::read_dir(&match_dir)
+                .unwrap()
+                .flatten()
+                .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "gib"))
+                .count();
+            assert_eq!(gibo_files, 1);
+        }
+        // Finished matches are not resumed.
+        assert!(!dir.join(minerva_ops::SESSION_STATE_FILE).exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn restart_resumes_the_saved_match_without_the_start_flow() {
+        let dir = std::env::temp_dir().join(format!("minerva-resume-{}", Uuid::new_v4()));
+        let mut snapshot = GameSnapshot {
+            board: BoardState::initial(),
+            ..GameSnapshot::default()
+        };
+        let mut record = GameRecord::new(PLAYER_NAME, OPPONENT_NAME);
+        for (side, from, to) in [
+            (PlayerSide::Blue, Square::new(0, 3), Square::new(0, 4)),
+            (PlayerSide::Red, Square::new(0, 6), Square::new(0, 5)),
+        ] {
+            let mv = Move::new(from, to);
+            snapshot.apply_move(side, &mv).unwrap();
+            record.push_move(side, mv, Some(PieceKind::Soldier), None);
+        }
+        let state = SessionState {
+            session_id: Uuid::new_v4(),
+            match_id: Some(Uuid::new_v4()),
+            our_side: Some(PlayerSide::Blue),
+            snapshot: snapshot.clone(),
+            record,
+            saved_at: Utc::now(),
+        };
+        state.save(&dir).unwrap();
+        let restarted = |board: BoardState| {
+            Orchestrator::new(
+                orchestrator_config(2),
+                mock_controller(),
+                FixedRecognizer { board },
+                CountingEngine::default(),
+                LocalServer::new(16),
+                TelemetryStore::with_persistence(&dir),
+            )
+        };
+
+        let mut orchestrator = restarted(snapshot.board.clone());
+        orchestrator.prepare_match().await.unwrap();
+
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        assert_eq!(orchestrator.session_id(), state.session_id);
+        let resumed = orchestrator.last_snapshot.as_ref().unwrap();
+        assert_eq!(resumed.ply, 2);
+        assert!(resumed.board.differences(&snapshot.board).is_empty());
+        // The ply cap is already reached: the match ends where it stood,
+        // under its saved ids and with its saved moves.
+        orchestrator.run().await.unwrap();
+        let match_dir = dir
+            .join(format!("session_{}", state.session_id))
+            .join(format!("match_{}", state.match_id.unwrap()));
+        assert!(match_dir.join(GAME_RECORD_FILE).is_file());
+        assert_eq!(orchestrator.game_record().moves.len(), 2);
+        assert!(!dir.join(minerva_ops::SESSION_STATE_FILE).exists());
+
+        // A board that does not match starts a new match.
+        state.save(&dir).unwrap();
+        let mut orchestrator = restarted(BoardState::initial());
+        orchestrator.prepare_match().await.unwrap();
+        assert!(!orchestrator.controller.recorded_actions().is_empty());
+        assert_ne!(orchestrator.session_id(), state.session_id);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn turn_events_share_a_turn_id() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(2),
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        // State changes belong to the orchestrator, not to a turn.
+        let events: Vec<_> = telemetry
+            .snapshot_events()
+            .await
+            .into_iter()
+            .filter(|e| !matches!(e.payload, EventPayload::State(_)))
+            .collect();
+        assert_eq!(events.len(), 8);
+        assert!(events.iter().all(|e| e.turn_id().is_some()));
+        for turn in events.chunks(4) {
+            let kinds: Vec<_> = turn.iter().map(|e| e.kind.clone()).collect();
+            assert_eq!(
+                kinds,
+                [
+                    EventKind::BoardUpdate,
+                    EventKind::BoardUpdate,
+                    EventKind::EngineDecision,
+                    EventKind::Telemetry
+                ]
+            );
+            assert!(turn.iter().all(|e| e.turn_id() == turn[0].turn_id()));
+        }
+        assert_ne!(events[0].turn_id(), events[4].turn_id());
+    }
+
+    fn state_changes(events: &[SystemEvent]) -> Vec<StateChangeEvent> {
+        events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::State(change) => Some(*change),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn match_walks_through_the_expected_states() {
+        use OrchestratorState::*;
+        let telemetry = TelemetryStore::new();
+        let status = StatusHandle::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(4),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        orchestrator.attach_status(status.clone());
+        assert_eq!(orchestrator.state(), Booting);
+
+        orchestrator.run().await.unwrap();
+
+        let changes = state_changes(&telemetry.snapshot_events().await);
+        let states: Vec<_> = changes.iter().map(|change| change.to).collect();
+        assert_eq!(
+            states,
+            [
+                Observing,
+                Thinking,
+                Moving,
+                WaitingForOpponent,
+                Thinking,
+                Moving,
+                WaitingForOpponent,
+                Thinking,
+                Moving,
+                Finished,
+            ]
+        );
+        assert_eq!(changes[0].from, Booting);
+        assert!(changes.windows(2).all(|pair| pair[0].to == pair[1].from));
+        assert_eq!(orchestrator.state(), Finished);
+        assert_eq!(status.report().state, Some(Finished));
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "invalid orchestrator transition Thinking -> Booting")]
+    async fn invalid_transitions_are_caught_in_debug_builds() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(4),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator
+            .set_state(OrchestratorState::Observing)
+            .await
+            .unwrap();
+        orchestrator
+            .set_state(OrchestratorState::Thinking)
+            .await
+            .unwrap();
+        let _ = orchestrator.set_state(OrchestratorState::Booting).await;
+    }
+
+    fn board_events(events: &[SystemEvent]) -> Vec<&BoardEvent> {
+        events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::Board(board) if e.kind == EventKind::BoardUpdate => Some(board),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Counts each callback; `on_decision` always fails.
+    #[derive(Default, Clone)]
+    struct CountingObserver {
+        counts: Arc<Mutex<BTreeMap<&'static str, usize>>>,
+    }
+
+    impl CountingObserver {
+        fn bump(&self, hook: &'static str) {
+            *self.counts.lock().unwrap().entry(hook).or_default() += 1;
+        }
+
+        fn count(&self, hook: &str) -> usize {
+            self.counts
+                .lock()
+                .unwrap()
+                .get(hook)
+                .copied()
+                .unwrap_or_default()
+        }
+    }
+
+    #[async_trait]
+    impl TurnObserver for CountingObserver {
+        async fn on_frame_captured(&self, _frame: &ImageFrame) -> Result<()> {
+            self.bump("frame");
+            Ok(())
+        }
+
+        async fn on_board_recognized(&self, _snapshot: &GameSnapshot) -> Result<()> {
+            self.bump("board");
+            Ok(())
+        }
+
+        async fn on_decision(&self, _decision: &EngineDecision) -> Result<()> {
+            self.bump("decision");
+            Err(orchestrator_error("observer failure"))
+        }
+
+        async fn on_move_applied(&self, _mv: &Move) -> Result<()> {
+            self.bump("move");
+            Ok(())
+        }
+
+        async fn on_turn_complete(&self, _turn: &TurnRecord) -> Result<()> {
+            self.bump("turn");
+            Ok(())
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn observers_see_every_stage_and_cannot_fail_the_turn() {
+        let observer = CountingObserver::default();
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::builder()
+            .config(orchestrator_config(10))
+            .controller(mock_controller())
+            .recognizer(EchoRecognizer::default())
+            .engine(CountingEngine::default())
+            .network(LocalServer::new(16))
+            .telemetry(telemetry.clone())
+            .observer(Box::new(observer.clone()))
+            .build()
+            .unwrap();
+        orchestrator.add_observer(Box::new(TracingObserver));
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(
+            observer.count("frame"),
+            orchestrator.recognizer.calls.load(Ordering::SeqCst)
+        );
+        assert_eq!(observer.count("board"), 2);
+        assert_eq!(observer.count("decision"), 2);
+        assert_eq!(observer.count("move"), 2);
+        assert_eq!(observer.count("turn"), 2);
+        assert_eq!(orchestrator.match_telemetry.turns.len(), 2);
+        let failures = telemetry
+            .snapshot_events()
+            .await
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    &e.payload,
+                    EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "observer")
+                )
+            })
+            .count();
+        assert_eq!(failures, 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn engine_is_told_our_clock_in_timed_games() {
+        /// The clock the engine saw on our first two turns, and our clock
+        /// between them.
+        async fn engine_clocks(base_ms: u64) -> (Vec<(Option<u64>, Option<u64>)>, u64) {
+            let mut config = orchestrator_config(10);
+            config.time_control.base_ms = base_ms;
+            config.time_control.increment_ms = 1_000;
+            let mut orchestrator = Orchestrator::new(
+                config,
+                mock_controller(),
+                EchoRecognizer::default(),
+                CountingEngine::default(),
+                LocalServer::new(16),
+                TelemetryStore::new(),
+            );
+            orchestrator.play_turn().await.unwrap();
+            let between = orchestrator.clocks().blue_ms;
+            orchestrator.play_turn().await.unwrap();
+            let clocks = orchestrator.engine.clocks.lock().unwrap().clone();
+            (clocks, between)
+        }
+
+        let (timed, between) = engine_clocks(60_000).await;
+        assert_eq!(
+            timed,
+            [(Some(60_000), Some(1_000)), (Some(between), Some(1_000))]
+        );
+        assert_ne!(between, 60_000);
+        let (untimed, _) = engine_clocks(0).await;
+        assert_eq!(untimed, [(None, None), (None, None)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clocks_are_charged_per_move_with_increment() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(10);
+        config.time_control.base_ms = 60_000;
+        config.time_control.increment_ms = 1_000;
+        config.low_time_warning_ms = Some(58_000);
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer {
+                stalls: 1,
+                ..EchoRecognizer::default()
+            },
+            SlowEngine {
+                inner: RuleBasedEngine::new(),
+                think: Duration::from_millis(2_000),
+                partial: None,
+            },
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        // Our move costs thinking plus two 5ms taps 30ms apart; the opponent
+        // replies on the second 25ms capture, one 500ms poll interval later.
+        let ours = 2_000 + 40;
+        let theirs = 25 + 500;
+        orchestrator.play_turn().await.unwrap();
+        assert_eq!(
+            orchestrator.clocks(),
+            GameClocks {
+                blue_ms: 60_000 - ours + 1_000,
+                red_ms: 60_000,
+            }
+        );
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+        let expected = GameClocks {
+            blue_ms: 60_000 - 3 * ours + 3 * 1_000,
+            red_ms: 60_000 - 2 * theirs + 2 * 1_000,
+        };
+        assert_eq!(orchestrator.clocks(), expected);
+
+        let events = telemetry.snapshot_events().await;
+        let boards = board_events(&events);
+        assert_eq!(boards.last().unwrap().snapshot.clocks, expected);
+        let warnings = events
+            .iter()
+            .filter(|e| {
+                matches!(&e.payload, EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "clock"))
+            })
+            .count();
+        assert_eq!(warnings, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hung_engine_is_cut_off_at_the_turn_deadline() {
+        let soldier_push = Move::new(Square::new(0, 3), Square::new(0, 4));
+        for partial in [None, Some(soldier_push.clone())] {
+            let telemetry = TelemetryStore::new();
+            let mut config = orchestrator_config(10);
+            config.turn_deadline_ms = 1_000;
+            let mut orchestrator = Orchestrator::new(
+                config,
+                mock_controller(),
+                EchoRecognizer::default(),
+                SlowEngine {
+                    inner: RuleBasedEngine::new(),
+                    think: Duration::from_secs(3_600),
+                    partial: partial.clone(),
+                },
+                LocalServer::new(16),
+                telemetry.clone(),
+            );
+            let sink = OpsEventSink::default();
+            orchestrator.attach_ops_events(&sink);
+            let _tracing = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+            );
+
+            let started = Instant::now();
+            orchestrator.play_turn().await.unwrap();
+
+            assert!(started.elapsed() < Duration::from_secs(2));
+            assert!(!orchestrator.controller.recorded_actions().is_empty());
+            let moves = &orchestrator.game_record().moves;
+            assert_eq!(moves.len(), 1);
+            if let Some(partial) = &partial {
+                assert_eq!(
+                    (moves[0].mv.from, moves[0].mv.to),
+                    (partial.from, partial.to)
+                );
+            }
+            let turn = &orchestrator.match_telemetry.turns[0];
+            assert!(turn.anomalies.iter().any(|a| a.contains("deadline")));
+            let events = telemetry.snapshot_events().await;
+            assert!(events.iter().any(|e| matches!(
+                &e.payload,
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "engine")
+            )));
+        }
+    }
+
+    #[test]
+    fn builder_reports_missing_parts() {
+        let missing = |builder: OrchestratorBuilder<
+            MockController,
+            EchoRecognizer,
+            CountingEngine,
+            LocalServer,
+        >| builder.build().err().unwrap().to_string();
+        let complete = || {
+            Orchestrator::builder()
+                .config(orchestrator_config(10))
+                .recognizer(EchoRecognizer::default())
+                .engine(CountingEngine::default())
+                .network(LocalServer::new(16))
+                .telemetry(TelemetryStore::new())
+        };
+
+        assert!(missing(complete()).contains("missing the controller"));
+        assert!(missing(OrchestratorBuilder::new()).contains("missing the config"));
+        assert!(complete().controller(mock_controller()).build().is_ok());
+    }
+
+    #[tokio::test]
+    async fn built_orchestrator_plays_like_one_from_new() {
+        let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut built = Orchestrator::builder()
+            .config(orchestrator_config(10))
+            .controller(mock_controller())
+            .recognizer(EchoRecognizer::default())
+            .engine(CountingEngine::default())
+            .network(LocalServer::new(16))
+            .telemetry(TelemetryStore::new())
+            .commands(receiver)
+            .build()
+            .unwrap();
+        let mut positional = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        built.play_turn().await.unwrap();
+        positional.play_turn().await.unwrap();
+
+        assert!(built.commands.is_some());
+        assert_eq!(
+            format!("{:?}", built.controller.recorded_actions()),
+            format!("{:?}", positional.controller.recorded_actions())
+        );
+        assert_eq!(
+            format!("{:?}", built.game_record().moves),
+            format!("{:?}", positional.game_record().moves)
+        );
+        drop(commands);
+    }
+
+    #[tokio::test]
+    async fn status_handle_follows_published_events() {
+        let status = StatusHandle::new();
+        let mut orchestrator = Orchestrator::builder()
+            .config(orchestrator_config(10))
+            .controller(mock_controller())
+            .recognizer(EchoRecognizer::default())
+            .engine(CountingEngine::default())
+            .network(LocalServer::new(16))
+            .telemetry(TelemetryStore::new())
+            .status_handle(status.clone())
+            .build()
+            .unwrap();
+
+        orchestrator.play_turn().await.unwrap();
+
+        let report = status.report();
+        assert_eq!(report.ply, Some(1));
+        assert_eq!(report.engine_depth, Some(1));
+        assert_eq!(
+            report.controller,
+            Some(orchestrator.controller.metrics().summary())
+        );
+        assert!(report.updated_at.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn configured_input_delays_are_honored() {
+        let timing = TimingConfig {
+            tap_gap_ms: 200,
+            post_move_settle_ms: 120,
+            start_flow_step_ms: 150,
+            inter_action_ms: 7,
+        };
+        let mut emulator = mock_controller_config();
+        emulator.timing = timing;
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            MockController::new(emulator),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator.timing = timing;
+
+        let started = Instant::now();
+        orchestrator
+            .apply_move(&Move::new(Square::new(0, 3), Square::new(0, 4)))
+            .await
+            .unwrap();
+        assert_eq!(started.elapsed(), Duration::from_millis(7 + 200 + 7 + 120));
+
+        let started = Instant::now();
+        orchestrator
+            .perform_start_sequence(FormationPreset::default())
+            .await
+            .unwrap();
+        // Three start-flow taps and two formation taps, each step then settling.
+        assert_eq!(
+            started.elapsed(),
+            Duration::from_millis(3 * 7 + 150 + 2 * 7 + 150)
+        );
+    }
+
+    fn setup_orchestrator(
+        controller: SetupFlowController,
+    ) -> Orchestrator<SetupFlowController, SetupScreenRecognizer, CountingEngine, LocalServer> {
+        Orchestrator::new(
+            orchestrator_config(10),
+            controller,
+            SetupScreenRecognizer,
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_sequence_waits_for_each_screen_and_retries_a_stuck_tap() {
+        // The confirmation dialog ignores the first Yes.
+        let mut orchestrator = setup_orchestrator(SetupFlowController::new(1, 1));
+
+        orchestrator
+            .perform_start_sequence(FormationPreset::default())
+            .await
+            .unwrap();
+
+        let taps: Vec<_> = orchestrator
+            .controller
+            .inner
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        let expected: Vec<_> = [
+            start_flow_action(StartFlowStep::Apply),
+            start_flow_action(StartFlowStep::ConfirmYes),
+            start_flow_action(StartFlowStep::ConfirmYes),
+            start_flow_action(StartFlowStep::ConfirmOk),
+            formation_action(FormationPreset::default()),
+            formation_confirm_action(),
+        ]
+        .iter()
+        .map(|action| format!("{action:?}"))
+        .collect();
+        assert_eq!(taps, expected);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn red_waits_for_the_opponent_to_pick_its_formation() {
+        let controller = SetupFlowController::new(usize::MAX, 0).picking_second(10);
+        let mut orchestrator = setup_orchestrator(controller);
+        orchestrator.config.our_side = Some(PlayerSide::Red);
+
+        orchestrator
+            .perform_start_sequence(FormationPreset::default())
+            .await
+            .unwrap();
+
+        // The wait outlasts the retries without tapping again.
+        assert!(orchestrator.config.max_retries < 10);
+        let actions = orchestrator.controller.inner.recorded_actions();
+        assert_eq!(actions.len(), 5);
+        assert_eq!(
+            format!("{:?}", actions[3]),
+            format!("{:?}", formation_action(FormationPreset::default()))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_sequence_names_the_step_that_never_advances() {
+        let mut orchestrator = setup_orchestrator(SetupFlowController::new(2, usize::MAX));
+
+        let err = orchestrator
+            .perform_start_sequence(FormationPreset::default())
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("step notice"), "{message}");
+        assert!(message.contains("NoticeDialog"), "{message}");
+        let attempts = orchestrator.config.max_retries as usize + 1;
+        assert_eq!(
+            orchestrator.controller.inner.recorded_actions().len(),
+            2 + attempts
+        );
+    }
+
+    #[tokio::test]
+    async fn board_updates_follow_recognition_and_our_move() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            EchoRecognizer::default(),
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let events = telemetry.snapshot_events().await;
+        let boards = board_events(&events);
+        let plies: Vec<u32> = boards.iter().map(|b| b.snapshot.ply).collect();
+        assert_eq!(plies, [0, 1, 2, 3]);
+        let record = &orchestrator.game_record().moves;
+        for (board, recorded) in boards[1..].iter().zip(record) {
+            let last = board.snapshot.last_move.as_ref().unwrap();
+            assert_eq!((last.from, last.to), (recorded.mv.from, recorded.mv.to));
+            assert_eq!(board.diffs.len(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn engine_events_carry_the_evaluated_fen() {
+        let board = BoardState::initial();
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(1),
+            mock_controller(),
+            FixedRecognizer {
+                board: board.clone(),
+            },
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        let fen = telemetry
+            .snapshot_events()
+            .await
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Engine(engine) => engine.fen.clone(),
+                _ => None,
+            })
+            .expect("engine event with a FEN");
+        assert_eq!(fen, board.to_fen());
+        let replayed = BoardState::from_fen(&fen).unwrap();
+        assert_eq!(replayed.fingerprint(), board.fingerprint());
+    }
+
+    #[tokio::test]
+    async fn unchanged_boards_can_be_skipped() {
+        // Side to move is mated, so no move is played and the board never changes.
+        let mut board = BoardState::empty();
+        for (file, rank, owner, kind) in [
+            (3, 0, PlayerSide::Blue, PieceKind::General),
+            (5, 8, PlayerSide::Red, PieceKind::General),
+            (8, 1, PlayerSide::Red, PieceKind::Chariot),
+            (4, 9, PlayerSide::Red, PieceKind::Chariot),
+        ] {
+            board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
+        }
+        for (skip, expected) in [(false, 2), (true, 1)] {
+            let mut config = orchestrator_config(10);
+            config.skip_unchanged_boards = skip;
+            let telemetry = TelemetryStore::new();
+            let mut orchestrator = Orchestrator::new(
+                config,
+                mock_controller(),
+                FixedRecognizer {
+                    board: board.clone(),
+                },
+                RuleBasedEngine::new(),
+                LocalServer::new(16),
+                telemetry.clone(),
+            );
+
+            orchestrator.play_turn().await.unwrap();
+            orchestrator.play_turn().await.unwrap();
+
+            let events = telemetry.snapshot_events().await;
+            assert_eq!(board_events(&events).len(), expected, "skip={skip}");
+        }
+    }
+
+    #[tokio::test]
+    async fn one_turn_record_per_turn_with_opponent_replies() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(7),
+            mock_controller(),
+            EchoRecognizer::default(),
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        let turns = telemetry.turns(None).await;
+        assert_eq!(turns.len(), 4);
+        for (idx, turn) in turns.iter().enumerate() {
+            assert_eq!(turn.index as usize, idx);
+            assert_eq!(turn.side, PlayerSide::Blue);
+            assert!(turn.chosen_move.is_some());
+            assert!(turn.anomalies.is_empty());
+        }
+        let sides: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|m| m.side)
+            .collect();
+        assert_eq!(sides.len(), 7);
+        assert!(sides.chunks(2).all(|pair| pair[0] == PlayerSide::Blue
+            && pair.get(1).is_none_or(|&side| side == PlayerSide::Red)));
+        assert_ne!(turns[0].board_hash, turns[1].board_hash);
+
+        let engine_turn_ids: Vec<_> = telemetry
+            .snapshot_events()
+            .await
+            .iter()
+            .filter(|e| e.kind == EventKind::EngineDecision)
+            .filter_map(|e| e.turn_id())
+            .collect();
+        let record_turn_ids: Vec<_> = turns.iter().map(|t| t.turn_id).collect();
+        assert_eq!(engine_turn_ids, record_turn_ids);
+        let match_id = telemetry.snapshot_matches().await[0].match_id;
+        assert_eq!(telemetry.turns(match_id).await.len(), 4);
+        assert!(telemetry.turns(Some(Uuid::new_v4())).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn wrong_side_move_is_not_tapped_and_triggers_rerecognition() {
+        let telemetry = TelemetryStore::new();
+        let controller = mock_controller();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(1),
+            controller,
+            MisalignedOnceRecognizer::default(),
+            WrongSideEngine,
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+        // Start from a tracked board so the recognizer's echo has pieces on it.
+        orchestrator.last_snapshot = Some(GameSnapshot {
+            board: BoardState::initial(),
+            ..GameSnapshot::default()
+        });
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        assert!(orchestrator.game_record().moves.is_empty());
+        assert_eq!(
+            orchestrator
+                .recognizer
+                .recognize_calls
+                .load(Ordering::SeqCst),
+            3
+        );
+        let events = telemetry.snapshot_events().await;
+        assert!(events.iter().any(|e| matches!(
+            &e.payload,
+            EventPayload::Ops(ops)
+                if ops.severity == Severity::Error
+                    && ops.tags.iter().any(|t| t == "side-mismatch")
+        )));
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert!(turn.chosen_move.is_none());
+        assert!(!turn.anomalies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn hold_move_is_reported_and_not_tapped() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            EchoRecognizer::default(),
+            HoldEngine,
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        assert!(orchestrator.game_record().moves.is_empty());
+        let tracked = orchestrator.last_snapshot.as_ref().unwrap();
+        assert!(tracked.board.differences(&BoardState::initial()).is_empty());
+        assert!(orchestrator.awaiting_opponent.is_none());
+        let events = telemetry.snapshot_events().await;
+        assert!(events.iter().any(|e| matches!(
+            &e.payload,
+            EventPayload::Ops(ops)
+                if ops.severity == Severity::Warning
+                    && ops.tags.iter().any(|t| t == "engine")
+        )));
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert!(turn.chosen_move.is_none());
+        assert!(turn.anomalies.iter().any(|a| a.contains("hold move")));
+    }
+
+    #[tokio::test]
+    async fn failed_taps_leave_the_tracked_snapshot_untouched() {
+        let mut controller = FlakyController::new(0);
+        controller.taps_fail = true;
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            controller,
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        assert!(orchestrator.play_turn().await.is_err());
+
+        let tracked = orchestrator.last_snapshot.as_ref().unwrap();
+        assert!(tracked.board.differences(&BoardState::initial()).is_empty());
+        assert_eq!(tracked.ply, 0);
+        assert!(orchestrator.game_record().moves.is_empty());
+        assert!(orchestrator.awaiting_opponent.is_none());
+        assert_eq!(
+            orchestrator.clocks(),
+            GameClocks::new(orchestrator.config.time_control.base_ms)
+        );
+    }
+
+    async fn blue_move(board: &BoardState) -> Move {
+        let ctx = TurnContext {
+            snapshot: GameSnapshot {
+                board: board.clone(),
+                ..GameSnapshot::default()
+            },
+            side: PlayerSide::Blue,
+            remaining_ms: None,
+            increment_ms: None,
+        };
+        let decision = RuleBasedEngine::default()
+            .evaluate_position(&ctx)
+            .await
+            .unwrap();
+        decision.best_move.unwrap()
+    }
+
+    #[tokio::test]
+    async fn ignored_move_is_recovered_by_resynchronizing() {
+        let initial = BoardState::initial();
+        let ignored = blue_move(&initial).await;
+        // The tracked board was wrong: the piece we moved is not there.
+        let mut actual = initial.clone();
+        actual.set_piece(ignored.from, None);
+        let replacement = blue_move(&actual).await;
+        let mut played = GameSnapshot {
+            board: actual.clone(),
+            ..GameSnapshot::default()
+        };
+        played.apply_move(PlayerSide::Blue, &replacement).unwrap();
+        let mut config = orchestrator_config(10);
+        config.max_move_rejections = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial.clone(), initial, actual, played.board]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert_ne!(
+            (replacement.from, replacement.to),
+            (ignored.from, ignored.to)
+        );
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(orchestrator.move_rejections, 1);
+        let moves = &orchestrator.game_record().moves;
+        assert_eq!(moves.len(), 1);
+        assert_eq!(
+            (moves[0].mv.from, moves[0].mv.to),
+            (replacement.from, replacement.to)
+        );
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert_eq!(
+            turn.chosen_move.as_ref().map(|mv| (mv.from, mv.to)),
+            Some((replacement.from, replacement.to))
+        );
+        assert!(turn
+            .anomalies
+            .iter()
+            .any(|a| a.starts_with("client ignored our move")));
+        let clear = format!("{:?}", clear_selection_action());
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert_eq!(actions.iter().filter(|a| **a == clear).count(), 1);
+    }
+
+    /// Echoes the tracked board, with `popup` covering the first `showing`
+    /// frames.
+    struct PopupRecognizer {
+        popup: PopupTemplate,
+        showing: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for PopupRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            Ok(hints.previous_snapshot.unwrap_or_default())
+        }
+
+        fn detect_popup(&self, _frame: &ImageFrame) -> Option<PopupTemplate> {
+            self.showing
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+                .then(|| self.popup.clone())
+        }
+    }
+
+    fn popup_orchestrator(
+        showing: usize,
+    ) -> Orchestrator<MockController, PopupRecognizer, CountingEngine, LocalServer> {
+        let mut config = orchestrator_config(10);
+        config.max_retries = 2;
+        Orchestrator::new(
+            config,
+            mock_controller(),
+            PopupRecognizer {
+                popup: PopupTemplate {
+                    name: "rematch".into(),
+                    anchor: Point::new(360, 560),
+                    dismiss: Point::new(450, 710),
+                },
+                showing: AtomicUsize::new(showing),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        )
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn popups_are_dismissed_before_the_board_is_read() {
+        let mut orchestrator = popup_orchestrator(2);
+
+        orchestrator.play_turn().await.unwrap();
+
+        let dismiss = format!("{:?}", popup_dismiss_action(&orchestrator.recognizer.popup));
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert_eq!(actions[..2], [dismiss.clone(), dismiss]);
+        assert!(actions.len() > 2, "the turn went on to play a move");
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert_eq!(
+            turn.anomalies
+                .iter()
+                .filter(|a| *a == "dismissed popup 'rematch'")
+                .count(),
+            2
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn a_popup_that_will_not_close_fails_the_capture() {
+        let orchestrator = popup_orchestrator(usize::MAX);
+
+        let err = orchestrator
+            .capture_and_recognize_once(&mut Vec::new())
+            .await
+            .err()
+            .unwrap();
+
+        assert!(
+            err.to_string()
+                .contains("popup 'rematch' still showing after 2 dismissals"),
+            "{err}"
+        );
+        assert_eq!(orchestrator.controller.recorded_actions().len(), 2);
+    }
+
+    /// A span as it closed: its parent's name and every recorded field.
+    #[derive(Debug)]
+    struct ClosedSpan {
+        name: &'static str,
+        parent: Option<&'static str>,
+        fields: BTreeMap<String, String>,
+    }
+
+    #[derive(Default)]
+    struct SpanFields(BTreeMap<String, String>);
+
+    impl tracing::field::Visit for SpanFields {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    /// Layer keeping every closed span, in closing order.
+    #[derive(Clone, Default)]
+    struct SpanCapture(Arc<Mutex<Vec<ClosedSpan>>>);
+
+    impl<S> tracing_subscriber::Layer<S> for SpanCapture
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = SpanFields::default();
+            attrs.record(&mut fields);
+            ctx.span(id).unwrap().extensions_mut().insert(fields);
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let span = ctx.span(id).unwrap();
+            values.record(span.extensions_mut().get_mut::<SpanFields>().unwrap());
+        }
+
+        fn on_close(&self, id: tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let span = ctx.span(&id).unwrap();
+            let fields = span
+                .extensions_mut()
+                .remove::<SpanFields>()
+                .unwrap_or_default();
+            self.0.lock().unwrap().push(ClosedSpan {
+                name: span.name(),
+                parent: span.parent().map(|parent| parent.name()),
+                fields: fields.0,
+            });
+        }
+    }
+
+    #[tokio::test]
+    async fn implausible_material_is_recaptured_before_reconciling() {
+        let initial = BoardState::initial();
+        let ours = blue_move(&initial).await;
+        let reply = Move::new(Square::new(0, 6), Square::new(0, 5));
+        let mut replied = initial.clone();
+        replied.move_piece(ours.from, ours.to).unwrap();
+        replied.move_piece(reply.from, reply.to).unwrap();
+        // A misread frame shows a third Red chariot next to the reply.
+        let mut misread = replied.clone();
+        misread.set_piece(
+            Square::new(4, 4),
+            Some(Piece {
+                owner: PlayerSide::Red,
+                kind: PieceKind::Chariot,
+            }),
+        );
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial, misread, replied]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let moves = &orchestrator.game_record().moves;
+        assert_eq!((moves[1].mv.from, moves[1].mv.to), (reply.from, reply.to));
+        assert!(orchestrator.match_telemetry.turns[1]
+            .anomalies
+            .iter()
+            .any(|a| a == "implausible material change: Red Chariot count rose from 2 to 3"));
+    }
+
+    #[tokio::test]
+    async fn turn_stages_are_traced_as_child_spans() {
+        let initial = BoardState::initial();
+        let mv = blue_move(&initial).await;
+        let mut played = initial.clone();
+        played.move_piece(mv.from, mv.to).unwrap();
+        let mut config = orchestrator_config(10);
+        config.max_move_rejections = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial, played]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        let capture = SpanCapture::default();
+        let _tracing =
+            tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        orchestrator.play_turn().await.unwrap();
+
+        let spans = capture.0.lock().unwrap();
+        let turn = spans.iter().find(|span| span.name == "turn").unwrap();
+        assert_eq!(turn.parent, None);
+        for field in ["turn_id", "ply", "side", "duration_ms"] {
+            assert!(turn.fields.contains_key(field), "turn lacks {field}");
+        }
+        assert_eq!(turn.fields["side"], "Blue");
+        let stages = ["capture", "recognize", "evaluate", "apply_move", "verify"];
+        for stage in stages {
+            let span = spans.iter().find(|span| span.name == stage).unwrap();
+            assert!(span.fields.contains_key("duration_ms"), "{stage}: {span:?}");
+        }
+        // The verification's capture nests under `verify`.
+        let parents: Vec<_> = spans
+            .iter()
+            .filter(|span| span.name == "capture")
+            .map(|span| span.parent)
+            .collect();
+        assert_eq!(parents, [Some("turn"), Some("verify")]);
+        assert!(spans
+            .iter()
+            .filter(|span| span.name != "turn" && span.name != "capture")
+            .all(|span| span.parent == Some("turn") || span.parent == Some("verify")));
+        let evaluate = spans.iter().find(|span| span.name == "evaluate").unwrap();
+        assert!(evaluate.fields.contains_key("nodes"));
+        let capture = spans.iter().find(|span| span.name == "capture").unwrap();
+        assert!(capture.fields.contains_key("frame_width"));
+
+        let record = &orchestrator.match_telemetry.turns[0];
+        assert_eq!(
+            record.stages.evaluate_ms.to_string(),
+            evaluate.fields["duration_ms"]
+        );
+    }
+
+    #[tokio::test]
+    async fn opponent_moves_are_published_as_events() {
+        let initial = BoardState::initial();
+        let mut game = GameSnapshot {
+            board: initial.clone(),
+            ..GameSnapshot::default()
+        };
+        game.apply_move(PlayerSide::Blue, &blue_move(&initial).await)
+            .unwrap();
+        let after_blue = game.board.clone();
+        let red_move = after_blue.legal_moves(PlayerSide::Red).remove(0);
+        let red_piece = after_blue.piece_at(red_move.from).unwrap();
+        game.apply_move(PlayerSide::Red, &red_move).unwrap();
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial, after_blue, game.board]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let events = telemetry.snapshot_events().await;
+        let opponent_moves: Vec<_> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::OpponentMove(opponent) => Some(opponent),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(opponent_moves.len(), 1);
+        let opponent = opponent_moves[0];
+        assert_eq!(opponent.side, PlayerSide::Red);
+        assert_eq!(
+            (opponent.mv.from, opponent.mv.to),
+            (red_move.from, red_move.to)
+        );
+        assert_eq!(opponent.piece, red_piece.kind);
+        assert_eq!(opponent.captured, None);
+        // The board the opponent's move produced is published just before it.
+        let last_move = board_events(&events)
+            .into_iter()
+            .find(|board| board.turn_id == opponent.turn_id)
+            .and_then(|board| board.snapshot.last_move.clone())
+            .unwrap();
+        assert_eq!((last_move.from, last_move.to), (red_move.from, red_move.to));
+    }
+
+    #[tokio::test]
+    async fn spectating_follows_both_sides_without_touching_the_device() {
+        let initial = BoardState::initial();
+        let mut game = GameSnapshot {
+            board: initial.clone(),
+            ..GameSnapshot::default()
+        };
+        game.apply_move(PlayerSide::Blue, &blue_move(&initial).await)
+            .unwrap();
+        let after_blue = game.board.clone();
+        let red_move = after_blue.legal_moves(PlayerSide::Red).remove(0);
+        game.apply_move(PlayerSide::Red, &red_move).unwrap();
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(10);
+        config.mode = OrchestratorMode::Spectate;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial, after_blue, game.board]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        for _ in 0..3 {
+            orchestrator.play_turn().await.unwrap();
+        }
+
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        let owners: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|mv| mv.side)
+            .collect();
+        assert_eq!(owners, [PlayerSide::Blue, PlayerSide::Red]);
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 3);
+        let events = telemetry.snapshot_events().await;
+        assert_eq!(board_events(&events).len(), 3);
+        let engine_events = events
+            .iter()
+            .filter(|e| matches!(e.payload, EventPayload::Engine(_)))
+            .count();
+        assert_eq!(engine_events, 3);
+        assert!(orchestrator
+            .match_telemetry
+            .turns
+            .iter()
+            .all(|turn| turn.chosen_move.is_none()));
+    }
+
+    /// Plays our opening move, then times the turn that waits two polls for
+    /// the opponent's reply with slow capture and recognition.
+    async fn timed_reply_turn(pipeline: bool) -> (Duration, LatencySample) {
+        let initial = BoardState::initial();
+        let mut ours = GameSnapshot {
+            board: initial.clone(),
+            ..GameSnapshot::default()
+        };
+        ours.apply_move(PlayerSide::Blue, &blue_move(&initial).await)
+            .unwrap();
+        let mut reply = ours.clone();
+        let red_move = ours.board.legal_moves(PlayerSide::Red).remove(0);
+        reply.apply_move(PlayerSide::Red, &red_move).unwrap();
+        let mut config = orchestrator_config(10);
+        config.pipeline = pipeline;
+        let mut controller = FlakyController::new(0);
+        controller.capture_delay = Duration::from_millis(100);
+        let mut recognizer = ScriptedBoardRecognizer::new(vec![
+            initial,
+            ours.board.clone(),
+            ours.board,
+            reply.board,
+        ]);
+        recognizer.delay = Duration::from_millis(200);
+        let mut orchestrator = Orchestrator::new(
+            config,
+            controller,
+            recognizer,
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator.play_turn().await.unwrap();
+
+        let started = Instant::now();
+        orchestrator.play_turn().await.unwrap();
+        let elapsed = started.elapsed();
+        assert_eq!(orchestrator.game_record().moves.len(), 3);
+        (
+            elapsed,
+            orchestrator.match_telemetry.turns[1].latency.clone(),
+        )
+    }
+
+    /// Scripted boards, noting when each was read.
+    struct PollTimesRecognizer {
+        inner: ScriptedBoardRecognizer,
+        reads: Mutex<Vec<Instant>>,
+    }
+
+    #[async_trait]
+    impl BoardRecognizer for PollTimesRecognizer {
+        async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+            self.inner.align_board(frame).await
+        }
+
+        async fn recognize(
+            &self,
+            frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            self.reads.lock().unwrap().push(Instant::now());
+            self.inner.recognize(frame, hints).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn opponent_polls_follow_the_schedule_and_restart_on_change() {
+        let initial = BoardState::initial();
+        let mut ours = GameSnapshot {
+            board: initial.clone(),
+            ..GameSnapshot::default()
+        };
+        ours.apply_move(PlayerSide::Blue, &blue_move(&initial).await)
+            .unwrap();
+        // A piece flickers out of view without a move being made.
+        let mut flicker = ours.board.clone();
+        flicker.set_piece(Square::new(0, 6), None);
+        let mut reply = ours.clone();
+        let red_move = ours.board.legal_moves(PlayerSide::Red).remove(0);
+        reply.apply_move(PlayerSide::Red, &red_move).unwrap();
+        let mut boards = vec![initial];
+        boards.extend(std::iter::repeat_n(ours.board.clone(), 8));
+        boards.extend([flicker, reply.board]);
+        let mut config = orchestrator_config(10);
+        config.poll_schedule = Some(PollSchedule {
+            fast_interval_ms: 200,
+            fast_window_ms: 1_000,
+            backoff: 2.0,
+            max_interval_ms: 1_600,
+            jitter: 0.0,
+        });
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            PollTimesRecognizer {
+                inner: ScriptedBoardRecognizer::new(boards),
+                reads: Mutex::new(Vec::new()),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        let status = StatusHandle::new();
+        orchestrator.attach_status(status.clone());
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(orchestrator.game_record().moves.len(), 3);
+        let reads = orchestrator.recognizer.reads.lock().unwrap();
+        // Each poll also spends the mock controller's 25ms capture.
+        let intervals: Vec<_> = reads[1..=10]
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).as_millis() - 25)
+            .collect();
+        assert_eq!(intervals, [200, 200, 200, 200, 200, 400, 800, 1_600, 200]);
+        assert_eq!(status.report().poll_interval_ms, Some(200));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn pipelining_hides_recognition_behind_the_next_capture() {
+        let (serial, serial_latency) = timed_reply_turn(false).await;
+        let (pipelined, pipelined_latency) = timed_reply_turn(true).await;
+
+        // Two fruitless polls each hide a 200ms recognition.
+        assert_eq!(serial_latency.prefetch_saved_ms, 0);
+        assert_eq!(pipelined_latency.prefetch_saved_ms, 400);
+        assert_eq!(serial - pipelined, Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    async fn repeatedly_ignored_moves_abort_the_turn() {
+        let mut config = orchestrator_config(10);
+        config.max_move_rejections = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        let err = orchestrator.play_turn().await.unwrap_err();
+
+        assert!(
+            err.to_string().contains("client ignored 2 of our moves"),
+            "{err}"
+        );
+        assert!(orchestrator.game_record().moves.is_empty());
+    }
+
+    #[tokio::test]
+    async fn empty_engine_answer_is_recaptured_then_played() {
+        let mut config = orchestrator_config(10);
+        config.max_retries = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            EmptyEngine {
+                empty_calls: 1,
+                ..EmptyEngine::default()
+            },
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(orchestrator.game_record().moves.len(), 1);
+        assert!(orchestrator.game_result().is_none());
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert!(turn.chosen_move.is_some());
+        assert!(turn
+            .anomalies
+            .iter()
+            .any(|a| a == "engine returned no move"));
+    }
+
+    #[tokio::test]
+    async fn persistent_empty_engine_answer_fails_the_turn() {
+        let mut config = orchestrator_config(10);
+        config.max_retries = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            EmptyEngine {
+                empty_calls: usize::MAX,
+                ..EmptyEngine::default()
+            },
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        let err = orchestrator.play_turn().await.unwrap_err();
+
+        assert!(
+            err.to_string().contains("no move after 2 re-captures"),
+            "{err}"
+        );
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 3);
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+    }
+
+    #[tokio::test]
+    async fn illegal_inferred_opponent_move_is_not_recorded() {
+        let initial = BoardState::initial();
+        let mut game = GameSnapshot {
+            board: initial.clone(),
+            ..GameSnapshot::default()
+        };
+        game.apply_move(PlayerSide::Blue, &blue_move(&initial).await)
+            .unwrap();
+        let after_blue = game.board.clone();
+        // A soldier cannot advance two ranks at once.
+        let mut teleported = after_blue.clone();
+        teleported
+            .move_piece(Square::new(8, 6), Square::new(8, 4))
+            .unwrap();
+        teleported.side_to_move = PlayerSide::Blue;
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![initial, after_blue, teleported]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let sides: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|m| m.side)
+            .collect();
+        assert_eq!(sides.first(), Some(&PlayerSide::Blue));
+        assert!(!sides.contains(&PlayerSide::Red));
+        assert!(orchestrator.match_telemetry.turns[1]
+            .anomalies
+            .iter()
+            .any(|anomaly| anomaly.starts_with("illegal inferred move i7i5")));
+        let events = telemetry.snapshot_events().await;
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e.payload, EventPayload::OpponentMove(_))));
+    }
+
+    #[tokio::test]
+    async fn inferred_opponent_move_advances_the_tracked_snapshot() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            PiecesOnlyRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let sides: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|m| m.side)
+            .collect();
+        assert_eq!(sides, [PlayerSide::Blue, PlayerSide::Red, PlayerSide::Blue]);
+        let tracked = orchestrator.last_snapshot.as_ref().unwrap();
+        assert_eq!(tracked.ply, 3);
+        assert_eq!(tracked.board.side_to_move, PlayerSide::Red);
+        let last = orchestrator.game_record().moves.last().unwrap();
+        assert!(tracked
+            .last_move
+            .as_ref()
+            .is_some_and(|mv| mv.from == last.mv.from && mv.to == last.mv.to));
+        assert!(orchestrator.match_telemetry.turns[1].anomalies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn engine_plays_our_side_despite_a_misread_side_to_move() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            MisreadSideRecognizer {
+                echo: EchoRecognizer::default(),
+                side: PlayerSide::Red,
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        for _ in 0..3 {
+            orchestrator.play_turn().await.unwrap();
+        }
+
+        // Blue's General sits in the bottom palace of the first board.
+        assert_eq!(orchestrator.our_side(), Some(PlayerSide::Blue));
+        assert_eq!(
+            *orchestrator.engine.sides.lock().unwrap(),
+            [PlayerSide::Blue; 3]
+        );
+        let sides: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|m| m.side)
+            .collect();
+        assert_eq!(
+            sides,
+            [
+                PlayerSide::Blue,
+                PlayerSide::Red,
+                PlayerSide::Blue,
+                PlayerSide::Red,
+                PlayerSide::Blue
+            ]
+        );
+    }
+
+    /// A client seating Red at the bottom: frames show the game turned half
+    /// around, and Blue answers each Red move with its first legal one.
+    struct RedSeatRecognizer;
+
+    #[async_trait]
+    impl BoardRecognizer for RedSeatRecognizer {
+        async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+            Ok(BoardState::initial().rotated())
+        }
+
+        async fn recognize(
+            &self,
+            _frame: &ImageFrame,
+            hints: RecognitionHints,
+        ) -> Result<GameSnapshot> {
+            let Some(snapshot) = hints.previous_snapshot else {
+                return Ok(GameSnapshot {
+                    board: BoardState::initial().rotated(),
+                    ..GameSnapshot::default()
+                });
+            };
+            let mut snapshot = rotate_snapshot(snapshot);
+            if snapshot.board.side_to_move == PlayerSide::Blue {
+                if let Some(reply) = snapshot.board.legal_moves(PlayerSide::Blue).first() {
+                    snapshot
+                        .apply_move(PlayerSide::Blue, &reply.clone())
+                        .map_err(orchestrator_error)?;
+                }
+            }
+            Ok(rotate_snapshot(snapshot))
+        }
+    }
+
+    #[tokio::test]
+    async fn red_seat_is_read_and_tapped_turned_around() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            RedSeatRecognizer,
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        // Blue opens, then each turn answers its move.
+        for _ in 0..3 {
+            orchestrator.play_turn().await.unwrap();
+        }
+
+        // Red's General sits in the bottom palace on screen.
+        assert_eq!(orchestrator.our_side(), Some(PlayerSide::Red));
+        assert_eq!(
+            *orchestrator.engine.sides.lock().unwrap(),
+            [PlayerSide::Red; 2]
+        );
+        let tracked = &orchestrator.last_snapshot.as_ref().unwrap().board;
+        assert_eq!(bottom_palace_general(tracked), Some(PlayerSide::Blue));
+        let geometry = BoardGeometry::default();
+        let expected: Vec<_> = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .filter(|m| m.side == PlayerSide::Red)
+            .flat_map(|m| [m.mv.from, m.mv.to])
+            .map(|square| {
+                let point = geometry.square_to_point(square.rotated()).unwrap();
+                format!(
+                    "{:?}",
+                    InputAction::Tap {
+                        x: point.x,
+                        y: point.y
+                    }
+                )
+            })
+            .collect();
+        assert_eq!(expected.len(), 4);
+        let taps: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert_eq!(taps, expected);
+    }
+
+    #[tokio::test]
+    async fn formations_are_recorded_once_the_side_is_known() {
+        let mut config = orchestrator_config(2);
+        config.formation = FormationPreset::SangMasangMa;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            RedSeatRecognizer,
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        // As read from the opponent's back rank at boot.
+        orchestrator.opponent_formation = Some(FormationPreset::MasangMasang);
+
+        orchestrator.run().await.unwrap();
+
+        let record = orchestrator.game_record();
+        assert_eq!(orchestrator.our_side(), Some(PlayerSide::Red));
+        assert_eq!(record.red_formation, Some(FormationPreset::SangMasangMa));
+        assert_eq!(record.blue_formation, Some(FormationPreset::MasangMasang));
+    }
+
+    #[tokio::test]
+    async fn nothing_is_played_on_the_opponents_turn() {
+        let mut config = orchestrator_config(10);
+        config.our_side = Some(PlayerSide::Red);
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        // A fresh game opens with Blue to move.
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(orchestrator.our_side(), Some(PlayerSide::Red));
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 0);
+        assert!(orchestrator.controller.recorded_actions().is_empty());
+        assert_eq!(orchestrator.awaiting_opponent, Some(PlayerSide::Blue));
+    }
+
+    #[test]
+    fn our_side_comes_from_the_bottom_palace_general() {
+        assert_eq!(
+            bottom_palace_general(&BoardState::initial()),
+            Some(PlayerSide::Blue)
+        );
+        let flipped = BoardState::from_fen("4K4/9/9/9/9/9/9/9/4k4/9 w").unwrap();
+        assert_eq!(bottom_palace_general(&flipped), Some(PlayerSide::Red));
+        assert_eq!(bottom_palace_general(&BoardState::empty()), None);
+    }
+
+    #[tokio::test]
+    async fn persistent_desync_is_recaptured_then_reported() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+        let played = orchestrator.game_record().moves[0].mv.clone();
+        // The screen still shows the position before our move.
+        orchestrator.play_turn().await.unwrap();
+
+        let events = telemetry.snapshot_events().await;
+        let desync = events
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "desync") => {
+                    Some(ops.message.clone())
+                }
+                _ => None,
+            })
+            .expect("desync ops event");
+        for square in [played.from, played.to] {
+            assert!(desync.contains(&square.to_string()));
+        }
+        let turn = &orchestrator.match_telemetry.turns[1];
+        assert!(turn.anomalies.iter().any(|a| a.contains("desync")));
+    }
+
+    #[test]
+    fn formation_mismatches_check_our_back_rank_only() {
+        let board = BoardState::initial();
+        assert!(formation_mismatches(
+            &board,
+            PlayerSide::Blue,
+            FormationPreset::MasangSangMa,
+            None
+        )
+        .is_empty());
+
+        let mismatched = formation_mismatches(
+            &board,
+            PlayerSide::Blue,
+            FormationPreset::SangMaMaSang,
+            None,
+        );
+        assert_eq!(mismatched, [1, 2, 6, 7].map(|file| Square::new(file, 0)));
+        for side in [PlayerSide::Blue, PlayerSide::Red] {
+            let laid_out = BoardState::initial_for(FormationPreset::SangMaMaSang, side);
+            assert!(
+                formation_mismatches(&laid_out, side, FormationPreset::SangMaMaSang, None)
+                    .is_empty()
+            );
+        }
+
+        // The opponent's horses and elephants may stand in any order.
+        let mut swapped = board.clone();
+        let (horse, elephant) = (Square::new(1, 9), Square::new(2, 9));
+        let piece = swapped.piece_at(horse);
+        swapped.set_piece(horse, swapped.piece_at(elephant));
+        swapped.set_piece(elephant, piece);
+        assert!(formation_mismatches(
+            &swapped,
+            PlayerSide::Blue,
+            FormationPreset::MasangSangMa,
+            None
+        )
+        .is_empty());
+        // Seated at the top, Red reads its files mirrored.
+        assert!(formation_mismatches(
+            &swapped,
+            PlayerSide::Red,
+            FormationPreset::MasangMasang,
+            None
+        )
+        .is_empty());
+    }
+
+    #[tokio::test]
+    async fn wrong_initial_board_is_reported_before_the_match() {
+        let mut config = orchestrator_config(1);
+        config.formation = FormationPreset::SangMaMaSang;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        assert_eq!(orchestrator.verify_initial_board().await.len(), 4);
+        orchestrator.config.formation = FormationPreset::MasangSangMa;
+        assert!(orchestrator.verify_initial_board().await.is_empty());
+
+        let events = orchestrator.take_ops_events();
+        let warmup: Vec<_> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "warmup") => Some(ops),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(warmup.len(), 1);
+        assert_eq!(warmup[0].severity, Severity::Error);
+        assert!(warmup[0].message.contains("SangMaMaSang"));
+    }
+
+    #[test]
+    fn opponent_formation_is_read_from_either_seat() {
+        let mut board = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Blue);
+        board.place_formation(FormationPreset::MasangMasang, PlayerSide::Red);
+        assert_eq!(
+            board.formation_of(PlayerSide::Red),
+            Some(FormationPreset::MasangMasang)
+        );
+        let theirs = Some(FormationPreset::MasangMasang);
+        assert!(formation_mismatches(
+            &board,
+            PlayerSide::Blue,
+            FormationPreset::SangMasangMa,
+            theirs
+        )
+        .is_empty());
+        // A known opponent formation is checked like ours.
+        assert_eq!(
+            formation_mismatches(
+                &board,
+                PlayerSide::Blue,
+                FormationPreset::SangMasangMa,
+                Some(FormationPreset::SangMasangMa)
+            )
+            .len(),
+            4
+        );
+
+        // Playing Red, the opponent's back rank is Blue's.
+        let mut board = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Red);
+        board.place_formation(FormationPreset::MasangMasang, PlayerSide::Blue);
+        assert_eq!(
+            board.formation_of(PlayerSide::Blue),
+            Some(FormationPreset::MasangMasang)
+        );
+        assert!(formation_mismatches(
+            &board,
+            PlayerSide::Red,
+            FormationPreset::SangMasangMa,
+            theirs
+        )
+        .is_empty());
+    }
+
+    #[tokio::test]
+    async fn start_board_is_reconstructed_from_both_formations() {
+        let mut expected = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Blue);
+        expected.place_formation(FormationPreset::MasangMasang, PlayerSide::Red);
+        // One of Red's soldiers is misread as missing.
+        let mut misread = expected.clone();
+        misread.set_piece(Square::new(4, 6), None);
+        let mut config = orchestrator_config(1);
+        config.formation = FormationPreset::SangMasangMa;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            FixedRecognizer { board: misread },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+
+        assert_eq!(
+            orchestrator.verify_initial_board().await,
+            [Square::new(4, 6)]
+        );
+        assert_eq!(
+            orchestrator.opponent_formation(),
+            Some(FormationPreset::MasangMasang)
+        );
+        let tracked = orchestrator.last_snapshot.as_ref().unwrap();
+        assert!(tracked.board.differences(&expected).is_empty());
+
+        // A configured formation wins over the board.
+        orchestrator.config.opponent_formation = Some(FormationPreset::SangMaMaSang);
+        assert_eq!(orchestrator.verify_initial_board().await.len(), 3);
+        assert_eq!(
+            orchestrator.opponent_formation(),
+            Some(FormationPreset::SangMaMaSang)
+        );
+    }
+
+    #[tokio::test]
+    async fn desync_rolls_back_to_the_matching_history_entry() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        let status = StatusHandle::new();
+        orchestrator.attach_status(status.clone());
+        // Two plies were tracked past the start, but the screen still shows it.
+        let mut snapshot = GameSnapshot {
+            board: BoardState::initial(),
+            ..GameSnapshot::default()
+        };
+        orchestrator.push_history(&snapshot, None);
+        for (side, from, to) in [
+            (PlayerSide::Blue, Square::new(0, 3), Square::new(0, 4)),
+            (PlayerSide::Red, Square::new(0, 6), Square::new(0, 5)),
+        ] {
+            let mv = Move::new(from, to);
+            snapshot.apply_move(side, &mv).unwrap();
+            orchestrator.record.push_move(side, mv.clone(), None, None);
+            orchestrator.push_history(&snapshot, Some(mv));
+        }
+        orchestrator.last_snapshot = Some(snapshot);
+
+        orchestrator.play_turn().await.unwrap();
+
+        let turn = &orchestrator.match_telemetry.turns[0];
+        assert!(
+            turn.anomalies
+                .iter()
+                .any(|a| a.contains("rolled back 2 plies to ply 0")),
+            "{:?}",
+            turn.anomalies
+        );
+        assert_eq!(turn.side, PlayerSide::Blue);
+        // The start position, then the move just played from it.
+        let plies: Vec<_> = orchestrator
+            .history()
+            .iter()
+            .map(|entry| entry.snapshot.ply)
+            .collect();
+        assert_eq!(plies, [0, 1]);
+        assert_eq!(orchestrator.game_record().moves.len(), 1);
+        let reported: Vec<_> = status.report().history.iter().map(|p| p.ply).collect();
+        assert_eq!(reported, [0, 1]);
+    }
+
+    #[test]
+    fn start_fen_seeds_tracked_board_and_invalid_fen_is_ignored() {
+        let new_orchestrator = |start_fen: &str| {
+            let mut config = orchestrator_config(1);
+            config.start_fen = Some(start_fen.into());
+            Orchestrator::new(
+                config,
+                mock_controller(),
+                EchoRecognizer::default(),
+                RuleBasedEngine::new(),
+                LocalServer::new(16),
+                TelemetryStore::new(),
+            )
+        };
+
+        let fen = "9/3k5/9/9/9/9/9/9/4R4/4K4 b";
+        let mut seeded = new_orchestrator(fen);
+        assert!(seeded.seed_from_fen());
+        let board = &seeded.last_snapshot.as_ref().unwrap().board;
+        assert_eq!(board.to_fen(), fen);
+
+        let mut fallback = new_orchestrator("not a fen");
+        assert!(!fallback.seed_from_fen());
+        assert!(fallback.last_snapshot.is_none());
+    }
+
+    #[test]
+    fn highlight_picks_the_opponent_move_out_of_noisy_diffs() {
+        let horse = Piece {
+            owner: PlayerSide::Red,
+            kind: PieceKind::Horse,
+        };
+        let (from, to) = (Square::new(1, 9), Square::new(2, 7));
+        let diffs = [
+            BoardDiff {
+                square: from,
+                before: Some(horse),
+                after: None,
+            },
+            BoardDiff {
+                square: to,
+                before: None,
+                after: Some(horse),
+            },
+            // A misread tile that leaves plain diff inference undecided.
+            BoardDiff {
+                square: Square::new(4, 5),
+                before: None,
+                after: Some(Piece {
+                    owner: PlayerSide::Blue,
+                    kind: PieceKind::Soldier,
+                }),
+            },
+        ];
+        assert_eq!(BoardState::infer_move_from_diffs(&diffs), None);
+
+        let mut anomalies = Vec::new();
+        let confirmed = opponent_move(&diffs, Some((to, from)), &mut anomalies);
+        assert_eq!(confirmed, Some((from, to, horse, None)));
+        assert!(anomalies.is_empty());
+
+        let elsewhere = (Square::new(0, 0), Square::new(0, 1));
+        assert_eq!(opponent_move(&diffs, Some(elsewhere), &mut anomalies), None);
+        assert_eq!(opponent_move(&diffs, None, &mut anomalies), None);
+        assert!(anomalies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn match_ends_when_side_to_move_has_no_legal_moves() {
+        let mut board = BoardState::empty();
+        for (file, rank, owner, kind) in [
+            (3, 0, PlayerSide::Blue, PieceKind::General),
+            (5, 8, PlayerSide::Red, PieceKind::General),
+            (8, 1, PlayerSide::Red, PieceKind::Chariot),
+            (4, 9, PlayerSide::Red, PieceKind::Chariot),
+        ] {
+            board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
+        }
+        let telemetry = TelemetryStore::new();
+        let controller = mock_controller();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(3),
+            controller,
+            FixedRecognizer { board },
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(
+            orchestrator.game_result(),
+            Some(GameResult::Win {
+                winner: PlayerSide::Red,
+                reason: WinReason::NoLegalMoves,
+            })
+        );
+        let events = telemetry.snapshot_events().await;
+        let engine_events = events
+            .iter()
+            .filter(|e| e.kind == EventKind::EngineDecision)
+            .count();
+        assert_eq!(engine_events, 1);
+        let end_details = events.iter().find_map(|e| match &e.payload {
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::MatchEnd,
+                details,
+            }) => details.clone(),
+            _ => None,
+        });
+        assert!(end_details.unwrap_or_default().contains("Red wins"));
+
+        let summary = events
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::MatchResult(summary) => Some(summary.clone()),
+                _ => None,
+            })
+            .expect("match result event");
+        assert_eq!(summary.result, orchestrator.game_result());
+        assert_eq!(summary.plies, 0);
+        assert_eq!(
+            summary.final_fen.as_deref(),
+            Some("4r4/5k3/9/9/9/9/9/9/8r/3K5 w")
+        );
+        assert!(summary.average_latency_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn match_runs_until_vision_reports_game_over() {
+        let result = GameResult::Win {
+            winner: PlayerSide::Blue,
+            reason: WinReason::GeneralCaptured,
+        };
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(50),
+            mock_controller(),
+            GameOverRecognizer {
+                echo: EchoRecognizer::default(),
+                after: 3,
+                result,
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(orchestrator.game_result(), Some(result));
+        // The reply seen with the end screen is recorded; the engine is not asked again.
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(orchestrator.game_record().moves.len(), 4);
+        assert_eq!(orchestrator.game_record().result, Some(result));
+        let details = match_end_details(&telemetry.snapshot_events().await);
+        assert!(
+            details.starts_with("match finished: Blue wins (GeneralCaptured)"),
+            "{details}"
+        );
+    }
+
+    #[tokio::test]
+    async fn ply_cap_stops_an_undecided_match() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(5),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(orchestrator.game_result(), None);
+        assert_eq!(orchestrator.game_record().moves.len(), 5);
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 3);
+        let details = match_end_details(&telemetry.snapshot_events().await);
+        assert!(
+            details.starts_with("match stopped: ply cap 5 reached"),
+            "{details}"
+        );
+    }
+
+    /// Ops events bridged from the `retry` warnings.
+    fn retry_events(events: &[SystemEvent]) -> Vec<&str> {
+        events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "retry") => {
+                    Some(ops.message.as_str())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn flaky_capture_is_retried_and_the_match_completes() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(3);
+        config.max_retries = 3;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            FlakyController::new(2),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(orchestrator.game_record().moves.len(), 3);
+        let events = telemetry.snapshot_events().await;
+        let retries = retry_events(&events);
+        assert_eq!(retries.len(), 2, "{retries:?}");
+        assert!(retries.iter().all(|m| m.starts_with("capture 실패")));
+        let details = match_end_details(&events);
+        assert!(
+            details.starts_with("match stopped: ply cap 3 reached"),
+            "{details}"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stale_frames_are_discarded_and_recaptured() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(10);
+        config.max_retries = 3;
+        let mut controller = FlakyController::new(0);
+        controller.stale_frames = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            controller,
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        orchestrator.max_frame_age = Some(Duration::from_secs(2));
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(orchestrator.controller.captures.load(Ordering::SeqCst), 3);
+        assert_eq!(orchestrator.game_record().moves.len(), 1);
+        let events = telemetry.snapshot_events().await;
+        let retries = retry_events(&events);
+        assert_eq!(retries.len(), 2, "{retries:?}");
+        assert!(retries.iter().all(|m| m.contains("stale frame")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exceeded_anomaly_budget_stops_input_and_aborts_the_match() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(10);
+        config.max_anomaly_score = Some(3);
+        config.back_on_anomaly_abort = true;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            // The screen never shows our move: every later look is a desync.
+            FixedRecognizer {
+                board: BoardState::initial(),
+            },
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+
+        assert!(orchestrator.run().await.is_err());
+
+        // Our first move, then only the Back press.
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert_eq!(actions.len(), 3, "{actions:?}");
+        assert!(actions[..2].iter().all(|a| a.starts_with("Tap")));
+        assert_eq!(actions[2], format!("{:?}", back_action()));
+
+        let events = telemetry.snapshot_events().await;
+        assert!(events.iter().any(|e| matches!(
+            &e.payload,
+            EventPayload::Ops(ops) if ops.severity == Severity::Error
+                && ops.tags.iter().any(|t| t == "anomaly")
+        )));
+        assert!(match_end_details(&events).starts_with("aborted: anomaly budget exceeded"));
+        let budget = telemetry.snapshot_matches().await[0].anomaly_budget;
+        assert_eq!(budget.desyncs, 2);
+        assert_eq!(budget.score, 4);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausted_retries_abort_the_match_with_a_descriptive_end() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(3);
+        config.max_retries = 2;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            FlakyController::new(usize::MAX),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        let err = orchestrator.run().await.unwrap_err();
+
+        assert!(err.to_string().contains("screencap failed"), "{err}");
+        // Two attempts per stage, and the failed turn is played once more.
+        assert_eq!(orchestrator.controller.captures.load(Ordering::SeqCst), 4);
+        let details = match_end_details(&telemetry.snapshot_events().await);
+        assert!(
+            details.starts_with("match aborted: turn 0 failed after retries"),
+            "{details}"
+        );
+    }
+
+    #[tokio::test]
+    async fn resign_command_concedes_the_match() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(50),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+        orchestrator.attach_commands(receiver);
+        commands.send(RemoteCommand::Resign).unwrap();
+
+        orchestrator.run().await.unwrap();
+
+        let resigned = GameResult::Win {
+            winner: PlayerSide::Red,
+            reason: WinReason::Resignation,
+        };
+        assert_eq!(orchestrator.game_result(), Some(resigned));
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 0);
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert!(actions.ends_with(&resign_taps()), "{actions:?}");
+        let details = match_end_details(&telemetry.snapshot_events().await);
+        assert!(
+            details.starts_with("match finished: Red wins (Resignation)"),
+            "{details}"
+        );
+    }
+
+    #[tokio::test]
+    async fn forced_moves_replace_the_engine_move_only_when_legal() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(50),
+            mock_controller(),
+            EchoRecognizer::default(),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let sink = OpsEventSink::default();
+        orchestrator.attach_ops_events(&sink);
+        let _tracing = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(minerva_ops::OpsEventLayer::new(sink)),
+        );
+        let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+        orchestrator.attach_commands(receiver);
+        let control = OrchestratorControl::new(commands);
+        let initial = BoardState::initial();
+        let engine_choice = blue_move(&initial).await;
+        let forced = initial
+            .legal_moves(PlayerSide::Blue)
+            .into_iter()
+            .find(|mv| (mv.from, mv.to) != (engine_choice.from, engine_choice.to))
+            .unwrap();
+
+        control.force_move(forced.from, forced.to).unwrap();
+        orchestrator.handle_commands().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+        // Nothing stands on the middle of the board.
+        control
+            .force_move(Square::new(4, 4), Square::new(4, 5))
+            .unwrap();
+        orchestrator.handle_commands().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let moves = &orchestrator.game_record().moves;
+        assert_eq!((moves[0].mv.from, moves[0].mv.to), (forced.from, forced.to));
+        assert_eq!(moves.len(), 3);
+        let geometry = BoardGeometry::default();
+        let taps: Vec<_> = [forced.from, forced.to]
+            .map(|square| {
+                let point = geometry.square_to_point(square).unwrap();
+                format!(
+                    "{:?}",
+                    InputAction::Tap {
+                        x: point.x,
+                        y: point.y
+                    }
+                )
+            })
+            .into();
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert!(actions.starts_with(&taps), "{actions:?}");
+
+        let events = telemetry.snapshot_events().await;
+        let overridden: Vec<_> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::Engine(engine) => Some(engine.overridden),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(overridden, [true, false]);
+        assert!(events.iter().any(|e| matches!(
+            &e.payload,
+            EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "override")
+        )));
+        assert!(orchestrator.match_telemetry.turns[1]
+            .anomalies
+            .iter()
+            .any(|a| a.contains("illegal")));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn captures_from_both_sides_are_recorded() {
+        let piece = |owner, kind| Some(Piece { owner, kind });
+        let mut start = BoardState::empty();
+        for (file, rank, owner, kind) in [
+            (4, 1, PlayerSide::Blue, PieceKind::General),
+            (4, 8, PlayerSide::Red, PieceKind::General),
+            (0, 0, PlayerSide::Blue, PieceKind::Chariot),
+            (0, 5, PlayerSide::Red, PieceKind::Soldier),
+            (0, 9, PlayerSide::Red, PieceKind::Chariot),
+        ] {
+            start.set_piece(Square::new(file, rank), piece(owner, kind));
+        }
+        let mut after_blue = start.clone();
+        after_blue
+            .move_piece(Square::new(0, 0), Square::new(0, 5))
+            .unwrap();
+        after_blue.side_to_move = PlayerSide::Red;
+        let mut after_red = after_blue.clone();
+        after_red
+            .move_piece(Square::new(0, 9), Square::new(0, 5))
+            .unwrap();
+        after_red.side_to_move = PlayerSide::Blue;
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(10),
+            mock_controller(),
+            ScriptedBoardRecognizer::new(vec![start, after_blue, after_red]),
+            CountingEngine::default(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+        orchestrator.attach_commands(receiver);
+        let control = OrchestratorControl::new(commands);
+
+        control
+            .force_move(Square::new(0, 0), Square::new(0, 5))
+            .unwrap();
+        orchestrator.handle_commands().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+        orchestrator.play_turn().await.unwrap();
+
+        let captures = &orchestrator.match_telemetry.captures;
+        assert_eq!(
+            captures.as_slice(),
+            [
+                CaptureRecord {
+                    ply: 1,
+                    square: Square::new(0, 5),
+                    piece: Piece {
+                        owner: PlayerSide::Red,
+                        kind: PieceKind::Soldier,
+                    },
+                },
+                CaptureRecord {
+                    ply: 2,
+                    square: Square::new(0, 5),
+                    piece: Piece {
+                        owner: PlayerSide::Blue,
+                        kind: PieceKind::Chariot,
+                    },
+                },
+            ]
+        );
+        let events = telemetry.snapshot_events().await;
+        let taken: Vec<_> = board_events(&events)
+            .iter()
+            .filter_map(|board| board.captured)
+            .collect();
+        assert_eq!(taken, captures.iter().map(|c| c.piece).collect::<Vec<_>>());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hopeless_scores_resign_after_the_opening_plies() {
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(50);
+        config.resign_threshold = Some(-5.0);
+        config.resign_persistence = 2;
+        config.resign_min_plies = 4;
+        // Our turns come at plies 0, 2, 4, ...: the first two are too early,
+        // the 0.0 breaks the streak, and the sixth turn completes one.
+        let scores = vec![-10.0, -10.0, -10.0, 0.0, -10.0, -10.0];
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            EchoRecognizer::default(),
+            ScriptedScoreEngine {
+                scores,
+                ..ScriptedScoreEngine::default()
+            },
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        assert_eq!(orchestrator.engine.calls.load(Ordering::SeqCst), 6);
+        assert_eq!(
+            orchestrator.game_result(),
+            Some(GameResult::Win {
+                winner: PlayerSide::Red,
+                reason: WinReason::Resignation,
+            })
+        );
+        let ours = orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .filter(|m| m.side == PlayerSide::Blue)
+            .count();
+        assert_eq!(ours, 5);
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert!(actions.ends_with(&resign_taps()), "{actions:?}");
+        let details = match_end_details(&telemetry.snapshot_events().await);
+        assert!(
+            details.starts_with("match finished: Red wins (Resignation)"),
+            "{details}"
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rule_based_engine_resigns_a_lost_position() {
+        // Blue has lost both chariots and cannons; every quiet move keeps
+        // the evaluation where it is, so only the position itself is hopeless.
+        let mut board = BoardState::initial();
+        for square in [(0, 0), (8, 0), (1, 2), (7, 2)] {
+            board.set_piece(Square::new(square.0, square.1), None);
+        }
+        let telemetry = TelemetryStore::new();
+        let mut config = orchestrator_config(10);
+        config.resign_threshold = Some(-5.0);
+        config.resign_persistence = 1;
+        config.resign_min_plies = 0;
+        let mut orchestrator = Orchestrator::new(
+            config,
+            mock_controller(),
+            FixedRecognizer { board },
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        assert_eq!(
+            orchestrator.game_result(),
+            Some(GameResult::Win {
+                winner: PlayerSide::Red,
+                reason: WinReason::Resignation,
+            })
+        );
+        let actions: Vec<_> = orchestrator
+            .controller
+            .recorded_actions()
+            .iter()
+            .map(|action| format!("{action:?}"))
+            .collect();
+        assert_eq!(actions, resign_taps());
+        let score = telemetry
+            .snapshot_events()
+            .await
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Engine(engine) => engine.score,
+                _ => None,
+            })
+            .unwrap();
+        assert!(score < -40.0, "{score}");
+    }
+
+    #[tokio::test]
+    async fn resignation_is_off_without_a_threshold() {
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(12),
+            mock_controller(),
+            EchoRecognizer::default(),
+            ScriptedScoreEngine {
+                scores: vec![-1_000.0; 12],
+                ..ScriptedScoreEngine::default()
+            },
+            LocalServer::new(16),
+            TelemetryStore::new(),
+        );
+        orchestrator.config.resign_min_plies = 0;
+
+        orchestrator.run().await.unwrap();
+
+        assert!(orchestrator.game_result().is_none());
+        assert_eq!(orchestrator.hopeless_turns, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn paused_match_plays_only_stepped_turns() {
+        let telemetry = TelemetryStore::new();
+        let (commands, receiver) = tokio::sync::mpsc::unbounded_channel();
+        let control = OrchestratorControl::new(commands);
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(50),
+            mock_controller(),
+            EchoRecognizer::default(),
+            PausingEngine {
+                inner: RuleBasedEngine::new(),
+                control: control.clone(),
+                calls: calls.clone(),
+            },
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+        orchestrator.attach_commands(receiver);
+
+        let operator = async {
+            sleep(Duration::from_secs(30)).await;
+            assert_eq!(calls.load(Ordering::SeqCst), 1);
+            control.step().unwrap();
+            sleep(Duration::from_secs(30)).await;
+            assert_eq!(calls.load(Ordering::SeqCst), 2);
+            control.resign().unwrap();
+        };
+        let (result, ()) = tokio::join!(orchestrator.run(), operator);
+        result.unwrap();
+
+        // Turn 1 (our move), then the stepped turn (reply and our move).
+        assert_eq!(orchestrator.game_record().moves.len(), 3);
+        let events = telemetry.snapshot_events().await;
+        let announcements: Vec<_> = events
+            .iter()
+            .filter_map(|e| match &e.payload {
+                EventPayload::Ops(ops) if ops.tags.iter().any(|t| t == "control") => {
+                    Some(ops.message.as_str())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            announcements,
+            ["paused: no turns until resumed", "stepping one turn"]
+        );
+        // Status captures keep the board published while paused.
+        assert!(board_events(&events).len() > 10);
+    }
+
+    #[tokio::test]
+    async fn persisted_match_includes_controller_summary() {
+        let dir = std::env::temp_dir().join(format!("minerva-controller-{}", Uuid::new_v4()));
+        let telemetry = TelemetryStore::with_persistence(&dir);
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(3),
+            mock_controller(),
+            EchoRecognizer::default(),
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        let recorded = &telemetry.snapshot_matches().await[0];
+        let match_file = telemetry
+            .match_dir(recorded.session_id, recorded.match_id)
+            .unwrap()
+            .join(minerva_ops::MATCH_FILE);
+        let persisted: MatchTelemetry =
+            serde_json::from_str(&std::fs::read_to_string(&match_file).unwrap()).unwrap();
+        let summary = persisted.controller_summary.expect("controller summary");
+        let counters = orchestrator.controller.metrics();
+        assert!(summary.successful_inputs > 0);
+        assert_eq!(summary.successful_inputs, counters.successful_inputs);
+        assert_eq!(summary.failed_inputs, 0);
+
+        let end_details = telemetry
+            .snapshot_events()
+            .await
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Lifecycle(LifecycleEvent {
+                    phase: LifecyclePhase::MatchEnd,
+                    details,
+                }) => details.clone(),
+                _ => None,
+            })
+            .unwrap_or_default();
+        assert!(end_details.contains(&format!("inputs ok={}", counters.successful_inputs)));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[tokio::test]
+    async fn match_result_summarizes_a_played_match() {
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(3),
+            mock_controller(),
+            EchoRecognizer::default(),
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.run().await.unwrap();
+
+        let events = telemetry.snapshot_events().await;
+        let position = events
+            .iter()
+            .position(|e| matches!(e.payload, EventPayload::MatchResult(_)))
+            .expect("match result event");
+        let EventPayload::MatchResult(summary) = &events[position].payload else {
+            unreachable!();
+        };
+        assert_eq!(events[position].kind, EventKind::Lifecycle);
+        assert!(events[position].match_id.is_some());
+        assert!(summary.result.is_none());
+        // Our two moves plus the opponent reply seen in between.
+        assert_eq!(summary.plies, 3);
+        let recorded = &telemetry.snapshot_matches().await[0];
+        assert_eq!(
+            summary.total_nodes,
+            recorded.engine_history.iter().map(|m| m.nodes).sum::<u64>()
+        );
+        let final_board = BoardState::from_fen(summary.final_fen.as_deref().unwrap()).unwrap();
+        assert!(!final_board.differences(&BoardState::initial()).is_empty());
+        assert!(matches!(
+            events[position + 1].payload,
+            EventPayload::Lifecycle(LifecycleEvent {
+                phase: LifecyclePhase::MatchEnd,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn board_event_and_turn_record_carry_saved_frame_path() {
+        let capture_dir = std::env::temp_dir().join(format!("minerva-frames-{}", Uuid::new_v4()));
+        let recognizer = CapturingRecognizer(TemplateMatchingRecognizer::new(VisionConfig {
+            backend: RecognizerBackend::TemplateMatching,
+            template_dir: capture_dir.join("no-templates").display().to_string(),
+            confidence_threshold: 0.8,
+            refresh_interval_ms: 0,
+            capture_dir: Some(capture_dir.display().to_string()),
+            tile_capture_dir: None,
+            capture_every_n: 1,
+            realign_low_confidence_squares: 90,
+            highlight_color: None,
+            clock_regions: None,
+            stable_captures: 1,
+            stable_capture_delay_ms: 80,
+            max_frame_age_ms: 2_000,
+            capture_region_margin: None,
+            popups: Vec::new(),
+        }));
+        let telemetry = TelemetryStore::new();
+        let mut orchestrator = Orchestrator::new(
+            orchestrator_config(1),
+            mock_controller().with_frame(ImageFrame::from_rgba(4, 4, vec![128; 4 * 4 * 4])),
+            recognizer,
+            RuleBasedEngine::new(),
+            LocalServer::new(16),
+            telemetry.clone(),
+        );
+
+        orchestrator.play_turn().await.unwrap();
+
+        let frame_path = telemetry
+            .snapshot_events()
+            .await
+            .iter()
+            .find_map(|e| match &e.payload {
+                EventPayload::Board(board) => Some(board.frame_path.clone()),
+                _ => None,
+            })
+            .flatten()
+            .expect("board event should carry the saved frame");
+        assert!(frame_path.is_file());
+        assert_eq!(
+            frame_path.extension().and_then(|ext| ext.to_str()),
+            Some("png")
+        );
+        let turns = &orchestrator.match_telemetry.turns;
+        assert_eq!(turns.len(), 1);
+        assert_eq!(turns[0].frame_path.as_ref(), Some(&frame_path));
+
+        std::fs::remove_dir_all(&capture_dir).ok();
+    }
+}
@@ -0,0 +1,91 @@
+//! Hooks called around the stages of a turn.
+
+use async_trait::async_trait;
+use minerva_types::{
+    game::{EngineDecision, GameSnapshot, Move},
+    telemetry::TurnRecord,
+    vision::ImageFrame,
+    Result,
+};
+use tracing::debug;
+
+/// Watches a match turn by turn. Every callback defaults to doing nothing;
+/// an error is logged by the orchestrator and never fails the turn.
+#[async_trait]
+pub trait TurnObserver: Send + Sync {
+    /// Every frame captured, including the polls while the opponent thinks.
+    async fn on_frame_captured(&self, _frame: &ImageFrame) -> Result<()> {
+        Ok(())
+    }
+
+    /// The board a turn settled on, once per turn after reconciliation.
+    async fn on_board_recognized(&self, _snapshot: &GameSnapshot) -> Result<()> {
+        Ok(())
+    }
+
+    /// Each engine decision the turn acts on, after any forced move.
+    async fn on_decision(&self, _decision: &EngineDecision) -> Result<()> {
+        Ok(())
+    }
+
+    /// Our move, once the client has accepted it.
+    async fn on_move_applied(&self, _mv: &Move) -> Result<()> {
+        Ok(())
+    }
+
+    /// A turn we evaluated, once its record is written.
+    async fn on_turn_complete(&self, _turn: &TurnRecord) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Logs every stage at debug level.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TracingObserver;
+
+#[async_trait]
+impl TurnObserver for TracingObserver {
+    async fn on_frame_captured(&self, frame: &ImageFrame) -> Result<()> {
+        debug!(
+            "프레임 캡처: {}x{} ({}, {})",
+            frame.width, frame.height, frame.origin.x, frame.origin.y
+        );
+        Ok(())
+    }
+
+    async fn on_board_recognized(&self, snapshot: &GameSnapshot) -> Result<()> {
+        debug!(
+            "보드 인식: {}수, {:?} 차례, 낮은 신뢰도 {}칸",
+            snapshot.ply,
+            snapshot.board.side_to_move,
+            snapshot.recognition.low_confidence.len()
+        );
+        Ok(())
+    }
+
+    async fn on_decision(&self, decision: &EngineDecision) -> Result<()> {
+        match &decision.best_move {
+            Some(mv) => debug!(
+                "엔진 결정: {} -> {} (평가 {:.2})",
+                mv.from, mv.to, decision.score
+            ),
+            None => debug!("엔진 결정: 수 없음 (평가 {:.2})", decision.score),
+        }
+        Ok(())
+    }
+
+    async fn on_move_applied(&self, mv: &Move) -> Result<()> {
+        debug!("수 적용: {} -> {}", mv.from, mv.to);
+        Ok(())
+    }
+
+    async fn on_turn_complete(&self, turn: &TurnRecord) -> Result<()> {
+        debug!(
+            "턴 {} 완료: {}ms, 이상 {}건",
+            turn.index,
+            turn.latency.total_ms,
+            turn.anomalies.len()
+        );
+        Ok(())
+    }
+}
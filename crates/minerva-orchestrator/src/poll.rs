@@ -0,0 +1,122 @@
+//! Cadence of the opponent-wait polls (see [`PollSchedule`]).
+
+use minerva_types::config::PollSchedule;
+use tokio::time::{Duration, Instant};
+
+/// Interval between polls while waiting for one opponent move.
+pub(crate) struct PollCadence {
+    schedule: Option<PollSchedule>,
+    /// Interval used without a schedule.
+    constant: Duration,
+    /// When the wait started or the screen last changed.
+    changed_at: Instant,
+    current: Duration,
+}
+
+impl PollCadence {
+    pub(crate) fn new(schedule: Option<PollSchedule>, constant: Duration, now: Instant) -> Self {
+        Self {
+            schedule,
+            constant,
+            changed_at: now,
+            current: Duration::ZERO,
+        }
+    }
+
+    /// The screen changed at `now`: poll fast again.
+    pub(crate) fn reset(&mut self, now: Instant) {
+        self.changed_at = now;
+    }
+
+    /// Interval before the poll after one taken at `now`, before jitter.
+    pub(crate) fn next(&mut self, now: Instant) -> Duration {
+        let Some(schedule) = self.schedule else {
+            self.current = self.constant;
+            return self.current;
+        };
+        let fast = Duration::from_millis(schedule.fast_interval_ms);
+        let in_window = now.saturating_duration_since(self.changed_at)
+            < Duration::from_millis(schedule.fast_window_ms);
+        self.current = if in_window {
+            fast
+        } else {
+            self.current
+                .max(fast)
+                .mul_f32(schedule.backoff.max(1.0))
+                .min(Duration::from_millis(schedule.max_interval_ms))
+        };
+        self.current
+    }
+
+    /// Random spread to apply to each interval.
+    pub(crate) fn jitter(&self) -> f32 {
+        self.schedule.map_or(0.0, |schedule| schedule.jitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> PollSchedule {
+        PollSchedule {
+            fast_interval_ms: 200,
+            fast_window_ms: 1_000,
+            backoff: 2.0,
+            max_interval_ms: 1_600,
+            jitter: 0.0,
+        }
+    }
+
+    /// Polls `count` times starting at `start`, each after the interval
+    /// the previous one chose, resetting on the polls listed in `changes`.
+    fn intervals(
+        cadence: &mut PollCadence,
+        start: Instant,
+        count: usize,
+        changes: &[usize],
+    ) -> Vec<u64> {
+        let mut now = start;
+        (0..count)
+            .map(|poll| {
+                if changes.contains(&poll) {
+                    cadence.reset(now);
+                }
+                let interval = cadence.next(now);
+                now += interval;
+                interval.as_millis() as u64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn polls_fast_then_back_off_to_the_cap() {
+        let start = Instant::now();
+        let mut cadence = PollCadence::new(Some(schedule()), Duration::from_millis(500), start);
+
+        assert_eq!(
+            intervals(&mut cadence, start, 10, &[]),
+            [200, 200, 200, 200, 200, 400, 800, 1_600, 1_600, 1_600]
+        );
+    }
+
+    #[test]
+    fn a_screen_change_restarts_the_fast_window() {
+        let start = Instant::now();
+        let mut cadence = PollCadence::new(Some(schedule()), Duration::from_millis(500), start);
+
+        assert_eq!(
+            intervals(&mut cadence, start, 10, &[7]),
+            [200, 200, 200, 200, 200, 400, 800, 200, 200, 200]
+        );
+    }
+
+    #[test]
+    fn without_a_schedule_the_interval_is_constant() {
+        let start = Instant::now();
+        let mut cadence = PollCadence::new(None, Duration::from_millis(500), start);
+
+        assert_eq!(intervals(&mut cadence, start, 3, &[1]), [500, 500, 500]);
+        assert_eq!(cadence.jitter(), 0.0);
+    }
+}
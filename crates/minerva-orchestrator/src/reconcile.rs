@@ -0,0 +1,275 @@
+//! Reconciling recognized boards against the internally tracked game state.
+
+use std::collections::HashMap;
+
+use minerva_types::{
+    board::{material_count, BoardState, Square},
+    game::{GameSnapshot, Move},
+};
+
+/// How a recognized board relates to the tracked one.
+#[derive(Debug, Clone)]
+pub enum Reconciliation {
+    /// The boards agree.
+    Unchanged,
+    /// The recognized board is the tracked board after this legal move by the
+    /// side to move; any other disagreement is on low-confidence squares.
+    Move(Move),
+    /// Every disagreement is on a low-confidence square; the tracked board stands.
+    Noise,
+    /// Confidently read squares that no single legal move explains.
+    Desync(Vec<Square>),
+}
+
+/// Classifies `recognized` against `tracked`, whose side to move is the side
+/// expected to have moved. A move is accepted only when exactly one legal move
+/// accounts for every confidently read difference.
+pub fn reconcile(tracked: &GameSnapshot, recognized: &GameSnapshot) -> Reconciliation {
+    let low_confidence = &recognized.recognition.low_confidence;
+    let confident_diffs = |board: &BoardState| -> Vec<Square> {
+        board
+            .differences(&recognized.board)
+            .into_iter()
+            .map(|diff| diff.square)
+            .filter(|square| !low_confidence.contains(square))
+            .collect()
+    };
+
+    let diffs = tracked.board.differences(&recognized.board);
+    if diffs.is_empty() {
+        return Reconciliation::Unchanged;
+    }
+    let conflicting = confident_diffs(&tracked.board);
+    if conflicting.is_empty() {
+        return Reconciliation::Noise;
+    }
+
+    let mover = tracked.board.side_to_move;
+    let mut explaining = tracked.board.legal_moves(mover).into_iter().filter(|mv| {
+        let mut after = tracked.board.clone();
+        after.move_piece(mv.from, mv.to).is_ok() && confident_diffs(&after).is_empty()
+    });
+    match (explaining.next(), explaining.next()) {
+        (Some(mv), None) => Reconciliation::Move(mv),
+        _ => Reconciliation::Desync(conflicting),
+    }
+}
+
+/// The tracked snapshot advanced by `reconciliation`, so ply, last move, and
+/// halfmove clock stay authoritative and vision only contributes its
+/// recognition report. Without a move the recognized side to move is kept
+/// (e.g. after a pass). `None` on a desync, when there is nothing to advance.
+pub fn expected_snapshot(
+    tracked: &GameSnapshot,
+    recognized: &GameSnapshot,
+    reconciliation: &Reconciliation,
+) -> Option<GameSnapshot> {
+    let mut expected = tracked.clone();
+    match reconciliation {
+        Reconciliation::Move(mv) => {
+            expected.apply_move(tracked.board.side_to_move, mv).ok()?;
+        }
+        Reconciliation::Unchanged | Reconciliation::Noise => {
+            expected.board.side_to_move = recognized.board.side_to_move;
+        }
+        Reconciliation::Desync(_) => return None,
+    }
+    expected.recognition = recognized.recognition.clone();
+    expected.clocks = recognized.clocks;
+    expected.created_at = recognized.created_at;
+    Some(expected)
+}
+
+/// Why the confidently read pieces of `recognized` cannot follow `tracked`
+/// by at most one move: a piece count went up, or more than one piece
+/// vanished. `None` when the material change is plausible; low-confidence
+/// squares are taken to hold what the tracked board has there.
+pub fn implausible_material(tracked: &GameSnapshot, recognized: &GameSnapshot) -> Option<String> {
+    let mut board = recognized.board.clone();
+    for &square in &recognized.recognition.low_confidence {
+        board.set_piece(square, tracked.board.piece_at(square));
+    }
+    let before = material_count(&tracked.board);
+    let after = material_count(&board);
+    let mut gained: Vec<_> = after
+        .iter()
+        .filter_map(|(&(side, kind), &count)| {
+            let was = before.get(&(side, kind)).copied().unwrap_or(0);
+            (count > was).then(|| format!("{side:?} {kind:?} count rose from {was} to {count}"))
+        })
+        .collect();
+    if !gained.is_empty() {
+        gained.sort();
+        return Some(gained.join(", "));
+    }
+    let total =
+        |counts: &HashMap<_, u8>| -> u32 { counts.values().map(|&count| u32::from(count)).sum() };
+    let lost = total(&before) - total(&after);
+    (lost > 1).then(|| format!("{lost} pieces vanished in one move"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_types::board::{Piece, PieceKind, PlayerSide};
+
+    /// Tracked initial position after Blue's first soldier push, Red to move.
+    fn tracked() -> GameSnapshot {
+        let mut snapshot = GameSnapshot {
+            board: BoardState::initial(),
+            ..GameSnapshot::default()
+        };
+        snapshot
+            .apply_move(
+                PlayerSide::Blue,
+                &Move::new(Square::new(0, 3), Square::new(0, 4)),
+            )
+            .unwrap();
+        snapshot
+    }
+
+    fn recognized(board: BoardState, low_confidence: &[Square]) -> GameSnapshot {
+        let mut snapshot = GameSnapshot {
+            board,
+            ..GameSnapshot::default()
+        };
+        snapshot.recognition.low_confidence = low_confidence.to_vec();
+        snapshot
+    }
+
+    fn stray_soldier() -> Option<Piece> {
+        Some(Piece {
+            owner: PlayerSide::Blue,
+            kind: PieceKind::Soldier,
+        })
+    }
+
+    #[test]
+    fn clean_opponent_move_is_accepted() {
+        let tracked = tracked();
+        let reply = Move::new(Square::new(8, 6), Square::new(8, 5));
+        let mut board = tracked.board.clone();
+        board.move_piece(reply.from, reply.to).unwrap();
+
+        let outcome = reconcile(&tracked, &recognized(board.clone(), &[]));
+        assert!(
+            matches!(&outcome, Reconciliation::Move(mv) if mv.from == reply.from && mv.to == reply.to)
+        );
+        assert!(matches!(
+            reconcile(&tracked, &recognized(tracked.board.clone(), &[])),
+            Reconciliation::Unchanged
+        ));
+
+        // A misread low-confidence tile on top of the move is dropped.
+        let noisy_square = Square::new(4, 5);
+        board.set_piece(noisy_square, stray_soldier());
+        let noisy = recognized(board, &[noisy_square]);
+        let outcome = reconcile(&tracked, &noisy);
+        assert!(matches!(outcome, Reconciliation::Move(_)));
+        let expected = expected_snapshot(&tracked, &noisy, &outcome).unwrap();
+        assert_eq!(expected.board.piece_at(noisy_square), None);
+        assert!(expected.board.piece_at(reply.to).is_some());
+        assert_eq!(expected.recognition.low_confidence, [noisy_square]);
+        // History advances from the tracked snapshot, not the recognized one.
+        assert_eq!(expected.ply, 2);
+        assert_eq!(expected.halfmove_clock, 2);
+        assert_eq!(expected.board.side_to_move, PlayerSide::Blue);
+        assert!(expected
+            .last_move
+            .is_some_and(|mv| mv.from == reply.from && mv.to == reply.to));
+    }
+
+    #[test]
+    fn sideways_and_palace_diagonal_replies_are_moves() {
+        let explains = |tracked: &GameSnapshot, reply: Move| {
+            let mut board = tracked.board.clone();
+            board.move_piece(reply.from, reply.to).unwrap();
+            matches!(
+                reconcile(tracked, &recognized(board, &[])),
+                Reconciliation::Move(mv) if mv.from == reply.from && mv.to == reply.to
+            )
+        };
+        // A soldier steps sideways on its own side of the board.
+        assert!(explains(
+            &tracked(),
+            Move::new(Square::new(0, 6), Square::new(1, 6))
+        ));
+        // A chariot runs from a palace corner through the empty center.
+        let palace = GameSnapshot {
+            board: BoardState::from_fen("4kr3/9/9/9/9/9/9/9/9/4K4 b").unwrap(),
+            ..GameSnapshot::default()
+        };
+        assert!(explains(
+            &palace,
+            Move::new(Square::new(5, 9), Square::new(3, 7))
+        ));
+    }
+
+    #[test]
+    fn single_low_confidence_tile_keeps_the_tracked_board() {
+        let tracked = tracked();
+        let noisy_square = Square::new(4, 5);
+        let mut board = tracked.board.clone();
+        board.set_piece(noisy_square, stray_soldier());
+        let noisy = recognized(board, &[noisy_square]);
+
+        let outcome = reconcile(&tracked, &noisy);
+        assert!(matches!(outcome, Reconciliation::Noise));
+        let expected = expected_snapshot(&tracked, &noisy, &outcome).unwrap();
+        assert_eq!(expected.board.differences(&tracked.board).len(), 0);
+        assert_eq!(
+            expected
+                .board
+                .differences(&noisy.board)
+                .iter()
+                .map(|diff| diff.square)
+                .collect::<Vec<_>>(),
+            [noisy_square]
+        );
+        assert_eq!(expected.ply, tracked.ply);
+    }
+
+    #[test]
+    fn unexplained_confident_diffs_are_a_desync() {
+        let tracked = tracked();
+        // Confidently read stray piece: not a move, not noise.
+        let mut board = tracked.board.clone();
+        board.set_piece(Square::new(4, 5), stray_soldier());
+        assert!(matches!(
+            reconcile(&tracked, &recognized(board, &[])),
+            Reconciliation::Desync(squares) if squares == [Square::new(4, 5)]
+        ));
+
+        // A different position altogether.
+        let desynced = recognized(BoardState::empty(), &[]);
+        let outcome = reconcile(&tracked, &desynced);
+        assert!(matches!(&outcome, Reconciliation::Desync(squares) if squares.len() == 32));
+        assert!(expected_snapshot(&tracked, &desynced, &outcome).is_none());
+    }
+
+    #[test]
+    fn implausible_material_changes_are_flagged() {
+        let tracked = tracked();
+        // A capture takes one piece off.
+        let mut board = tracked.board.clone();
+        board.set_piece(Square::new(0, 4), None);
+        assert!(implausible_material(&tracked, &recognized(board, &[])).is_none());
+
+        // An extra soldier cannot appear.
+        let mut board = tracked.board.clone();
+        board.set_piece(Square::new(4, 5), stray_soldier());
+        let change = implausible_material(&tracked, &recognized(board.clone(), &[])).unwrap();
+        assert_eq!(change, "Blue Soldier count rose from 5 to 6");
+        // Unless it is only a low-confidence read.
+        assert!(implausible_material(&tracked, &recognized(board, &[Square::new(4, 5)])).is_none());
+
+        // Two pieces cannot vanish at once.
+        let mut board = tracked.board.clone();
+        board.set_piece(Square::new(2, 3), None);
+        board.set_piece(Square::new(4, 3), None);
+        assert_eq!(
+            implausible_material(&tracked, &recognized(board, &[])).as_deref(),
+            Some("2 pieces vanished in one move")
+        );
+    }
+}
@@ -0,0 +1,628 @@
+//! Scripted dependencies for driving whole matches in tests.
+//!
+//! [`ScenarioBuilder`] wires a [`ScriptedController`], [`ScriptedRecognizer`]
+//! and [`ScriptedEngine`] to a [`LocalServer`] and [`TelemetryStore`], runs
+//! the match, and hands back what happened as a [`ScenarioResult`]. Enabled
+//! for other crates by the `test-util` feature.
+
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+use async_trait::async_trait;
+use minerva_controller::{ControllerMetrics, DeviceController, InputAction, MockController};
+use minerva_engine::GameEngine;
+use minerva_network::LocalServer;
+use minerva_ops::TelemetryStore;
+use minerva_types::{
+    board::{BoardState, PlayerSide, Square},
+    config::{
+        EmulatorConfig, OrchestratorConfig, OrchestratorMode, RetryPolicy, ScreencapFormat,
+        TimingConfig,
+    },
+    events::SystemEvent,
+    game::{EngineDecision, GameResult, GameSnapshot, Move, TurnContext},
+    time_control::TimeControl,
+    ui::{BoardGeometry, DialogButtons, FormationPreset, MatchDialog, Point},
+    vision::ImageFrame,
+    MinervaError, Result,
+};
+use minerva_vision::{BoardRecognizer, RecognitionHints};
+
+use crate::{MatchRunner, Orchestrator};
+
+/// The orchestrator a scenario runs.
+pub type ScenarioOrchestrator =
+    Orchestrator<ScriptedController, ScriptedRecognizer, ScriptedEngine, LocalServer>;
+
+/// Orchestrator settings for scenarios: Blue-to-move openings, one retry,
+/// and no post-move look, since scripted boards only advance when read.
+pub fn scenario_config(max_plies: u32) -> OrchestratorConfig {
+    OrchestratorConfig {
+        mode: OrchestratorMode::Play,
+        time_control: TimeControl::blitz(),
+        max_retries: 1,
+        formation: FormationPreset::default(),
+        opponent_formation: None,
+        start_fen: None,
+        opponent_timeout_secs: 300,
+        draw_after_halfmoves: None,
+        max_plies,
+        skip_unchanged_boards: false,
+        log_board: false,
+        our_side: None,
+        low_time_warning_ms: None,
+        turn_deadline_ms: 30_000,
+        resign_threshold: None,
+        resign_persistence: 3,
+        resign_min_plies: 20,
+        max_move_rejections: 0,
+        pipeline: false,
+        poll_schedule: None,
+        accept_draw_below: -2.0,
+        dialog_buttons: DialogButtons::default(),
+        min_move_delay_ms: 0,
+        max_move_delay_ms: None,
+        resume_max_age_secs: 600,
+        dry_run: false,
+        max_anomaly_score: None,
+        back_on_anomaly_abort: false,
+    }
+}
+
+/// Emulator settings for [`ScriptedController`]: the default board geometry
+/// and no pause between inputs.
+pub fn scenario_emulator_config() -> EmulatorConfig {
+    EmulatorConfig {
+        serial: "scenario".into(),
+        socket: "scenario".into(),
+        fixed_resolution: None,
+        adb_path: None,
+        tap_offset: (0, 0),
+        geometry: BoardGeometry::default(),
+        screencap_format: ScreencapFormat::Png,
+        retry: RetryPolicy::default(),
+        timing: TimingConfig {
+            inter_action_ms: 0,
+            ..TimingConfig::default()
+        },
+        batch_inputs: true,
+        tap_jitter_px: None,
+    }
+}
+
+/// The taps [`ScriptedController`] records for playing `mv`.
+pub fn move_taps(mv: &Move) -> Vec<InputAction> {
+    let geometry = BoardGeometry::default();
+    [mv.from, mv.to]
+        .into_iter()
+        .filter_map(|square| geometry.square_to_point(square))
+        .map(|Point { x, y }| InputAction::Tap { x, y })
+        .collect()
+}
+
+/// Returns `items` front to back, repeating the last one once the rest are
+/// used up; `None` only when nothing was scripted.
+fn next_scripted<T: Clone>(items: &Mutex<VecDeque<T>>) -> Option<T> {
+    let mut items = items
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if items.len() > 1 {
+        items.pop_front()
+    } else {
+        items.front().cloned()
+    }
+}
+
+/// Controller that returns scripted frames and records every input.
+pub struct ScriptedController {
+    inner: MockController,
+    frames: Mutex<VecDeque<ImageFrame>>,
+}
+
+impl ScriptedController {
+    /// Captures `frames` in order, repeating the last; an empty script
+    /// captures empty frames.
+    pub fn new(frames: Vec<ImageFrame>) -> Self {
+        Self {
+            inner: MockController::new(scenario_emulator_config()),
+            frames: Mutex::new(frames.into()),
+        }
+    }
+
+    pub fn recorded_actions(&self) -> Vec<InputAction> {
+        self.inner.recorded_actions()
+    }
+}
+
+#[async_trait]
+impl DeviceController for ScriptedController {
+    async fn connect(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn capture_frame(&self) -> Result<ImageFrame> {
+        Ok(next_scripted(&self.frames).unwrap_or_else(ImageFrame::empty))
+    }
+
+    async fn tap_square(&self, square: Square) -> Result<()> {
+        self.inner.tap_square(square).await
+    }
+
+    async fn tap_point(&self, point: Point) -> Result<()> {
+        self.inner.tap_point(point).await
+    }
+
+    async fn inject_actions(&self, actions: Vec<InputAction>) -> Result<()> {
+        self.inner.inject_actions(actions).await
+    }
+
+    fn metrics(&self) -> ControllerMetrics {
+        self.inner.metrics()
+    }
+}
+
+/// One scripted read: the board, and any dialog shown over it.
+#[derive(Debug, Clone)]
+pub struct ScriptedScreen {
+    pub board: BoardState,
+    pub dialog: Option<MatchDialog>,
+}
+
+impl From<BoardState> for ScriptedScreen {
+    fn from(board: BoardState) -> Self {
+        Self {
+            board,
+            dialog: None,
+        }
+    }
+}
+
+/// Recognizer that reads scripted screens in order, repeating the last, over
+/// the tracked snapshot so ply and clocks carry over. The dialog reported
+/// for a frame is the one on the screen last read.
+pub struct ScriptedRecognizer {
+    screens: Mutex<VecDeque<ScriptedScreen>>,
+    dialog: Mutex<Option<MatchDialog>>,
+    calls: AtomicUsize,
+}
+
+impl ScriptedRecognizer {
+    /// Reads `screens` in order; an empty script reads the initial position.
+    pub fn new(screens: Vec<ScriptedScreen>) -> Self {
+        Self {
+            screens: Mutex::new(screens.into()),
+            dialog: Mutex::new(None),
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// Boards recognized so far.
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl BoardRecognizer for ScriptedRecognizer {
+    async fn align_board(&self, _frame: &ImageFrame) -> Result<BoardState> {
+        Ok(BoardState::initial())
+    }
+
+    async fn recognize(
+        &self,
+        _frame: &ImageFrame,
+        hints: RecognitionHints,
+    ) -> Result<GameSnapshot> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let screen = next_scripted(&self.screens)
+            .unwrap_or_else(|| ScriptedScreen::from(BoardState::initial()));
+        *self.dialog.lock().unwrap_or_else(|p| p.into_inner()) = screen.dialog;
+        let mut snapshot = hints.previous_snapshot.unwrap_or_default();
+        snapshot.board = screen.board;
+        Ok(snapshot)
+    }
+
+    fn recognize_dialog(&self, _frame: &ImageFrame) -> Option<MatchDialog> {
+        *self.dialog.lock().unwrap_or_else(|p| p.into_inner())
+    }
+}
+
+/// Engine that answers with scripted decisions in order and fails once they
+/// run out, recording the side it was asked to play each time.
+pub struct ScriptedEngine {
+    decisions: Mutex<VecDeque<EngineDecision>>,
+    sides: Mutex<Vec<PlayerSide>>,
+}
+
+impl ScriptedEngine {
+    pub fn new(decisions: Vec<EngineDecision>) -> Self {
+        Self {
+            decisions: Mutex::new(decisions.into()),
+            sides: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Sides the engine was asked to play, in order.
+    pub fn sides(&self) -> Vec<PlayerSide> {
+        self.sides
+            .lock()
+            .map(|sides| sides.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl GameEngine for ScriptedEngine {
+    async fn warm_up(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn evaluate_position(&self, ctx: &TurnContext) -> Result<EngineDecision> {
+        if let Ok(mut sides) = self.sides.lock() {
+            sides.push(ctx.side);
+        }
+        self.decisions
+            .lock()
+            .ok()
+            .and_then(|mut decisions| decisions.pop_front())
+            .ok_or_else(|| {
+                MinervaError::Engine(format!(
+                    "scripted engine has no decision left at ply {}",
+                    ctx.snapshot.ply
+                ))
+            })
+    }
+}
+
+/// A decision to play `mv` with nothing else reported.
+pub fn decision(mv: Move) -> EngineDecision {
+    EngineDecision {
+        best_move: Some(mv),
+        candidates: Vec::new(),
+        searched_nodes: 1,
+        depth: 1,
+        duration_ms: 0,
+        score: 0.0,
+        gain: 0.0,
+        result: None,
+        reject_reasons: Vec::new(),
+    }
+}
+
+/// Declares a match: what the screen shows, what the engine decides, and
+/// the inputs that should come out.
+pub struct ScenarioBuilder {
+    config: OrchestratorConfig,
+    frames: Vec<ImageFrame>,
+    screens: Vec<ScriptedScreen>,
+    decisions: Vec<EngineDecision>,
+    start_sequence: Option<FormationPreset>,
+    expected_actions: Option<Vec<InputAction>>,
+}
+
+impl Default for ScenarioBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScenarioBuilder {
+    /// A ten-ply match with [`scenario_config`] and nothing scripted.
+    pub fn new() -> Self {
+        Self {
+            config: scenario_config(10),
+            frames: Vec::new(),
+            screens: Vec::new(),
+            decisions: Vec::new(),
+            start_sequence: None,
+            expected_actions: None,
+        }
+    }
+
+    pub fn config(mut self, config: OrchestratorConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Adjusts the orchestrator settings in place.
+    pub fn configure(mut self, adjust: impl FnOnce(&mut OrchestratorConfig)) -> Self {
+        adjust(&mut self.config);
+        self
+    }
+
+    /// Appends a frame for the controller to capture.
+    pub fn frame(mut self, frame: ImageFrame) -> Self {
+        self.frames.push(frame);
+        self
+    }
+
+    /// Appends a board for the recognizer to read.
+    pub fn board(mut self, board: BoardState) -> Self {
+        self.screens.push(board.into());
+        self
+    }
+
+    pub fn boards(mut self, boards: impl IntoIterator<Item = BoardState>) -> Self {
+        self.screens
+            .extend(boards.into_iter().map(ScriptedScreen::from));
+        self
+    }
+
+    /// Appends a read of `dialog` over the last scripted board.
+    pub fn dialog(mut self, dialog: MatchDialog) -> Self {
+        let board = self
+            .screens
+            .last()
+            .map_or_else(BoardState::initial, |screen| screen.board.clone());
+        self.screens.push(ScriptedScreen {
+            board,
+            dialog: Some(dialog),
+        });
+        self
+    }
+
+    /// Appends an engine decision.
+    pub fn decision(mut self, decision: EngineDecision) -> Self {
+        self.decisions.push(decision);
+        self
+    }
+
+    /// Appends an engine decision to play `mv`.
+    pub fn engine_move(self, mv: Move) -> Self {
+        self.decision(decision(mv))
+    }
+
+    /// Runs the start sequence for `formation` before the match, as boot
+    /// does outside spectator mode.
+    pub fn start_sequence(mut self, formation: FormationPreset) -> Self {
+        self.start_sequence = Some(formation);
+        self
+    }
+
+    /// Inputs the scenario must produce, in order; [`Self::run`] panics
+    /// when the recorded ones differ.
+    pub fn expect_actions(mut self, actions: impl IntoIterator<Item = InputAction>) -> Self {
+        self.expected_actions = Some(actions.into_iter().collect());
+        self
+    }
+
+    /// Builds the orchestrator without running anything.
+    pub fn build(&self) -> ScenarioOrchestrator {
+        Orchestrator::new(
+            self.config.clone(),
+            ScriptedController::new(self.frames.clone()),
+            ScriptedRecognizer::new(self.screens.clone()),
+            ScriptedEngine::new(self.decisions.clone()),
+            LocalServer::new(64),
+            TelemetryStore::new(),
+        )
+    }
+
+    /// Runs the start sequence, if declared, and then the match.
+    pub async fn run(self) -> ScenarioResult {
+        let mut orchestrator = self.build();
+        let mut result = Ok(());
+        if let Some(formation) = self.start_sequence {
+            result = orchestrator.perform_start_sequence(formation).await;
+        }
+        if result.is_ok() {
+            result = orchestrator.run().await;
+        }
+        let events = orchestrator.telemetry.snapshot_events().await;
+        let actions = orchestrator.controller.recorded_actions();
+        if let Some(expected) = &self.expected_actions {
+            assert_eq!(
+                format!("{actions:?}"),
+                format!("{expected:?}"),
+                "recorded controller actions differ from the scenario"
+            );
+        }
+        ScenarioResult {
+            result,
+            events,
+            actions,
+            orchestrator,
+        }
+    }
+}
+
+/// What a scenario produced.
+pub struct ScenarioResult {
+    /// How [`MatchRunner::run`] (or the start sequence) ended.
+    pub result: Result<()>,
+    /// Every event published, in order.
+    pub events: Vec<SystemEvent>,
+    /// Every input sent to the controller, in order.
+    pub actions: Vec<InputAction>,
+    /// The orchestrator after the match, for anything else worth checking.
+    pub orchestrator: ScenarioOrchestrator,
+}
+
+impl ScenarioResult {
+    pub fn game_result(&self) -> Option<GameResult> {
+        self.orchestrator.game_result()
+    }
+
+    /// Moves recorded for the match, as `(from, to)` pairs.
+    pub fn moves(&self) -> Vec<(Square, Square)> {
+        self.orchestrator
+            .game_record()
+            .moves
+            .iter()
+            .map(|recorded| (recorded.mv.from, recorded.mv.to))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minerva_controller::{
+        dialog_answer_action, formation_action, formation_confirm_action, start_flow_action,
+    };
+    use minerva_types::{
+        events::{EventPayload, LifecyclePhase},
+        game::DrawReason,
+        ui::StartFlowStep,
+    };
+
+    fn played(board: &BoardState, side: PlayerSide, mv: &Move) -> BoardState {
+        let mut next = board.clone();
+        next.move_piece(mv.from, mv.to).unwrap();
+        next.side_to_move = side.opponent();
+        next
+    }
+
+    #[tokio::test]
+    async fn turn_loop_plays_scripted_moves_between_replies() {
+        let ours = [
+            Move::new(Square::new(0, 3), Square::new(0, 4)),
+            Move::new(Square::new(2, 3), Square::new(2, 4)),
+        ];
+        let reply = Move::new(Square::new(8, 6), Square::new(8, 5));
+        let start = BoardState::initial();
+        let after_ours = played(&start, PlayerSide::Blue, &ours[0]);
+        let after_reply = played(&after_ours, PlayerSide::Red, &reply);
+
+        let scenario = ScenarioBuilder::new()
+            .configure(|config| config.max_plies = 3)
+            .boards([start, after_reply])
+            .engine_move(ours[0].clone())
+            .engine_move(ours[1].clone())
+            .expect_actions(ours.iter().flat_map(move_taps))
+            .run()
+            .await;
+
+        scenario.result.as_ref().unwrap();
+        assert_eq!(
+            scenario.moves(),
+            [
+                (ours[0].from, ours[0].to),
+                (reply.from, reply.to),
+                (ours[1].from, ours[1].to)
+            ]
+        );
+        assert_eq!(
+            scenario.orchestrator.engine.sides(),
+            [PlayerSide::Blue, PlayerSide::Blue]
+        );
+        let phases: Vec<_> = scenario
+            .events
+            .iter()
+            .filter_map(|event| match &event.payload {
+                EventPayload::Lifecycle(lifecycle) => Some(lifecycle.phase),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            phases,
+            [LifecyclePhase::MatchStart, LifecyclePhase::MatchEnd]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn start_sequence_taps_through_setup_before_the_match() {
+        let formation = FormationPreset::SangMaMaSang;
+        let scenario = ScenarioBuilder::new()
+            .configure(|config| config.max_plies = 0)
+            .start_sequence(formation)
+            .expect_actions([
+                start_flow_action(StartFlowStep::Apply),
+                start_flow_action(StartFlowStep::ConfirmYes),
+                start_flow_action(StartFlowStep::ConfirmOk),
+                formation_action(formation),
+                formation_confirm_action(),
+            ])
+            .run()
+            .await;
+
+        scenario.result.as_ref().unwrap();
+        assert!(scenario.moves().is_empty());
+    }
+
+    /// The initial position without `side`'s chariots and cannons.
+    fn stripped(side: PlayerSide) -> BoardState {
+        let mut board = BoardState::initial();
+        let ranks = match side {
+            PlayerSide::Blue => [0, 2],
+            PlayerSide::Red => [9, 7],
+        };
+        for (file, rank) in [(0, ranks[0]), (8, ranks[0]), (1, ranks[1]), (7, ranks[1])] {
+            board.set_piece(Square::new(file, rank), None);
+        }
+        board
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hopeless_position_accepts_a_draw_offer() {
+        let ours = Move::new(Square::new(0, 3), Square::new(0, 4));
+        let start = stripped(PlayerSide::Blue);
+        let after_ours = played(&start, PlayerSide::Blue, &ours);
+
+        let scenario = ScenarioBuilder::new()
+            .boards([start, after_ours])
+            .dialog(MatchDialog::DrawOffer)
+            .engine_move(ours.clone())
+            .expect_actions(
+                move_taps(&ours)
+                    .into_iter()
+                    .chain([dialog_answer_action(DialogButtons::default(), true)]),
+            )
+            .run()
+            .await;
+
+        scenario.result.as_ref().unwrap();
+        assert_eq!(
+            scenario.game_result(),
+            Some(GameResult::Draw {
+                reason: DrawReason::Agreement
+            })
+        );
+        assert!(scenario.events.iter().any(|event| matches!(
+            &event.payload,
+            EventPayload::Ops(ops) if ops.tags.iter().any(|tag| tag == "dialog")
+        )));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn draw_answer_follows_the_position_not_the_best_move() {
+        // Both positions have quiet moves that change nothing, so only the
+        // material on the board tells them apart.
+        for (side_down, accept) in [(PlayerSide::Blue, true), (PlayerSide::Red, false)] {
+            let mut orchestrator = ScenarioBuilder::new().build();
+            orchestrator.last_snapshot = Some(GameSnapshot {
+                board: stripped(side_down),
+                ..GameSnapshot::default()
+            });
+            let mut anomalies = Vec::new();
+
+            let accepted = orchestrator
+                .answer_dialog(MatchDialog::Bikjang, &mut anomalies)
+                .await
+                .unwrap();
+
+            assert_eq!(accepted, accept, "{side_down:?} down");
+            assert_eq!(
+                format!("{:?}", orchestrator.controller.recorded_actions()),
+                format!(
+                    "{:?}",
+                    [dialog_answer_action(DialogButtons::default(), accept)]
+                )
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn running_out_of_decisions_aborts_the_match() {
+        let scenario = ScenarioBuilder::new().run().await;
+
+        let err = scenario.result.unwrap_err();
+        assert!(err.to_string().contains("no decision left"), "{err}");
+        assert!(scenario.actions.is_empty());
+    }
+}
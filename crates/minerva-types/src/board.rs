@@ -1,7 +1,11 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
+use crate::ui::FormationPreset;
+
 /// Represents the two players in a Janggi game.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PlayerSide {
     Blue,
     Red,
@@ -17,7 +21,7 @@ impl PlayerSide {
 }
 
 /// Piece kind in Korean Janggi.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PieceKind {
     General,
     Guard,
@@ -28,8 +32,27 @@ pub enum PieceKind {
     Soldier,
 }
 
+impl PieceKind {
+    /// Korean name used in gibo records and the console (졸 for Blue's
+    /// soldiers, 병 for Red's).
+    pub fn label(self, owner: PlayerSide) -> &'static str {
+        match self {
+            PieceKind::General => "장",
+            PieceKind::Guard => "사",
+            PieceKind::Elephant => "상",
+            PieceKind::Horse => "마",
+            PieceKind::Chariot => "차",
+            PieceKind::Cannon => "포",
+            PieceKind::Soldier => match owner {
+                PlayerSide::Blue => "졸",
+                PlayerSide::Red => "병",
+            },
+        }
+    }
+}
+
 /// Lightweight board coordinate (0-indexed).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Square {
     pub file: u8,
     pub rank: u8,
@@ -53,6 +76,47 @@ impl Square {
             None
         }
     }
+
+    /// The same point seen from the opposite seat, the board turned half
+    /// around: `a1` becomes `i10`.
+    pub fn rotated(self) -> Square {
+        Square::new(
+            BoardState::DEFAULT_WIDTH - 1 - self.file,
+            BoardState::DEFAULT_HEIGHT - 1 - self.rank,
+        )
+    }
+}
+
+/// Algebraic coordinates: file `a`-`i` from the left, rank `1`-`10` from
+/// Blue's side, so `Square::new(4, 2)` is `e3`.
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", (b'a' + self.file) as char, self.rank + 1)
+    }
+}
+
+impl FromStr for Square {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim();
+        let mut chars = normalized.chars();
+        let file = chars
+            .next()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_lowercase() as u8 - b'a')
+            .ok_or_else(|| format!("잘못된 좌표: {normalized}"))?;
+        let rank = chars
+            .as_str()
+            .parse::<u8>()
+            .ok()
+            .and_then(|rank| rank.checked_sub(1))
+            .ok_or_else(|| format!("잘못된 좌표: {normalized}"))?;
+        if file >= BoardState::DEFAULT_WIDTH || rank >= BoardState::DEFAULT_HEIGHT {
+            return Err(format!("보드 밖 좌표: {normalized}"));
+        }
+        Ok(Square::new(file, rank))
+    }
 }
 
 /// Piece with its owner and optional promotion metadata.
@@ -97,6 +161,75 @@ impl BoardState {
         board
     }
 
+    /// The starting position with `side`'s horses and elephants arranged as
+    /// `preset`. Blue sits at rank 0 and Red at the top, whose files read
+    /// mirrored from its seat; the other side keeps the layout of
+    /// [`Self::initial`], since its formation is its own choice.
+    pub fn initial_for(preset: FormationPreset, side: PlayerSide) -> Self {
+        let mut board = Self::initial();
+        board.place_formation(preset, side);
+        board
+    }
+
+    /// Puts `side`'s horses and elephants on its back rank as `preset`.
+    pub fn place_formation(&mut self, preset: FormationPreset, side: PlayerSide) {
+        let squares = self.formation_squares(side);
+        for (square, kind) in squares.into_iter().zip(preset.minor_pieces()) {
+            self.set_piece(square, Some(Piece { owner: side, kind }));
+        }
+    }
+
+    /// The formation `side`'s back rank shows, if its horses and elephants
+    /// all stand where one of the presets puts them.
+    pub fn formation_of(&self, side: PlayerSide) -> Option<FormationPreset> {
+        let kinds = self.formation_squares(side).map(|square| {
+            self.piece_at(square)
+                .filter(|piece| piece.owner == side)
+                .map(|piece| piece.kind)
+        });
+        FormationPreset::ALL
+            .into_iter()
+            .find(|preset| kinds == preset.minor_pieces().map(Some))
+    }
+
+    /// `side`'s [`FormationPreset::MINOR_FILES`], left to right from its
+    /// seat: Blue sits at rank 0, Red at the top, facing it.
+    fn formation_squares(&self, side: PlayerSide) -> [Square; 4] {
+        let mut files = FormationPreset::MINOR_FILES;
+        let rank = match side {
+            PlayerSide::Blue => 0,
+            PlayerSide::Red => {
+                files.reverse();
+                self.height - 1
+            }
+        };
+        files.map(|file| Square::new(file, rank))
+    }
+
+    /// Stable FNV-1a hash of the piece placement and side to move.
+    pub fn position_hash(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        let side = self.side_to_move as u8;
+        let cells = self.pieces.iter().map(|cell| match cell {
+            Some(piece) => 1 + (piece.owner as u8) * 7 + piece.kind as u8,
+            None => 0,
+        });
+        std::iter::once(side)
+            .chain(cells)
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Cheap identity of the position for change detection: two boards
+    /// with the same pieces and side to move share it, and any change to
+    /// either almost surely alters it. The same value as
+    /// [`Self::position_hash`], so it can be compared with stored hashes.
+    pub fn fingerprint(&self) -> u64 {
+        self.position_hash()
+    }
+
     pub fn index(&self, square: Square) -> Option<usize> {
         if square.file < self.width && square.rank < self.height {
             Some((square.rank as usize) * (self.width as usize) + square.file as usize)
@@ -123,22 +256,32 @@ impl BoardState {
     pub fn move_piece(&mut self, from: Square, to: Square) -> Result<Option<Piece>, String> {
         let moving = self
             .piece_at(from)
-            .ok_or_else(|| format!("원점에 기물이 없습니다: ({},{})", from.file, from.rank))?;
+            .ok_or_else(|| format!("원점에 기물이 없습니다: {from}"))?;
         let captured = self.piece_at(to);
         if !self.set_piece(to, Some(moving)) {
-            return Err(format!(
-                "목표 좌표가 유효하지 않습니다: ({},{})",
-                to.file, to.rank
-            ));
+            return Err(format!("목표 좌표가 유효하지 않습니다: {to}"));
         }
         self.set_piece(from, None);
         Ok(captured)
     }
 
+    /// The board seen from the opposite seat: turned half around, each piece
+    /// keeping its owner. Rotating twice gives the board back.
+    pub fn rotated(&self) -> Self {
+        Self {
+            side_to_move: self.side_to_move,
+            pieces: self.pieces.iter().rev().copied().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
     pub fn is_empty(&self, square: Square) -> bool {
         self.piece_at(square).is_none()
     }
 
+    /// Squares whose piece differs from `other`, rank by rank from rank 0
+    /// and file by file within a rank.
     pub fn differences(&self, other: &BoardState) -> Vec<BoardDiff> {
         let mut diffs = Vec::new();
         let width = self.width.min(other.width);
@@ -160,38 +303,23 @@ impl BoardState {
         diffs
     }
 
+    /// The move that explains `diffs`: one square vacated and one filled,
+    /// the latter possibly by a capture. A Janggi move always changes exactly
+    /// two squares, so any other set of diffs (an extra misread tile, two
+    /// pieces appearing) is ambiguous and yields `None` rather than a guess.
     pub fn infer_move_from_diffs(
         diffs: &[BoardDiff],
     ) -> Option<(Square, Square, Piece, Option<Piece>)> {
-        let mut from_square = None;
-        let mut to_square = None;
-        let mut moving_piece = None;
-        let mut captured_piece = None;
-
-        for diff in diffs {
-            match (diff.before, diff.after) {
-                (Some(before), None) => {
-                    from_square = Some(diff.square);
-                    moving_piece = Some(before);
-                }
-                (None, Some(after)) => {
-                    to_square = Some(diff.square);
-                    moving_piece = Some(after);
-                }
-                (Some(before), Some(after)) if before != after => {
-                    to_square = Some(diff.square);
-                    moving_piece = Some(after);
-                    captured_piece = Some(before);
-                }
-                _ => {}
-            }
-        }
-
-        if let (Some(from), Some(to), Some(piece)) = (from_square, to_square, moving_piece) {
-            Some((from, to, piece, captured_piece))
-        } else {
-            None
-        }
+        let [a, b] = diffs else {
+            return None;
+        };
+        let (from, to) = match (a.after, b.after) {
+            (None, Some(_)) => (a, b),
+            (Some(_), None) => (b, a),
+            _ => return None,
+        };
+        from.before?;
+        Some((from.square, to.square, to.after?, to.before))
     }
 
     fn setup_initial_positions(&mut self) {
@@ -260,6 +388,16 @@ impl BoardState {
     }
 }
 
+/// How many pieces of each kind each side has on `board`; kinds a side has
+/// none of are absent.
+pub fn material_count(board: &BoardState) -> HashMap<(PlayerSide, PieceKind), u8> {
+    let mut counts = HashMap::new();
+    for piece in board.pieces.iter().flatten() {
+        *counts.entry((piece.owner, piece.kind)).or_insert(0) += 1;
+    }
+    counts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,6 +411,67 @@ mod tests {
         assert!(board.index(invalid).is_none());
     }
 
+    #[test]
+    fn rotation_seats_the_board_for_the_other_side() {
+        assert_eq!(Square::new(0, 0).rotated(), Square::new(8, 9));
+        assert_eq!(Square::new(4, 2).rotated(), Square::new(4, 7));
+
+        let board = BoardState::initial();
+        let rotated = board.rotated();
+        let blue_general = board.find_general(PlayerSide::Blue).unwrap();
+        assert_eq!(
+            rotated.find_general(PlayerSide::Blue),
+            Some(blue_general.rotated())
+        );
+        assert_eq!(rotated.rotated().to_fen(), board.to_fen());
+    }
+
+    #[test]
+    fn fingerprint_tracks_pieces_and_side_to_move() {
+        let board = BoardState::initial();
+        assert_eq!(board.fingerprint(), BoardState::initial().fingerprint());
+
+        let mut moved = board.clone();
+        moved
+            .move_piece(Square::new(0, 3), Square::new(0, 4))
+            .unwrap();
+        assert_ne!(moved.fingerprint(), board.fingerprint());
+
+        let mut other_side = board.clone();
+        other_side.side_to_move = PlayerSide::Red;
+        assert_ne!(other_side.fingerprint(), board.fingerprint());
+    }
+
+    #[test]
+    fn material_count_tallies_each_side_and_kind() {
+        let mut board = BoardState::initial();
+        let counts = material_count(&board);
+        assert_eq!(counts[&(PlayerSide::Blue, PieceKind::Soldier)], 5);
+        assert_eq!(counts[&(PlayerSide::Red, PieceKind::Chariot)], 2);
+        assert_eq!(counts[&(PlayerSide::Red, PieceKind::General)], 1);
+        assert_eq!(counts.values().map(|&n| u32::from(n)).sum::<u32>(), 32);
+
+        board.set_piece(Square::new(0, 3), None);
+        let counts = material_count(&board);
+        assert_eq!(counts[&(PlayerSide::Blue, PieceKind::Soldier)], 4);
+    }
+
+    #[test]
+    fn square_algebraic_round_trip() {
+        assert_eq!(Square::new(4, 2).to_string(), "e3");
+        assert_eq!(Square::new(0, 9).to_string(), "a10");
+        for rank in 0..BoardState::DEFAULT_HEIGHT {
+            for file in 0..BoardState::DEFAULT_WIDTH {
+                let square = Square::new(file, rank);
+                assert_eq!(square.to_string().parse::<Square>(), Ok(square));
+            }
+        }
+        assert_eq!(" E3 ".parse::<Square>(), Ok(Square::new(4, 2)));
+        for invalid in ["", "e", "e0", "e11", "j1", "3e", "e3x", "e-1"] {
+            assert!(invalid.parse::<Square>().is_err(), "{invalid}");
+        }
+    }
+
     #[test]
     fn opponent_switch() {
         assert_eq!(PlayerSide::Blue.opponent(), PlayerSide::Red);
@@ -305,6 +504,52 @@ mod tests {
             .is_some());
     }
 
+    #[test]
+    fn formation_layouts_arrange_one_sides_back_rank() {
+        let back_rank = |board: &BoardState, rank: u8| -> Vec<PieceKind> {
+            FormationPreset::MINOR_FILES
+                .iter()
+                .map(|&file| board.piece_at(Square::new(file, rank)).unwrap().kind)
+                .collect()
+        };
+        use PieceKind::{Elephant as Sang, Horse as Ma};
+
+        let blue = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Blue);
+        assert_eq!(back_rank(&blue, 0), [Sang, Ma, Sang, Ma]);
+        assert_eq!(back_rank(&blue, 9), [Ma, Sang, Sang, Ma]);
+        assert_eq!(
+            blue.piece_at(Square::new(1, 0)).unwrap().owner,
+            PlayerSide::Blue
+        );
+
+        // Red's left is the board's right.
+        let red = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Red);
+        assert_eq!(back_rank(&red, 9), [Ma, Sang, Ma, Sang]);
+        assert_eq!(back_rank(&red, 0), [Ma, Sang, Sang, Ma]);
+
+        let standard = BoardState::initial_for(FormationPreset::MasangSangMa, PlayerSide::Red);
+        assert!(standard.differences(&BoardState::initial()).is_empty());
+    }
+
+    #[test]
+    fn each_sides_formation_is_read_from_its_back_rank() {
+        let mut board = BoardState::initial_for(FormationPreset::SangMasangMa, PlayerSide::Blue);
+        board.place_formation(FormationPreset::MasangMasang, PlayerSide::Red);
+        assert_eq!(
+            board.formation_of(PlayerSide::Blue),
+            Some(FormationPreset::SangMasangMa)
+        );
+        assert_eq!(
+            board.formation_of(PlayerSide::Red),
+            Some(FormationPreset::MasangMasang)
+        );
+
+        board
+            .move_piece(Square::new(1, 0), Square::new(3, 2))
+            .unwrap();
+        assert_eq!(board.formation_of(PlayerSide::Blue), None);
+    }
+
     #[test]
     fn board_differences_detect_changes() {
         let a = BoardState::initial();
@@ -318,4 +563,54 @@ mod tests {
         assert_eq!(inferred.0, from);
         assert_eq!(inferred.1, to);
     }
+
+    #[test]
+    fn captures_are_inferred_from_two_diffs() {
+        let mut before = BoardState::empty();
+        let chariot = Piece {
+            owner: PlayerSide::Blue,
+            kind: PieceKind::Chariot,
+        };
+        let soldier = Piece {
+            owner: PlayerSide::Red,
+            kind: PieceKind::Soldier,
+        };
+        let (from, to) = (Square::new(0, 0), Square::new(0, 6));
+        before.set_piece(from, Some(chariot));
+        before.set_piece(to, Some(soldier));
+        let mut after = before.clone();
+        after.move_piece(from, to).unwrap();
+
+        let inferred = BoardState::infer_move_from_diffs(&before.differences(&after));
+        assert_eq!(inferred, Some((from, to, chariot, Some(soldier))));
+    }
+
+    #[test]
+    fn ambiguous_diffs_infer_no_move() {
+        let before = BoardState::initial();
+        let mut after = before.clone();
+        after
+            .move_piece(Square::new(0, 3), Square::new(0, 4))
+            .unwrap();
+        // A misread tile on top of the real move.
+        after.set_piece(
+            Square::new(4, 5),
+            Some(Piece {
+                owner: PlayerSide::Blue,
+                kind: PieceKind::Soldier,
+            }),
+        );
+        let diffs = before.differences(&after);
+        assert_eq!(diffs.len(), 3);
+        assert_eq!(BoardState::infer_move_from_diffs(&diffs), None);
+
+        // Two pieces appearing and nothing vacated is no move either.
+        let mut appeared = before.clone();
+        appeared.set_piece(Square::new(1, 4), before.piece_at(Square::new(0, 3)));
+        appeared.set_piece(Square::new(3, 4), before.piece_at(Square::new(0, 3)));
+        let diffs = before.differences(&appeared);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(BoardState::infer_move_from_diffs(&diffs), None);
+        assert_eq!(BoardState::infer_move_from_diffs(&[]), None);
+    }
 }
@@ -4,7 +4,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{MinervaError, Result};
 
-use crate::{time_control::TimeControl, ui::FormationPreset};
+use crate::{
+    board::{BoardState, PlayerSide},
+    time_control::TimeControl,
+    ui::{BoardGeometry, ClockRegions, DialogButtons, FormationPreset, PopupTemplate},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmulatorConfig {
@@ -12,10 +16,150 @@ pub struct EmulatorConfig {
     pub socket: String,
     pub fixed_resolution: Option<(u32, u32)>,
     pub adb_path: Option<String>,
+    /// Global pixel offset `(dx, dy)` added to every tap point.
+    #[serde(default)]
+    pub tap_offset: (i32, i32),
+    /// Board layout on screen, for both taps and recognition.
+    #[serde(default)]
+    pub geometry: BoardGeometry,
+    /// How `screencap` output is requested from the device.
+    #[serde(default)]
+    pub screencap_format: ScreencapFormat,
+    /// Retry behavior for ADB capture and input commands.
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Pauses between inputs, for emulators that need longer to register them.
+    #[serde(default)]
+    pub timing: TimingConfig,
+    /// Send multi-action inputs as one `adb shell` invocation instead of one
+    /// process per action.
+    #[serde(default = "default_batch_inputs")]
+    pub batch_inputs: bool,
+    /// Shift each board tap by a random offset of up to this many pixels
+    /// on each axis, kept inside the tapped square's cell; unset taps the
+    /// exact intersection.
+    #[serde(default)]
+    pub tap_jitter_px: Option<u32>,
+}
+
+fn default_batch_inputs() -> bool {
+    true
+}
+
+/// Delays around input, in milliseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct TimingConfig {
+    /// Between the origin and destination taps of a move, so the selection
+    /// registers first.
+    pub tap_gap_ms: u64,
+    /// After our move is tapped, before the next capture.
+    pub post_move_settle_ms: u64,
+    /// After each step of the start/formation and resign sequences.
+    pub start_flow_step_ms: u64,
+    /// After each injected input action.
+    pub inter_action_ms: u64,
+}
+
+/// Longest accepted [`TimingConfig`] delay.
+pub const MAX_TIMING_MS: u64 = 10_000;
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self {
+            tap_gap_ms: 30,
+            post_move_settle_ms: 0,
+            start_flow_step_ms: 150,
+            inter_action_ms: 10,
+        }
+    }
+}
+
+/// Retry-with-backoff settings: attempt `n` waits `base_delay_ms * 2^(n-1)`,
+/// capped at `max_delay_ms`, then scaled by a random factor in
+/// `1 ± jitter` before the next attempt.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Total attempts including the first; 1 disables retries.
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
+    pub jitter: f32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 100,
+            max_delay_ms: 2_000,
+            jitter: 0.2,
+        }
+    }
+}
+
+/// Opponent-wait polling cadence: every `fast_interval_ms` for the first
+/// `fast_window_ms` after the wait starts or the screen last changed, then
+/// each interval `backoff` times the previous one up to `max_interval_ms`,
+/// scaled by a random factor in `1 ± jitter`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PollSchedule {
+    pub fast_interval_ms: u64,
+    pub fast_window_ms: u64,
+    pub backoff: f32,
+    pub max_interval_ms: u64,
+    pub jitter: f32,
+}
+
+impl Default for PollSchedule {
+    fn default() -> Self {
+        Self {
+            fast_interval_ms: 200,
+            fast_window_ms: 10_000,
+            backoff: 1.5,
+            max_interval_ms: 5_000,
+            jitter: 0.0,
+        }
+    }
+}
+
+/// `png` asks for PNG-encoded screenshots (`screencap -p`); `raw` skips the
+/// encoding and reads the raw framebuffer dump, which is faster on most
+/// devices. Either way a PNG signature in the output is decoded as PNG.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreencapFormat {
+    #[default]
+    Png,
+    Raw,
+}
+
+/// Whether the orchestrator plays or only watches.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum OrchestratorMode {
+    #[default]
+    Play,
+    /// Recognize both players' moves and publish engine evaluations without
+    /// ever sending input to the device.
+    Spectate,
+}
+
+/// Board recognition strategy built for the match.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RecognizerBackend {
+    /// Per-square template matching against `template_dir`.
+    #[default]
+    TemplateMatching,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VisionConfig {
+    #[serde(default)]
+    pub backend: RecognizerBackend,
     pub template_dir: String,
     pub confidence_threshold: f32,
     pub refresh_interval_ms: u64,
@@ -23,6 +167,58 @@ pub struct VisionConfig {
     pub capture_dir: Option<String>,
     #[serde(default)]
     pub tile_capture_dir: Option<String>,
+    /// Save the frame and tiles of every Nth recognition only, starting
+    /// with the first; 0 saves none while keeping the directories set.
+    #[serde(default = "default_capture_every_n")]
+    pub capture_every_n: u32,
+    /// Re-align the board when more than this many squares come back low-confidence.
+    #[serde(default = "default_realign_low_confidence_squares")]
+    pub realign_low_confidence_squares: usize,
+    /// RGB of the client's last-move highlight; unset disables detection.
+    #[serde(default)]
+    pub highlight_color: Option<[u8; 3]>,
+    /// Clock display regions read with the `digit_0`..`digit_9` templates;
+    /// unset leaves the snapshot clocks alone.
+    #[serde(default)]
+    pub clock_regions: Option<ClockRegions>,
+    /// Consecutive recognitions that must agree before a board is acted on,
+    /// to skip frames caught mid-animation; 1 disables the check.
+    #[serde(default = "default_stable_captures")]
+    pub stable_captures: u32,
+    /// Delay between stabilization captures.
+    #[serde(default = "default_stable_capture_delay_ms")]
+    pub stable_capture_delay_ms: u64,
+    /// Frames older than this when they arrive are discarded and captured
+    /// again; 0 disables the check.
+    #[serde(default = "default_max_frame_age_ms")]
+    pub max_frame_age_ms: u64,
+    /// Once the board is aligned, capture only the board plus this many
+    /// pixels on each side; unset always captures the full screen.
+    #[serde(default)]
+    pub capture_region_margin: Option<u32>,
+    /// Popups to dismiss whenever a capture shows one.
+    #[serde(default)]
+    pub popups: Vec<PopupTemplate>,
+}
+
+fn default_realign_low_confidence_squares() -> usize {
+    BoardState::DEFAULT_WIDTH as usize
+}
+
+fn default_capture_every_n() -> u32 {
+    1
+}
+
+fn default_stable_captures() -> u32 {
+    1
+}
+
+fn default_stable_capture_delay_ms() -> u64 {
+    80
+}
+
+fn default_max_frame_age_ms() -> u64 {
+    2_000
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +226,59 @@ pub struct EngineConfig {
     pub threads: usize,
     pub max_depth: u8,
     pub nnue_path: Option<String>,
+    #[serde(default)]
+    pub positional: PositionalWeights,
+    /// Playing strength from 0 to [`MAX_SKILL_LEVEL`]; lower levels add more
+    /// random noise to move scores. The engine searches a single ply, so the
+    /// level does not change the depth.
+    #[serde(default = "default_skill_level")]
+    pub skill_level: u8,
+    /// Seed for the skill noise; the same seed replays the same choices.
+    #[serde(default)]
+    pub seed: u64,
+    /// CSV file (relative to `ops.telemetry_dir`) or path that gets one row
+    /// of evaluation terms per engine decision, for tuning the weights.
+    #[serde(default)]
+    pub eval_log: Option<String>,
+}
+
+/// Strongest [`EngineConfig::skill_level`], at which no noise is added.
+pub const MAX_SKILL_LEVEL: u8 = 20;
+
+fn default_skill_level() -> u8 {
+    MAX_SKILL_LEVEL
+}
+
+/// Weights of the positional terms the engine adds to material, in soldier
+/// units.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PositionalWeights {
+    /// Per Guard next to its own General.
+    pub guard_shield: f32,
+    /// Penalty when no friendly piece covers the General's file toward the enemy.
+    pub open_file: f32,
+    /// Per direction in which a Cannon has a piece to jump over.
+    pub cannon_screen: f32,
+    /// Per Horse or Elephant that has left its back rank.
+    pub development: f32,
+    /// Penalty per piece pinned against its own General.
+    pub pinned: f32,
+    /// Deom: credited to Han (Red) for moving second, debited from Cho (Blue).
+    pub han_compensation: f32,
+}
+
+impl Default for PositionalWeights {
+    fn default() -> Self {
+        Self {
+            guard_shield: 0.3,
+            open_file: 0.5,
+            cannon_screen: 0.1,
+            development: 0.25,
+            pinned: 0.4,
+            han_compensation: 1.5,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,20 +286,187 @@ pub struct NetworkConfig {
     pub bind_addr: String,
     pub websocket_port: u16,
     pub auth_token: Option<String>,
+    /// Port of the read-only HTTP `/status` endpoint on `bind_addr`; unset
+    /// disables it.
+    #[serde(default)]
+    pub status_port: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpsConfig {
     pub log_level: String,
     pub telemetry_dir: String,
+    /// Log file name (relative to `telemetry_dir`) or path; rotated daily.
+    #[serde(default)]
+    pub log_file: Option<String>,
+    #[serde(default)]
+    pub log_format: LogFormat,
+    #[serde(default = "default_log_stderr")]
+    pub log_stderr: bool,
+    /// Oldest events beyond this count are evicted from memory.
+    #[serde(default = "default_max_events_in_memory")]
+    pub max_events_in_memory: usize,
+    /// Seconds between health samples; 0 disables the heartbeat.
+    #[serde(default = "default_health_interval_secs")]
+    pub health_interval_secs: u64,
+    /// Size budget for `telemetry_dir`; the oldest matches are pruned beyond it.
+    #[serde(default)]
+    pub telemetry_max_mb: Option<u64>,
+    /// Matches older than this are pruned.
+    #[serde(default)]
+    pub telemetry_max_age_days: Option<u64>,
+}
+
+fn default_health_interval_secs() -> u64 {
+    30
+}
+
+fn default_max_events_in_memory() -> usize {
+    5_000
+}
+
+fn default_log_stderr() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestratorConfig {
+    #[serde(default)]
+    pub mode: OrchestratorMode,
     pub time_control: TimeControl,
+    /// Per-operation retry budget: attempts at each capture, recognition, and
+    /// move input (with exponential backoff) before the turn fails, and
+    /// re-captures of a board that disagrees with the tracked one. A failed
+    /// turn is tried once more before the match aborts.
     pub max_retries: u8,
     #[serde(default)]
     pub formation: FormationPreset,
+    /// The opponent's formation when known in advance; unset, it is read
+    /// from the opponent's back rank on the first capture.
+    #[serde(default)]
+    pub opponent_formation: Option<FormationPreset>,
+    /// Janggi FEN to start from instead of the standard setup; skips the
+    /// start/formation sequence when valid.
+    #[serde(default)]
+    pub start_fen: Option<String>,
+    /// How long to wait for the opponent's move before giving up.
+    #[serde(default = "default_opponent_timeout_secs")]
+    pub opponent_timeout_secs: u64,
+    /// Declare a draw after this many plies without a capture; unset disables it.
+    #[serde(default)]
+    pub draw_after_halfmoves: Option<u32>,
+    /// Safety cap: stop an undecided match after this many plies (or turns).
+    #[serde(default = "default_max_plies")]
+    pub max_plies: u32,
+    /// Publish a BoardUpdate only when the position differs from the last one.
+    #[serde(default)]
+    pub skip_unchanged_boards: bool,
+    /// Log each recognized board as an ASCII diagram at info level.
+    #[serde(default)]
+    pub log_board: bool,
+    /// Side Minerva plays; unset detects it from the first recognized board.
+    #[serde(default)]
+    pub our_side: Option<PlayerSide>,
+    /// Warn (as an Ops event) once our remaining time drops below this.
+    #[serde(default)]
+    pub low_time_warning_ms: Option<u64>,
+    /// Hard cap on one engine search; past it the best-so-far or an
+    /// emergency one-ply move is played instead.
+    #[serde(default = "default_turn_deadline_ms")]
+    pub turn_deadline_ms: u64,
+    /// Resign once the engine scores our position below this (soldier
+    /// units) for `resign_persistence` turns in a row; unset never resigns.
+    #[serde(default)]
+    pub resign_threshold: Option<f32>,
+    #[serde(default = "default_resign_persistence")]
+    pub resign_persistence: u32,
+    /// No resignation before this ply, however bad the score.
+    #[serde(default = "default_resign_min_plies")]
+    pub resign_min_plies: u32,
+    /// Moves the client may ignore per match before it is aborted; each
+    /// move is checked on screen after it is tapped. 0 skips the check.
+    #[serde(default = "default_max_move_rejections")]
+    pub max_move_rejections: u32,
+    /// Overlap capturing the next frame with recognizing the current one
+    /// while waiting for the opponent.
+    #[serde(default)]
+    pub pipeline: bool,
+    /// Adaptive cadence for polling the opponent's move; unset polls every
+    /// `vision.refresh_interval_ms`.
+    #[serde(default)]
+    pub poll_schedule: Option<PollSchedule>,
+    /// Accept a draw offer or bikjang when the engine scores our position
+    /// at or below this (soldier units); above it the dialog is declined.
+    #[serde(default = "default_accept_draw_below")]
+    pub accept_draw_below: f32,
+    /// Where the draw/bikjang dialog's buttons are.
+    #[serde(default)]
+    pub dialog_buttons: DialogButtons,
+    /// Least time from the start of thinking to tapping our move, so easy
+    /// positions are not answered instantly; 0 taps once the engine decides.
+    #[serde(default)]
+    pub min_move_delay_ms: u64,
+    /// With this set, each move instead waits for a random think time
+    /// between `min_move_delay_ms` and this, which keeps blitz playable.
+    #[serde(default)]
+    pub max_move_delay_ms: Option<u64>,
+    /// On boot, resume the match saved in the telemetry directory's
+    /// `state.json` if it was saved at most this long ago; 0 never resumes.
+    #[serde(default = "default_resume_max_age_secs")]
+    pub resume_max_age_secs: u64,
+    /// Recognize and decide as usual but only report the taps that would
+    /// be sent, as `dry_run` Ops events; the device gets no input.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Weighted anomaly score (recognition 1, desync 2, ignored move or
+    /// failed input 3) past which the match is aborted and input stops;
+    /// `None` never aborts on anomalies.
+    #[serde(default)]
+    pub max_anomaly_score: Option<u32>,
+    /// Press Back once when aborting on the anomaly budget, to leave the
+    /// board for a screen where stray taps do no harm.
+    #[serde(default)]
+    pub back_on_anomaly_abort: bool,
+}
+
+fn default_opponent_timeout_secs() -> u64 {
+    300
+}
+
+fn default_resume_max_age_secs() -> u64 {
+    600
+}
+
+fn default_turn_deadline_ms() -> u64 {
+    30_000
+}
+
+fn default_resign_persistence() -> u32 {
+    3
+}
+
+fn default_resign_min_plies() -> u32 {
+    20
+}
+
+fn default_max_move_rejections() -> u32 {
+    3
+}
+
+fn default_accept_draw_below() -> f32 {
+    -2.0
+}
+
+fn default_max_plies() -> u32 {
+    400
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -80,6 +496,19 @@ impl MinervaConfig {
         })
     }
 
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path_ref = path.as_ref();
+        let doc = toml::to_string(self).map_err(|err| {
+            MinervaError::Configuration(format!("failed to serialize config: {err}"))
+        })?;
+        fs::write(path_ref, doc).map_err(|err| {
+            MinervaError::Configuration(format!(
+                "unable to write config file {}: {err}",
+                path_ref.display()
+            ))
+        })
+    }
+
     pub fn validate(&self) -> Result<()> {
         if self.engine.threads == 0 {
             return Err(MinervaError::Configuration(
@@ -91,21 +520,69 @@ impl MinervaConfig {
                 "engine.max_depth must be greater than zero".into(),
             ));
         }
+        if self.engine.skill_level > MAX_SKILL_LEVEL {
+            return Err(MinervaError::Configuration(format!(
+                "engine.skill_level must be at most {MAX_SKILL_LEVEL}"
+            )));
+        }
+        let timing = self.emulator.timing;
+        for (name, value) in [
+            ("tap_gap_ms", timing.tap_gap_ms),
+            ("post_move_settle_ms", timing.post_move_settle_ms),
+            ("start_flow_step_ms", timing.start_flow_step_ms),
+            ("inter_action_ms", timing.inter_action_ms),
+        ] {
+            if value > MAX_TIMING_MS {
+                return Err(MinervaError::Configuration(format!(
+                    "emulator.timing.{name} must be at most {MAX_TIMING_MS}ms"
+                )));
+            }
+        }
         if !(0.0..=1.0).contains(&self.vision.confidence_threshold) {
             return Err(MinervaError::Configuration(
                 "vision.confidence_threshold must be between 0.0 and 1.0".into(),
             ));
         }
+        if self.vision.stable_captures == 0 {
+            return Err(MinervaError::Configuration(
+                "vision.stable_captures must be greater than zero".into(),
+            ));
+        }
         if self.network.websocket_port == 0 {
             return Err(MinervaError::Configuration(
                 "network.websocket_port must be a valid port (>0)".into(),
             ));
         }
+        if self.ops.max_events_in_memory == 0 {
+            return Err(MinervaError::Configuration(
+                "ops.max_events_in_memory must be greater than zero".into(),
+            ));
+        }
         if self.orchestrator.max_retries == 0 {
             return Err(MinervaError::Configuration(
                 "orchestrator.max_retries must be greater than zero".into(),
             ));
         }
+        if self.orchestrator.max_plies == 0 {
+            return Err(MinervaError::Configuration(
+                "orchestrator.max_plies must be greater than zero".into(),
+            ));
+        }
+        if self.orchestrator.resign_threshold.is_some() && self.orchestrator.resign_persistence == 0
+        {
+            return Err(MinervaError::Configuration(
+                "orchestrator.resign_persistence must be greater than zero".into(),
+            ));
+        }
+        if self
+            .orchestrator
+            .max_move_delay_ms
+            .is_some_and(|max| max < self.orchestrator.min_move_delay_ms)
+        {
+            return Err(MinervaError::Configuration(
+                "orchestrator.max_move_delay_ms must not be below min_move_delay_ms".into(),
+            ));
+        }
         Ok(())
     }
 }
@@ -125,29 +602,59 @@ mod tests {
                 socket: "127.0.0.1:5555".into(),
                 fixed_resolution: Some((1080, 1920)),
                 adb_path: None,
+                tap_offset: (4, -2),
+                geometry: BoardGeometry::default(),
+                screencap_format: ScreencapFormat::Png,
+                retry: RetryPolicy::default(),
+                timing: TimingConfig::default(),
+                batch_inputs: true,
+                tap_jitter_px: None,
             },
             vision: VisionConfig {
+                backend: RecognizerBackend::TemplateMatching,
                 template_dir: "templates".into(),
                 confidence_threshold: 0.9,
                 refresh_interval_ms: 250,
                 capture_dir: Some("captures".into()),
                 tile_capture_dir: Some("captures/tiles".into()),
+                capture_every_n: 1,
+                realign_low_confidence_squares: 9,
+                highlight_color: None,
+                clock_regions: None,
+                stable_captures: 1,
+                stable_capture_delay_ms: 80,
+                max_frame_age_ms: 2_000,
+                capture_region_margin: None,
+                popups: Vec::new(),
             },
             engine: EngineConfig {
                 threads: 2,
                 max_depth: 4,
                 nnue_path: None,
+                positional: PositionalWeights::default(),
+                skill_level: MAX_SKILL_LEVEL,
+                seed: 0,
+                eval_log: None,
             },
             network: NetworkConfig {
                 bind_addr: "0.0.0.0".into(),
                 websocket_port: 3100,
                 auth_token: Some("token".into()),
+                status_port: None,
             },
             ops: OpsConfig {
                 log_level: "debug".into(),
                 telemetry_dir: "telemetry".into(),
+                log_file: None,
+                log_format: LogFormat::Text,
+                log_stderr: true,
+                max_events_in_memory: 5_000,
+                health_interval_secs: 30,
+                telemetry_max_mb: None,
+                telemetry_max_age_days: None,
             },
             orchestrator: OrchestratorConfig {
+                mode: OrchestratorMode::Play,
                 time_control: TimeControl {
                     mode: TimeControlMode::Rapid,
                     base_ms: 15 * 60 * 1000,
@@ -156,11 +663,34 @@ mod tests {
                 },
                 max_retries: 2,
                 formation: FormationPreset::SangMasangMa,
+                opponent_formation: None,
+                start_fen: None,
+                opponent_timeout_secs: 300,
+                draw_after_halfmoves: None,
+                max_plies: 400,
+                skip_unchanged_boards: false,
+                log_board: false,
+                our_side: None,
+                low_time_warning_ms: None,
+                turn_deadline_ms: default_turn_deadline_ms(),
+                resign_threshold: None,
+                resign_persistence: 3,
+                resign_min_plies: 20,
+                max_move_rejections: 3,
+                pipeline: false,
+                poll_schedule: None,
+                accept_draw_below: -2.0,
+                dialog_buttons: DialogButtons::default(),
+                min_move_delay_ms: 0,
+                max_move_delay_ms: None,
+                resume_max_age_secs: 600,
+                dry_run: false,
+                max_anomaly_score: None,
+                back_on_anomaly_abort: false,
             },
         };
 
-        let doc = toml::to_string(&config).expect("serialize config");
-        fs::write(&temp_path, doc).expect("write temp config");
+        config.save_to_file(&temp_path).expect("write temp config");
 
         let loaded = MinervaConfig::from_file(&temp_path).expect("load config");
         assert_eq!(loaded.engine.max_depth, config.engine.max_depth);
@@ -169,6 +699,7 @@ mod tests {
             config.orchestrator.max_retries
         );
         assert_eq!(loaded.orchestrator.formation, config.orchestrator.formation);
+        assert_eq!(loaded.emulator.tap_offset, config.emulator.tap_offset);
         fs::remove_file(&temp_path).expect("cleanup temp config");
     }
 
@@ -180,32 +711,86 @@ mod tests {
                 socket: "device".into(),
                 fixed_resolution: None,
                 adb_path: None,
+                tap_offset: (0, 0),
+                geometry: BoardGeometry::default(),
+                screencap_format: ScreencapFormat::Png,
+                retry: RetryPolicy::default(),
+                timing: TimingConfig::default(),
+                batch_inputs: true,
+                tap_jitter_px: None,
             },
             vision: VisionConfig {
+                backend: RecognizerBackend::TemplateMatching,
                 template_dir: "templates".into(),
                 confidence_threshold: 0.5,
                 refresh_interval_ms: 250,
                 capture_dir: None,
                 tile_capture_dir: None,
+                capture_every_n: 1,
+                realign_low_confidence_squares: 9,
+                highlight_color: None,
+                clock_regions: None,
+                stable_captures: 1,
+                stable_capture_delay_ms: 80,
+                max_frame_age_ms: 2_000,
+                capture_region_margin: None,
+                popups: Vec::new(),
             },
             engine: EngineConfig {
                 threads: 0,
                 max_depth: 1,
                 nnue_path: None,
+                positional: PositionalWeights::default(),
+                skill_level: MAX_SKILL_LEVEL,
+                seed: 0,
+                eval_log: None,
             },
             network: NetworkConfig {
                 bind_addr: "0.0.0.0".into(),
                 websocket_port: 3000,
                 auth_token: None,
+                status_port: None,
             },
             ops: OpsConfig {
                 log_level: "info".into(),
                 telemetry_dir: "telemetry".into(),
+                log_file: None,
+                log_format: LogFormat::Text,
+                log_stderr: true,
+                max_events_in_memory: 5_000,
+                health_interval_secs: 30,
+                telemetry_max_mb: None,
+                telemetry_max_age_days: None,
             },
             orchestrator: OrchestratorConfig {
+                mode: OrchestratorMode::Play,
                 time_control: TimeControl::blitz(),
                 max_retries: 1,
                 formation: FormationPreset::default(),
+                opponent_formation: None,
+                start_fen: None,
+                opponent_timeout_secs: 300,
+                draw_after_halfmoves: None,
+                max_plies: 400,
+                skip_unchanged_boards: false,
+                log_board: false,
+                our_side: None,
+                low_time_warning_ms: None,
+                turn_deadline_ms: default_turn_deadline_ms(),
+                resign_threshold: None,
+                resign_persistence: 3,
+                resign_min_plies: 20,
+                max_move_rejections: 3,
+                pipeline: false,
+                poll_schedule: None,
+                accept_draw_below: -2.0,
+                dialog_buttons: DialogButtons::default(),
+                min_move_delay_ms: 0,
+                max_move_delay_ms: None,
+                resume_max_age_secs: 600,
+                dry_run: false,
+                max_anomaly_score: None,
+                back_on_anomaly_abort: false,
             },
         };
 
@@ -214,6 +799,9 @@ mod tests {
         config.engine.max_depth = 0;
         assert!(config.validate().is_err());
         config.engine.max_depth = 4;
+        config.engine.skill_level = MAX_SKILL_LEVEL + 1;
+        assert!(config.validate().is_err());
+        config.engine.skill_level = 0;
         config.vision.confidence_threshold = 1.5;
         assert!(config.validate().is_err());
         config.vision.confidence_threshold = 0.9;
@@ -223,6 +811,16 @@ mod tests {
         config.orchestrator.max_retries = 0;
         assert!(config.validate().is_err());
         config.orchestrator.max_retries = 1;
+        config.orchestrator.max_plies = 0;
+        assert!(config.validate().is_err());
+        config.orchestrator.max_plies = 400;
+        config.emulator.timing.tap_gap_ms = MAX_TIMING_MS + 1;
+        assert!(config.validate().is_err());
+        config.emulator.timing.tap_gap_ms = 30;
+        config.orchestrator.min_move_delay_ms = 800;
+        config.orchestrator.max_move_delay_ms = Some(500);
+        assert!(config.validate().is_err());
+        config.orchestrator.max_move_delay_ms = Some(2_000);
         assert!(config.validate().is_ok());
     }
 }
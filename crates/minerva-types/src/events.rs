@@ -1,10 +1,13 @@
+use std::{fmt, path::PathBuf, str::FromStr};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    board::BoardDiff,
-    telemetry::{EngineMetrics, LatencySample},
+    board::{BoardDiff, Piece, PieceKind, PlayerSide, Square},
+    game::{GameResult, Move},
+    telemetry::{EngineMetrics, HealthSample, LatencySample},
 };
 
 /// High-level event bus message kinds moving through the system.
@@ -18,6 +21,54 @@ pub enum EventKind {
     Ops,
 }
 
+impl EventKind {
+    pub const ALL: [EventKind; 6] = [
+        EventKind::Lifecycle,
+        EventKind::BoardUpdate,
+        EventKind::EngineDecision,
+        EventKind::Telemetry,
+        EventKind::Network,
+        EventKind::Ops,
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Lifecycle => "Lifecycle",
+            EventKind::BoardUpdate => "BoardUpdate",
+            EventKind::EngineDecision => "EngineDecision",
+            EventKind::Telemetry => "Telemetry",
+            EventKind::Network => "Network",
+            EventKind::Ops => "Ops",
+        }
+    }
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for EventKind {
+    type Err = String;
+
+    /// Accepts the variant name or its short alias (`Board`, `Engine`), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.trim();
+        if normalized.eq_ignore_ascii_case("board") {
+            return Ok(EventKind::BoardUpdate);
+        }
+        if normalized.eq_ignore_ascii_case("engine") {
+            return Ok(EventKind::EngineDecision);
+        }
+        Self::ALL
+            .iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(normalized))
+            .cloned()
+            .ok_or_else(|| format!("알 수 없는 이벤트 종류: {}", normalized))
+    }
+}
+
 /// Immutable event envelope for logging, networking, and replay.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemEvent {
@@ -25,6 +76,12 @@ pub struct SystemEvent {
     pub kind: EventKind,
     pub timestamp: DateTime<Utc>,
     pub payload: EventPayload,
+    /// Process-wide session the event belongs to, stamped by the orchestrator.
+    #[serde(default)]
+    pub session_id: Option<Uuid>,
+    /// Match the event belongs to; `None` outside of a match.
+    #[serde(default)]
+    pub match_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +92,9 @@ pub enum EventPayload {
     Telemetry(TelemetryEvent),
     Network(NetworkEvent),
     Ops(OpsEvent),
+    MatchResult(MatchResultEvent),
+    State(StateChangeEvent),
+    OpponentMove(OpponentMoveEvent),
     Unknown(serde_json::Value),
 }
 
@@ -44,6 +104,19 @@ pub struct LifecycleEvent {
     pub details: Option<String>,
 }
 
+/// End-of-match summary, published with kind `Lifecycle` just before `MatchEnd`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchResultEvent {
+    /// Winner and reason, or `None` if the match stopped undecided.
+    pub result: Option<GameResult>,
+    pub plies: u32,
+    /// Last tracked position.
+    pub final_fen: Option<String>,
+    pub total_nodes: u64,
+    /// Mean end-to-end turn latency.
+    pub average_latency_ms: Option<u64>,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum LifecyclePhase {
     Boot,
@@ -53,22 +126,153 @@ pub enum LifecyclePhase {
     Shutdown,
 }
 
+/// What the orchestrator is doing; every change is published as a
+/// [`StateChangeEvent`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum OrchestratorState {
+    Booting,
+    /// Capturing and recognizing the board at the start of our turn.
+    Observing,
+    WaitingForOpponent,
+    Thinking,
+    Moving,
+    /// Checking on screen that the client took our move.
+    Verifying,
+    Paused,
+    Finished,
+}
+
+impl OrchestratorState {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            OrchestratorState::Booting => "Booting",
+            OrchestratorState::Observing => "Observing",
+            OrchestratorState::WaitingForOpponent => "WaitingForOpponent",
+            OrchestratorState::Thinking => "Thinking",
+            OrchestratorState::Moving => "Moving",
+            OrchestratorState::Verifying => "Verifying",
+            OrchestratorState::Paused => "Paused",
+            OrchestratorState::Finished => "Finished",
+        }
+    }
+
+    /// Whether the orchestrator may enter this state from `previous`.
+    pub fn can_follow(self, previous: OrchestratorState) -> bool {
+        use OrchestratorState::*;
+        match self {
+            Booting => previous == Finished,
+            Thinking => matches!(previous, Observing | WaitingForOpponent | Verifying),
+            Moving => previous == Thinking,
+            Verifying => previous == Moving,
+            WaitingForOpponent => previous != Booting,
+            Observing | Paused | Finished => true,
+        }
+    }
+}
+
+impl fmt::Display for OrchestratorState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Move the opponent was seen to play, published with kind `BoardUpdate`
+/// just after the board it produced. Displays as `Red: 마 h8->g6 x졸`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpponentMoveEvent {
+    pub side: PlayerSide,
+    pub mv: Move,
+    pub piece: PieceKind,
+    pub captured: Option<Piece>,
+    #[serde(default)]
+    pub turn_id: Option<Uuid>,
+}
+
+impl fmt::Display for OpponentMoveEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?}: {} {}->{}",
+            self.side,
+            self.piece.label(self.side),
+            self.mv.from,
+            self.mv.to
+        )?;
+        if let Some(captured) = self.captured {
+            write!(f, " x{}", captured.kind.label(captured.owner))?;
+        }
+        Ok(())
+    }
+}
+
+/// Orchestrator state transition, published with kind `Lifecycle`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StateChangeEvent {
+    pub from: OrchestratorState,
+    pub to: OrchestratorState,
+}
+
+/// Operator command delivered to a running match from outside the orchestrator.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RemoteCommand {
+    /// Concede the game in progress.
+    Resign,
+    /// Stop taking turns after the current one; the board is still captured
+    /// and published for status.
+    Pause,
+    /// Leave the paused state.
+    Resume,
+    /// While paused, take exactly one more turn.
+    Step,
+    /// Play this move on our next turn instead of the engine's, if it is
+    /// legal on the tracked board then.
+    ForceMove { from: Square, to: Square },
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoardEvent {
     pub snapshot: crate::game::GameSnapshot,
     pub diffs: Vec<BoardDiff>,
+    /// Saved screenshot the snapshot was recognized from, if any.
+    #[serde(default)]
+    pub frame_path: Option<PathBuf>,
+    /// Turn that produced this recognition.
+    #[serde(default)]
+    pub turn_id: Option<Uuid>,
+    /// Piece taken by the move that led to this board.
+    #[serde(default)]
+    pub captured: Option<Piece>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EngineEvent {
     pub metrics: EngineMetrics,
     pub best_line: Vec<crate::game::Move>,
+    /// Engine evaluation after the chosen move, from the perspective of the
+    /// side to move (see [`crate::game::EngineDecision::score`]).
+    #[serde(default)]
+    pub score: Option<f32>,
+    /// Turn the decision was made for.
+    #[serde(default)]
+    pub turn_id: Option<Uuid>,
+    /// An operator's forced move was played instead of the engine's.
+    #[serde(default)]
+    pub overridden: bool,
+    /// Position the engine evaluated, as FEN with its side to move, so the
+    /// decision can be replayed from the event alone.
+    #[serde(default)]
+    pub fen: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TelemetryEvent {
     pub latency: Option<LatencySample>,
     pub notes: Option<String>,
+    /// Turn the latency sample was measured for, if any.
+    #[serde(default)]
+    pub turn_id: Option<Uuid>,
+    #[serde(default)]
+    pub health: Option<HealthSample>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,6 +285,29 @@ pub struct NetworkEvent {
 pub struct OpsEvent {
     pub message: String,
     pub tags: Vec<String>,
+    #[serde(default)]
+    pub severity: Severity,
+}
+
+/// How urgently an operational event needs attention.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+/// Builds an Ops event with the given severity.
+pub fn ops_event(severity: Severity, message: impl Into<String>, tags: Vec<String>) -> SystemEvent {
+    SystemEvent::new(
+        EventKind::Ops,
+        EventPayload::Ops(OpsEvent {
+            message: message.into(),
+            tags,
+            severity,
+        }),
+    )
 }
 
 impl SystemEvent {
@@ -90,6 +317,196 @@ impl SystemEvent {
             kind,
             timestamp: Utc::now(),
             payload,
+            session_id: None,
+            match_id: None,
+        }
+    }
+}
+
+impl SystemEvent {
+    /// Turn this event belongs to, for payloads that carry one.
+    pub fn turn_id(&self) -> Option<Uuid> {
+        match &self.payload {
+            EventPayload::Board(board) => board.turn_id,
+            EventPayload::Engine(engine) => engine.turn_id,
+            EventPayload::Telemetry(telemetry) => telemetry.turn_id,
+            EventPayload::OpponentMove(opponent) => opponent.turn_id,
+            _ => None,
         }
     }
+
+    /// Free-form text carried by the payload, used for text search.
+    pub fn searchable_text(&self) -> Vec<&str> {
+        match &self.payload {
+            EventPayload::Lifecycle(lifecycle) => {
+                lifecycle.details.as_deref().into_iter().collect()
+            }
+            EventPayload::Telemetry(telemetry) => telemetry.notes.as_deref().into_iter().collect(),
+            EventPayload::Network(network) => vec![network.topic.as_str()],
+            EventPayload::Ops(ops) => std::iter::once(ops.message.as_str())
+                .chain(ops.tags.iter().map(String::as_str))
+                .collect(),
+            EventPayload::State(change) => vec![change.to.as_str()],
+            EventPayload::Board(_)
+            | EventPayload::Engine(_)
+            | EventPayload::MatchResult(_)
+            | EventPayload::OpponentMove(_)
+            | EventPayload::Unknown(_) => Vec::new(),
+        }
+    }
+}
+
+/// Selection criteria for querying recorded events.
+///
+/// Empty fields match everything. Results are ordered newest first and
+/// truncated to `limit` when set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventFilter {
+    #[serde(default)]
+    pub kinds: Vec<EventKind>,
+    #[serde(default)]
+    pub since: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Only events stamped with this match (see [`SystemEvent::match_id`]).
+    #[serde(default)]
+    pub match_id: Option<Uuid>,
+    /// Only events stamped with this session.
+    #[serde(default)]
+    pub session_id: Option<Uuid>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl EventFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn kind(mut self, kind: EventKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn match_id(mut self, match_id: Uuid) -> Self {
+        self.match_id = Some(match_id);
+        self
+    }
+
+    pub fn session_id(mut self, session_id: Uuid) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Checks every criterion except `limit`, which applies to the result set.
+    pub fn matches(&self, event: &SystemEvent) -> bool {
+        if !self.kinds.is_empty() && !self.kinds.contains(&event.kind) {
+            return false;
+        }
+        if self.since.is_some_and(|since| event.timestamp < since) {
+            return false;
+        }
+        if self.until.is_some_and(|until| event.timestamp > until) {
+            return false;
+        }
+        if self.match_id.is_some() && event.match_id != self.match_id {
+            return false;
+        }
+        if self.session_id.is_some() && event.session_id != self.session_id {
+            return false;
+        }
+        if let Some(needle) = self.text.as_deref().filter(|t| !t.is_empty()) {
+            let needle = needle.to_lowercase();
+            return event
+                .searchable_text()
+                .iter()
+                .any(|text| text.to_lowercase().contains(&needle));
+        }
+        true
+    }
+
+    /// Applies the filter to `events`, returning matches newest first.
+    pub fn apply<'a, I>(&self, events: I) -> Vec<SystemEvent>
+    where
+        I: IntoIterator<Item = &'a SystemEvent>,
+    {
+        let mut selected: Vec<SystemEvent> = events
+            .into_iter()
+            .filter(|event| self.matches(event))
+            .cloned()
+            .collect();
+        // Reverse first so events sharing a timestamp keep newest-first order.
+        selected.reverse();
+        selected.sort_by_key(|event| std::cmp::Reverse(event.timestamp));
+        if let Some(limit) = self.limit {
+            selected.truncate(limit);
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_event_without_ids_still_parses() {
+        let event = ops_event(Severity::Warning, "hello", Vec::new());
+        let mut value = serde_json::to_value(&event).unwrap();
+        let object = value.as_object_mut().unwrap();
+        object.remove("session_id");
+        object.remove("match_id");
+        value["payload"]["Ops"]
+            .as_object_mut()
+            .unwrap()
+            .remove("severity");
+
+        let parsed: SystemEvent = serde_json::from_value(value).expect("parse legacy event");
+        assert_eq!(parsed.id, event.id);
+        assert!(parsed.session_id.is_none());
+        assert!(parsed.match_id.is_none());
+        match parsed.payload {
+            EventPayload::Ops(ops) => assert_eq!(ops.severity, Severity::Info),
+            other => panic!("unexpected payload: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn opponent_moves_read_as_piece_route_and_capture() {
+        let mut event = OpponentMoveEvent {
+            side: PlayerSide::Red,
+            mv: Move::new(Square::new(7, 7), Square::new(6, 5)),
+            piece: PieceKind::Horse,
+            captured: None,
+            turn_id: None,
+        };
+        assert_eq!(event.to_string(), "Red: 마 h8->g6");
+        event.captured = Some(Piece {
+            owner: PlayerSide::Blue,
+            kind: PieceKind::Soldier,
+        });
+        assert_eq!(event.to_string(), "Red: 마 h8->g6 x졸");
+    }
 }
@@ -0,0 +1,309 @@
+//! Janggi FEN parsing and formatting.
+//!
+//! Ranks are listed from Red's back rank (rank 9) down to Blue's (rank 0).
+//! Uppercase letters are Blue (초) pieces and lowercase are Red (한):
+//! `k` general, `a` guard, `b` elephant, `n` horse, `r` chariot, `c` cannon,
+//! `p` soldier. The optional second field is the side to move, `w` for Blue
+//! and `b` for Red; any further fields are ignored.
+//!
+//! [`BoardState::to_ascii`] draws the same letters as a grid for logs, and
+//! `Display`/`FromStr` on [`BoardState`] use the FEN.
+
+use std::{fmt, str::FromStr};
+
+use crate::board::{BoardState, Piece, PieceKind, PlayerSide, Square};
+
+impl BoardState {
+    /// Parses a FEN, naming the rank (counted from the top, as written) and
+    /// column of the first problem: a wrong number of ranks, an unknown
+    /// letter, a `0` run, a rank too long or too short, or a side without
+    /// exactly one General.
+    pub fn from_fen(fen: &str) -> Result<BoardState, String> {
+        let mut fields = fen.split_whitespace();
+        let placement = fields.next().ok_or("FEN이 비어 있습니다")?;
+        let side_to_move = match fields.next() {
+            None | Some("w") => PlayerSide::Blue,
+            Some("b") => PlayerSide::Red,
+            Some(other) => return Err(format!("알 수 없는 차례 표기: {other}")),
+        };
+
+        let mut board = BoardState::empty();
+        board.side_to_move = side_to_move;
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != board.height as usize {
+            return Err(format!(
+                "FEN 줄 수가 {}개입니다 ({}개 필요)",
+                ranks.len(),
+                board.height
+            ));
+        }
+
+        for (row, text) in ranks.iter().enumerate() {
+            let rank = board.height - 1 - row as u8;
+            let mut file = 0u8;
+            for (column, c) in text.chars().enumerate() {
+                let at = || format!("{}번째 줄 {}번째 문자", row + 1, column + 1);
+                if let Some(empty) = c.to_digit(10) {
+                    if empty == 0 {
+                        return Err(format!("{}: 빈 칸 수는 1 이상이어야 합니다", at()));
+                    }
+                    file = file.saturating_add(empty as u8);
+                } else {
+                    let piece = piece_from_char(c)
+                        .ok_or_else(|| format!("{}: 알 수 없는 기물 문자 {c:?}", at()))?;
+                    board.set_piece(Square::new(file, rank), Some(piece));
+                    file = file.saturating_add(1);
+                }
+                if file > board.width {
+                    return Err(format!("{}: 칸 수가 {}개를 넘습니다", at(), board.width));
+                }
+            }
+            if file != board.width {
+                return Err(format!(
+                    "{}번째 줄의 칸 수가 {}개입니다 ({}개 필요)",
+                    row + 1,
+                    file,
+                    board.width
+                ));
+            }
+        }
+
+        for side in [PlayerSide::Blue, PlayerSide::Red] {
+            let generals = board
+                .pieces
+                .iter()
+                .flatten()
+                .filter(|p| p.owner == side && p.kind == PieceKind::General)
+                .count();
+            if generals != 1 {
+                return Err(format!("{side:?} 장군이 {generals}개입니다"));
+            }
+        }
+        Ok(board)
+    }
+
+    /// The placement, rank 9 first, and the side to move (`w` or `b`).
+    pub fn to_fen(&self) -> String {
+        let mut rows = Vec::with_capacity(self.height as usize);
+        for rank in (0..self.height).rev() {
+            let mut row = String::new();
+            let mut empty = 0;
+            for file in 0..self.width {
+                match self.piece_at(Square::new(file, rank)) {
+                    Some(piece) => {
+                        if empty > 0 {
+                            row.push_str(&empty.to_string());
+                            empty = 0;
+                        }
+                        row.push(piece_char(piece));
+                    }
+                    None => empty += 1,
+                }
+            }
+            if empty > 0 {
+                row.push_str(&empty.to_string());
+            }
+            rows.push(row);
+        }
+        let side = match self.side_to_move {
+            PlayerSide::Blue => 'w',
+            PlayerSide::Red => 'b',
+        };
+        format!("{} {}", rows.join("/"), side)
+    }
+
+    /// Multi-line diagram with Red's back rank on top: files `a`-`i` across,
+    /// ranks `1`-`10` down the side, `.` for empty points, FEN piece letters,
+    /// and the side to move on the last line.
+    pub fn to_ascii(&self) -> String {
+        let files: Vec<String> = (0..self.width)
+            .map(|file| ((b'a' + file) as char).to_string())
+            .collect();
+        let header = format!("   {}", files.join(" "));
+        let mut lines = vec![header.clone()];
+        for rank in (0..self.height).rev() {
+            let row: Vec<String> = (0..self.width)
+                .map(|file| {
+                    self.piece_at(Square::new(file, rank))
+                        .map_or('.', piece_char)
+                        .to_string()
+                })
+                .collect();
+            lines.push(format!("{:>2} {}", rank + 1, row.join(" ")));
+        }
+        lines.push(header);
+        let side = match self.side_to_move {
+            PlayerSide::Blue => "Blue (uppercase)",
+            PlayerSide::Red => "Red (lowercase)",
+        };
+        lines.push(format!("{side} to move"));
+        lines.join("\n")
+    }
+}
+
+impl fmt::Display for BoardState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_fen())
+    }
+}
+
+impl FromStr for BoardState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_fen(s)
+    }
+}
+
+fn piece_from_char(c: char) -> Option<Piece> {
+    let kind = match c.to_ascii_lowercase() {
+        'k' => PieceKind::General,
+        'a' => PieceKind::Guard,
+        'b' => PieceKind::Elephant,
+        'n' => PieceKind::Horse,
+        'r' => PieceKind::Chariot,
+        'c' => PieceKind::Cannon,
+        'p' => PieceKind::Soldier,
+        _ => return None,
+    };
+    let owner = if c.is_ascii_uppercase() {
+        PlayerSide::Blue
+    } else {
+        PlayerSide::Red
+    };
+    Some(Piece { owner, kind })
+}
+
+fn piece_char(piece: Piece) -> char {
+    let c = match piece.kind {
+        PieceKind::General => 'k',
+        PieceKind::Guard => 'a',
+        PieceKind::Elephant => 'b',
+        PieceKind::Horse => 'n',
+        PieceKind::Chariot => 'r',
+        PieceKind::Cannon => 'c',
+        PieceKind::Soldier => 'p',
+    };
+    match piece.owner {
+        PlayerSide::Blue => c.to_ascii_uppercase(),
+        PlayerSide::Red => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_position_round_trips() {
+        let initial = BoardState::initial();
+        let fen = initial.to_fen();
+        let parsed = BoardState::from_fen(&fen).unwrap();
+        assert!(initial.differences(&parsed).is_empty());
+        assert_eq!(parsed.side_to_move, PlayerSide::Blue);
+    }
+
+    #[test]
+    fn midgame_positions_round_trip() {
+        let mut board = BoardState::initial();
+        for (from, to) in [
+            ((0, 3), (0, 4)),
+            ((8, 6), (8, 5)),
+            ((1, 2), (1, 8)),
+            ((7, 9), (6, 7)),
+            ((0, 0), (0, 2)),
+        ] {
+            board
+                .move_piece(Square::new(from.0, from.1), Square::new(to.0, to.1))
+                .unwrap();
+            board.side_to_move = board.side_to_move.opponent();
+            let parsed: BoardState = board.to_string().parse().unwrap();
+            assert!(board.differences(&parsed).is_empty(), "{board}");
+            assert_eq!(parsed.side_to_move, board.side_to_move);
+        }
+        assert_eq!(
+            board.to_fen(),
+            "rnbakab1r/1C7/1c4nc1/p1p1p1p2/8p/P8/2P1P1P1P/R6C1/9/1NBAKABNR b"
+        );
+
+        for fen in [
+            "5k3/1n7/9/1p7/9/1Cc6/1P7/9/9/1c1K5 w",
+            "4k4/9/4r4/9/9/9/R8/9/4K4/9 b",
+        ] {
+            assert_eq!(BoardState::from_fen(fen).unwrap().to_string(), fen);
+        }
+    }
+
+    #[test]
+    fn parses_endgame_with_side_to_move() {
+        let board = BoardState::from_fen("9/3k5/9/9/9/9/9/9/4R4/4K4 b").unwrap();
+        assert_eq!(board.side_to_move, PlayerSide::Red);
+        assert_eq!(board.find_general(PlayerSide::Red), Some(Square::new(3, 8)));
+        assert_eq!(
+            board.piece_at(Square::new(4, 1)),
+            Some(Piece {
+                owner: PlayerSide::Blue,
+                kind: PieceKind::Chariot,
+            })
+        );
+    }
+
+    #[test]
+    fn ascii_diagram_of_initial_position() {
+        let expected = [
+            "   a b c d e f g h i",
+            "10 r n b a k a b n r",
+            " 9 . . . . . . . . .",
+            " 8 . c . . . . . c .",
+            " 7 p . p . p . p . p",
+            " 6 . . . . . . . . .",
+            " 5 . . . . . . . . .",
+            " 4 P . P . P . P . P",
+            " 3 . C . . . . . C .",
+            " 2 . . . . . . . . .",
+            " 1 R N B A K A B N R",
+            "   a b c d e f g h i",
+            "Blue (uppercase) to move",
+        ];
+        assert_eq!(BoardState::initial().to_ascii(), expected.join("\n"));
+    }
+
+    #[test]
+    fn rejects_malformed_fen() {
+        for fen in [
+            "",
+            "9/9/9",
+            "9/3k5/9/9/9/9/9/9/9/4K3",
+            "9/3k5/9/9/9/9/9/9/9/4K4 x",
+            "9/3x5/9/9/9/9/9/9/9/4K4",
+            "9/9/9/9/9/9/9/9/9/4K4",
+            "9/3k5/9/9/9/9/9/9/9/4K40",
+            "9/3k6/9/9/9/9/9/9/9/4K4",
+        ] {
+            assert!(BoardState::from_fen(fen).is_err(), "accepted {fen:?}");
+        }
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_character() {
+        let err = |fen: &str| BoardState::from_fen(fen).unwrap_err();
+        assert_eq!(err("9/9/9"), "FEN 줄 수가 3개입니다 (10개 필요)");
+        assert_eq!(
+            err("9/3x5/9/9/9/9/9/9/9/4K4"),
+            "2번째 줄 2번째 문자: 알 수 없는 기물 문자 'x'"
+        );
+        assert_eq!(
+            err("9/3k6/9/9/9/9/9/9/9/4K4"),
+            "2번째 줄 3번째 문자: 칸 수가 9개를 넘습니다"
+        );
+        assert_eq!(
+            err("9/3k5/9/9/9/9/9/9/9/4K40"),
+            "10번째 줄 4번째 문자: 빈 칸 수는 1 이상이어야 합니다"
+        );
+        assert_eq!(
+            err("9/3k5/9/9/9/9/9/9/9/4K3"),
+            "10번째 줄의 칸 수가 8개입니다 (9개 필요)"
+        );
+        assert!("9/3k5/9/9/9/9/9/9/9/4K4 b".parse::<BoardState>().is_ok());
+    }
+}
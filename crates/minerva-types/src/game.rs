@@ -1,7 +1,12 @@
+use std::{fmt, str::FromStr};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::board::{BoardState, PlayerSide, Square};
+use crate::{
+    board::{BoardState, Piece, PlayerSide, Square},
+    vision::RecognitionReport,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Move {
@@ -11,6 +16,63 @@ pub struct Move {
     pub confidence: Option<f32>,
 }
 
+impl Move {
+    pub fn new(from: Square, to: Square) -> Self {
+        Self {
+            from,
+            to,
+            promotion: None,
+            confidence: None,
+        }
+    }
+
+    /// Parses coordinate notation such as `b3e3`, with an optional `-` or
+    /// `x` (capture) between the squares: `b3-e3`, `b3xe3`. Both squares
+    /// must be on the board (see [`Square`]'s `FromStr`).
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let normalized = s.trim();
+        let invalid = || format!("잘못된 수 표기: {normalized}");
+        // The from-square is a file letter and a one- or two-digit rank.
+        let split = normalized
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| !c.is_ascii_digit())
+            .map(|(i, _)| i)
+            .ok_or_else(invalid)?;
+        let (from, rest) = normalized.split_at(split);
+        let to = rest
+            .strip_prefix(['-', 'x', 'X'])
+            .filter(|to| to.starts_with(|c: char| c.is_ascii_alphabetic()))
+            .unwrap_or(rest);
+        if to.is_empty() || to.starts_with(char::is_whitespace) {
+            return Err(invalid());
+        }
+        Ok(Self::new(from.parse()?, to.parse()?))
+    }
+
+    /// The move in coordinate notation, `x` between the squares when it
+    /// captures: `b3e3`, `b3xe3`.
+    pub fn notation(&self, captures: bool) -> String {
+        let separator = if captures { "x" } else { "" };
+        format!("{}{separator}{}", self.from, self.to)
+    }
+}
+
+/// Coordinate notation, `e1e2`.
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.from, self.to)
+    }
+}
+
+impl FromStr for Move {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MoveCandidate {
     pub mv: Move,
@@ -26,24 +88,100 @@ pub struct GameSnapshot {
     pub phase: GamePhase,
     pub clocks: GameClocks,
     pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub recognition: RecognitionReport,
+    /// Plies since the last capture, for no-capture draw adjudication.
+    #[serde(default)]
+    pub halfmove_clock: u32,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct GameClocks {
     pub blue_ms: u64,
     pub red_ms: u64,
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+impl GameClocks {
+    /// Both sides starting with `base_ms`.
+    pub fn new(base_ms: u64) -> Self {
+        Self {
+            blue_ms: base_ms,
+            red_ms: base_ms,
+        }
+    }
+
+    pub fn remaining_ms(&self, side: PlayerSide) -> u64 {
+        match side {
+            PlayerSide::Blue => self.blue_ms,
+            PlayerSide::Red => self.red_ms,
+        }
+    }
+
+    /// Charges `spent_ms` to `side` (stopping at zero), then adds the
+    /// per-move `increment_ms`.
+    pub fn charge(&mut self, side: PlayerSide, spent_ms: u64, increment_ms: u64) {
+        let remaining = match side {
+            PlayerSide::Blue => &mut self.blue_ms,
+            PlayerSide::Red => &mut self.red_ms,
+        };
+        *remaining = remaining.saturating_sub(spent_ms) + increment_ms;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 pub enum GamePhase {
+    #[default]
     Opening,
     Midgame,
     Endgame,
 }
 
-impl Default for GamePhase {
-    fn default() -> Self {
-        GamePhase::Opening
+/// Why a game was won.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum WinReason {
+    /// The losing side had no legal move; in Janggi this loses even out of check.
+    NoLegalMoves,
+    GeneralCaptured,
+    Resignation,
+}
+
+/// Why a game was drawn.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DrawReason {
+    Bikjang,
+    MoveLimit,
+    Agreement,
+    /// Too many plies in a row without a capture.
+    NoCapture,
+}
+
+/// Final outcome of a game.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum GameResult {
+    Win {
+        winner: PlayerSide,
+        reason: WinReason,
+    },
+    Draw {
+        reason: DrawReason,
+    },
+}
+
+impl GameResult {
+    pub fn winner(&self) -> Option<PlayerSide> {
+        match self {
+            GameResult::Win { winner, .. } => Some(*winner),
+            GameResult::Draw { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameResult::Win { winner, reason } => write!(f, "{:?} wins ({:?})", winner, reason),
+            GameResult::Draw { reason } => write!(f, "draw ({:?})", reason),
+        }
     }
 }
 
@@ -54,12 +192,62 @@ pub struct EngineDecision {
     pub searched_nodes: u64,
     pub depth: u8,
     pub duration_ms: u128,
+    /// Evaluation of the position after the chosen move, from the side to
+    /// move's perspective; the evaluation of the position itself when there
+    /// is no move.
+    #[serde(default)]
+    pub score: f32,
+    /// How much the chosen move changed the evaluation (`score` minus the
+    /// evaluation before it).
+    #[serde(default)]
+    pub gain: f32,
+    /// Set when the evaluated position is already decided.
+    #[serde(default)]
+    pub result: Option<GameResult>,
+    /// Moves filtered out as illegal during the search, with the reason.
+    #[serde(skip)]
+    pub reject_reasons: Vec<(Move, String)>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TurnContext {
     pub snapshot: GameSnapshot,
     pub side: PlayerSide,
+    /// `side`'s remaining clock; `None` in untimed games.
+    #[serde(default)]
+    pub remaining_ms: Option<u64>,
+    /// Time added to `side`'s clock after the move.
+    #[serde(default)]
+    pub increment_ms: Option<u64>,
+}
+
+impl TurnContext {
+    /// Moves the remaining clock is assumed to cover when budgeting one move.
+    pub const MOVES_TO_GO: u64 = 20;
+
+    /// An untimed turn for `side` in `snapshot`.
+    pub fn new(snapshot: GameSnapshot, side: PlayerSide) -> Self {
+        Self {
+            snapshot,
+            side,
+            remaining_ms: None,
+            increment_ms: None,
+        }
+    }
+
+    /// Adds `side`'s clock so the engine can budget its own think time.
+    pub fn with_clock(mut self, remaining_ms: u64, increment_ms: u64) -> Self {
+        self.remaining_ms = Some(remaining_ms);
+        self.increment_ms = Some(increment_ms);
+        self
+    }
+
+    /// Think time for this move: a share of the remaining clock plus the
+    /// increment, or `None` when the game is untimed.
+    pub fn time_budget_ms(&self) -> Option<u64> {
+        let remaining = self.remaining_ms?;
+        Some(remaining / Self::MOVES_TO_GO + self.increment_ms.unwrap_or(0))
+    }
 }
 
 impl Default for GameSnapshot {
@@ -71,25 +259,111 @@ impl Default for GameSnapshot {
             phase: GamePhase::Opening,
             clocks: GameClocks::default(),
             created_at: Utc::now(),
+            recognition: RecognitionReport::default(),
+            halfmove_clock: 0,
         }
     }
 }
 
 impl GameSnapshot {
-    pub fn apply_move(&mut self, side: PlayerSide, mv: &Move) -> Result<(), String> {
-        let moving_piece = self.board.piece_at(mv.from).ok_or_else(|| {
-            format!(
-                "원점에 기물이 없습니다: ({},{})",
-                mv.from.file, mv.from.rank
-            )
-        })?;
-        if moving_piece.owner != side {
-            return Err("선택한 말이 현재 플레이어의 것이 아닙니다".into());
-        }
-        self.board.move_piece(mv.from, mv.to)?;
+    /// Plays `mv` for `side` and returns the piece it captured, if any. Moves
+    /// that [`BoardState::is_legal_move`] refuses leave the snapshot untouched.
+    pub fn apply_move(&mut self, side: PlayerSide, mv: &Move) -> Result<Option<Piece>, String> {
+        self.board
+            .is_legal_move(side, mv)
+            .map_err(|reason| reason.to_string())?;
+        let captured = self.board.move_piece(mv.from, mv.to)?;
         self.board.side_to_move = side.opponent();
         self.last_move = Some(mv.clone());
         self.ply += 1;
-        Ok(())
+        self.record_halfmove(captured.is_some());
+        Ok(captured)
+    }
+
+    /// Advances [`Self::halfmove_clock`] for a ply, resetting it on a capture.
+    pub fn record_halfmove(&mut self, captured: bool) {
+        self.halfmove_clock = if captured { 0 } else { self.halfmove_clock + 1 };
+    }
+}
+
+/// A position the orchestrator accepted, with the move that produced it
+/// (`None` for a starting position or a board adopted as recognized).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub snapshot: GameSnapshot,
+    pub mv: Option<Move>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_notation_round_trips() {
+        let corners = [
+            Square::new(0, 0),
+            Square::new(8, 0),
+            Square::new(0, 9),
+            Square::new(8, 9),
+        ];
+        for from in corners {
+            for to in corners {
+                let mv = Move::new(from, to);
+                let parsed: Move = mv.to_string().parse().unwrap();
+                assert_eq!((parsed.from, parsed.to), (from, to), "{mv}");
+            }
+        }
+        assert_eq!(
+            Move::new(Square::new(0, 0), Square::new(8, 9)).to_string(),
+            "a1i10"
+        );
+
+        let mv = Move::parse("b3e3").unwrap();
+        assert_eq!((mv.from, mv.to), (Square::new(1, 2), Square::new(4, 2)));
+        for written in ["b3-e3", "b3xe3", " B3XE3 "] {
+            let parsed = Move::parse(written).unwrap();
+            assert_eq!((parsed.from, parsed.to), (mv.from, mv.to), "{written}");
+        }
+        assert_eq!(mv.notation(true), "b3xe3");
+        assert_eq!(mv.notation(false), "b3e3");
+        let long = Move::parse("a10xa9").unwrap();
+        assert_eq!((long.from, long.to), (Square::new(0, 9), Square::new(0, 8)));
+
+        for invalid in [
+            "", "b3", "b3e", "b3-", "b3x", "j1a1", "a0a1", "a1a11", "a1j1", "a1 a2", "e1e2e3",
+        ] {
+            assert!(Move::parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn time_budget_is_a_share_of_the_clock_plus_increment() {
+        let untimed = TurnContext::new(GameSnapshot::default(), PlayerSide::Blue);
+        assert_eq!(untimed.time_budget_ms(), None);
+        let timed = untimed.with_clock(60_000, 2_000);
+        assert_eq!(timed.time_budget_ms(), Some(60_000 / 20 + 2_000));
+    }
+
+    #[test]
+    fn halfmove_clock_counts_quiet_plies_and_resets_on_capture() {
+        let mut snapshot = GameSnapshot::default();
+        let blue = Move::new(Square::new(0, 3), Square::new(0, 4));
+        let red = Move::new(Square::new(0, 6), Square::new(0, 5));
+        assert_eq!(snapshot.apply_move(PlayerSide::Blue, &blue), Ok(None));
+        assert_eq!(snapshot.apply_move(PlayerSide::Red, &red), Ok(None));
+        assert_eq!(snapshot.halfmove_clock, 2);
+
+        let capture = Move::new(Square::new(0, 4), Square::new(0, 5));
+        let captured = snapshot.apply_move(PlayerSide::Blue, &capture).unwrap();
+        assert_eq!(captured.map(|piece| piece.owner), Some(PlayerSide::Red));
+        assert_eq!(snapshot.halfmove_clock, 0);
+    }
+
+    #[test]
+    fn snapshots_without_halfmove_clock_still_deserialize() {
+        let mut json = serde_json::to_value(GameSnapshot::default()).unwrap();
+        json.as_object_mut().unwrap().remove("halfmove_clock");
+        let snapshot: GameSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(snapshot.halfmove_clock, 0);
     }
 }
@@ -3,7 +3,9 @@
 pub mod board;
 pub mod config;
 pub mod events;
+pub mod fen;
 pub mod game;
+pub mod rules;
 pub mod telemetry;
 pub mod time_control;
 pub mod ui;
@@ -0,0 +1,670 @@
+//! Janggi movement rules shared by the engine, orchestrator, and vision.
+
+use std::collections::HashSet;
+
+use thiserror::Error;
+
+use crate::{
+    board::{BoardState, Piece, PieceKind, PlayerSide, Square},
+    game::{GameResult, Move, WinReason},
+};
+
+/// Reason recorded for moves that would leave the mover's General attacked.
+pub const REJECT_SELF_CHECK: &str = "self-check";
+
+const ORTHOGONAL: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DIAGONAL: [(i8, i8); 4] = [(1, 1), (-1, 1), (1, -1), (-1, -1)];
+
+/// Why [`BoardState::is_legal_move`] refused a move.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum IllegalMoveReason {
+    #[error("원점에 기물이 없습니다: {0}")]
+    EmptyOrigin(Square),
+    #[error("{0}의 기물은 {1:?}의 것이 아닙니다")]
+    NotOwnPiece(Square, PlayerSide),
+    #[error("{kind:?}은(는) {from}에서 {to}로 움직일 수 없습니다")]
+    Unreachable {
+        kind: PieceKind,
+        from: Square,
+        to: Square,
+    },
+    #[error("{from}{to} 이후 자기 궁이 장군을 받습니다")]
+    SelfCheck { from: Square, to: Square },
+}
+
+impl BoardState {
+    /// Moves for `side` that do not leave its own General attacked.
+    pub fn legal_moves(&self, side: PlayerSide) -> Vec<Move> {
+        self.legal_moves_with_rejects(side).0
+    }
+
+    /// Like [`Self::legal_moves`], but also returns each pseudo-legal move
+    /// that was filtered out together with the reason.
+    pub fn legal_moves_with_rejects(&self, side: PlayerSide) -> (Vec<Move>, Vec<(Move, String)>) {
+        let mut legal = Vec::new();
+        let mut rejected = Vec::new();
+        for mv in self.pseudo_legal_moves(side) {
+            let mut next = self.clone();
+            match next.move_piece(mv.from, mv.to) {
+                Err(err) => rejected.push((mv, err)),
+                Ok(_) if next.is_in_check(side) => {
+                    rejected.push((mv, REJECT_SELF_CHECK.to_string()))
+                }
+                Ok(_) => legal.push(mv),
+            }
+        }
+        (legal, rejected)
+    }
+
+    /// Checks `mv` for `side` against the moving piece's movement pattern
+    /// (legs, screens, palace lines) and rejects it if it leaves `side`'s
+    /// General attacked. Uses the same generators as [`Self::legal_moves`].
+    pub fn is_legal_move(&self, side: PlayerSide, mv: &Move) -> Result<(), IllegalMoveReason> {
+        let piece = self
+            .piece_at(mv.from)
+            .ok_or(IllegalMoveReason::EmptyOrigin(mv.from))?;
+        if piece.owner != side {
+            return Err(IllegalMoveReason::NotOwnPiece(mv.from, side));
+        }
+        let mut moves = Vec::new();
+        self.piece_moves(piece, mv.from, &mut moves);
+        if !moves.iter().any(|candidate| candidate.to == mv.to) {
+            return Err(IllegalMoveReason::Unreachable {
+                kind: piece.kind,
+                from: mv.from,
+                to: mv.to,
+            });
+        }
+        let mut next = self.clone();
+        if next.move_piece(mv.from, mv.to).is_err() || next.is_in_check(side) {
+            return Err(IllegalMoveReason::SelfCheck {
+                from: mv.from,
+                to: mv.to,
+            });
+        }
+        Ok(())
+    }
+
+    /// Moves following each piece's movement pattern, ignoring check.
+    pub fn pseudo_legal_moves(&self, side: PlayerSide) -> Vec<Move> {
+        let mut moves = Vec::new();
+        for rank in 0..self.height {
+            for file in 0..self.width {
+                let square = Square::new(file, rank);
+                if let Some(piece) = self.piece_at(square) {
+                    if piece.owner == side {
+                        self.piece_moves(piece, square, &mut moves);
+                    }
+                }
+            }
+        }
+        moves
+    }
+
+    /// Pseudo-legal moves of `piece` standing on `from`.
+    fn piece_moves(&self, piece: Piece, from: Square, moves: &mut Vec<Move>) {
+        let side = piece.owner;
+        match piece.kind {
+            PieceKind::Soldier => soldier_moves(self, side, from, moves),
+            PieceKind::Chariot => chariot_moves(self, side, from, moves),
+            PieceKind::Horse => horse_moves(self, side, from, moves),
+            PieceKind::Cannon => cannon_moves(self, side, from, moves),
+            PieceKind::Elephant => elephant_moves(self, side, from, moves),
+            PieceKind::Guard | PieceKind::General => palace_moves(self, side, from, moves),
+        }
+    }
+
+    /// Squares `side` attacks: every empty or enemy-occupied square one of
+    /// its pieces could move to, ignoring whether the move exposes its own
+    /// General. Squares held by `side`'s own pieces are never included.
+    pub fn attacked_squares(&self, side: PlayerSide) -> HashSet<Square> {
+        self.pseudo_legal_moves(side)
+            .into_iter()
+            .map(|mv| mv.to)
+            .collect()
+    }
+
+    /// Whether any opposing piece attacks `side`'s General.
+    pub fn is_in_check(&self, side: PlayerSide) -> bool {
+        let Some(general) = self.find_general(side) else {
+            return false;
+        };
+        self.attacked_squares(side.opponent()).contains(&general)
+    }
+
+    pub fn find_general(&self, side: PlayerSide) -> Option<Square> {
+        (0..self.height)
+            .flat_map(|rank| (0..self.width).map(move |file| Square::new(file, rank)))
+            .find(|&square| {
+                self.piece_at(square)
+                    .is_some_and(|p| p.owner == side && p.kind == PieceKind::General)
+            })
+    }
+
+    /// Outcome for `side` to move, if the game is already over.
+    ///
+    /// Unlike chess there is no stalemate: a side without a legal move loses
+    /// whether or not it is in check.
+    pub fn game_result(&self, side: PlayerSide) -> Option<GameResult> {
+        let reason = if self.find_general(side).is_none() {
+            WinReason::GeneralCaptured
+        } else if self.legal_moves(side).is_empty() {
+            WinReason::NoLegalMoves
+        } else {
+            return None;
+        };
+        Some(GameResult::Win {
+            winner: side.opponent(),
+            reason,
+        })
+    }
+
+    fn can_land(&self, side: PlayerSide, to: Square) -> bool {
+        self.piece_at(to).is_none_or(|p| p.owner != side)
+    }
+}
+
+/// One step forward or sideways on any rank (Janggi has no river), plus
+/// forward diagonal steps along the lines of the enemy palace.
+fn soldier_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    let forward = match side {
+        PlayerSide::Blue => 1,
+        PlayerSide::Red => -1,
+    };
+    let steps = [(0, forward), (-1, 0), (1, 0)]
+        .into_iter()
+        .filter_map(|(df, dr)| from.offset(df, dr));
+    let diagonals = [-1, 1]
+        .into_iter()
+        .filter_map(|df| palace_diagonal_step(board, from, df, forward));
+    for to in steps.chain(diagonals) {
+        if board.can_land(side, to) {
+            moves.push(Move::new(from, to));
+        }
+    }
+}
+
+/// Slides along files and ranks, and along the palace diagonals from a
+/// palace corner or center.
+fn chariot_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    for (df, dr) in ORTHOGONAL.into_iter().chain(DIAGONAL) {
+        let mut current = from;
+        while let Some(next) = ray_step(board, current, df, dr) {
+            if let Some(piece) = board.piece_at(next) {
+                if piece.owner != side {
+                    moves.push(Move::new(from, next));
+                }
+                break;
+            }
+            moves.push(Move::new(from, next));
+            current = next;
+        }
+    }
+}
+
+/// Cannons move only by jumping exactly one screen, which may not be another
+/// cannon, and can never capture a cannon. Like the Chariot they also run
+/// along the palace diagonals, so a corner Cannon can jump the center.
+fn cannon_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    for (df, dr) in ORTHOGONAL.into_iter().chain(DIAGONAL) {
+        let mut current = from;
+        let mut screen_found = false;
+        while let Some(next) = ray_step(board, current, df, dr) {
+            match board.piece_at(next) {
+                Some(piece) if piece.kind == PieceKind::Cannon => break,
+                Some(piece) => {
+                    if screen_found {
+                        if piece.owner != side {
+                            moves.push(Move::new(from, next));
+                        }
+                        break;
+                    }
+                    screen_found = true;
+                }
+                None if screen_found => moves.push(Move::new(from, next)),
+                None => {}
+            }
+            current = next;
+        }
+    }
+}
+
+/// The next square of a sliding ray: any orthogonal step, or a diagonal
+/// step that follows a palace line.
+fn ray_step(board: &BoardState, from: Square, df: i8, dr: i8) -> Option<Square> {
+    if df != 0 && dr != 0 {
+        palace_diagonal_step(board, from, df, dr)
+    } else {
+        from.offset(df, dr)
+    }
+}
+
+/// `from` stepped diagonally by (`df`, `dr`), if that step runs along a
+/// palace diagonal: between a corner and the center of the same palace.
+fn palace_diagonal_step(board: &BoardState, from: Square, df: i8, dr: i8) -> Option<Square> {
+    let to = from.offset(df, dr)?;
+    let centers = [Square::new(4, 1), Square::new(4, board.height - 2)];
+    let near = |center: Square, square: Square| {
+        center.file.abs_diff(square.file) <= 1 && center.rank.abs_diff(square.rank) <= 1
+    };
+    centers
+        .into_iter()
+        .any(|center| (from == center || to == center) && near(center, from) && near(center, to))
+        .then_some(to)
+}
+
+fn horse_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    let patterns = [
+        ((1, 0), (1, 1)),
+        ((1, 0), (1, -1)),
+        ((-1, 0), (-1, 1)),
+        ((-1, 0), (-1, -1)),
+        ((0, 1), (1, 1)),
+        ((0, 1), (-1, 1)),
+        ((0, -1), (1, -1)),
+        ((0, -1), (-1, -1)),
+    ];
+    for (leg, dest) in patterns {
+        if let Some(block) = from.offset(leg.0, leg.1) {
+            if board.is_empty(block) {
+                if let Some(to) = block.offset(dest.0, dest.1) {
+                    if board.can_land(side, to) {
+                        moves.push(Move::new(from, to));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One orthogonal step followed by two diagonal steps outward; every
+/// intermediate point must be empty.
+fn elephant_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    let patterns = [
+        ((1, 0), (1, 1)),
+        ((1, 0), (1, -1)),
+        ((-1, 0), (-1, 1)),
+        ((-1, 0), (-1, -1)),
+        ((0, 1), (1, 1)),
+        ((0, 1), (-1, 1)),
+        ((0, -1), (1, -1)),
+        ((0, -1), (-1, -1)),
+    ];
+    for (leg, diag) in patterns {
+        let Some(first) = from.offset(leg.0, leg.1) else {
+            continue;
+        };
+        let Some(second) = first.offset(diag.0, diag.1) else {
+            continue;
+        };
+        if !board.is_empty(first) || !board.is_empty(second) {
+            continue;
+        }
+        if let Some(to) = second.offset(diag.0, diag.1) {
+            if board.can_land(side, to) {
+                moves.push(Move::new(from, to));
+            }
+        }
+    }
+}
+
+/// General and Guard steps inside the palace. Diagonal steps follow the
+/// palace lines, so they are only available from the corners and the center.
+fn palace_moves(board: &BoardState, side: PlayerSide, from: Square, moves: &mut Vec<Move>) {
+    let palace_files = [3u8, 4, 5];
+    let palace_ranks = match side {
+        PlayerSide::Blue => [0u8, 1, 2],
+        PlayerSide::Red => [board.height - 3, board.height - 2, board.height - 1],
+    };
+    let on_diagonal = (from.file as i16 + from.rank as i16 - palace_ranks[0] as i16 - 3) % 2 == 0;
+
+    let directions: [(i8, i8); 8] = [
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (-1, 1),
+        (1, -1),
+        (-1, -1),
+    ];
+    for (df, dr) in directions {
+        if df != 0 && dr != 0 && !on_diagonal {
+            continue;
+        }
+        if let Some(to) = from.offset(df, dr) {
+            if palace_files.contains(&to.file)
+                && palace_ranks.contains(&to.rank)
+                && board.can_land(side, to)
+            {
+                moves.push(Move::new(from, to));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Piece;
+
+    fn place(board: &mut BoardState, file: u8, rank: u8, owner: PlayerSide, kind: PieceKind) {
+        board.set_piece(Square::new(file, rank), Some(Piece { owner, kind }));
+    }
+
+    #[test]
+    fn initial_position_has_moves_for_both_sides() {
+        let board = BoardState::initial();
+        for side in [PlayerSide::Blue, PlayerSide::Red] {
+            let moves = board.legal_moves(side);
+            assert!(!moves.is_empty());
+            assert!(moves
+                .iter()
+                .all(|mv| board.piece_at(mv.from).is_some_and(|p| p.owner == side)));
+            assert!(!board.is_in_check(side));
+        }
+        let soldier_push = Move::new(Square::new(0, 3), Square::new(0, 4));
+        assert!(board
+            .legal_moves(PlayerSide::Blue)
+            .iter()
+            .any(|mv| mv.from == soldier_push.from && mv.to == soldier_push.to));
+    }
+
+    #[test]
+    fn initial_attack_sets_cover_the_expected_squares() {
+        let board = BoardState::initial();
+        for side in [PlayerSide::Blue, PlayerSide::Red] {
+            let attacked = board.attacked_squares(side);
+            // Chariots 4, horses 2 more beside them, palace 3, soldier pushes 5
+            // and sideways steps 4: elephants are hemmed in and the cannons
+            // have no screen.
+            assert_eq!(attacked.len(), 18, "{side:?}");
+            assert!(attacked.iter().all(|&square| board
+                .piece_at(square)
+                .is_none_or(|piece| piece.owner != side)));
+            assert!(!attacked.contains(&board.find_general(side.opponent()).unwrap()));
+        }
+    }
+
+    #[test]
+    fn check_filtering_pins_pieces_to_the_general() {
+        let mut board = BoardState::empty();
+        place(&mut board, 4, 0, PlayerSide::Blue, PieceKind::General);
+        place(&mut board, 4, 2, PlayerSide::Blue, PieceKind::Chariot);
+        place(&mut board, 4, 9, PlayerSide::Red, PieceKind::Chariot);
+        place(&mut board, 3, 8, PlayerSide::Red, PieceKind::General);
+
+        let pseudo = board.pseudo_legal_moves(PlayerSide::Blue);
+        let legal = board.legal_moves(PlayerSide::Blue);
+        assert!(legal.len() < pseudo.len());
+        assert!(legal
+            .iter()
+            .filter(|mv| mv.from == Square::new(4, 2))
+            .all(|mv| mv.to.file == 4));
+
+        let (kept, rejected) = board.legal_moves_with_rejects(PlayerSide::Blue);
+        assert_eq!(kept.len(), legal.len());
+        assert_eq!(kept.len() + rejected.len(), pseudo.len());
+        assert!(rejected
+            .iter()
+            .all(|(mv, reason)| mv.from == Square::new(4, 2) && reason == REJECT_SELF_CHECK));
+    }
+
+    /// Sorted legal destinations of the piece on (`file`, `rank`) for the side
+    /// to move in `fen`.
+    fn targets(fen: &str, file: u8, rank: u8) -> Vec<(u8, u8)> {
+        let board = BoardState::from_fen(fen).unwrap();
+        let mut targets: Vec<_> = board
+            .legal_moves(board.side_to_move)
+            .into_iter()
+            .filter(|mv| mv.from == Square::new(file, rank))
+            .map(|mv| (mv.to.file, mv.to.rank))
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    #[test]
+    fn cannon_needs_a_non_cannon_screen_and_cannot_capture_cannons() {
+        // Up: jumps the soldier, lands beyond or takes the horse.
+        // Down: jumps its own soldier but stops short of the Red cannon.
+        // Right: a cannon cannot serve as the screen.
+        let fen = "5k3/1n7/9/1p7/9/1Cc6/1P7/9/9/1c1K5 w";
+        assert_eq!(targets(fen, 1, 4), [(1, 1), (1, 2), (1, 7), (1, 8)]);
+    }
+
+    #[test]
+    fn elephant_is_blocked_by_either_leg() {
+        // Own soldier on the first leg upward, Red soldier on the second leg
+        // right-down, own soldier on the down-left landing square.
+        let fen = "5k3/9/9/1n7/4P4/4B4/6p2/9/2P6/3K5 w";
+        assert_eq!(targets(fen, 4, 4), [(1, 2), (1, 6), (6, 1), (7, 6)]);
+    }
+
+    #[test]
+    fn general_and_guard_stay_on_palace_lines() {
+        let fen = "9/4k4/9/9/9/9/9/9/3K5/5A3 w";
+        // The middle of a palace edge has no diagonal.
+        assert_eq!(targets(fen, 3, 1), [(3, 0), (3, 2), (4, 1)]);
+        // A corner only reaches the center diagonally.
+        assert_eq!(targets(fen, 5, 0), [(4, 0), (4, 1), (5, 1)]);
+        let red = "9/4k4/9/9/9/9/9/9/3K5/5A3 b";
+        assert_eq!(
+            targets(red, 4, 8),
+            [
+                (3, 7),
+                (3, 8),
+                (3, 9),
+                (4, 7),
+                (4, 9),
+                (5, 7),
+                (5, 8),
+                (5, 9)
+            ]
+        );
+    }
+
+    #[test]
+    fn soldier_steps_forward_or_sideways_on_any_rank() {
+        // No river: a soldier on its own half already steps sideways.
+        let fen = "3k5/9/9/9/9/9/4P4/9/9/4K4 w";
+        assert_eq!(targets(fen, 4, 3), [(3, 3), (4, 4), (5, 3)]);
+        let red = "3k5/9/9/p8/9/9/9/9/9/4K4 b";
+        assert_eq!(targets(red, 0, 6), [(0, 5), (1, 6)]);
+        // On the last rank only the sideways steps remain.
+        let fen = "P2k5/9/9/9/9/9/9/9/9/4K4 w";
+        assert_eq!(targets(fen, 0, 9), [(1, 9)]);
+    }
+
+    #[test]
+    fn soldier_follows_the_enemy_palace_diagonals() {
+        // From a near corner of the enemy palace, forward to the center too.
+        let fen = "9/9/3P1k3/9/9/9/9/9/9/4K4 w";
+        assert_eq!(targets(fen, 3, 7), [(2, 7), (3, 8), (4, 7), (4, 8)]);
+        // From the center, forward to both far corners.
+        let fen = "9/4P4/5k3/9/9/9/9/9/9/4K4 w";
+        assert_eq!(targets(fen, 4, 8), [(3, 8), (3, 9), (4, 9), (5, 8), (5, 9)]);
+        // The middle of a palace edge has no diagonal.
+        let fen = "9/5P3/3k5/9/9/9/9/9/9/4K4 w";
+        assert_eq!(targets(fen, 5, 8), [(4, 8), (5, 9), (6, 8)]);
+        // Red soldiers follow the Blue palace lines the same way.
+        let red = "4k4/9/9/9/9/9/9/5p3/9/3K5 b";
+        assert_eq!(targets(red, 5, 2), [(4, 1), (4, 2), (5, 1), (6, 2)]);
+    }
+
+    #[test]
+    fn chariot_runs_along_the_palace_diagonals() {
+        // From the center, hemmed in by its own soldiers: both forward
+        // diagonals, and down to the empty back-rank middle.
+        let fen = "4k4/9/9/9/9/9/9/4P4/3PRP3/3P1K3 w";
+        assert_eq!(targets(fen, 4, 1), [(3, 2), (4, 0), (5, 2)]);
+        // From a corner through the empty center, capturing on the far corner.
+        let fen = "4k4/9/9/9/9/9/9/5p3/3P5/2PRK4 w";
+        assert_eq!(targets(fen, 3, 0), [(4, 1), (5, 2)]);
+    }
+
+    #[test]
+    fn cannon_jumps_along_the_palace_diagonals() {
+        // Cannons beside and below leave only the diagonal, over the Guard
+        // in the center to the far corner.
+        let fen = "2cc5/3cak3/9/9/9/9/9/9/9/4K4 b";
+        assert_eq!(targets(fen, 3, 9), [(5, 7)]);
+        // A cannon in the center is no screen.
+        let fen = "2cc5/3cck3/9/9/9/9/9/9/9/4K4 b";
+        assert_eq!(targets(fen, 3, 9), []);
+    }
+
+    #[test]
+    fn bikjang_is_not_check_and_does_not_restrict_moves() {
+        // Moving the chariot off the file leaves the Generals facing; that is
+        // a draw claim, not check.
+        let fen = "4k4/9/9/9/9/4R4/9/9/9/4K4 w";
+        let board = BoardState::from_fen(fen).unwrap();
+        let sideways = Move::new(Square::new(4, 4), Square::new(0, 4));
+        assert!(targets(fen, 4, 4).contains(&(0, 4)));
+        let mut next = board.clone();
+        next.move_piece(sideways.from, sideways.to).unwrap();
+        assert!(!next.is_in_check(PlayerSide::Blue));
+        assert!(!next.is_in_check(PlayerSide::Red));
+        assert_eq!(targets(fen, 4, 0), [(3, 0), (4, 1), (5, 0)]);
+    }
+
+    #[test]
+    fn check_must_be_answered() {
+        // The Red chariot checks down file 4: block with the chariot or step
+        // the General off the file.
+        let fen = "4k4/9/4r4/9/9/9/R8/9/4K4/9 w";
+        let board = BoardState::from_fen(fen).unwrap();
+        assert!(board.is_in_check(PlayerSide::Blue));
+        let mut moves: Vec<_> = board
+            .legal_moves(PlayerSide::Blue)
+            .into_iter()
+            .map(|mv| ((mv.from.file, mv.from.rank), (mv.to.file, mv.to.rank)))
+            .collect();
+        moves.sort();
+        assert_eq!(
+            moves,
+            [
+                ((0, 3), (4, 3)),
+                ((4, 1), (3, 0)),
+                ((4, 1), (3, 1)),
+                ((4, 1), (3, 2)),
+                ((4, 1), (5, 0)),
+                ((4, 1), (5, 1)),
+                ((4, 1), (5, 2)),
+            ]
+        );
+    }
+
+    /// [`BoardState::is_legal_move`] for the side to move in `fen`.
+    fn check(fen: &str, from: (u8, u8), to: (u8, u8)) -> Result<(), IllegalMoveReason> {
+        let board = BoardState::from_fen(fen).unwrap();
+        let mv = Move::new(Square::new(from.0, from.1), Square::new(to.0, to.1));
+        board.is_legal_move(board.side_to_move, &mv)
+    }
+
+    fn unreachable(fen: &str, from: (u8, u8), to: (u8, u8)) -> bool {
+        matches!(
+            check(fen, from, to),
+            Err(IllegalMoveReason::Unreachable { .. })
+        )
+    }
+
+    #[test]
+    fn is_legal_move_follows_each_piece_pattern() {
+        // Soldiers on c4 and c7, chariot on a1 behind a soldier on a4, horse
+        // on h1 with its left leg blocked by a stray guard, elephant on b3.
+        let fen = "4k4/9/9/2P6/9/9/P1P6/1B7/4K4/R5AN1 w";
+        assert_eq!(check(fen, (2, 3), (2, 4)), Ok(()));
+        assert_eq!(check(fen, (2, 3), (3, 3)), Ok(()), "sideways on any rank");
+        assert!(unreachable(fen, (2, 3), (2, 2)), "backwards");
+        assert!(unreachable(fen, (2, 3), (3, 4)), "diagonal off the palace");
+        assert_eq!(check(fen, (2, 6), (3, 6)), Ok(()));
+        assert_eq!(check(fen, (0, 0), (0, 2)), Ok(()));
+        assert!(unreachable(fen, (0, 0), (0, 3)), "own piece");
+        assert!(unreachable(fen, (0, 0), (0, 5)), "jumps a piece");
+        assert!(unreachable(fen, (0, 0), (1, 1)), "diagonal");
+        assert_eq!(check(fen, (7, 0), (8, 2)), Ok(()));
+        assert!(unreachable(fen, (7, 0), (5, 1)), "leg blocked");
+        assert_eq!(check(fen, (1, 2), (3, 5)), Ok(()));
+        assert!(unreachable(fen, (1, 2), (3, 4)), "not an elephant step");
+
+        // Cannon on b5 from the screen test above.
+        let fen = "5k3/1n7/9/1p7/9/1Cc6/1P7/9/9/1c1K5 w";
+        assert_eq!(check(fen, (1, 4), (1, 7)), Ok(()));
+        assert_eq!(check(fen, (1, 4), (1, 8)), Ok(()));
+        assert!(unreachable(fen, (1, 4), (1, 5)), "no screen");
+        assert!(unreachable(fen, (1, 4), (1, 0)), "captures a cannon");
+        assert!(unreachable(fen, (1, 4), (3, 4)), "cannon as screen");
+
+        let fen = "9/4k4/9/9/9/9/9/9/3K5/5A3 w";
+        assert_eq!(check(fen, (3, 1), (4, 1)), Ok(()));
+        assert!(unreachable(fen, (3, 1), (4, 2)), "no palace line");
+        assert!(unreachable(fen, (3, 1), (2, 1)), "leaves the palace");
+        assert_eq!(check(fen, (5, 0), (4, 1)), Ok(()));
+        assert!(unreachable(fen, (5, 0), (5, 2)), "two steps");
+
+        // Soldiers on the near corners of the Red palace, then on its center.
+        let fen = "3k5/9/3P1P3/9/9/9/9/9/9/4K4 w";
+        assert_eq!(check(fen, (3, 7), (4, 8)), Ok(()), "corner to center");
+        assert!(unreachable(fen, (3, 7), (2, 8)), "diagonal off the palace");
+        assert!(unreachable(fen, (5, 7), (6, 8)), "leaving the palace");
+        let fen = "3k5/4P4/9/9/9/9/9/9/9/4K4 w";
+        assert_eq!(check(fen, (4, 8), (5, 9)), Ok(()), "center to corner");
+        assert!(unreachable(fen, (4, 8), (3, 7)), "diagonal backwards");
+        let fen = "4k4/9/9/9/9/9/9/9/9/3RK4 w";
+        assert_eq!(
+            check(fen, (3, 0), (5, 2)),
+            Ok(()),
+            "chariot along the diagonal"
+        );
+        let fen = "4k4/9/9/9/9/9/9/9/4P4/3RKC3 w";
+        assert!(
+            unreachable(fen, (3, 0), (5, 2)),
+            "chariot blocked at the center"
+        );
+        assert_eq!(
+            check(fen, (5, 0), (3, 2)),
+            Ok(()),
+            "cannon jumps the center"
+        );
+    }
+
+    #[test]
+    fn is_legal_move_names_the_refusal() {
+        let fen = "4k4/9/4r4/9/9/9/R8/9/4K4/9 w";
+        assert_eq!(check(fen, (0, 3), (4, 3)), Ok(()));
+        assert_eq!(
+            check(fen, (0, 3), (0, 4)),
+            Err(IllegalMoveReason::SelfCheck {
+                from: Square::new(0, 3),
+                to: Square::new(0, 4),
+            })
+        );
+        assert_eq!(
+            check(fen, (4, 7), (4, 6)),
+            Err(IllegalMoveReason::NotOwnPiece(
+                Square::new(4, 7),
+                PlayerSide::Blue
+            ))
+        );
+        assert_eq!(
+            check(fen, (2, 2), (2, 3)),
+            Err(IllegalMoveReason::EmptyOrigin(Square::new(2, 2)))
+        );
+    }
+
+    #[test]
+    fn missing_general_is_a_loss() {
+        let mut board = BoardState::empty();
+        place(&mut board, 4, 8, PlayerSide::Red, PieceKind::General);
+        place(&mut board, 0, 3, PlayerSide::Blue, PieceKind::Soldier);
+        assert_eq!(
+            board.game_result(PlayerSide::Blue),
+            Some(GameResult::Win {
+                winner: PlayerSide::Red,
+                reason: WinReason::GeneralCaptured,
+            })
+        );
+    }
+}
@@ -1,5 +1,14 @@
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    board::{Piece, PlayerSide, Square},
+    events::{LifecyclePhase, OrchestratorState},
+    game::{HistoryEntry, Move},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LatencySample {
@@ -7,6 +16,10 @@ pub struct LatencySample {
     pub decision_ms: u64,
     pub injection_ms: u64,
     pub total_ms: u64,
+    /// Waiting time saved by overlapping capture with recognition
+    /// (`orchestrator.pipeline`); 0 when the pipeline is off.
+    #[serde(default)]
+    pub prefetch_saved_ms: u64,
     pub captured_at: DateTime<Utc>,
 }
 
@@ -16,11 +29,235 @@ pub struct EngineMetrics {
     pub depth: u8,
     pub nps: u64,
     pub hashfull: f32,
+    /// Wall-clock search time reported by the engine.
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MatchTelemetry {
+    #[serde(default)]
+    pub session_id: Option<Uuid>,
+    #[serde(default)]
+    pub match_id: Option<Uuid>,
     pub latency_samples: Vec<LatencySample>,
     pub engine_history: Vec<EngineMetrics>,
     pub notes: Vec<String>,
+    /// One entry per played turn; supersedes the parallel vectors above.
+    #[serde(default)]
+    pub turns: Vec<TurnRecord>,
+    /// Controller input counters, filled in when the match is recorded.
+    #[serde(default)]
+    pub controller_summary: Option<ControllerSummary>,
+    /// Pieces taken by either side, in order, from the moves played rather
+    /// than the client's captured tray.
+    #[serde(default)]
+    pub captures: Vec<CaptureRecord>,
+    /// Positions the orchestrator held at the end of the match, oldest
+    /// first, minus any rolled back after a desync.
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+    /// Irregularities charged against `orchestrator.max_anomaly_score`.
+    #[serde(default)]
+    pub anomaly_budget: AnomalyBudget,
+}
+
+/// Kind of irregularity charged to a match's [`AnomalyBudget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// A board that had to be read again: unstable, realigned, implausible
+    /// material, or a failed recognition.
+    Recognition,
+    /// A recognized board that no single move explains.
+    Desync,
+    /// A move the client ignored.
+    MoveRejection,
+    /// A failed capture or input.
+    ControllerFailure,
+}
+
+impl AnomalyKind {
+    /// Points charged per occurrence; the kinds that leave taps landing on
+    /// the wrong square weigh more.
+    pub fn weight(self) -> u32 {
+        match self {
+            Self::Recognition => 1,
+            Self::Desync => 2,
+            Self::MoveRejection | Self::ControllerFailure => 3,
+        }
+    }
+}
+
+/// Irregularities counted over a match, by kind, and their weighted score.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnomalyBudget {
+    pub recognition: u32,
+    pub desyncs: u32,
+    pub move_rejections: u32,
+    pub controller_failures: u32,
+    pub score: u32,
+}
+
+impl AnomalyBudget {
+    /// Counts one `kind` anomaly and adds its weight to the score.
+    pub fn charge(&mut self, kind: AnomalyKind) {
+        let count = match kind {
+            AnomalyKind::Recognition => &mut self.recognition,
+            AnomalyKind::Desync => &mut self.desyncs,
+            AnomalyKind::MoveRejection => &mut self.move_rejections,
+            AnomalyKind::ControllerFailure => &mut self.controller_failures,
+        };
+        *count += 1;
+        self.score += kind.weight();
+    }
+}
+
+/// A piece taken during a match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptureRecord {
+    /// Ply of the capturing move, counted after it was played.
+    pub ply: u32,
+    pub square: Square,
+    pub piece: Piece,
+}
+
+/// Health snapshot of a running Minerva, served read-only at `/status`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatusReport {
+    /// Most recent lifecycle phase published.
+    pub phase: Option<LifecyclePhase>,
+    /// Orchestrator state after the most recent transition.
+    #[serde(default)]
+    pub state: Option<OrchestratorState>,
+    /// Ply of the last published board.
+    pub ply: Option<u32>,
+    pub engine_depth: Option<u8>,
+    /// Last engine evaluation, from the perspective of the side to move.
+    pub engine_score: Option<f32>,
+    pub controller: Option<ControllerSummary>,
+    /// Recent accepted positions, oldest first.
+    #[serde(default)]
+    pub history: Vec<HistoryPosition>,
+    /// Current wait between polls for the opponent's move.
+    #[serde(default)]
+    pub poll_interval_ms: Option<u64>,
+    /// When any of the above last changed.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Compact view of a [`HistoryEntry`] for the status report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryPosition {
+    pub ply: u32,
+    pub side_to_move: PlayerSide,
+    /// Origin and destination of the move that led here.
+    pub mv: Option<(Square, Square)>,
+    /// [`crate::board::BoardState::position_hash`] of the board.
+    pub position_hash: u64,
+}
+
+impl From<&HistoryEntry> for HistoryPosition {
+    fn from(entry: &HistoryEntry) -> Self {
+        Self {
+            ply: entry.snapshot.ply,
+            side_to_move: entry.snapshot.board.side_to_move,
+            mv: entry.mv.as_ref().map(|mv| (mv.from, mv.to)),
+            position_hash: entry.snapshot.board.position_hash(),
+        }
+    }
+}
+
+/// Persisted view of the controller's input counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControllerSummary {
+    pub successful_inputs: u64,
+    pub failed_inputs: u64,
+    /// Extra attempts spent by the retry policy on captures and inputs.
+    #[serde(default)]
+    pub retries: u64,
+    /// Injection latency of the most recent input.
+    pub last_latency_ms: Option<u64>,
+}
+
+impl std::fmt::Display for ControllerSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "inputs ok={} failed={}",
+            self.successful_inputs, self.failed_inputs
+        )?;
+        if self.retries > 0 {
+            write!(f, " retries={}", self.retries)?;
+        }
+        if let Some(ms) = self.last_latency_ms {
+            write!(f, " last={ms}ms")?;
+        }
+        Ok(())
+    }
+}
+
+/// Everything observed and decided during a single orchestrator turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnRecord {
+    /// Zero-based position of the turn within its match.
+    pub index: u32,
+    pub turn_id: Uuid,
+    pub side: PlayerSide,
+    /// [`crate::board::BoardState::position_hash`] of the recognized board.
+    pub board_hash: u64,
+    pub chosen_move: Option<Move>,
+    pub engine: EngineMetrics,
+    pub latency: LatencySample,
+    /// Time spent in each stage, as recorded on the turn's tracing spans.
+    #[serde(default)]
+    pub stages: StageDurations,
+    pub recognition: RecognitionSummary,
+    /// Saved screenshot for this turn, if captures are persisted.
+    #[serde(default)]
+    pub frame_path: Option<PathBuf>,
+    /// Irregularities worth a look when reviewing the turn.
+    #[serde(default)]
+    pub anomalies: Vec<String>,
+}
+
+/// Milliseconds a turn spent in each stage, summed over repeats such as
+/// opponent polls, re-captures, and retried moves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageDurations {
+    pub capture_ms: u64,
+    pub recognize_ms: u64,
+    pub evaluate_ms: u64,
+    pub apply_move_ms: u64,
+    pub verify_ms: u64,
+}
+
+/// Periodic process health heartbeat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthSample {
+    /// Resident set size, where the platform exposes it.
+    pub rss_bytes: Option<u64>,
+    /// Live tokio tasks on the sampling runtime.
+    pub alive_tasks: Option<usize>,
+    /// Events waiting in the telemetry writer queue.
+    pub writer_queue_depth: usize,
+    pub uptime_secs: u64,
+    pub events_recorded: u64,
+    pub events_dropped: u64,
+    pub captured_at: DateTime<Utc>,
+}
+
+/// Condensed [`crate::vision::RecognitionReport`] for a turn.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecognitionSummary {
+    pub low_confidence_squares: usize,
+    pub mean_confidence: Option<f32>,
+}
+
+impl From<&crate::vision::RecognitionReport> for RecognitionSummary {
+    fn from(report: &crate::vision::RecognitionReport) -> Self {
+        Self {
+            low_confidence_squares: report.low_confidence.len(),
+            mean_confidence: report.mean_confidence,
+        }
+    }
 }
@@ -1,8 +1,8 @@
-use crate::board::Square;
+use crate::board::{BoardState, PieceKind, Square};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Point {
     pub x: u32,
     pub y: u32,
@@ -12,6 +12,43 @@ impl Point {
     pub const fn new(x: u32, y: u32) -> Self {
         Self { x, y }
     }
+
+    /// Shifts the point by `(dx, dy)`, clamping at the screen origin.
+    pub fn offset(self, (dx, dy): (i32, i32)) -> Self {
+        Self {
+            x: self.x.saturating_add_signed(dx),
+            y: self.y.saturating_add_signed(dy),
+        }
+    }
+}
+
+/// Axis-aligned screen rectangle in device pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScreenRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ScreenRect {
+    /// `self` in the coordinates of an image whose top-left pixel sits at
+    /// `origin` on screen, or `None` if it starts left of or above it.
+    pub fn relative_to(self, origin: Point) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(origin.x)?,
+            y: self.y.checked_sub(origin.y)?,
+            ..self
+        })
+    }
+}
+
+/// Where the client draws each side's remaining time. Not every client shows
+/// clocks, so the layout is optional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClockRegions {
+    pub blue: ScreenRect,
+    pub red: ScreenRect,
 }
 
 pub const START_APPLY: Point = Point::new(550, 1180);
@@ -39,21 +76,154 @@ pub fn start_flow_point(step: StartFlowStep) -> Point {
     }
 }
 
+/// Screens the client shows while a match is being set up, told apart by
+/// matching a `ui_*` template around the button tapped on each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StartScreen {
+    /// Lobby with the match application button.
+    Lobby,
+    /// "Apply for a match?" with its Yes button.
+    ConfirmDialog,
+    /// Match found notice with its OK button.
+    NoticeDialog,
+    /// Formation choice with its confirm button.
+    FormationPicker,
+}
+
+impl StartScreen {
+    pub const ALL: [StartScreen; 4] = [
+        StartScreen::Lobby,
+        StartScreen::ConfirmDialog,
+        StartScreen::NoticeDialog,
+        StartScreen::FormationPicker,
+    ];
+
+    /// Template file stem that identifies the screen.
+    pub const fn template_label(self) -> &'static str {
+        match self {
+            StartScreen::Lobby => "ui_lobby",
+            StartScreen::ConfirmDialog => "ui_confirm",
+            StartScreen::NoticeDialog => "ui_notice",
+            StartScreen::FormationPicker => "ui_formation",
+        }
+    }
+
+    /// Screen point the template is centered on.
+    pub const fn anchor(self) -> Point {
+        match self {
+            StartScreen::Lobby => START_APPLY,
+            StartScreen::ConfirmDialog => START_CONFIRM_YES,
+            StartScreen::NoticeDialog => START_CONFIRM_OK,
+            StartScreen::FormationPicker => FORMATION_CONFIRM,
+        }
+    }
+}
+
+/// Title of the dialogs the client raises mid-match.
+pub const DIALOG_TITLE: Point = Point::new(360, 600);
+pub const DIALOG_ACCEPT: Point = Point::new(280, 710);
+pub const DIALOG_DECLINE: Point = Point::new(450, 710);
+
+/// Dialogs the client raises mid-match, blocking the board until answered,
+/// told apart by matching a `ui_*` template around their title.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchDialog {
+    /// The opponent offers a draw.
+    DrawOffer,
+    /// The generals face each other; accepting settles the game as a draw.
+    Bikjang,
+}
+
+impl MatchDialog {
+    pub const ALL: [MatchDialog; 2] = [MatchDialog::DrawOffer, MatchDialog::Bikjang];
+
+    /// Template file stem that identifies the dialog.
+    pub const fn template_label(self) -> &'static str {
+        match self {
+            MatchDialog::DrawOffer => "ui_draw_offer",
+            MatchDialog::Bikjang => "ui_bikjang",
+        }
+    }
+
+    /// Screen point the template is centered on.
+    pub const fn anchor(self) -> Point {
+        DIALOG_TITLE
+    }
+}
+
+/// Where the answer buttons of a [`MatchDialog`] are.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DialogButtons {
+    pub accept: Point,
+    pub decline: Point,
+}
+
+impl Default for DialogButtons {
+    fn default() -> Self {
+        Self {
+            accept: DIALOG_ACCEPT,
+            decline: DIALOG_DECLINE,
+        }
+    }
+}
+
+/// A popup that can cover the board during a session (disconnect warning,
+/// rematch prompt, ad): recognized by its `popup_<name>` template centered
+/// on `anchor`, and closed by tapping `dismiss`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PopupTemplate {
+    pub name: String,
+    pub anchor: Point,
+    pub dismiss: Point,
+}
+
+impl PopupTemplate {
+    /// Prefix of popup template file names.
+    pub const LABEL_PREFIX: &'static str = "popup_";
+
+    pub fn template_label(&self) -> String {
+        format!("{}{}", Self::LABEL_PREFIX, self.name)
+    }
+}
+
+/// Empty area below the board; tapping it drops any piece selection.
+pub const CLEAR_SELECTION: Point = Point::new(360, 960);
+
+pub const RESIGN_MENU: Point = Point::new(660, 60);
+pub const RESIGN_CONFIRM: Point = Point::new(280, 710);
+
+/// Taps that concede the game in progress, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResignStep {
+    Menu,
+    Confirm,
+}
+
+pub fn resign_point(step: ResignStep) -> Point {
+    match step {
+        ResignStep::Menu => RESIGN_MENU,
+        ResignStep::Confirm => RESIGN_CONFIRM,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum FormationPreset {
     MasangMasang,
     SangMasangMa,
+    #[default]
     MasangSangMa,
     SangMaMaSang,
 }
 
-impl Default for FormationPreset {
-    fn default() -> Self {
-        FormationPreset::MasangSangMa
-    }
-}
-
 impl FormationPreset {
+    pub const ALL: [FormationPreset; 4] = [
+        FormationPreset::MasangMasang,
+        FormationPreset::SangMasangMa,
+        FormationPreset::MasangSangMa,
+        FormationPreset::SangMaMaSang,
+    ];
+
     pub const fn as_str(self) -> &'static str {
         match self {
             FormationPreset::MasangMasang => "MasangMasang",
@@ -63,6 +233,22 @@ impl FormationPreset {
         }
     }
 
+    /// Back-rank files holding the horses and elephants, left to right as
+    /// seen from the bottom of the board.
+    pub const MINOR_FILES: [u8; 4] = [1, 2, 6, 7];
+
+    /// Horses and elephants on [`Self::MINOR_FILES`], left to right as seen
+    /// by the player who chose the formation.
+    pub const fn minor_pieces(self) -> [PieceKind; 4] {
+        use PieceKind::{Elephant as Sang, Horse as Ma};
+        match self {
+            FormationPreset::MasangMasang => [Ma, Sang, Ma, Sang],
+            FormationPreset::SangMasangMa => [Sang, Ma, Sang, Ma],
+            FormationPreset::MasangSangMa => [Ma, Sang, Sang, Ma],
+            FormationPreset::SangMaMaSang => [Sang, Ma, Ma, Sang],
+        }
+    }
+
     pub fn variants() -> &'static [&'static str] {
         &[
             "MasangMasang",
@@ -112,13 +298,124 @@ pub fn formation_point(preset: FormationPreset) -> Point {
     }
 }
 
-pub const BOARD_FILES: [u32; 9] = [40, 125, 200, 280, 360, 440, 520, 600, 680];
-pub const BOARD_RANKS: [u32; 10] = [880, 800, 740, 670, 600, 530, 450, 380, 300, 240];
+/// Where the board's intersections sit on screen. Input and recognition
+/// both map squares through it, so calibrating it moves taps and tile crops
+/// together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoardGeometry {
+    /// Screen position of file 0, rank 0.
+    pub origin: Point,
+    /// Pixels from one file to the next.
+    pub file_spacing: f32,
+    /// Pixels from one rank to the next; negative as ranks go up the screen.
+    pub rank_spacing: f32,
+    /// Squares the client draws off the regular grid.
+    pub overrides: Vec<SquareOverride>,
+}
+
+/// Exact screen position of one square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquareOverride {
+    pub file: u8,
+    pub rank: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Default for BoardGeometry {
+    fn default() -> Self {
+        Self {
+            origin: Point::new(40, 880),
+            file_spacing: 80.0,
+            rank_spacing: -71.0,
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl BoardGeometry {
+    /// Screen position of `square`'s intersection; `None` off the board or
+    /// off the screen.
+    pub fn square_to_point(&self, square: Square) -> Option<Point> {
+        if square.file >= BoardState::DEFAULT_WIDTH || square.rank >= BoardState::DEFAULT_HEIGHT {
+            return None;
+        }
+        if let Some(exact) = self
+            .overrides
+            .iter()
+            .find(|o| o.file == square.file && o.rank == square.rank)
+        {
+            return Some(Point::new(exact.x, exact.y));
+        }
+        let x = self.origin.x as f32 + self.file_spacing * square.file as f32;
+        let y = self.origin.y as f32 + self.rank_spacing * square.rank as f32;
+        (x >= 0.0 && y >= 0.0).then(|| Point::new(x.round() as u32, y.round() as u32))
+    }
+
+    /// Every on-screen square with its intersection, file by file.
+    pub fn points(&self) -> impl Iterator<Item = (Square, Point)> + '_ {
+        (0..BoardState::DEFAULT_WIDTH)
+            .flat_map(|file| {
+                (0..BoardState::DEFAULT_HEIGHT).map(move |rank| Square::new(file, rank))
+            })
+            .filter_map(|square| Some((square, self.square_to_point(square)?)))
+    }
+
+    /// Half the width and height of the tile cropped around an
+    /// intersection: a little under half the spacing, at least 8 pixels.
+    pub fn cell_half_size(&self) -> (u32, u32) {
+        let half = |spacing: f32| (spacing.abs() * 0.45).max(8.0) as u32;
+        (half(self.file_spacing), half(self.rank_spacing))
+    }
+
+    /// The rectangle covering every tile plus `margin` pixels on each side,
+    /// clamped at the screen origin.
+    pub fn bounds(&self, margin: u32) -> ScreenRect {
+        let (half_w, half_h) = self.cell_half_size();
+        let (mut x0, mut y0, mut x1, mut y1) = (u32::MAX, u32::MAX, 0, 0);
+        for (_, point) in self.points() {
+            x0 = x0.min(point.x.saturating_sub(half_w + margin));
+            y0 = y0.min(point.y.saturating_sub(half_h + margin));
+            x1 = x1.max(point.x + half_w + margin);
+            y1 = y1.max(point.y + half_h + margin);
+        }
+        if x0 > x1 {
+            return ScreenRect {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            };
+        }
+        ScreenRect {
+            x: x0,
+            y: y0,
+            width: x1 - x0,
+            height: y1 - y0,
+        }
+    }
 
-pub fn square_to_point(square: Square) -> Option<Point> {
-    let file = BOARD_FILES.get(square.file as usize)?;
-    let rank = BOARD_RANKS.get(square.rank as usize)?;
-    Some(Point::new(*file, *rank))
+    /// The same layout seen from an image whose top-left pixel sits at
+    /// `origin` on screen, e.g. a frame cropped to [`Self::bounds`].
+    pub fn relative_to(&self, origin: Point) -> Self {
+        Self {
+            origin: Point::new(
+                self.origin.x.saturating_sub(origin.x),
+                self.origin.y.saturating_sub(origin.y),
+            ),
+            overrides: self
+                .overrides
+                .iter()
+                .map(|o| SquareOverride {
+                    x: o.x.saturating_sub(origin.x),
+                    y: o.y.saturating_sub(origin.y),
+                    ..*o
+                })
+                .collect(),
+            ..self.clone()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -128,9 +425,67 @@ mod tests {
 
     #[test]
     fn map_square_to_point() {
-        let square = Square::new(0, 0);
-        let point = square_to_point(square).expect("map square");
+        let geometry = BoardGeometry::default();
+        let point = geometry
+            .square_to_point(Square::new(0, 0))
+            .expect("map square");
         assert_eq!(point, Point::new(40, 880));
+        assert_eq!(
+            geometry.square_to_point(Square::new(8, 9)),
+            Some(Point::new(680, 241))
+        );
+        assert_eq!(geometry.square_to_point(Square::new(9, 0)), None);
+        assert_eq!(geometry.points().count(), 90);
+    }
+
+    #[test]
+    fn overridden_squares_keep_their_exact_position() {
+        let geometry = BoardGeometry {
+            overrides: vec![SquareOverride {
+                file: 4,
+                rank: 1,
+                x: 365,
+                y: 805,
+            }],
+            ..BoardGeometry::default()
+        };
+        assert_eq!(
+            geometry.square_to_point(Square::new(4, 1)),
+            Some(Point::new(365, 805))
+        );
+        assert_eq!(
+            geometry.square_to_point(Square::new(4, 2)),
+            Some(Point::new(360, 738))
+        );
+    }
+
+    #[test]
+    fn bounds_cover_every_tile_and_map_into_the_cropped_frame() {
+        let geometry = BoardGeometry::default();
+        let bounds = geometry.bounds(10);
+        assert_eq!(
+            bounds,
+            ScreenRect {
+                x: 0,
+                y: 200,
+                width: 726,
+                height: 721,
+            }
+        );
+        let local = geometry.relative_to(Point::new(bounds.x, bounds.y));
+        for (square, point) in geometry.points() {
+            assert_eq!(
+                local.square_to_point(square),
+                Some(Point::new(point.x - bounds.x, point.y - bounds.y))
+            );
+        }
+    }
+
+    #[test]
+    fn point_offset_clamps_at_origin() {
+        let point = Point::new(40, 880);
+        assert_eq!(point.offset((3, -5)), Point::new(43, 875));
+        assert_eq!(point.offset((-50, 0)), Point::new(0, 880));
     }
 
     #[test]
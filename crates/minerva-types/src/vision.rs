@@ -1,6 +1,11 @@
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::board::Square;
+use crate::ui::{Point, ScreenRect};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImageFrame {
     pub width: u32,
@@ -8,6 +13,10 @@ pub struct ImageFrame {
     /// Raw RGBA pixel buffer. Early iterations may keep PNG bytes instead.
     pub data: Vec<u8>,
     pub captured_at: DateTime<Utc>,
+    /// Screen position of the top-left pixel; non-zero for a frame cropped
+    /// to a capture region.
+    #[serde(default)]
+    pub origin: Point,
 }
 
 impl ImageFrame {
@@ -17,6 +26,7 @@ impl ImageFrame {
             height: 0,
             data: Vec::new(),
             captured_at: Utc::now(),
+            origin: Point::default(),
         }
     }
 
@@ -26,6 +36,78 @@ impl ImageFrame {
             height,
             data,
             captured_at: Utc::now(),
+            origin: Point::default(),
+        }
+    }
+
+    /// The part of the frame inside `region` (screen coordinates), keeping
+    /// its screen origin. A frame that is not raw RGBA is returned whole.
+    pub fn crop(&self, region: ScreenRect) -> ImageFrame {
+        let raw = self.data.len() == (self.width * self.height * 4) as usize;
+        let Some(local) = region.relative_to(self.origin).filter(|_| raw) else {
+            return self.clone();
+        };
+        let x0 = local.x.min(self.width);
+        let y0 = local.y.min(self.height);
+        let width = local.width.min(self.width - x0);
+        let height = local.height.min(self.height - y0);
+        let mut data = Vec::with_capacity((width * height * 4) as usize);
+        for y in y0..y0 + height {
+            let start = ((y * self.width + x0) * 4) as usize;
+            data.extend_from_slice(&self.data[start..start + (width * 4) as usize]);
+        }
+        ImageFrame {
+            width,
+            height,
+            data,
+            captured_at: self.captured_at,
+            origin: Point::new(self.origin.x + x0, self.origin.y + y0),
         }
     }
 }
+
+/// Per-recognition confidence summary attached to a snapshot.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecognitionReport {
+    /// Squares expected to hold a piece whose best template match was rejected.
+    pub low_confidence: Vec<Square>,
+    /// Mean confidence (1.0 = exact match) over accepted tiles.
+    pub mean_confidence: Option<f32>,
+    /// Saved copy of the recognized frame, when captures are persisted.
+    #[serde(default)]
+    pub frame_path: Option<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crop_keeps_pixels_at_their_screen_position() {
+        let (width, height) = (6, 4);
+        let data = (0..width * height)
+            .flat_map(|i| [i as u8, 0, 0, 255])
+            .collect();
+        let frame = ImageFrame::from_rgba(width, height, data);
+        let region = ScreenRect {
+            x: 2,
+            y: 1,
+            width: 3,
+            height: 10,
+        };
+        let cropped = frame.crop(region);
+        assert_eq!((cropped.width, cropped.height), (3, 3));
+        assert_eq!(cropped.origin, Point::new(2, 1));
+        // Screen pixel (3, 2) is index 2 * 6 + 3 in the full frame.
+        assert_eq!(cropped.data[(cropped.width + 1) as usize * 4], 15);
+
+        let again = cropped.crop(ScreenRect {
+            x: 3,
+            y: 2,
+            width: 1,
+            height: 1,
+        });
+        assert_eq!(again.origin, Point::new(3, 2));
+        assert_eq!(again.data[0], 15);
+    }
+}
@@ -1,20 +1,25 @@
 //! Board recognition abstractions.
 
-use std::{collections::HashMap, fs, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use async_trait::async_trait;
 use chrono::Utc;
 use image::{imageops, DynamicImage, GenericImageView, ImageBuffer, Rgba};
 use minerva_types::{
     board::{BoardState, Piece, PieceKind, PlayerSide, Square},
-    config::VisionConfig,
-    game::GameSnapshot,
-    ui::{BOARD_FILES, BOARD_RANKS},
-    vision::ImageFrame,
+    config::{RecognizerBackend, VisionConfig},
+    game::{GameResult, GameSnapshot},
+    ui::{BoardGeometry, ClockRegions, MatchDialog, Point, PopupTemplate, ScreenRect, StartScreen},
+    vision::{ImageFrame, RecognitionReport},
     MinervaError, Result,
 };
 use tokio::time::{sleep, Duration};
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
 /// Additional context that can guide recognition.
 #[derive(Debug, Clone, Default)]
@@ -26,17 +31,141 @@ pub struct RecognitionHints {
 pub trait BoardRecognizer: Send + Sync {
     async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState>;
     async fn recognize(&self, frame: &ImageFrame, hints: RecognitionHints) -> Result<GameSnapshot>;
+
+    /// Whether the snapshot's confidence report suggests the board is misaligned.
+    fn needs_realignment(&self, _snapshot: &GameSnapshot) -> bool {
+        false
+    }
+
+    /// The two squares the client highlights for the last move, in no
+    /// particular order (the board tells origin from destination).
+    fn recognize_last_move_highlight(&self, _frame: &ImageFrame) -> Option<(Square, Square)> {
+        None
+    }
+
+    /// The result shown by the client's end-of-game screen, if `frame` is one.
+    fn recognize_game_over(&self, _frame: &ImageFrame) -> Option<GameResult> {
+        None
+    }
+
+    /// A setup problem worth showing before the match starts, e.g. missing
+    /// templates.
+    fn setup_problem(&self) -> Option<String> {
+        None
+    }
+
+    /// Where the last alignment found the board on screen, if this
+    /// recognizer tracks it; frames can then be cropped to just the board.
+    fn board_geometry(&self) -> Option<BoardGeometry> {
+        None
+    }
+
+    /// Which match-setup screen `frame` shows, if any.
+    fn recognize_screen(&self, _frame: &ImageFrame) -> Option<StartScreen> {
+        None
+    }
+
+    /// Whether [`Self::recognize_screen`] can tell every setup screen apart;
+    /// without it the start sequence is tapped blind.
+    fn recognizes_screens(&self) -> bool {
+        false
+    }
+
+    /// The mid-match dialog `frame` shows, if any.
+    fn recognize_dialog(&self, _frame: &ImageFrame) -> Option<MatchDialog> {
+        None
+    }
+
+    /// A configured popup covering `frame`, if any, to be dismissed before
+    /// the board is read.
+    fn detect_popup(&self, _frame: &ImageFrame) -> Option<PopupTemplate> {
+        None
+    }
 }
 
+#[async_trait]
+impl<R: BoardRecognizer + ?Sized> BoardRecognizer for Box<R> {
+    async fn align_board(&self, frame: &ImageFrame) -> Result<BoardState> {
+        (**self).align_board(frame).await
+    }
+
+    async fn recognize(&self, frame: &ImageFrame, hints: RecognitionHints) -> Result<GameSnapshot> {
+        (**self).recognize(frame, hints).await
+    }
+
+    fn needs_realignment(&self, snapshot: &GameSnapshot) -> bool {
+        (**self).needs_realignment(snapshot)
+    }
+
+    fn recognize_last_move_highlight(&self, frame: &ImageFrame) -> Option<(Square, Square)> {
+        (**self).recognize_last_move_highlight(frame)
+    }
+
+    fn recognize_game_over(&self, frame: &ImageFrame) -> Option<GameResult> {
+        (**self).recognize_game_over(frame)
+    }
+
+    fn setup_problem(&self) -> Option<String> {
+        (**self).setup_problem()
+    }
+
+    fn board_geometry(&self) -> Option<BoardGeometry> {
+        (**self).board_geometry()
+    }
+
+    fn recognize_screen(&self, frame: &ImageFrame) -> Option<StartScreen> {
+        (**self).recognize_screen(frame)
+    }
+
+    fn recognizes_screens(&self) -> bool {
+        (**self).recognizes_screens()
+    }
+
+    fn recognize_dialog(&self, frame: &ImageFrame) -> Option<MatchDialog> {
+        (**self).recognize_dialog(frame)
+    }
+
+    fn detect_popup(&self, frame: &ImageFrame) -> Option<PopupTemplate> {
+        (**self).detect_popup(frame)
+    }
+}
+
+/// Builds the recognizer selected by `config.backend`, reading the board at
+/// `geometry`.
+pub fn build_recognizer(
+    config: &VisionConfig,
+    geometry: &BoardGeometry,
+) -> Box<dyn BoardRecognizer> {
+    match config.backend {
+        RecognizerBackend::TemplateMatching => Box::new(
+            TemplateMatchingRecognizer::new(config.clone()).with_geometry(geometry.clone()),
+        ),
+    }
+}
+
+/// Max per-channel distance for a pixel to count as highlight colored.
+const HIGHLIGHT_TOLERANCE: u8 = 24;
+/// Fraction of a tile that must be highlight colored; pieces cover the rest.
+const HIGHLIGHT_MIN_COVERAGE: f32 = 0.2;
+/// Min luminance difference from the clock background for a pixel to be ink.
+const CLOCK_INK_CONTRAST: u8 = 64;
+
 /// Simple recognizer placeholder using template matching semantics.
 pub struct TemplateMatchingRecognizer {
     _template_dir: PathBuf,
     capture_dir: Option<PathBuf>,
     tile_capture_dir: Option<PathBuf>,
-    cell_half_width: u32,
-    cell_half_height: u32,
+    /// Frames and tiles are saved every this many recognitions; 0 never.
+    capture_every_n: u32,
+    recognitions: AtomicU64,
+    geometry: BoardGeometry,
     confidence_threshold: f32,
+    realign_low_confidence_squares: usize,
+    highlight_color: Option<[u8; 3]>,
+    clock_regions: Option<ClockRegions>,
+    popups: Vec<PopupTemplate>,
     templates: TemplateSet,
+    template_report: TemplateLoadReport,
 }
 
 impl TemplateMatchingRecognizer {
@@ -44,30 +173,86 @@ impl TemplateMatchingRecognizer {
         let template_dir = PathBuf::from(&config.template_dir);
         let capture_dir = config.capture_dir.as_ref().map(PathBuf::from);
         let tile_capture_dir = config.tile_capture_dir.as_ref().map(PathBuf::from);
-        let (cell_half_width, cell_half_height) = compute_cell_half_sizes();
 
         info!(
             "Vision 템플릿 경로: {:?}, 캡처 저장: {:?}, 타일 저장: {:?}",
             template_dir, capture_dir, tile_capture_dir
         );
 
-        let templates = match TemplateSet::load(&template_dir) {
-            Ok(set) => set,
+        let (templates, template_report) = match TemplateSet::load(&template_dir) {
+            Ok(loaded) => loaded,
             Err(err) => {
                 warn!("템플릿 로드 실패: {err}; 인식은 빈 상태로 진행됩니다.");
-                TemplateSet::default()
+                (TemplateSet::default(), TemplateLoadReport::unloaded())
             }
         };
+        if template_report.is_complete() {
+            info!("{template_report}");
+        } else {
+            warn!(tags = "templates", "템플릿 점검 실패: {template_report}");
+        }
 
         Self {
             _template_dir: template_dir,
             capture_dir,
             tile_capture_dir,
-            cell_half_width,
-            cell_half_height,
+            capture_every_n: config.capture_every_n,
+            recognitions: AtomicU64::new(0),
+            geometry: BoardGeometry::default(),
             confidence_threshold: config.confidence_threshold,
+            realign_low_confidence_squares: config.realign_low_confidence_squares,
+            highlight_color: config.highlight_color,
+            clock_regions: config.clock_regions,
+            popups: config.popups,
             templates,
+            template_report,
+        }
+    }
+
+    /// Reads tiles at `geometry` instead of the default board layout.
+    pub fn with_geometry(mut self, geometry: BoardGeometry) -> Self {
+        self.geometry = geometry;
+        self
+    }
+
+    /// What was loaded from the template directory at construction.
+    pub fn template_report(&self) -> &TemplateLoadReport {
+        &self.template_report
+    }
+
+    /// Loads a PNG/JPG screenshot and recognizes it without any hints.
+    pub async fn recognize_path(&self, path: &Path) -> Result<GameSnapshot> {
+        let frame = load_frame(path)?;
+        self.recognize(&frame, RecognitionHints::default()).await
+    }
+
+    /// Distance between the `label` UI template and the frame area it would
+    /// cover centered on screen point `anchor`, if loaded, inside the frame,
+    /// and within the confidence threshold.
+    fn ui_template_distance(
+        &self,
+        image: &DynamicImage,
+        frame: &ImageFrame,
+        label: &str,
+        anchor: Point,
+    ) -> Option<f32> {
+        let template = self.templates.screens.get(label)?;
+        let (width, height) = template.dimensions();
+        let x = anchor.x.checked_sub(frame.origin.x + width / 2)?;
+        let y = anchor.y.checked_sub(frame.origin.y + height / 2)?;
+        if x + width > image.width() || y + height > image.height() {
+            return None;
         }
+        let crop = image.crop_imm(x, y, width, height);
+        let distance = template_distance(&crop, template) / 255.0;
+        (distance <= self.confidence_threshold).then_some(distance)
+    }
+
+    /// Counts a recognition and tells whether its frame and tiles are saved
+    /// (see `VisionConfig::capture_every_n`).
+    fn sample_capture(&self) -> bool {
+        let index = self.recognitions.fetch_add(1, Ordering::Relaxed);
+        self.capture_every_n > 0 && index.is_multiple_of(u64::from(self.capture_every_n))
     }
 
     fn persist_capture(&self, frame: &ImageFrame) -> Result<Option<PathBuf>> {
@@ -93,6 +278,27 @@ impl TemplateMatchingRecognizer {
         Ok(Some(path))
     }
 
+    /// Overwrites each side's clock in `snapshot` that reads cleanly; an
+    /// unreadable clock keeps its previous value.
+    fn read_clocks(&self, frame: &ImageFrame, regions: ClockRegions, snapshot: &mut GameSnapshot) {
+        let Some(image) = frame_image(frame) else {
+            return;
+        };
+        let threshold = self.confidence_threshold;
+        let read = |rect: ScreenRect| {
+            let rect = rect.relative_to(frame.origin)?;
+            self.templates.read_clock(&image, rect, threshold)
+        };
+        match read(regions.blue) {
+            Some(ms) => snapshot.clocks.blue_ms = ms,
+            None => debug!("Blue 시계를 읽지 못했습니다"),
+        }
+        match read(regions.red) {
+            Some(ms) => snapshot.clocks.red_ms = ms,
+            None => debug!("Red 시계를 읽지 못했습니다"),
+        }
+    }
+
     fn export_tiles(&self, frame: &ImageFrame) -> Result<()> {
         let Some(dir) = &self.tile_capture_dir else {
             return Ok(());
@@ -111,26 +317,26 @@ impl TemplateMatchingRecognizer {
         };
         let timestamp = Utc::now().format("%Y%m%d_%H%M%S_%3f");
 
-        for (file_idx, &cx) in BOARD_FILES.iter().enumerate() {
-            for (rank_idx, &cy) in BOARD_RANKS.iter().enumerate() {
-                let x0 = cx.saturating_sub(self.cell_half_width);
-                let y0 = cy.saturating_sub(self.cell_half_height);
+        let geometry = self.geometry.relative_to(frame.origin);
+        let (half_w, half_h) = geometry.cell_half_size();
+        for (sq, center) in geometry.points() {
+            let x0 = center.x.saturating_sub(half_w);
+            let y0 = center.y.saturating_sub(half_h);
 
-                let max_width = frame.width.saturating_sub(x0);
-                let max_height = frame.height.saturating_sub(y0);
-                let crop_width = (self.cell_half_width * 2).min(max_width);
-                let crop_height = (self.cell_half_height * 2).min(max_height);
+            let max_width = frame.width.saturating_sub(x0);
+            let max_height = frame.height.saturating_sub(y0);
+            let crop_width = (half_w * 2).min(max_width);
+            let crop_height = (half_h * 2).min(max_height);
 
-                if crop_width == 0 || crop_height == 0 {
-                    continue;
-                }
-
-                let tile = imageops::crop_imm(&buffer, x0, y0, crop_width, crop_height).to_image();
-                let filename = format!("f{}_r{}_{}.png", file_idx + 1, rank_idx + 1, timestamp);
-                let path = dir.join(filename);
-                tile.save(&path)
-                    .map_err(|err| vision_error(format!("타일 저장 실패: {err}")))?;
+            if crop_width == 0 || crop_height == 0 {
+                continue;
             }
+
+            let tile = imageops::crop_imm(&buffer, x0, y0, crop_width, crop_height).to_image();
+            let filename = format!("f{}_r{}_{}.png", sq.file + 1, sq.rank + 1, timestamp);
+            let path = dir.join(filename);
+            tile.save(&path)
+                .map_err(|err| vision_error(format!("타일 저장 실패: {err}")))?;
         }
 
         Ok(())
@@ -155,22 +361,35 @@ impl BoardRecognizer for TemplateMatchingRecognizer {
         if let Some(prev) = hints.previous_snapshot.as_ref() {
             board.side_to_move = prev.board.side_to_move;
         }
-        if let Ok(Some(path)) = self.persist_capture(frame) {
-            info!("저장된 스크린샷: {:?}", path);
-        }
-        if let Err(err) = self.export_tiles(frame) {
-            tracing::warn!("타일 추출 실패: {err}");
+        let mut frame_path = None;
+        if self.sample_capture() {
+            match self.persist_capture(frame) {
+                Ok(Some(path)) => {
+                    info!("저장된 스크린샷: {:?}", path);
+                    frame_path = Some(path);
+                }
+                Ok(None) => {}
+                Err(err) => tracing::warn!("스크린샷 저장 실패: {err}"),
+            }
+            if let Err(err) = self.export_tiles(frame) {
+                tracing::warn!("타일 추출 실패: {err}");
+            }
         }
-        self.templates.recognize_tiles(
+        let mut report = self.templates.recognize_tiles(
             frame,
             &mut board,
-            self.cell_half_width,
-            self.cell_half_height,
+            hints.previous_snapshot.as_ref().map(|prev| &prev.board),
+            &self.geometry.relative_to(frame.origin),
             self.confidence_threshold,
         );
 
+        report.frame_path = frame_path;
         let mut snapshot = hints.previous_snapshot.clone().unwrap_or_default();
+        if let Some(regions) = self.clock_regions {
+            self.read_clocks(frame, regions, &mut snapshot);
+        }
         snapshot.board = board;
+        snapshot.recognition = report;
         snapshot.created_at = Utc::now();
         info!(
             "Returning mock snapshot; hints present: {}",
@@ -178,42 +397,225 @@ impl BoardRecognizer for TemplateMatchingRecognizer {
         );
         Ok(snapshot)
     }
+
+    fn needs_realignment(&self, snapshot: &GameSnapshot) -> bool {
+        snapshot.recognition.low_confidence.len() > self.realign_low_confidence_squares
+    }
+
+    fn recognize_last_move_highlight(&self, frame: &ImageFrame) -> Option<(Square, Square)> {
+        let color = self.highlight_color?;
+        let geometry = self.geometry.relative_to(frame.origin);
+        let highlighted = highlighted_squares(frame, color, &geometry);
+        match highlighted.as_slice() {
+            &[a, b] => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    fn setup_problem(&self) -> Option<String> {
+        (!self.template_report.is_complete())
+            .then(|| format!("템플릿 점검 실패: {}", self.template_report))
+    }
+
+    fn board_geometry(&self) -> Option<BoardGeometry> {
+        Some(self.geometry.clone())
+    }
+
+    fn recognize_screen(&self, frame: &ImageFrame) -> Option<StartScreen> {
+        let image = frame_image(frame)?;
+        StartScreen::ALL
+            .into_iter()
+            .filter_map(|screen| {
+                self.ui_template_distance(&image, frame, screen.template_label(), screen.anchor())
+                    .map(|distance| (screen, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(screen, _)| screen)
+    }
+
+    fn recognizes_screens(&self) -> bool {
+        StartScreen::ALL
+            .iter()
+            .all(|screen| self.templates.screens.contains_key(screen.template_label()))
+    }
+
+    fn recognize_dialog(&self, frame: &ImageFrame) -> Option<MatchDialog> {
+        let image = frame_image(frame)?;
+        MatchDialog::ALL
+            .into_iter()
+            .filter_map(|dialog| {
+                self.ui_template_distance(&image, frame, dialog.template_label(), dialog.anchor())
+                    .map(|distance| (dialog, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(dialog, _)| dialog)
+    }
+
+    fn detect_popup(&self, frame: &ImageFrame) -> Option<PopupTemplate> {
+        if self.popups.is_empty() {
+            return None;
+        }
+        let image = frame_image(frame)?;
+        self.popups
+            .iter()
+            .filter_map(|popup| {
+                self.ui_template_distance(&image, frame, &popup.template_label(), popup.anchor)
+                    .map(|distance| (popup, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(popup, _)| popup.clone())
+    }
 }
 
-fn compute_cell_half_sizes() -> (u32, u32) {
-    fn average_spacing(values: &[u32]) -> f32 {
-        if values.len() < 2 {
-            return 1.0;
+/// Squares whose tile around the intersection center is mostly `color`.
+fn highlighted_squares(
+    frame: &ImageFrame,
+    color: [u8; 3],
+    geometry: &BoardGeometry,
+) -> Vec<Square> {
+    let mut squares = Vec::new();
+    if frame.width == 0 || frame.height == 0 {
+        return squares;
+    }
+    let (half_w, half_h) = geometry.cell_half_size();
+    for (sq, center) in geometry.points() {
+        let (cx, cy) = (center.x, center.y);
+        let x0 = cx.saturating_sub(half_w);
+        let y0 = cy.saturating_sub(half_h);
+        let x1 = (cx + half_w).min(frame.width);
+        let y1 = (cy + half_h).min(frame.height);
+        if x0 >= x1 || y0 >= y1 {
+            continue;
         }
-        let mut total = 0f32;
-        let mut count = 0f32;
-        for window in values.windows(2) {
-            let delta = window[1] as i32 - window[0] as i32;
-            total += (delta.abs()) as f32;
-            count += 1.0;
+        let mut matching = 0usize;
+        for y in y0..y1 {
+            for x in x0..x1 {
+                let idx = ((y * frame.width + x) * 4) as usize;
+                let Some(pixel) = frame.data.get(idx..idx + 3) else {
+                    continue;
+                };
+                if pixel
+                    .iter()
+                    .zip(color)
+                    .all(|(&p, c)| p.abs_diff(c) <= HIGHLIGHT_TOLERANCE)
+                {
+                    matching += 1;
+                }
+            }
         }
-        if count == 0.0 {
-            1.0
-        } else {
-            total / count
+        let total = ((x1 - x0) * (y1 - y0)) as f32;
+        if matching as f32 / total >= HIGHLIGHT_MIN_COVERAGE {
+            squares.push(sq);
+        }
+    }
+    squares
+}
+
+/// The frame as an image, or `None` when it is empty or not raw RGBA.
+fn frame_image(frame: &ImageFrame) -> Option<DynamicImage> {
+    if frame.width == 0 || frame.height == 0 {
+        return None;
+    }
+    ImageBuffer::<Rgba<u8>, _>::from_raw(frame.width, frame.height, frame.data.clone())
+        .map(DynamicImage::ImageRgba8)
+}
+
+/// Decodes an image file into an RGBA [`ImageFrame`].
+pub fn load_frame(path: &Path) -> Result<ImageFrame> {
+    let image = image::open(path)
+        .map_err(|err| vision_error(format!("이미지 로드 실패({:?}): {err}", path)))?;
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    Ok(ImageFrame::from_rgba(width, height, rgba.into_raw()))
+}
+
+const TEMPLATE_OWNERS: [&str; 2] = ["blue", "red"];
+const TEMPLATE_KINDS: [&str; 7] = [
+    "general", "guard", "elephant", "horse", "chariot", "cannon", "soldier",
+];
+/// Non-piece templates that may sit next to the piece set; they classify as
+/// empty squares.
+const AUXILIARY_TEMPLATES: [&str; 1] = ["board_reference"];
+/// Prefix of the optional clock digit templates, `digit_0` to `digit_9`.
+const DIGIT_TEMPLATE_PREFIX: &str = "digit_";
+
+/// What [`TemplateSet::load`] found in the template directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TemplateLoadReport {
+    /// Labels that loaded, sorted.
+    pub loaded: Vec<String>,
+    /// `owner_kind` labels with no usable template.
+    pub missing: Vec<String>,
+    /// Image files whose name is not a known label; they are not loaded.
+    pub unexpected: Vec<PathBuf>,
+    /// Image files that could not be decoded, with the reason.
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+impl TemplateLoadReport {
+    /// Report for a template directory that could not be read at all.
+    fn unloaded() -> Self {
+        Self {
+            missing: expected_labels(),
+            ..Self::default()
         }
     }
 
-    let avg_width = average_spacing(&BOARD_FILES);
-    let avg_height = average_spacing(&BOARD_RANKS);
-    let half_width = ((avg_width * 0.45).max(8.0)) as u32;
-    let half_height = ((avg_height * 0.45).max(8.0)) as u32;
-    (half_width, half_height)
+    /// Whether every piece has a template and no file was skipped.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.unexpected.is_empty() && self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for TemplateLoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "템플릿 {}개 로드", self.loaded.len())?;
+        if !self.missing.is_empty() {
+            write!(f, ", 누락: {}", self.missing.join(", "))?;
+        }
+        if !self.unexpected.is_empty() {
+            let names: Vec<_> = self
+                .unexpected
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            write!(f, ", 알 수 없는 파일: {}", names.join(", "))?;
+        }
+        for (path, reason) in &self.failed {
+            write!(f, ", 디코딩 실패 {}: {reason}", path.display())?;
+        }
+        Ok(())
+    }
+}
+
+/// Every `owner_kind` label the recognizer needs a template for.
+fn expected_labels() -> Vec<String> {
+    TEMPLATE_OWNERS
+        .iter()
+        .flat_map(|owner| {
+            TEMPLATE_KINDS
+                .iter()
+                .map(move |kind| format!("{owner}_{kind}"))
+        })
+        .collect()
 }
 
 #[derive(Default, Clone)]
 struct TemplateSet {
     templates: HashMap<String, DynamicImage>,
+    /// Clock digit templates, kept apart so they never classify board tiles.
+    digits: HashMap<u8, DynamicImage>,
+    /// Setup screen templates by label, likewise kept apart.
+    screens: HashMap<String, DynamicImage>,
 }
 
 impl TemplateSet {
-    fn load(dir: &PathBuf) -> Result<Self> {
-        let mut templates = HashMap::new();
+    /// Decodes every known template in `dir` in parallel and checks the set
+    /// against [`expected_labels`]. Misnamed files are skipped rather than
+    /// loaded, since any non-piece template classifies tiles as empty.
+    fn load(dir: &Path) -> Result<(Self, TemplateLoadReport)> {
+        let mut report = TemplateLoadReport::default();
+        let mut labelled = Vec::new();
         if dir.is_dir() {
             for entry in fs::read_dir(dir)
                 .map_err(|err| vision_error(format!("템플릿 디렉터리 읽기 실패: {err}")))?
@@ -221,54 +623,208 @@ impl TemplateSet {
                 let entry =
                     entry.map_err(|err| vision_error(format!("템플릿 파일 읽기 실패: {err}")))?;
                 let path = entry.path();
-                if path
+                if !path
                     .extension()
                     .and_then(|s| s.to_str())
-                    .map_or(false, |ext| matches!(ext, "png" | "jpg" | "jpeg"))
+                    .is_some_and(|ext| matches!(ext, "png" | "jpg" | "jpeg"))
                 {
-                    if let Ok(image) = image::open(&path) {
-                        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
-                            templates.insert(stem.to_string(), image);
-                        }
+                    continue;
+                }
+                match path.file_stem().and_then(|s| s.to_str()) {
+                    Some(stem)
+                        if parse_label(stem).is_some()
+                            || parse_digit_label(stem).is_some()
+                            || is_screen_label(stem)
+                            || AUXILIARY_TEMPLATES.contains(&stem) =>
+                    {
+                        labelled.push((stem.to_string(), path.clone()));
                     }
+                    _ => report.unexpected.push(path),
                 }
             }
         }
-        Ok(Self { templates })
+
+        let decoded: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = labelled
+                .iter()
+                .map(|(label, path)| {
+                    let handle =
+                        scope.spawn(move || image::open(path).map_err(|err| err.to_string()));
+                    (label.clone(), path.clone(), handle)
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|(label, path, handle)| {
+                    let image = handle
+                        .join()
+                        .unwrap_or_else(|_| Err("decoder panicked".to_string()));
+                    (label, path, image)
+                })
+                .collect()
+        });
+
+        let mut templates = HashMap::new();
+        let mut digits = HashMap::new();
+        let mut screens = HashMap::new();
+        for (label, path, image) in decoded {
+            match image {
+                Ok(image) => {
+                    report.loaded.push(label.clone());
+                    match parse_digit_label(&label) {
+                        Some(digit) => digits.insert(digit, image),
+                        None if is_screen_label(&label) => screens.insert(label, image),
+                        None => templates.insert(label, image),
+                    };
+                }
+                Err(reason) => report.failed.push((path, reason)),
+            }
+        }
+        report.loaded.sort();
+        report.missing = expected_labels()
+            .into_iter()
+            .filter(|label| !templates.contains_key(label))
+            .collect();
+        report.unexpected.sort();
+        report.failed.sort();
+        Ok((
+            Self {
+                templates,
+                digits,
+                screens,
+            },
+            report,
+        ))
     }
 
+    /// Reads an `M:SS` or `H:MM:SS` clock from `rect` of `image`, in
+    /// milliseconds. Glyphs are split on ink-free columns; a glyph with an
+    /// ink-free row inside it is a colon, anything else must match a digit
+    /// template (cropped to the digit's ink) within `confidence_threshold`.
+    fn read_clock(
+        &self,
+        image: &DynamicImage,
+        rect: ScreenRect,
+        confidence_threshold: f32,
+    ) -> Option<u64> {
+        if self.digits.is_empty() {
+            return None;
+        }
+        let width = rect.width.min(image.width().saturating_sub(rect.x));
+        let height = rect.height.min(image.height().saturating_sub(rect.y));
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let region = image.crop_imm(rect.x, rect.y, width, height);
+        let luma = region.to_luma8();
+        let border: Vec<u32> = (0..width)
+            .flat_map(|x| [(x, 0), (x, height - 1)])
+            .chain((0..height).flat_map(|y| [(0, y), (width - 1, y)]))
+            .map(|(x, y)| u32::from(luma.get_pixel(x, y)[0]))
+            .collect();
+        let background = (border.iter().sum::<u32>() / border.len() as u32) as u8;
+        let ink =
+            |x: u32, y: u32| luma.get_pixel(x, y)[0].abs_diff(background) > CLOCK_INK_CONTRAST;
+
+        let mut groups = vec![Vec::new()];
+        let mut x = 0;
+        while x < width {
+            if !(0..height).any(|y| ink(x, y)) {
+                x += 1;
+                continue;
+            }
+            let x0 = x;
+            while x < width && (0..height).any(|y| ink(x, y)) {
+                x += 1;
+            }
+            let rows: Vec<bool> = (0..height).map(|y| (x0..x).any(|gx| ink(gx, y))).collect();
+            let y0 = rows.iter().position(|&r| r)? as u32;
+            let y1 = rows.iter().rposition(|&r| r)? as u32 + 1;
+            if rows[y0 as usize..y1 as usize].contains(&false) {
+                groups.push(Vec::new());
+                continue;
+            }
+            let glyph = region.crop_imm(x0, y0, x - x0, y1 - y0);
+            let (digit, distance) = self
+                .digits
+                .iter()
+                .map(|(&digit, template)| (digit, template_distance(&glyph, template) / 255.0))
+                .min_by(|a, b| a.1.total_cmp(&b.1))?;
+            if distance > confidence_threshold {
+                return None;
+            }
+            groups.last_mut()?.push(u64::from(digit));
+        }
+
+        if !(2..=3).contains(&groups.len()) || groups.iter().any(Vec::is_empty) {
+            return None;
+        }
+        let values: Vec<u64> = groups
+            .iter()
+            .map(|group| group.iter().fold(0, |acc, d| acc * 10 + d))
+            .collect();
+        if values[1..].iter().any(|&v| v >= 60) {
+            return None;
+        }
+        let seconds = values.iter().fold(0, |acc, v| acc * 60 + v);
+        Some(seconds * 1000)
+    }
+
+    /// Classifies every tile into `board`. Squares occupied in `expected` whose
+    /// best match is rejected are reported as low-confidence.
     fn recognize_tiles(
         &self,
         frame: &ImageFrame,
         board: &mut BoardState,
-        half_w: u32,
-        half_h: u32,
+        expected: Option<&BoardState>,
+        geometry: &BoardGeometry,
         confidence_threshold: f32,
-    ) {
-        if self.templates.is_empty() || frame.width == 0 || frame.height == 0 {
-            return;
+    ) -> RecognitionReport {
+        let mut report = RecognitionReport::default();
+        if self.templates.is_empty() {
+            return report;
         }
-        let Some(buffer) =
-            ImageBuffer::<Rgba<u8>, _>::from_raw(frame.width, frame.height, frame.data.clone())
-        else {
-            return;
+        let Some(big) = frame_image(frame) else {
+            return report;
         };
-        let big = DynamicImage::ImageRgba8(buffer);
-
-        for (file_idx, &cx) in BOARD_FILES.iter().enumerate() {
-            for (rank_idx, &cy) in BOARD_RANKS.iter().enumerate() {
-                let sq = Square::new(file_idx as u8, rank_idx as u8);
-                let tile = crop_tile(&big, cx, cy, half_w, half_h);
-                if let Some((owner, kind)) =
-                    classify_tile(&tile, &self.templates, confidence_threshold)
-                {
+
+        let mut confidence_sum = 0f32;
+        let mut accepted = 0usize;
+        let (half_w, half_h) = geometry.cell_half_size();
+        for (sq, center) in geometry.points() {
+            let tile = crop_tile(&big, center.x, center.y, half_w, half_h);
+            let Some(tile_match) = classify_tile(&tile, &self.templates) else {
+                continue;
+            };
+            let piece = (tile_match.distance <= confidence_threshold)
+                .then(|| parse_label(tile_match.label))
+                .flatten();
+            match piece {
+                Some((owner, kind)) => {
                     board.set_piece(sq, Some(Piece { owner, kind }));
+                    confidence_sum += 1.0 - tile_match.distance;
+                    accepted += 1;
+                }
+                None => {
+                    if expected.is_some_and(|b| !b.is_empty(sq)) {
+                        report.low_confidence.push(sq);
+                    }
                 }
             }
         }
+        if accepted > 0 {
+            report.mean_confidence = Some(confidence_sum / accepted as f32);
+        }
+        report
     }
 }
 
+/// Best template for a tile with its normalized distance (0.0 = identical).
+struct TileMatch<'a> {
+    label: &'a str,
+    distance: f32,
+}
+
 fn crop_tile(image: &DynamicImage, cx: u32, cy: u32, half_w: u32, half_h: u32) -> DynamicImage {
     let x0 = cx.saturating_sub(half_w);
     let y0 = cy.saturating_sub(half_h);
@@ -278,11 +834,10 @@ fn crop_tile(image: &DynamicImage, cx: u32, cy: u32, half_w: u32, half_h: u32) -
     DynamicImage::ImageRgba8(crop)
 }
 
-fn classify_tile(
+fn classify_tile<'a>(
     tile: &DynamicImage,
-    templates: &HashMap<String, DynamicImage>,
-    threshold: f32,
-) -> Option<(PlayerSide, PieceKind)> {
+    templates: &'a HashMap<String, DynamicImage>,
+) -> Option<TileMatch<'a>> {
     let mut best_score = f32::MAX;
     let mut best_label: Option<&str> = None;
     for (label, template) in templates.iter() {
@@ -292,15 +847,10 @@ fn classify_tile(
             best_label = Some(label);
         }
     }
-    if let Some(label) = best_label {
-        let normalized = best_score / 255.0;
-        if normalized > threshold {
-            return None;
-        }
-        parse_label(label)
-    } else {
-        None
-    }
+    best_label.map(|label| TileMatch {
+        label,
+        distance: best_score / 255.0,
+    })
 }
 
 fn template_distance(a: &DynamicImage, b: &DynamicImage) -> f32 {
@@ -350,6 +900,482 @@ fn parse_label(label: &str) -> Option<(PlayerSide, PieceKind)> {
     Some((owner, kind))
 }
 
+/// Whether `label` names a setup-screen, mid-match dialog, or popup
+/// template.
+fn is_screen_label(label: &str) -> bool {
+    label.starts_with(PopupTemplate::LABEL_PREFIX)
+        || StartScreen::ALL
+            .iter()
+            .map(|screen| screen.template_label())
+            .chain(
+                MatchDialog::ALL
+                    .iter()
+                    .map(|dialog| dialog.template_label()),
+            )
+            .any(|ui| ui == label)
+}
+
+/// The digit of a `digit_N` template label.
+fn parse_digit_label(label: &str) -> Option<u8> {
+    let digit = label.strip_prefix(DIGIT_TEMPLATE_PREFIX)?;
+    match digit.as_bytes() {
+        &[d @ b'0'..=b'9'] => Some(d - b'0'),
+        _ => None,
+    }
+}
+
 pub fn vision_error(message: impl Into<String>) -> MinervaError {
     MinervaError::Vision(message.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vision_config(template_dir: &Path) -> VisionConfig {
+        VisionConfig {
+            backend: RecognizerBackend::TemplateMatching,
+            template_dir: template_dir.to_string_lossy().into_owned(),
+            confidence_threshold: 0.2,
+            refresh_interval_ms: 250,
+            capture_dir: None,
+            tile_capture_dir: None,
+            capture_every_n: 1,
+            realign_low_confidence_squares: 9,
+            highlight_color: None,
+            clock_regions: None,
+            stable_captures: 1,
+            stable_capture_delay_ms: 80,
+            max_frame_age_ms: 2_000,
+            capture_region_margin: None,
+            popups: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn recognize_path_loads_png_screenshot() {
+        let dir = std::env::temp_dir().join(format!("minerva-vision-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let screenshot = dir.join("board.png");
+        ImageBuffer::from_pixel(720, 1280, Rgba([200u8, 170, 120, 255]))
+            .save(&screenshot)
+            .unwrap();
+
+        let recognizer = TemplateMatchingRecognizer::new(vision_config(&dir.join("templates")));
+        let frame = load_frame(&screenshot).unwrap();
+        assert_eq!((frame.width, frame.height), (720, 1280));
+        assert_eq!(frame.data.len(), 720 * 1280 * 4);
+
+        let snapshot = recognizer.recognize_path(&screenshot).await.unwrap();
+        assert_eq!(snapshot.board.differences(&BoardState::empty()).len(), 0);
+
+        assert!(recognizer
+            .recognize_path(&dir.join("missing.png"))
+            .await
+            .is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn captures_are_saved_every_nth_recognition() {
+        let dir = std::env::temp_dir().join(format!("minerva-sampling-{}", std::process::id()));
+        let mut config = vision_config(&dir.join("templates"));
+        config.capture_dir = Some(dir.join("frames").to_string_lossy().into_owned());
+        config.capture_every_n = 3;
+        let recognizer = TemplateMatchingRecognizer::new(config.clone());
+        let frame = ImageFrame::from_rgba(8, 8, vec![0; 8 * 8 * 4]);
+
+        let mut saved = Vec::new();
+        for _ in 0..5 {
+            let snapshot = recognizer
+                .recognize(&frame, RecognitionHints::default())
+                .await
+                .unwrap();
+            saved.push(snapshot.recognition.frame_path.is_some());
+        }
+        assert_eq!(saved, [true, false, false, true, false]);
+
+        config.capture_every_n = 0;
+        let recognizer = TemplateMatchingRecognizer::new(config);
+        let snapshot = recognizer
+            .recognize(&frame, RecognitionHints::default())
+            .await
+            .unwrap();
+        assert!(snapshot.recognition.frame_path.is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn template_report_flags_missing_misnamed_and_broken_files() {
+        let dir = std::env::temp_dir().join(format!("minerva-templates-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let tile = ImageBuffer::from_pixel(4, 4, Rgba([10u8, 20, 30, 255]));
+        for label in expected_labels() {
+            if label != "red_general" && label != "blue_horse" {
+                tile.save(dir.join(format!("{label}.png"))).unwrap();
+            }
+        }
+        tile.save(dir.join("board_reference.png")).unwrap();
+        tile.save(dir.join("red_genral.png")).unwrap();
+        fs::write(dir.join("blue_horse.png"), b"not a png").unwrap();
+        fs::write(dir.join("notes.txt"), b"ignored").unwrap();
+
+        let recognizer = TemplateMatchingRecognizer::new(vision_config(&dir));
+        let report = recognizer.template_report();
+        assert_eq!(report.loaded.len(), 13);
+        assert!(report.loaded.contains(&"board_reference".to_string()));
+        assert_eq!(report.missing, ["blue_horse", "red_general"]);
+        assert_eq!(report.unexpected, [dir.join("red_genral.png")]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, dir.join("blue_horse.png"));
+        assert!(!report.is_complete());
+        assert!(!recognizer.templates.templates.contains_key("red_genral"));
+
+        let unreadable = TemplateMatchingRecognizer::new(vision_config(Path::new("missing")));
+        assert_eq!(unreadable.template_report().missing.len(), 14);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn configured_backend_is_built_and_reports_setup_problems() {
+        let recognizer = build_recognizer(
+            &vision_config(Path::new("missing")),
+            &BoardGeometry::default(),
+        );
+        let problem = recognizer.setup_problem().unwrap();
+        assert!(problem.contains("누락"), "{problem}");
+    }
+
+    /// 720x1280 board-colored frame with the given tiles of `geometry`
+    /// filled by `color`, each with a "piece" disc covering its center.
+    fn frame_with_highlights(
+        geometry: &BoardGeometry,
+        squares: &[Square],
+        color: [u8; 3],
+    ) -> ImageFrame {
+        let (half_w, half_h) = geometry.cell_half_size();
+        let mut image = ImageBuffer::from_pixel(720, 1280, Rgba([200u8, 170, 120, 255]));
+        for sq in squares {
+            let center = geometry.square_to_point(*sq).unwrap();
+            let (cx, cy) = (center.x, center.y);
+            for y in cy - half_h..cy + half_h {
+                for x in cx.saturating_sub(half_w)..cx + half_w {
+                    let (dx, dy) = (x as i64 - cx as i64, y as i64 - cy as i64);
+                    let inside_piece = dx * dx + dy * dy < (half_h as i64 / 2).pow(2);
+                    let pixel = if inside_piece {
+                        Rgba([30, 30, 30, 255])
+                    } else {
+                        Rgba([color[0], color[1], color[2], 255])
+                    };
+                    image.put_pixel(x, y, pixel);
+                }
+            }
+        }
+        ImageFrame::from_rgba(720, 1280, image.into_raw())
+    }
+
+    #[test]
+    fn last_move_highlight_needs_exactly_two_tiles() {
+        let yellow = [240, 220, 60];
+        let config = VisionConfig {
+            highlight_color: Some(yellow),
+            ..vision_config(Path::new("missing-templates"))
+        };
+        let recognizer = TemplateMatchingRecognizer::new(config);
+        let (from, to) = (Square::new(1, 0), Square::new(2, 2));
+        let geometry = BoardGeometry::default();
+
+        let frame = frame_with_highlights(&geometry, &[from, to], yellow);
+        assert_eq!(
+            recognizer.recognize_last_move_highlight(&frame),
+            Some((from, to))
+        );
+
+        let plain = frame_with_highlights(&geometry, &[], yellow);
+        assert_eq!(recognizer.recognize_last_move_highlight(&plain), None);
+        let noisy = frame_with_highlights(&geometry, &[from, to, Square::new(4, 4)], yellow);
+        assert_eq!(recognizer.recognize_last_move_highlight(&noisy), None);
+
+        let disabled = TemplateMatchingRecognizer::new(vision_config(Path::new("missing")));
+        assert_eq!(disabled.recognize_last_move_highlight(&frame), None);
+    }
+
+    #[test]
+    fn tiles_follow_the_configured_geometry() {
+        let yellow = [240, 220, 60];
+        let shifted = BoardGeometry {
+            origin: minerva_types::ui::Point::new(100, 950),
+            file_spacing: 66.0,
+            rank_spacing: -74.0,
+            ..BoardGeometry::default()
+        };
+        let config = VisionConfig {
+            highlight_color: Some(yellow),
+            ..vision_config(Path::new("missing-templates"))
+        };
+        let (from, to) = (Square::new(7, 9), Square::new(8, 9));
+        let frame = frame_with_highlights(&shifted, &[from, to], yellow);
+
+        let calibrated = TemplateMatchingRecognizer::new(config.clone()).with_geometry(shifted);
+        assert_eq!(
+            calibrated.recognize_last_move_highlight(&frame),
+            Some((from, to))
+        );
+        let uncalibrated = TemplateMatchingRecognizer::new(config);
+        assert_ne!(
+            uncalibrated.recognize_last_move_highlight(&frame),
+            Some((from, to))
+        );
+    }
+
+    #[test]
+    fn frames_cropped_to_the_board_read_the_same() {
+        let yellow = [240, 220, 60];
+        let geometry = BoardGeometry::default();
+        let config = VisionConfig {
+            highlight_color: Some(yellow),
+            ..vision_config(Path::new("missing-templates"))
+        };
+        let (from, to) = (Square::new(2, 0), Square::new(2, 4));
+        let frame = frame_with_highlights(&geometry, &[from, to], yellow);
+        let cropped = frame.crop(geometry.bounds(8));
+        assert!(cropped.origin.y > 0 && cropped.height < frame.height);
+
+        let recognizer = TemplateMatchingRecognizer::new(config);
+        assert_eq!(
+            recognizer.recognize_last_move_highlight(&cropped),
+            Some((from, to))
+        );
+        assert_eq!(recognizer.board_geometry(), Some(geometry));
+    }
+
+    /// 3x5 bitmaps of the digits 0-9, one string per row.
+    const DIGIT_GLYPHS: [[&str; 5]; 10] = [
+        ["###", "#.#", "#.#", "#.#", "###"],
+        ["##.", ".#.", ".#.", ".#.", "###"],
+        ["###", "..#", "###", "#..", "###"],
+        ["###", "..#", "###", "..#", "###"],
+        ["#.#", "#.#", "###", "..#", "..#"],
+        ["###", "#..", "###", "..#", "###"],
+        ["###", "#..", "###", "#.#", "###"],
+        ["###", "..#", "..#", "..#", "..#"],
+        ["###", "#.#", "###", "#.#", "###"],
+        ["###", "#.#", "###", "..#", "###"],
+    ];
+    const COLON_GLYPH: [&str; 5] = ["...", ".#.", "...", ".#.", "..."];
+    const GLYPH_SCALE: u32 = 4;
+    const CLOCK_BACKGROUND: Rgba<u8> = Rgba([40, 40, 40, 255]);
+    const CLOCK_INK: Rgba<u8> = Rgba([235, 235, 235, 255]);
+
+    fn draw_glyph(image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>, glyph: &[&str; 5], x: u32, y: u32) {
+        for (row, line) in glyph.iter().enumerate() {
+            for (col, cell) in line.chars().enumerate() {
+                let pixel = if cell == '#' {
+                    CLOCK_INK
+                } else {
+                    CLOCK_BACKGROUND
+                };
+                for dy in 0..GLYPH_SCALE {
+                    for dx in 0..GLYPH_SCALE {
+                        let px = x + col as u32 * GLYPH_SCALE + dx;
+                        let py = y + row as u32 * GLYPH_SCALE + dy;
+                        image.put_pixel(px, py, pixel);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws `text` (digits and colons) into `rect`, padded from its edges.
+    fn draw_clock(image: &mut ImageBuffer<Rgba<u8>, Vec<u8>>, rect: ScreenRect, text: &str) {
+        for y in rect.y..rect.y + rect.height {
+            for x in rect.x..rect.x + rect.width {
+                image.put_pixel(x, y, CLOCK_BACKGROUND);
+            }
+        }
+        let mut x = rect.x + 4;
+        for ch in text.chars() {
+            let glyph = match ch.to_digit(10) {
+                Some(d) => &DIGIT_GLYPHS[d as usize],
+                None => &COLON_GLYPH,
+            };
+            draw_glyph(image, glyph, x, rect.y + 4);
+            x += 4 * GLYPH_SCALE;
+        }
+    }
+
+    #[test]
+    fn setup_screens_are_told_apart_by_ui_templates() {
+        let dir = std::env::temp_dir().join(format!("minerva-screens-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let colors = [
+            (StartScreen::Lobby, Rgba([220u8, 40, 40, 255])),
+            (StartScreen::ConfirmDialog, Rgba([40, 200, 40, 255])),
+            (StartScreen::NoticeDialog, Rgba([40, 40, 220, 255])),
+            (StartScreen::FormationPicker, Rgba([240, 240, 240, 255])),
+        ];
+        let (width, height) = (60, 30);
+        for (screen, color) in &colors[..3] {
+            ImageBuffer::from_pixel(width, height, *color)
+                .save(dir.join(format!("{}.png", screen.template_label())))
+                .unwrap();
+        }
+        let partial = TemplateMatchingRecognizer::new(vision_config(&dir));
+        assert!(!partial.recognizes_screens());
+        let (last, color) = colors[3];
+        ImageBuffer::from_pixel(width, height, color)
+            .save(dir.join(format!("{}.png", last.template_label())))
+            .unwrap();
+        let recognizer = TemplateMatchingRecognizer::new(vision_config(&dir));
+        assert!(recognizer.recognizes_screens());
+        assert!(recognizer.templates.templates.is_empty());
+
+        let background = Rgba([100u8, 100, 100, 255]);
+        let blank = ImageBuffer::from_pixel(720, 1280, background);
+        let frame = |image: ImageBuffer<Rgba<u8>, Vec<u8>>| {
+            ImageFrame::from_rgba(720, 1280, image.into_raw())
+        };
+        assert_eq!(recognizer.recognize_screen(&frame(blank.clone())), None);
+        for (screen, color) in colors {
+            let mut image = blank.clone();
+            let anchor = screen.anchor();
+            for y in anchor.y - height / 2..anchor.y + height / 2 {
+                for x in anchor.x - width / 2..anchor.x + width / 2 {
+                    image.put_pixel(x, y, color);
+                }
+            }
+            assert_eq!(recognizer.recognize_screen(&frame(image)), Some(screen));
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn match_dialogs_are_recognized_over_the_board() {
+        let dir = std::env::temp_dir().join(format!("minerva-dialogs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let colors = [
+            (MatchDialog::DrawOffer, Rgba([230u8, 200, 40, 255])),
+            (MatchDialog::Bikjang, Rgba([160, 40, 200, 255])),
+        ];
+        let (width, height) = (60, 30);
+        for (dialog, color) in colors {
+            ImageBuffer::from_pixel(width, height, color)
+                .save(dir.join(format!("{}.png", dialog.template_label())))
+                .unwrap();
+        }
+        let recognizer = TemplateMatchingRecognizer::new(vision_config(&dir));
+        assert!(recognizer.templates.templates.is_empty());
+
+        let blank = ImageBuffer::from_pixel(720, 1280, Rgba([100u8, 100, 100, 255]));
+        let frame = |image: ImageBuffer<Rgba<u8>, Vec<u8>>| {
+            ImageFrame::from_rgba(720, 1280, image.into_raw())
+        };
+        assert_eq!(recognizer.recognize_dialog(&frame(blank.clone())), None);
+        for (dialog, color) in colors {
+            let mut image = blank.clone();
+            let anchor = dialog.anchor();
+            for y in anchor.y - height / 2..anchor.y + height / 2 {
+                for x in anchor.x - width / 2..anchor.x + width / 2 {
+                    image.put_pixel(x, y, color);
+                }
+            }
+            assert_eq!(recognizer.recognize_dialog(&frame(image)), Some(dialog));
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn configured_popups_are_detected_at_their_anchor() {
+        let dir = std::env::temp_dir().join(format!("minerva-popups-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let popup = PopupTemplate {
+            name: "disconnect".into(),
+            anchor: Point::new(360, 600),
+            dismiss: Point::new(360, 760),
+        };
+        let color = Rgba([20u8, 160, 160, 255]);
+        let (width, height) = (60, 30);
+        ImageBuffer::from_pixel(width, height, color)
+            .save(dir.join(format!("{}.png", popup.template_label())))
+            .unwrap();
+        let mut config = vision_config(&dir);
+        config.popups = vec![popup.clone()];
+        let recognizer = TemplateMatchingRecognizer::new(config);
+        assert!(recognizer.template_report().unexpected.is_empty());
+
+        let blank = ImageBuffer::from_pixel(720, 1280, Rgba([100u8, 100, 100, 255]));
+        let frame = |image: ImageBuffer<Rgba<u8>, Vec<u8>>| {
+            ImageFrame::from_rgba(720, 1280, image.into_raw())
+        };
+        assert_eq!(recognizer.detect_popup(&frame(blank.clone())), None);
+        let mut image = blank;
+        for y in popup.anchor.y - height / 2..popup.anchor.y + height / 2 {
+            for x in popup.anchor.x - width / 2..popup.anchor.x + width / 2 {
+                image.put_pixel(x, y, color);
+            }
+        }
+        assert_eq!(recognizer.detect_popup(&frame(image)), Some(popup));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn clocks_are_read_from_digit_templates() {
+        let dir = std::env::temp_dir().join(format!("minerva-clocks-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (digit, glyph) in DIGIT_GLYPHS.iter().enumerate() {
+            let mut template =
+                ImageBuffer::from_pixel(3 * GLYPH_SCALE, 5 * GLYPH_SCALE, CLOCK_BACKGROUND);
+            draw_glyph(&mut template, glyph, 0, 0);
+            template
+                .save(dir.join(format!("digit_{digit}.png")))
+                .unwrap();
+        }
+        let regions = ClockRegions {
+            blue: ScreenRect {
+                x: 20,
+                y: 1000,
+                width: 140,
+                height: 28,
+            },
+            red: ScreenRect {
+                x: 20,
+                y: 100,
+                width: 140,
+                height: 28,
+            },
+        };
+        let mut image = ImageBuffer::from_pixel(720, 1280, Rgba([200u8, 170, 120, 255]));
+        draw_clock(&mut image, regions.blue, "9:05");
+        draw_clock(&mut image, regions.red, "1:02:30");
+        let frame = ImageFrame::from_rgba(720, 1280, image.into_raw());
+
+        let recognizer = TemplateMatchingRecognizer::new(VisionConfig {
+            clock_regions: Some(regions),
+            ..vision_config(&dir)
+        });
+        assert_eq!(recognizer.template_report().loaded.len(), 10);
+        assert!(recognizer.templates.templates.is_empty());
+        let snapshot = recognizer
+            .recognize(&frame, RecognitionHints::default())
+            .await
+            .unwrap();
+        assert_eq!(snapshot.clocks.blue_ms, (9 * 60 + 5) * 1000);
+        assert_eq!(snapshot.clocks.red_ms, (3600 + 2 * 60 + 30) * 1000);
+
+        // An unreadable clock keeps the previous value.
+        let mut previous = GameSnapshot::default();
+        previous.clocks.red_ms = 42_000;
+        let blank = ImageFrame::from_rgba(720, 1280, vec![0; 720 * 1280 * 4]);
+        let snapshot = recognizer
+            .recognize(
+                &blank,
+                RecognitionHints {
+                    previous_snapshot: Some(previous),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(snapshot.clocks.red_ms, 42_000);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}